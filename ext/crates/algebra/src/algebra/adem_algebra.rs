@@ -21,7 +21,7 @@ use fp::vector::{FpVector, SliceMut};
 use once::OnceVec;
 
 use crate::algebra::combinatorics::{self, MAX_XI_TAU};
-use crate::algebra::{Algebra, Bialgebra, GeneratedAlgebra};
+use crate::algebra::{Algebra, Bialgebra, GeneratedAlgebra, HopfAlgebra};
 
 #[cfg(feature = "json")]
 use {crate::algebra::JsonAlgebra, serde::Deserialize, serde_json::value::Value};
@@ -1598,6 +1598,8 @@ impl Bialgebra for AdemAlgebra {
     }
 }
 
+impl HopfAlgebra for AdemAlgebra {}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -2,42 +2,10 @@ use fp::vector::FpVector;
 use once::OnceVec;
 
 use fp::prime::{minus_one_to_the_n, Binomial, ValidPrime};
-use fp::{const_for, MAX_MULTINOMIAL_LEN, NUM_PRIMES, PRIMES, PRIME_TO_INDEX_MAP};
+use fp::MAX_MULTINOMIAL_LEN;
 
 pub const MAX_XI_TAU: usize = MAX_MULTINOMIAL_LEN;
 
-/// If p is the nth prime, then `XI_DEGREES[n][i - 1]` is the degree of $ξ_i$ at the prime p divided by
-/// q, where q = 2p - 2 if p != 2 and 1 if p = 2.
-const XI_DEGREES: [[i32; MAX_XI_TAU]; NUM_PRIMES] = {
-    let mut res = [[0; 10]; 8];
-    const_for! { p_idx in 0 .. NUM_PRIMES {
-        let p = PRIMES[p_idx];
-        let mut p_to_the_i = p;
-        const_for! { x in 0 .. MAX_XI_TAU {
-            res[p_idx][x] = ((p_to_the_i - 1) / (p - 1)) as i32;
-            // At some point the powers overflow. The values are not going to be useful, so use
-            // something replacement that is suitable for const evaluation.
-            p_to_the_i = p_to_the_i.overflowing_mul(p).0;
-        }}
-    }}
-    res
-};
-
-/// If p is the nth prime, then `TAU_DEGREES[n][i]` is the degree of $τ_i$ at the prime p. Its value is
-/// nonsense at the prime 2
-const TAU_DEGREES: [[i32; MAX_XI_TAU]; NUM_PRIMES] = {
-    let mut res = [[0; 10]; 8];
-    const_for! { p_idx in 0 .. NUM_PRIMES {
-        let p = PRIMES[p_idx];
-        let mut p_to_the_i: u32 = 1;
-        const_for! { x in 0 .. MAX_XI_TAU {
-            res[p_idx][x] = (2_u32.overflowing_mul(p_to_the_i).0 - 1) as i32;
-            p_to_the_i = p_to_the_i.overflowing_mul(p).0;
-        }}
-    }}
-    res
-};
-
 pub fn adem_relation_coefficient(p: ValidPrime, x: u32, y: u32, j: u32, e1: u32, e2: u32) -> u32 {
     let pi32 = *p as i32;
     let x = x as i32;
@@ -82,12 +50,37 @@ pub fn inadmissible_pairs(p: ValidPrime, generic: bool, degree: i32) -> Vec<(u32
     inadmissible_pairs
 }
 
-pub fn tau_degrees(p: ValidPrime) -> &'static [i32] {
-    &TAU_DEGREES[PRIME_TO_INDEX_MAP[*p as usize]]
+/// The degrees of $τ_i$ at the prime p in the generic Steenrod algebra. Degrees that do not fit in
+/// an `i32` are replaced by `i32::MAX`.
+pub const fn tau_degrees(p: ValidPrime) -> [i32; MAX_XI_TAU] {
+    let p = p.value() as i64;
+    let mut res = [i32::MAX; MAX_XI_TAU];
+    let mut p_to_the_i: i64 = 1;
+    let mut i = 0;
+    while i < MAX_XI_TAU && 2 * p_to_the_i - 1 <= i32::MAX as i64 {
+        res[i] = (2 * p_to_the_i - 1) as i32;
+        p_to_the_i *= p;
+        i += 1;
+    }
+    res
 }
 
-pub fn xi_degrees(p: ValidPrime) -> &'static [i32] {
-    &XI_DEGREES[PRIME_TO_INDEX_MAP[*p as usize]]
+/// The degrees of $ξ_i$ at the prime p divided by q, where q = 2p - 2 for the generic algebra and
+/// 1 otherwise.
+/// In particular, `xi_degrees(p)[i - 1]` is the degree of $ξ_i$ divided by q. Degrees that do not
+/// fit in an `i32` are replaced by `i32::MAX`.
+pub const fn xi_degrees(p: ValidPrime) -> [i32; MAX_XI_TAU] {
+    let p = p.value() as i64;
+    let mut res = [i32::MAX; MAX_XI_TAU];
+    let mut p_to_the_i: i64 = p;
+    let mut i = 0;
+    // We check that the actual degree, and not just the degree divided by q, fits in an i32.
+    while i < MAX_XI_TAU && 2 * (p_to_the_i - 1) <= i32::MAX as i64 {
+        res[i] = ((p_to_the_i - 1) / (p - 1)) as i32;
+        p_to_the_i *= p;
+        i += 1;
+    }
+    res
 }
 
 pub struct TruncatedPolynomialMonomialBasis {
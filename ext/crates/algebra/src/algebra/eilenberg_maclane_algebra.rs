@@ -0,0 +1,540 @@
+use crate::algebra::{adem_algebra::AdemBasisElement, AdemAlgebra, Algebra};
+use fp::prime::ValidPrime;
+use fp::vector::{FpVector, SliceMut};
+use itertools::Itertools;
+use once::OnceVec;
+use rustc_hash::FxHashMap as HashMap;
+
+use std::sync::Mutex;
+
+/// The coefficient group $\pi$ of an Eilenberg–MacLane space $K(\pi, n)$.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum EilenbergMacLaneGroup {
+    /// The integers $\mathbb{Z}$.
+    Z,
+    /// The cyclic group $\mathbb{Z}/p$, where $p$ is the prime we work over.
+    ZModP,
+}
+
+impl std::str::FromStr for EilenbergMacLaneGroup {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> anyhow::Result<Self> {
+        match s {
+            "Z" => Ok(Self::Z),
+            "Z/p" => Ok(Self::ZModP),
+            _ => Err(anyhow::anyhow!("Unknown group: {}", s)),
+        }
+    }
+}
+
+/// A monomial in the generators, listed with multiplicity in increasing order. Each generator is
+/// specified by its degree and index.
+type Monomial = Vec<(i32, usize)>;
+
+/// What $P^I \iota_n$ is for an admissible sequence $I$.
+enum Evaluation {
+    Zero,
+    Generator,
+    /// The $p$th power of $P^{I'} \iota_n$, where $I = (s_1, I')$, given by its degree and index.
+    Power(i32, usize),
+}
+
+/// The cohomology ring $H^*(K(\pi, n); \mathbb{F}_p)$, where $\pi$ is $\mathbb{Z}$ or
+/// $\mathbb{Z}/p$.
+///
+/// By the work of Serre and Cartan, this is the free graded commutative algebra on the classes $P^I
+/// \iota_n$, where $I$ runs over the admissible sequences of excess less than $n$, as well as those
+/// of excess $n$ that start with a Bockstein. For $\pi = \mathbb{Z}$, we exclude the sequences that
+/// end with a Bockstein (or $\mathrm{Sq}^1$). The basis is given by the monomials in these
+/// generators.
+///
+/// This also computes the action of the Steenrod algebra using the Cartan formula, where
+/// admissible sequences of excess $n$ not starting with a Bockstein act as $p$th powers.
+pub struct EilenbergMacLaneAlgebra {
+    p: ValidPrime,
+    pub group: EilenbergMacLaneGroup,
+    pub n: u32,
+    adem: AdemAlgebra,
+    lock: Mutex<()>,
+    /// degree -> index -> index of the admissible sequence in the Adem basis in degree `degree - n`
+    generators: OnceVec<Vec<usize>>,
+    /// degree -> index of the admissible sequence -> index of the generator
+    generator_index: OnceVec<HashMap<usize, usize>>,
+    /// degree -> index -> monomial
+    basis: OnceVec<Vec<Monomial>>,
+    /// degree -> monomial -> index
+    basis_index: OnceVec<HashMap<Monomial, usize>>,
+}
+
+impl std::fmt::Display for EilenbergMacLaneAlgebra {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self.group {
+            EilenbergMacLaneGroup::Z => write!(f, "H^*(K(Z, {}))", self.n),
+            EilenbergMacLaneGroup::ZModP => write!(f, "H^*(K(Z/{}, {}))", self.p, self.n),
+        }
+    }
+}
+
+impl EilenbergMacLaneAlgebra {
+    pub fn new(p: ValidPrime, group: EilenbergMacLaneGroup, n: u32) -> Self {
+        assert!(n > 0, "K(π, 0) is not connected");
+        Self {
+            p,
+            group,
+            n,
+            adem: AdemAlgebra::new(p, *p != 2, false, false),
+            lock: Mutex::new(()),
+            generators: OnceVec::new(),
+            generator_index: OnceVec::new(),
+            basis: OnceVec::new(),
+            basis_index: OnceVec::new(),
+        }
+    }
+
+    fn generic(&self) -> bool {
+        *self.p != 2
+    }
+
+    /// Whether the generators in degree `degree` anticommute with each other.
+    fn is_exterior(&self, degree: i32) -> bool {
+        self.generic() && degree % 2 == 1
+    }
+
+    fn evaluate(&self, degree: i32, idx: usize) -> Evaluation {
+        let elt = self.adem.basis_element_from_index(degree, idx);
+        let n = self.n as i32;
+        let (leading_bockstein, trailing_bockstein) = if self.generic() {
+            (
+                elt.bocksteins & 1 == 1,
+                (elt.bocksteins >> elt.ps.len()) & 1 == 1,
+            )
+        } else {
+            (false, elt.ps.last() == Some(&1))
+        };
+
+        if elt.excess > n || (self.group == EilenbergMacLaneGroup::Z && trailing_bockstein) {
+            Evaluation::Zero
+        } else if elt.excess == n && !leading_bockstein {
+            let q = self.adem.q();
+            let tail = AdemBasisElement {
+                degree: degree - q * elt.ps[0] as i32,
+                excess: 0,
+                bocksteins: elt.bocksteins >> 1,
+                ps: elt.ps[1..].to_vec(),
+                p_or_sq: elt.p_or_sq,
+            };
+            Evaluation::Power(tail.degree, self.adem.basis_element_to_index(&tail))
+        } else {
+            Evaluation::Generator
+        }
+    }
+
+    /// The monomial $P^I \iota_n$, where $I$ is the `idx`th admissible sequence in degree
+    /// `degree`. This is a power of a generator, or `None` if it is zero.
+    fn evaluate_to_monomial(&self, degree: i32, idx: usize) -> Option<Monomial> {
+        match self.evaluate(degree, idx) {
+            Evaluation::Zero => None,
+            Evaluation::Generator => {
+                let gen_deg = degree + self.n as i32;
+                Some(vec![(
+                    gen_deg,
+                    self.generator_index[gen_deg as usize][&idx],
+                )])
+            }
+            Evaluation::Power(degree, idx) => {
+                let m = self.evaluate_to_monomial(degree, idx)?;
+                Some(m.iter().flat_map(|&x| vec![x; *self.p as usize]).collect())
+            }
+        }
+    }
+
+    fn degree_of(m: &[(i32, usize)]) -> i32 {
+        m.iter().map(|&(d, _)| d).sum()
+    }
+
+    /// Multiplies two monomials, returning the sign and the product, or `None` if the product is
+    /// zero.
+    fn multiply_monomials(
+        &self,
+        a: &[(i32, usize)],
+        b: &[(i32, usize)],
+    ) -> Option<(u32, Monomial)> {
+        let mut sign = false;
+        for y in b {
+            if self.is_exterior(y.0) {
+                if a.contains(y) {
+                    return None;
+                }
+                let inversions = a.iter().filter(|x| self.is_exterior(x.0) && *x > y).count();
+                sign ^= inversions % 2 == 1;
+            }
+        }
+        let product = a.iter().merge(b).copied().collect();
+        Some((if sign { *self.p - 1 } else { 1 }, product))
+    }
+
+    fn compute_generators(&self, degree: i32) {
+        let adem_degree = degree - self.n as i32;
+        let mut generators = Vec::new();
+        let mut generator_index = HashMap::default();
+        for idx in 0..self.adem.dimension(adem_degree) {
+            if !matches!(self.evaluate(adem_degree, idx), Evaluation::Generator) {
+                continue;
+            }
+            generator_index.insert(idx, generators.len());
+            generators.push(idx);
+        }
+        self.generators.push(generators);
+        self.generator_index.push(generator_index);
+    }
+
+    fn compute_monomials(&self, degree: i32) {
+        // Every monomial of positive degree is its smallest generator times a monomial whose
+        // generators are at least as large.
+        let mut basis: Vec<Monomial> = Vec::new();
+        if degree == 0 {
+            basis.push(Vec::new());
+        }
+        for gen_deg in 1..=degree {
+            for gen_idx in 0..self.generators[gen_deg as usize].len() {
+                let g = (gen_deg, gen_idx);
+                for rest in &self.basis[(degree - gen_deg) as usize] {
+                    if rest
+                        .first()
+                        .is_none_or(|&x| x > g || (x == g && !self.is_exterior(gen_deg)))
+                    {
+                        let mut m = Vec::with_capacity(rest.len() + 1);
+                        m.push(g);
+                        m.extend_from_slice(rest);
+                        basis.push(m);
+                    }
+                }
+            }
+        }
+        self.basis_index.push(
+            basis
+                .iter()
+                .enumerate()
+                .map(|(i, m)| (m.clone(), i))
+                .collect(),
+        );
+        self.basis.push(basis);
+    }
+
+    fn generator_to_string(&self, degree: i32, idx: usize) -> String {
+        let adem_degree = degree - self.n as i32;
+        let elt = self
+            .adem
+            .basis_element_from_index(adem_degree, self.generators[degree as usize][idx]);
+        format!("{}ι_{}", elt.to_string().replace(' ', ""), self.n)
+    }
+
+    /// Adds `coeff` times $P^{\mathrm{power}}$ of the generator `g` to `result`, or the Bockstein if
+    /// `bockstein` is set. At the prime 2, this is $\mathrm{Sq}^{\mathrm{power}}$.
+    fn act_on_generator(
+        &self,
+        result: &mut FpVector,
+        coeff: u32,
+        bockstein: bool,
+        power: u32,
+        g: (i32, usize),
+    ) {
+        let (op_deg, op_idx) = self.adem.beps_pn(bockstein as u32, power);
+        let adem_degree = g.0 - self.n as i32;
+        let output_deg = op_deg + adem_degree;
+        let mut product = FpVector::new(self.p, self.adem.dimension(output_deg));
+        self.adem.multiply_basis_elements(
+            product.as_slice_mut(),
+            coeff,
+            op_deg,
+            op_idx,
+            adem_degree,
+            self.generators[g.0 as usize][g.1],
+        );
+        for (idx, c) in product.iter_nonzero() {
+            if let Some(m) = self.evaluate_to_monomial(output_deg, idx) {
+                result.add_basis_element(
+                    self.basis_index[(output_deg + self.n as i32) as usize][&m],
+                    c,
+                );
+            }
+        }
+    }
+
+    /// Computes $P^{\mathrm{power}}$ (or the Bockstein) of a monomial using the Cartan formula.
+    /// Results on the tails of monomials are cached in `cache`.
+    fn act_on_monomial(
+        &self,
+        cache: &mut HashMap<(bool, u32, Monomial), FpVector>,
+        bockstein: bool,
+        power: u32,
+        m: &[(i32, usize)],
+    ) -> FpVector {
+        let p = self.p;
+        let op_deg = if bockstein {
+            1
+        } else {
+            power as i32 * self.adem.q()
+        };
+        let degree = Self::degree_of(m);
+        let key = (bockstein, power, m.to_vec());
+        if let Some(v) = cache.get(&key) {
+            return v.clone();
+        }
+
+        let mut result = FpVector::new(p, self.dimension(degree + op_deg));
+        if m.is_empty() {
+            if op_deg == 0 {
+                result.set_entry(0, 1);
+            }
+        } else if op_deg == 0 {
+            result.set_entry(self.basis_index[degree as usize][m], 1);
+        } else {
+            let g = m[0];
+            let rest = &m[1..];
+            let rest_deg = degree - g.0;
+            if bockstein {
+                // β(g x) = β(g) x + (-1)^{|g|} g β(x)
+                let mut first = FpVector::new(p, self.dimension(g.0 + 1));
+                self.act_on_generator(&mut first, 1, true, 0, g);
+                let x = self.basis_vector(rest_deg, rest);
+                self.multiply_into(&mut result, 1, g.0 + 1, &first, rest_deg, &x);
+
+                let beta_rest = self.act_on_monomial(cache, true, 0, rest);
+                let sign = if g.0 % 2 == 1 { *p - 1 } else { 1 };
+                let g_vec = self.basis_vector(g.0, &[g]);
+                self.multiply_into(&mut result, sign, g.0, &g_vec, rest_deg + 1, &beta_rest);
+            } else {
+                // P^a(g x) = \sum_b P^b(g) P^{a - b}(x), where P^b(g) vanishes if b is larger than
+                // the degree of g (or half of it at odd primes).
+                let q = self.adem.q();
+                let max_b = if self.generic() { g.0 / 2 } else { g.0 };
+                for b in 0..=std::cmp::min(power, max_b as u32) {
+                    let first_deg = g.0 + q * b as i32;
+                    let first = if b == 0 {
+                        self.basis_vector(g.0, &[g])
+                    } else {
+                        let mut first = FpVector::new(p, self.dimension(first_deg));
+                        self.act_on_generator(&mut first, 1, false, b, g);
+                        first
+                    };
+                    if first.is_zero() {
+                        continue;
+                    }
+                    let second = self.act_on_monomial(cache, false, power - b, rest);
+                    let second_deg = rest_deg + q * (power - b) as i32;
+                    self.multiply_into(&mut result, 1, first_deg, &first, second_deg, &second);
+                }
+            }
+        }
+        cache.insert(key, result.clone());
+        result
+    }
+
+    fn basis_vector(&self, degree: i32, m: &[(i32, usize)]) -> FpVector {
+        let mut v = FpVector::new(self.p, self.dimension(degree));
+        v.set_entry(self.basis_index[degree as usize][m], 1);
+        v
+    }
+
+    fn multiply_into(
+        &self,
+        result: &mut FpVector,
+        coeff: u32,
+        left_degree: i32,
+        left: &FpVector,
+        right_degree: i32,
+        right: &FpVector,
+    ) {
+        self.multiply_element_by_element(
+            result.as_slice_mut(),
+            coeff,
+            left_degree,
+            left.as_slice(),
+            right_degree,
+            right.as_slice(),
+        );
+    }
+
+    /// Adds `coeff` times $P^{\mathrm{power}}$ of a basis element to `result`, or the Bockstein if
+    /// `bockstein` is set, in which case `power` must be zero. At the prime 2, this is
+    /// $\mathrm{Sq}^{\mathrm{power}}$. The basis must be computed through the degree of the
+    /// output.
+    pub fn steenrod_action_on_basis(
+        &self,
+        mut result: SliceMut,
+        coeff: u32,
+        bockstein: bool,
+        power: u32,
+        degree: i32,
+        idx: usize,
+    ) {
+        assert!(!bockstein || power == 0);
+        let mut cache = HashMap::default();
+        let v = self.act_on_monomial(
+            &mut cache,
+            bockstein,
+            power,
+            &self.basis[degree as usize][idx],
+        );
+        result.add(v.as_slice(), coeff);
+    }
+}
+
+impl Algebra for EilenbergMacLaneAlgebra {
+    fn prime(&self) -> ValidPrime {
+        self.p
+    }
+
+    fn compute_basis(&self, degree: i32) {
+        self.adem.compute_basis(degree - self.n as i32);
+
+        let _lock = self.lock.lock().unwrap();
+        for t in self.basis.len() as i32..=degree {
+            if t < self.n as i32 {
+                self.generators.push(Vec::new());
+                self.generator_index.push(HashMap::default());
+            } else {
+                self.compute_generators(t);
+            }
+            self.compute_monomials(t);
+        }
+    }
+
+    fn dimension(&self, degree: i32) -> usize {
+        if degree < 0 {
+            0
+        } else {
+            self.basis[degree as usize].len()
+        }
+    }
+
+    fn multiply_basis_elements(
+        &self,
+        mut result: SliceMut,
+        coeff: u32,
+        r_degree: i32,
+        r_idx: usize,
+        s_degree: i32,
+        s_idx: usize,
+    ) {
+        let r = &self.basis[r_degree as usize][r_idx];
+        let s = &self.basis[s_degree as usize][s_idx];
+        if let Some((c, m)) = self.multiply_monomials(r, s) {
+            let idx = self.basis_index[(r_degree + s_degree) as usize][&m];
+            result.add_basis_element(idx, (c * coeff) % *self.p);
+        }
+    }
+
+    fn basis_element_to_string(&self, degree: i32, idx: usize) -> String {
+        let m = &self.basis[degree as usize][idx];
+        if m.is_empty() {
+            return "1".to_string();
+        }
+        m.iter()
+            .dedup_with_count()
+            .map(|(count, &(d, i))| {
+                let g = self.generator_to_string(d, i);
+                if count == 1 {
+                    g
+                } else {
+                    format!("{g}^{count}")
+                }
+            })
+            .join(" ")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn product(algebra: &EilenbergMacLaneAlgebra, r: (i32, usize), s: (i32, usize)) -> FpVector {
+        let mut result = FpVector::new(algebra.prime(), algebra.dimension(r.0 + s.0));
+        algebra.multiply_basis_elements(result.as_slice_mut(), 1, r.0, r.1, s.0, s.1);
+        result
+    }
+
+    fn act(
+        algebra: &EilenbergMacLaneAlgebra,
+        bockstein: bool,
+        power: u32,
+        degree: i32,
+        idx: usize,
+    ) -> FpVector {
+        let op_deg = if bockstein {
+            1
+        } else {
+            power as i32 * algebra.adem.q()
+        };
+        let mut result = FpVector::new(algebra.prime(), algebra.dimension(degree + op_deg));
+        algebra.steenrod_action_on_basis(result.as_slice_mut(), 1, bockstein, power, degree, idx);
+        result
+    }
+
+    #[test]
+    fn test_k_z2_1() {
+        // H^*(K(Z/2, 1)) = F_2[ι] with |ι| = 1 and Sq^i ι^n = (n choose i) ι^{n + i}
+        let p = ValidPrime::new(2);
+        let algebra = EilenbergMacLaneAlgebra::new(p, EilenbergMacLaneGroup::ZModP, 1);
+        algebra.compute_basis(20);
+        for t in 0..=20 {
+            assert_eq!(algebra.dimension(t), 1);
+        }
+        assert_eq!(algebra.basis_element_to_string(3, 0), "ι_1^3");
+
+        let one = FpVector::from_slice(p, &[1]);
+        assert_eq!(product(&algebra, (3, 0), (5, 0)), one);
+        assert_eq!(product(&algebra, (0, 0), (7, 0)), one);
+
+        assert_eq!(act(&algebra, false, 1, 1, 0), one);
+        assert_eq!(act(&algebra, false, 2, 3, 0), one);
+        assert!(act(&algebra, false, 1, 2, 0).is_zero());
+        assert!(act(&algebra, false, 2, 1, 0).is_zero());
+        assert_eq!(act(&algebra, false, 4, 6, 0), one);
+        assert!(act(&algebra, false, 4, 9, 0).is_zero());
+    }
+
+    #[test]
+    fn test_k_z_2() {
+        // H^*(K(Z, 2)) = F_2[ι] with |ι| = 2 and Sq^{2i} ι^n = (n choose i) ι^{n + i}
+        let p = ValidPrime::new(2);
+        let algebra = EilenbergMacLaneAlgebra::new(p, EilenbergMacLaneGroup::Z, 2);
+        algebra.compute_basis(20);
+        for t in 0..=20 {
+            assert_eq!(algebra.dimension(t), (t % 2 == 0) as usize);
+        }
+        assert_eq!(algebra.basis_element_to_string(6, 0), "ι_2^3");
+
+        let one = FpVector::from_slice(p, &[1]);
+        assert_eq!(product(&algebra, (2, 0), (4, 0)), one);
+        assert!(act(&algebra, false, 1, 2, 0).is_zero());
+        assert_eq!(act(&algebra, false, 2, 2, 0), one);
+        assert!(act(&algebra, false, 2, 4, 0).is_zero());
+        assert_eq!(act(&algebra, false, 2, 6, 0), one);
+    }
+
+    #[test]
+    fn test_k_z3_1() {
+        // H^*(K(Z/3, 1)) = Λ[x] ⊗ F_3[y] with y = βx
+        let p = ValidPrime::new(3);
+        let algebra = EilenbergMacLaneAlgebra::new(p, EilenbergMacLaneGroup::ZModP, 1);
+        algebra.compute_basis(12);
+        for t in 0..=12 {
+            assert_eq!(algebra.dimension(t), 1);
+        }
+
+        let one = FpVector::from_slice(p, &[1]);
+        assert!(product(&algebra, (1, 0), (1, 0)).is_zero());
+        assert_eq!(product(&algebra, (1, 0), (2, 0)), one);
+        assert_eq!(product(&algebra, (2, 0), (1, 0)), one);
+        assert_eq!(product(&algebra, (3, 0), (2, 0)), one);
+        assert!(product(&algebra, (3, 0), (1, 0)).is_zero());
+
+        // βx = y and P^1 y = y^3
+        assert_eq!(act(&algebra, true, 0, 1, 0), one);
+        assert_eq!(act(&algebra, false, 1, 2, 0), one);
+        assert!(act(&algebra, true, 0, 2, 0).is_zero());
+    }
+}
@@ -0,0 +1,125 @@
+use crate::algebra::Bialgebra;
+use fp::vector::{FpVector, Slice, SliceMut};
+use rustc_hash::FxHashMap as HashMap;
+
+/// A [`Bialgebra`] with an antipode, i.e. a Hopf algebra. For the Steenrod algebra, the antipode
+/// is usually called the conjugation and denoted $\chi$.
+///
+/// The algebra is assumed to be connected, i.e. one-dimensional in degree 0, in which case the
+/// antipode is determined by the coproduct.
+pub trait HopfAlgebra: Bialgebra {
+    /// Computes the coproduct $\Delta(x)$ of an arbitrary basis element, as a list of
+    /// `(coeff, (deg_1, idx_1), (deg_2, idx_2))` in the same format as
+    /// [`GeneratedAlgebra::decompose_basis_element`](crate::algebra::GeneratedAlgebra::decompose_basis_element).
+    ///
+    /// The default implementation multiplies out the coproducts of the factors given by
+    /// [`Bialgebra::decompose`], with the usual signs
+    /// $(a \otimes b)(c \otimes d) = (-1)^{|b||c|} ac \otimes bd$.
+    fn coproduct_basis_element(
+        &self,
+        op_deg: i32,
+        op_idx: usize,
+    ) -> Vec<(u32, (i32, usize), (i32, usize))> {
+        let p = *self.prime();
+        let mut terms: HashMap<((i32, usize), (i32, usize)), u32> = HashMap::default();
+        terms.insert(((0, 0), (0, 0)), 1);
+
+        // The first factor is the rightmost one, so we multiply on the left
+        for (deg, idx) in self.decompose(op_deg, op_idx) {
+            let mut new_terms: HashMap<_, u32> = HashMap::default();
+            for (l_deg, l_idx, r_deg, r_idx) in self.coproduct(deg, idx) {
+                for (&((a_deg, a_idx), (b_deg, b_idx)), &c) in &terms {
+                    let c = if (r_deg * a_deg) % 2 == 0 { c } else { p - c };
+
+                    let mut left = FpVector::new(self.prime(), self.dimension(l_deg + a_deg));
+                    self.multiply_basis_elements(
+                        left.as_slice_mut(),
+                        1,
+                        l_deg,
+                        l_idx,
+                        a_deg,
+                        a_idx,
+                    );
+                    let mut right = FpVector::new(self.prime(), self.dimension(r_deg + b_deg));
+                    self.multiply_basis_elements(
+                        right.as_slice_mut(),
+                        1,
+                        r_deg,
+                        r_idx,
+                        b_deg,
+                        b_idx,
+                    );
+
+                    for (i, u) in left.iter_nonzero() {
+                        for (j, v) in right.iter_nonzero() {
+                            let entry = new_terms
+                                .entry(((l_deg + a_deg, i), (r_deg + b_deg, j)))
+                                .or_insert(0);
+                            *entry = (*entry + c * u * v) % p;
+                        }
+                    }
+                }
+            }
+            terms = new_terms;
+        }
+
+        let mut result: Vec<_> = terms
+            .into_iter()
+            .filter(|&(_, c)| c != 0)
+            .map(|(((l_deg, l_idx), (r_deg, r_idx)), c)| (c, (l_deg, l_idx), (r_deg, r_idx)))
+            .collect();
+        result.sort_unstable_by_key(|&(_, l, r)| (l, r));
+        result
+    }
+
+    /// Adds `coeff` times the antipode of a basis element to `result`.
+    ///
+    /// The default implementation uses the relation $\sum x' \chi(x'') = 0$ for $|x| > 0$, where
+    /// $\Delta(x) = \sum x' \otimes x''$.
+    fn antipode_on_basis(&self, mut result: SliceMut, coeff: u32, op_deg: i32, op_idx: usize) {
+        let value = antipode_with_memo(self, op_deg, op_idx, &mut HashMap::default());
+        result.add(value.as_slice(), coeff);
+    }
+
+    /// Adds `coeff` times the antipode of `input` to `result`.
+    fn antipode(&self, mut result: SliceMut, coeff: u32, degree: i32, input: Slice) {
+        let p = *self.prime();
+        for (i, v) in input.iter_nonzero() {
+            self.antipode_on_basis(result.copy(), (coeff * v) % p, degree, i);
+        }
+    }
+}
+
+fn antipode_with_memo<A: HopfAlgebra + ?Sized>(
+    algebra: &A,
+    op_deg: i32,
+    op_idx: usize,
+    memo: &mut HashMap<(i32, usize), FpVector>,
+) -> FpVector {
+    if let Some(v) = memo.get(&(op_deg, op_idx)) {
+        return v.clone();
+    }
+    let p = algebra.prime();
+    let mut result = FpVector::new(p, algebra.dimension(op_deg));
+    if op_deg == 0 {
+        result.set_entry(0, 1);
+        return result;
+    }
+    for (c, (l_deg, l_idx), (r_deg, r_idx)) in algebra.coproduct_basis_element(op_deg, op_idx) {
+        // This is the term 1 ⊗ x
+        if l_deg == 0 {
+            continue;
+        }
+        let partial = antipode_with_memo(algebra, r_deg, r_idx, memo);
+        algebra.multiply_basis_element_by_element(
+            result.as_slice_mut(),
+            *p - c,
+            l_deg,
+            l_idx,
+            r_deg,
+            partial.as_slice(),
+        );
+    }
+    memo.insert((op_deg, op_idx), result.clone());
+    result
+}
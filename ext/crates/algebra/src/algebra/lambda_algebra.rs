@@ -0,0 +1,387 @@
+//! The Lambda algebra at the prime 2.
+//!
+//! The Lambda algebra $\Lambda$ is the differential graded algebra generated by $\lambda_i$ for $i
+//! \geq 0$, subject to the relations
+//! $$
+//!     \lambda_i \lambda_{2i + 1 + n} = \sum_{j \geq 0} \binom{n - j - 1}{j} \lambda_{i + n - j}
+//!     \lambda_{2i + 1 + j}, \quad n \geq 0,
+//! $$
+//! with differential
+//! $$
+//!     d(\lambda_n) = \sum_{j \geq 1} \binom{n - j}{j} \lambda_{n - j} \lambda_{j - 1}.
+//! $$
+//! Its homology is $\Ext_A(\mathbb{F}_2, \mathbb{F}_2)$, and $\lambda_{2^i - 1}$ is a cycle
+//! representing $h_i$. A basis is given by the admissible monomials $\lambda_{i_1} \cdots
+//! \lambda_{i_s}$, i.e. those with $2 i_j \geq i_{j + 1}$ for all $j$.
+//!
+//! The span $\Lambda(n)$ of the admissible monomials with $i_1 < n$ is a subcomplex whose homology
+//! is the $E_2$ page of the unstable Adams spectral sequence of $S^n$.
+//!
+//! The generator $\lambda_i$ has stem $i$ and Adams filtration $1$. As an [`Algebra`], we grade
+//! $\Lambda$ by the internal degree $t = n + s$, so that $\lambda_i$ has degree $i + 1$ and each
+//! degree is finite dimensional. The basis of each degree is sorted by length first and then
+//! lexicographically, so that the basis elements of each filtration form a contiguous block.
+
+use std::fmt;
+use std::ops::Range;
+use std::sync::Mutex;
+
+use itertools::Itertools;
+use rustc_hash::FxHashMap as HashMap;
+
+use fp::prime::{Binomial, ValidPrime};
+use fp::vector::SliceMut;
+use once::OnceVec;
+
+use crate::algebra::Algebra;
+
+/// An admissible monomial $\lambda_{i_1} \cdots \lambda_{i_s}$.
+#[derive(Debug, Clone)]
+pub struct LambdaBasisElement {
+    /// The internal degree $t$ of the element.
+    pub degree: i32,
+    /// The indices $i_1, \ldots, i_s$.
+    pub lambdas: Vec<u32>,
+}
+
+impl LambdaBasisElement {
+    /// The Adams filtration, which is the length of the monomial.
+    pub fn s(&self) -> u32 {
+        self.lambdas.len() as u32
+    }
+
+    pub fn stem(&self) -> i32 {
+        self.degree - self.s() as i32
+    }
+
+    /// The smallest $n$ such that this element lies in $\Lambda(n)$. This is $i_1 + 1$, or $0$ for
+    /// the unit.
+    pub fn sphere(&self) -> u32 {
+        self.lambdas.first().map_or(0, |&i| i + 1)
+    }
+}
+
+impl PartialEq for LambdaBasisElement {
+    fn eq(&self, other: &Self) -> bool {
+        self.lambdas == other.lambdas
+    }
+}
+
+impl Eq for LambdaBasisElement {}
+
+impl std::hash::Hash for LambdaBasisElement {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.lambdas.hash(state);
+    }
+}
+
+impl fmt::Display for LambdaBasisElement {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.lambdas.is_empty() {
+            write!(f, "1")
+        } else {
+            write!(
+                f,
+                "{}",
+                self.lambdas.iter().map(|i| format!("λ{i}")).format(" ")
+            )
+        }
+    }
+}
+
+/// Returns the first position `k` at which `lambdas[k] lambdas[k + 1]` is inadmissible.
+fn inadmissible_position(lambdas: &[u32]) -> Option<usize> {
+    lambdas.windows(2).position(|w| 2 * w[0] < w[1])
+}
+
+/// An [`Algebra`] implementing the Lambda algebra, using the admissible basis.
+pub struct LambdaAlgebra {
+    p: ValidPrime,
+    lock: Mutex<()>,
+
+    /// degree -> index -> LambdaBasisElement
+    basis_table: OnceVec<Vec<LambdaBasisElement>>,
+    /// degree -> LambdaBasisElement -> index
+    basis_element_to_index_map: OnceVec<HashMap<LambdaBasisElement, usize>>,
+    /// degree -> s -> index of the first basis element of length s
+    length_offsets: OnceVec<Vec<usize>>,
+}
+
+impl fmt::Display for LambdaAlgebra {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "LambdaAlgebra(p={})", self.prime())
+    }
+}
+
+impl Algebra for LambdaAlgebra {
+    fn prime(&self) -> ValidPrime {
+        self.p
+    }
+
+    fn default_filtration_one_products(&self) -> Vec<(String, i32, usize)> {
+        self.compute_basis(8);
+        (0..4)
+            .map(|i| {
+                let degree = 1 << i;
+                let elt = LambdaBasisElement {
+                    degree,
+                    lambdas: vec![degree as u32 - 1],
+                };
+                (format!("h_{i}"), degree, self.basis_element_to_index(&elt))
+            })
+            .collect()
+    }
+
+    fn compute_basis(&self, max_degree: i32) {
+        let _lock = self.lock.lock().unwrap();
+
+        let next_degree = self.basis_table.len() as i32;
+        if max_degree < next_degree {
+            return;
+        }
+
+        self.generate_basis(next_degree, max_degree);
+        self.generate_basis_element_to_index_map(next_degree, max_degree);
+    }
+
+    fn dimension(&self, degree: i32) -> usize {
+        if degree < 0 {
+            0
+        } else {
+            self.basis_table[degree as usize].len()
+        }
+    }
+
+    fn multiply_basis_elements(
+        &self,
+        result: SliceMut,
+        coeff: u32,
+        r_degree: i32,
+        r_idx: usize,
+        s_degree: i32,
+        s_idx: usize,
+    ) {
+        let r = self.basis_element_from_index(r_degree, r_idx);
+        let s = self.basis_element_from_index(s_degree, s_idx);
+        let lambdas = r.lambdas.iter().chain(&s.lambdas).copied().collect();
+        self.add_monomial(result, coeff, r_degree + s_degree, lambdas);
+    }
+
+    fn basis_element_to_string(&self, degree: i32, idx: usize) -> String {
+        format!("{}", self.basis_element_from_index(degree, idx))
+    }
+}
+
+impl LambdaAlgebra {
+    /// Only the prime 2 is supported.
+    pub fn new(p: ValidPrime) -> Self {
+        assert_eq!(
+            *p, 2,
+            "The Lambda algebra is only implemented at the prime 2"
+        );
+        Self {
+            p,
+            lock: Mutex::new(()),
+            basis_table: OnceVec::new(),
+            basis_element_to_index_map: OnceVec::new(),
+            length_offsets: OnceVec::new(),
+        }
+    }
+
+    /// An admissible monomial of degree `n` is either the unit or $\lambda_i$ followed by an
+    /// admissible monomial of degree `n - i - 1` whose first index is at most $2i$.
+    fn generate_basis(&self, next_degree: i32, max_degree: i32) {
+        for n in next_degree..=max_degree {
+            let mut basis = Vec::new();
+            if n == 0 {
+                basis.push(LambdaBasisElement {
+                    degree: 0,
+                    lambdas: Vec::new(),
+                });
+            }
+            for i in 0..n as u32 {
+                for tail in &*self.basis_table[(n - i as i32 - 1) as usize] {
+                    if tail.lambdas.first().is_none_or(|&j| j <= 2 * i) {
+                        let mut lambdas = Vec::with_capacity(tail.lambdas.len() + 1);
+                        lambdas.push(i);
+                        lambdas.extend_from_slice(&tail.lambdas);
+                        basis.push(LambdaBasisElement { degree: n, lambdas });
+                    }
+                }
+            }
+            basis.sort_by(|a, b| {
+                a.lambdas
+                    .len()
+                    .cmp(&b.lambdas.len())
+                    .then_with(|| a.lambdas.cmp(&b.lambdas))
+            });
+
+            let mut offsets = vec![0; n as usize + 2];
+            for elt in &basis {
+                offsets[elt.lambdas.len() + 1] += 1;
+            }
+            for s in 1..offsets.len() {
+                offsets[s] += offsets[s - 1];
+            }
+            self.length_offsets.push(offsets);
+            self.basis_table.push(basis);
+        }
+    }
+
+    fn generate_basis_element_to_index_map(&self, next_degree: i32, max_degree: i32) {
+        for n in next_degree..=max_degree {
+            let basis = &self.basis_table[n as usize];
+            let mut map = HashMap::default();
+            map.reserve(basis.len());
+            for (i, basis) in basis.iter().enumerate() {
+                map.insert(basis.clone(), i);
+            }
+            self.basis_element_to_index_map.push(map);
+        }
+    }
+
+    pub fn basis_element_from_index(&self, degree: i32, idx: usize) -> &LambdaBasisElement {
+        &self.basis_table[degree as usize][idx]
+    }
+
+    pub fn try_basis_element_to_index(&self, elt: &LambdaBasisElement) -> Option<usize> {
+        self.basis_element_to_index_map[elt.degree as usize]
+            .get(elt)
+            .copied()
+    }
+
+    pub fn basis_element_to_index(&self, elt: &LambdaBasisElement) -> usize {
+        self.try_basis_element_to_index(elt)
+            .unwrap_or_else(|| panic!("Didn't find element: {:?}", elt))
+    }
+
+    /// The indices of the basis elements of degree `degree` and length `s`.
+    pub fn length_range(&self, degree: i32, s: u32) -> Range<usize> {
+        if degree < 0 || s > degree as u32 {
+            return 0..0;
+        }
+        let offsets = &self.length_offsets[degree as usize];
+        offsets[s as usize]..offsets[s as usize + 1]
+    }
+
+    /// Adds `coeff` times the monomial $\lambda_{i_1} \cdots \lambda_{i_s}$ to `result`, where
+    /// `lambdas` need not be admissible. The monomial must have degree `degree`.
+    pub fn add_monomial(&self, mut result: SliceMut, coeff: u32, degree: i32, lambdas: Vec<u32>) {
+        if coeff.is_multiple_of(2) {
+            return;
+        }
+        let mut stack = vec![lambdas];
+        while let Some(mut lambdas) = stack.pop() {
+            let k = match inadmissible_position(&lambdas) {
+                Some(k) => k,
+                None => {
+                    let elt = LambdaBasisElement { degree, lambdas };
+                    result.add_basis_element(self.basis_element_to_index(&elt), 1);
+                    continue;
+                }
+            };
+            let i = lambdas[k];
+            let n = lambdas[k + 1] - 2 * i - 1;
+            for j in 0..n.div_ceil(2) {
+                if u32::binomial2(n - j - 1, j) == 1 {
+                    lambdas[k] = i + n - j;
+                    lambdas[k + 1] = 2 * i + 1 + j;
+                    stack.push(lambdas.clone());
+                }
+            }
+        }
+    }
+
+    /// Adds `coeff` times the differential of a basis element to `result`. The differential
+    /// preserves the degree and increases the length by one.
+    pub fn differential(&self, mut result: SliceMut, coeff: u32, degree: i32, idx: usize) {
+        if coeff.is_multiple_of(2) {
+            return;
+        }
+        let lambdas = &self.basis_element_from_index(degree, idx).lambdas;
+        for (k, &n) in lambdas.iter().enumerate() {
+            for j in 1..=n / 2 {
+                if u32::binomial2(n - j, j) == 1 {
+                    let mut term = Vec::with_capacity(lambdas.len() + 1);
+                    term.extend_from_slice(&lambdas[..k]);
+                    term.push(n - j);
+                    term.push(j - 1);
+                    term.extend_from_slice(&lambdas[k + 1..]);
+                    self.add_monomial(result.copy(), 1, degree, term);
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use fp::vector::FpVector;
+
+    #[test]
+    fn basis() {
+        let algebra = LambdaAlgebra::new(ValidPrime::new(2));
+        algebra.compute_basis(4);
+
+        let basis: Vec<String> = (0..algebra.dimension(4))
+            .map(|i| algebra.basis_element_to_string(4, i))
+            .collect();
+        assert_eq!(
+            basis,
+            ["λ3", "λ1 λ1", "λ2 λ0", "λ1 λ0 λ0", "λ0 λ0 λ0 λ0"]
+                .into_iter()
+                .map(String::from)
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(algebra.length_range(4, 2), 1..3);
+        assert_eq!(algebra.length_range(4, 3), 3..4);
+    }
+
+    #[test]
+    fn relations() {
+        let p = ValidPrime::new(2);
+        let algebra = LambdaAlgebra::new(p);
+        algebra.compute_basis(4);
+
+        // λ0 λ1 = 0 and λ0 λ2 = λ1 λ1
+        let mut result = FpVector::new(p, algebra.dimension(3));
+        algebra.add_monomial(result.as_slice_mut(), 1, 3, vec![0, 1]);
+        assert!(result.is_zero());
+
+        let mut result = FpVector::new(p, algebra.dimension(4));
+        algebra.add_monomial(result.as_slice_mut(), 1, 4, vec![0, 2]);
+        assert_eq!(algebra.element_to_string(4, result.as_slice()), "λ1 λ1");
+    }
+
+    #[test]
+    fn differential_squares_to_zero() {
+        let p = ValidPrime::new(2);
+        let algebra = LambdaAlgebra::new(p);
+        algebra.compute_basis(16);
+
+        for t in 0..=16 {
+            let dim = algebra.dimension(t);
+            for idx in 0..dim {
+                let mut d = FpVector::new(p, dim);
+                algebra.differential(d.as_slice_mut(), 1, t, idx);
+
+                let s = algebra.basis_element_from_index(t, idx).s();
+                for (i, _) in d.iter_nonzero() {
+                    assert_eq!(algebra.basis_element_from_index(t, i).s(), s + 1);
+                }
+
+                let mut dd = FpVector::new(p, dim);
+                for (i, _) in d.iter_nonzero() {
+                    algebra.differential(dd.as_slice_mut(), 1, t, i);
+                }
+                assert!(
+                    dd.is_zero(),
+                    "d^2 is nonzero on {}",
+                    algebra.basis_element_to_string(t, idx)
+                );
+            }
+        }
+    }
+}
@@ -3,7 +3,7 @@ use rustc_hash::FxHashMap as HashMap;
 use std::sync::Mutex;
 
 use crate::algebra::combinatorics;
-use crate::algebra::{Algebra, Bialgebra, GeneratedAlgebra};
+use crate::algebra::{Algebra, Bialgebra, GeneratedAlgebra, HopfAlgebra};
 use fp::prime::{integer_power, Binomial, BitflagIterator, ValidPrime};
 use fp::vector::{FpVector, Slice, SliceMut};
 use once::OnceVec;
@@ -148,6 +148,24 @@ impl std::fmt::Display for MilnorAlgebra {
 
 impl MilnorAlgebra {
     pub fn new(p: ValidPrime) -> Self {
+        Self::new_with_generic(p, *p != 2)
+    }
+
+    /// Construct the Milnor algebra with a specified choice of presentation. At odd primes the
+    /// algebra must be generic. At the prime 2, the generic algebra has dual $\mathbb{F}_2[\xi_1,
+    /// \xi_2, \ldots] \otimes E[\tau_0, \tau_1, \ldots]$ with $|\xi_i| = 2(2^i - 1)$ and $|\tau_i| =
+    /// 2^{i + 1} - 1$. This is the $\mathbb{C}$-motivic Steenrod algebra modulo $\tau$.
+    pub fn new_with_generic(p: ValidPrime, generic: bool) -> Self {
+        assert!(
+            generic || *p == 2,
+            "The Milnor algebra at odd primes must be generic"
+        );
+        #[cfg(not(feature = "odd-primes"))]
+        assert!(
+            !generic,
+            "The generic Milnor algebra requires the odd-primes feature"
+        );
+
         let profile = MilnorProfile {
             truncated: false,
             q_part: !0,
@@ -157,7 +175,7 @@ impl MilnorAlgebra {
         Self {
             p,
             #[cfg(feature = "odd-primes")]
-            generic: *p != 2,
+            generic,
             profile,
             lock: Mutex::new(()),
             ppart_table: OnceVec::new(),
@@ -214,6 +232,11 @@ impl Algebra for MilnorAlgebra {
             } else {
                 0x8001
             }
+            + if self.generic() != (*self.p != 2) {
+                0x4000
+            } else {
+                0
+            }
     }
 
     fn prime(&self) -> ValidPrime {
@@ -578,8 +601,7 @@ impl MilnorAlgebra {
     fn compute_ppart(&self, max_degree: i32) {
         self.ppart_table.extend(0, |_| vec![Vec::new()]);
 
-        let p = *self.prime() as i32;
-        let q = if p == 2 { 1 } else { 2 * p - 2 };
+        let q = self.q();
         let new_deg = max_degree / q;
 
         let xi_degrees = combinatorics::xi_degrees(self.prime());
@@ -1421,11 +1443,18 @@ mod tests {
     use expect_test::expect;
     use rstest::rstest;
 
-    #[rstest(p, max_degree, case(2, 32), case(3, 106))]
+    #[rstest(
+        p,
+        generic,
+        max_degree,
+        case(2, false, 32),
+        case(2, true, 64),
+        case(3, true, 106)
+    )]
     #[trace]
-    fn test_milnor_basis(p: u32, max_degree: i32) {
+    fn test_milnor_basis(p: u32, generic: bool, max_degree: i32) {
         let p = ValidPrime::new(p);
-        let algebra = MilnorAlgebra::new(p); //p != 2
+        let algebra = MilnorAlgebra::new_with_generic(p, generic);
         algebra.compute_basis(max_degree);
         for i in 1..max_degree {
             let dim = algebra.dimension(i);
@@ -1439,11 +1468,18 @@ mod tests {
         }
     }
 
-    #[rstest(p, max_degree, case(2, 32), case(3, 106))]
+    #[rstest(
+        p,
+        generic,
+        max_degree,
+        case(2, false, 32),
+        case(2, true, 64),
+        case(3, true, 106)
+    )]
     #[trace]
-    fn test_milnor_decompose(p: u32, max_degree: i32) {
+    fn test_milnor_decompose(p: u32, generic: bool, max_degree: i32) {
         let p = ValidPrime::new(p);
-        let algebra = MilnorAlgebra::new(p);
+        let algebra = MilnorAlgebra::new_with_generic(p, generic);
         algebra.compute_basis(max_degree);
         for i in 1..max_degree {
             let dim = algebra.dimension(i);
@@ -1485,11 +1521,18 @@ mod tests {
     }
 
     use crate::module::ModuleFailedRelationError;
-    #[rstest(p, max_degree, case(2, 32), case(3, 106))]
+    #[rstest(
+        p,
+        generic,
+        max_degree,
+        case(2, false, 32),
+        case(2, true, 64),
+        case(3, true, 106)
+    )]
     #[trace]
-    fn test_adem_relations(p: u32, max_degree: i32) {
+    fn test_adem_relations(p: u32, generic: bool, max_degree: i32) {
         let p = ValidPrime::new(p);
-        let algebra = MilnorAlgebra::new(p); // , p != 2
+        let algebra = MilnorAlgebra::new_with_generic(p, generic);
         algebra.compute_basis(max_degree + 2);
         let mut output_vec = FpVector::new(p, 0);
         for i in 1..max_degree {
@@ -1648,6 +1691,95 @@ mod tests {
 
         assert_eq!(m.next(), None);
     }
+
+    /// At the prime 2, $\chi(Sq(n))$ is the sum of all Milnor basis elements in degree $n$.
+    #[test]
+    fn test_antipode_sq() {
+        let p = ValidPrime::new(2);
+        let algebra = MilnorAlgebra::new(p);
+        algebra.compute_basis(20);
+        for n in 1..=20 {
+            let mut result = FpVector::new(p, algebra.dimension(n));
+            algebra.antipode_on_basis(result.as_slice_mut(), 1, n, algebra.beps_pn(0, n as _).1);
+            assert!(result.iter().all(|x| x == 1), "χ(Sq({n}))");
+        }
+    }
+
+    #[rstest(p, max_degree, case(2, 16), case(3, 40))]
+    #[trace]
+    fn test_antipode(p: u32, max_degree: i32) {
+        let p = ValidPrime::new(p);
+        let algebra = MilnorAlgebra::new(p);
+        algebra.compute_basis(max_degree);
+
+        for degree in 0..=max_degree {
+            let dim = algebra.dimension(degree);
+            let mut chi = FpVector::new(p, dim);
+            let mut chi_chi = FpVector::new(p, dim);
+            for idx in 0..dim {
+                // The counit
+                let coproduct = algebra.coproduct_basis_element(degree, idx);
+                assert!(coproduct.contains(&(1, (0, 0), (degree, idx))));
+                assert!(coproduct.contains(&(1, (degree, idx), (0, 0))));
+
+                chi.set_to_zero();
+                chi_chi.set_to_zero();
+                algebra.antipode_on_basis(chi.as_slice_mut(), 1, degree, idx);
+                algebra.antipode(chi_chi.as_slice_mut(), 1, degree, chi.as_slice());
+                chi_chi.add_basis_element(idx, *p - 1);
+                assert!(
+                    chi_chi.is_zero(),
+                    "χχ({}) ≠ {}",
+                    algebra.basis_element_to_string(degree, idx),
+                    algebra.basis_element_to_string(degree, idx)
+                );
+            }
+        }
+
+        // χ(ab) = (-1)^{|a||b|} χ(b) χ(a)
+        for a_deg in 1..max_degree {
+            for b_deg in 1..=max_degree - a_deg {
+                let degree = a_deg + b_deg;
+                for a_idx in 0..algebra.dimension(a_deg) {
+                    for b_idx in 0..algebra.dimension(b_deg) {
+                        let mut product = FpVector::new(p, algebra.dimension(degree));
+                        algebra.multiply_basis_elements(
+                            product.as_slice_mut(),
+                            1,
+                            a_deg,
+                            a_idx,
+                            b_deg,
+                            b_idx,
+                        );
+                        let mut lhs = FpVector::new(p, algebra.dimension(degree));
+                        algebra.antipode(lhs.as_slice_mut(), 1, degree, product.as_slice());
+
+                        let mut chi_a = FpVector::new(p, algebra.dimension(a_deg));
+                        let mut chi_b = FpVector::new(p, algebra.dimension(b_deg));
+                        algebra.antipode_on_basis(chi_a.as_slice_mut(), 1, a_deg, a_idx);
+                        algebra.antipode_on_basis(chi_b.as_slice_mut(), 1, b_deg, b_idx);
+                        let sign = if (a_deg * b_deg) % 2 == 0 { 1 } else { *p - 1 };
+                        let mut rhs = FpVector::new(p, algebra.dimension(degree));
+                        algebra.multiply_element_by_element(
+                            rhs.as_slice_mut(),
+                            sign,
+                            b_deg,
+                            chi_b.as_slice(),
+                            a_deg,
+                            chi_a.as_slice(),
+                        );
+                        assert_eq!(
+                            lhs,
+                            rhs,
+                            "χ({} {})",
+                            algebra.basis_element_to_string(a_deg, a_idx),
+                            algebra.basis_element_to_string(b_deg, b_idx)
+                        );
+                    }
+                }
+            }
+        }
+    }
 }
 
 impl MilnorAlgebra {
@@ -1725,3 +1857,76 @@ impl Bialgebra for MilnorAlgebra {
         vec![(op_deg, op_idx)]
     }
 }
+
+impl HopfAlgebra for MilnorAlgebra {
+    /// The coproduct is given by $\Delta(P(R)) = \sum_{R' + R'' = R} P(R') \otimes P(R'')$, and
+    /// the $Q_i$ are primitive. This works at all primes and with any profile, since the terms
+    /// appearing lie in any sub-Hopf algebra containing the element.
+    fn coproduct_basis_element(
+        &self,
+        op_deg: i32,
+        op_idx: usize,
+    ) -> Vec<(u32, (i32, usize), (i32, usize))> {
+        let p = *self.prime();
+        let q = self.q();
+        let xi_degrees = combinatorics::xi_degrees(self.prime());
+        let tau_degrees = combinatorics::tau_degrees(self.prime());
+
+        let elt = self.basis_element_from_index(op_deg, op_idx);
+        let mut result = Vec::new();
+        let mut left_ppart: PPart = vec![0; elt.p_part.len()];
+        loop {
+            // Iterate through the submasks of elt.q_part
+            let mut left_qpart = elt.q_part;
+            loop {
+                let right_qpart = elt.q_part ^ left_qpart;
+                let mut left_degree = 0;
+                for (i, &r) in left_ppart.iter().enumerate() {
+                    left_degree += r as i32 * xi_degrees[i] * q;
+                }
+                // Moving the Q_i in the right factor past those in the left factor
+                let mut swaps = 0;
+                for k in BitflagIterator::set_bit_iterator(left_qpart as u64) {
+                    left_degree += tau_degrees[k];
+                    swaps += (right_qpart & ((1 << k) - 1)).count_ones();
+                }
+
+                let mut left = MilnorBasisElement {
+                    q_part: left_qpart,
+                    p_part: left_ppart.clone(),
+                    degree: left_degree,
+                };
+                let mut right = MilnorBasisElement {
+                    q_part: right_qpart,
+                    p_part: elt
+                        .p_part
+                        .iter()
+                        .zip(&left_ppart)
+                        .map(|(r, s)| r - s)
+                        .collect(),
+                    degree: op_deg - left_degree,
+                };
+                for ppart in [&mut left.p_part, &mut right.p_part] {
+                    while ppart.last() == Some(&0) {
+                        ppart.pop();
+                    }
+                }
+                result.push((
+                    if swaps % 2 == 0 { 1 } else { p - 1 },
+                    (left.degree, self.basis_element_to_index(&left)),
+                    (right.degree, self.basis_element_to_index(&right)),
+                ));
+
+                if left_qpart == 0 {
+                    break;
+                }
+                left_qpart = (left_qpart - 1) & elt.q_part;
+            }
+
+            if left_ppart.is_empty() || Self::increment_p_part(&mut left_ppart, &elt.p_part) {
+                break;
+            }
+        }
+        result
+    }
+}
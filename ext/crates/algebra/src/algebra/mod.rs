@@ -12,11 +12,20 @@ pub use algebra_trait::{Algebra, GeneratedAlgebra};
 mod bialgebra_trait;
 pub use bialgebra_trait::Bialgebra;
 
+mod hopf_algebra_trait;
+pub use hopf_algebra_trait::HopfAlgebra;
+
 pub mod combinatorics;
 
+mod eilenberg_maclane_algebra;
+pub use eilenberg_maclane_algebra::{EilenbergMacLaneAlgebra, EilenbergMacLaneGroup};
+
 pub mod field;
 pub use field::Field;
 
+pub mod lambda_algebra;
+pub use lambda_algebra::LambdaAlgebra;
+
 pub mod milnor_algebra;
 pub use milnor_algebra::{MilnorAlgebra, MilnorAlgebraT};
 
@@ -1,7 +1,8 @@
 #[cfg(feature = "json")]
 use crate::algebra::JsonAlgebra;
 use crate::algebra::{
-    AdemAlgebra, AdemAlgebraT, Algebra, Bialgebra, GeneratedAlgebra, MilnorAlgebra, MilnorAlgebraT,
+    AdemAlgebra, AdemAlgebraT, Algebra, Bialgebra, GeneratedAlgebra, HopfAlgebra, MilnorAlgebra,
+    MilnorAlgebraT,
 };
 use crate::dispatch_algebra;
 use fp::prime::ValidPrime;
@@ -134,6 +135,26 @@ impl Bialgebra for SteenrodAlgebra {
     }
 }
 
+impl HopfAlgebra for SteenrodAlgebra {
+    fn coproduct_basis_element(
+        &self,
+        op_deg: i32,
+        op_idx: usize,
+    ) -> Vec<(u32, (i32, usize), (i32, usize))> {
+        match self {
+            SteenrodAlgebra::AdemAlgebra(a) => a.coproduct_basis_element(op_deg, op_idx),
+            SteenrodAlgebra::MilnorAlgebra(a) => a.coproduct_basis_element(op_deg, op_idx),
+        }
+    }
+
+    fn antipode_on_basis(&self, result: SliceMut, coeff: u32, op_deg: i32, op_idx: usize) {
+        match self {
+            SteenrodAlgebra::AdemAlgebra(a) => a.antipode_on_basis(result, coeff, op_deg, op_idx),
+            SteenrodAlgebra::MilnorAlgebra(a) => a.antipode_on_basis(result, coeff, op_deg, op_idx),
+        }
+    }
+}
+
 #[cfg(feature = "json")]
 #[derive(Deserialize, Debug)]
 struct MilnorProfileOption {
@@ -146,6 +167,7 @@ struct MilnorProfileOption {
 #[derive(Deserialize, Debug)]
 struct AlgebraSpec {
     p: ValidPrime,
+    generic: Option<bool>,
     algebra: Option<Vec<String>>,
     profile: Option<MilnorProfileOption>,
 }
@@ -159,6 +181,14 @@ impl SteenrodAlgebra {
             AlgebraType::Milnor => SteenrodAlgebra::MilnorAlgebra(MilnorAlgebra::new(p)),
         }
     }
+
+    /// Whether the algebra uses the generic presentation, i.e. has generators $β$ and $P^i$.
+    pub fn generic(&self) -> bool {
+        match self {
+            SteenrodAlgebra::AdemAlgebra(a) => a.generic,
+            SteenrodAlgebra::MilnorAlgebra(a) => a.generic(),
+        }
+    }
 }
 
 #[cfg(feature = "json")]
@@ -178,7 +208,21 @@ impl SteenrodAlgebra {
             }
         }
 
-        let mut algebra = Self::new(spec.p, algebra_type);
+        // The algebra at an odd prime is always generic, so the flag only matters at p = 2.
+        let mut algebra = if *spec.p == 2 && spec.generic == Some(true) {
+            if cfg!(not(feature = "odd-primes")) {
+                return Err(anyhow!(
+                    "The generic algebra at p = 2 requires the odd-primes feature"
+                ));
+            }
+            if algebra_type != AlgebraType::Milnor {
+                println!("Generic algebra at p = 2 is only supported in the Milnor basis");
+                println!("Using milnor instead");
+            }
+            Self::MilnorAlgebra(MilnorAlgebra::new_with_generic(spec.p, true))
+        } else {
+            Self::new(spec.p, algebra_type)
+        };
 
         if let Self::MilnorAlgebra(inner) = &mut algebra {
             if let Some(profile) = spec.profile {
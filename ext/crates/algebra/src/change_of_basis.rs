@@ -298,6 +298,7 @@ pub fn adem_plist(
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::algebra::HopfAlgebra;
     use fp::prime::ValidPrime;
     use rstest::rstest;
 
@@ -373,4 +374,36 @@ mod tests {
             }
         }
     }
+
+    #[rstest(p, max_degree, case(2, 20), case(3, 40))]
+    #[trace]
+    fn test_antipode_adem_to_milnor(p: u32, max_degree: i32) {
+        let p = ValidPrime::new(p);
+        let adem = AdemAlgebra::new(p, *p != 2, false, false);
+        let milnor = MilnorAlgebra::new(p);
+        adem.compute_basis(max_degree);
+        milnor.compute_basis(max_degree);
+
+        for degree in 0..=max_degree {
+            let dim = adem.dimension(degree);
+            for idx in 0..dim {
+                let mut adem_chi = FpVector::new(p, dim);
+                adem.antipode_on_basis(adem_chi.as_slice_mut(), 1, degree, idx);
+                let mut expected = FpVector::new(p, dim);
+                adem_to_milnor(&adem, &milnor, &mut expected, 1, degree, &adem_chi);
+
+                let mut milnor_elt = FpVector::new(p, dim);
+                adem_to_milnor_on_basis(&adem, &milnor, &mut milnor_elt, 1, degree, idx);
+                let mut result = FpVector::new(p, dim);
+                milnor.antipode(result.as_slice_mut(), 1, degree, milnor_elt.as_slice());
+
+                assert_eq!(
+                    result,
+                    expected,
+                    "χ({})",
+                    adem.basis_element_to_string(degree, idx)
+                );
+            }
+        }
+    }
 }
@@ -0,0 +1,176 @@
+use crate::algebra::{
+    milnor_algebra::MilnorBasisElement, Algebra, HopfAlgebra, SteenrodAlgebra,
+    SteenrodAlgebraBorrow, SteenrodAlgebraT,
+};
+use crate::module::FDModule;
+use bivec::BiVec;
+use fp::matrix::Subspace;
+use fp::vector::FpVector;
+
+use std::sync::Arc;
+
+#[cfg(feature = "json")]
+use {serde::Deserialize, serde_json::Value};
+
+/// Constructs the cohomology of the $n$th Brown–Gitler spectrum at the prime 2, which is
+/// $$ H^*(B(n)) = A / A\\{\chi(\mathrm{Sq}^i) : i > n\\}. $$
+///
+/// The basis elements are named after representatives in $A$. Dually, the homology
+/// is spanned by the monomials in $\bar\xi_i$ of weight at most $n$, where $\bar\xi_i$ has weight
+/// $2^{i - 1}$, so the module vanishes above degree $2n - 1$.
+pub fn brown_gitler_module(
+    algebra: Arc<SteenrodAlgebra>,
+    n: u32,
+) -> anyhow::Result<FDModule<SteenrodAlgebra>> {
+    let p = algebra.prime();
+    if *p != 2 {
+        return Err(anyhow::anyhow!(
+            "Brown–Gitler modules are only supported at the prime 2"
+        ));
+    }
+    let max = std::cmp::max(2 * n as i32 - 1, 0);
+    algebra.compute_basis(max);
+
+    let sq = |i: i32| match algebra.steenrod_algebra() {
+        SteenrodAlgebraBorrow::BorrowAdem(a) => Some(a.beps_pn(0, i as u32).1),
+        SteenrodAlgebraBorrow::BorrowMilnor(a) => {
+            a.try_basis_element_to_index(&MilnorBasisElement {
+                q_part: 0,
+                p_part: vec![i as _],
+                degree: i,
+            })
+        }
+    };
+
+    // The left ideal generated by the χ(Sq^i) with i > n.
+    let mut relations = Vec::new();
+    for i in n as i32 + 1..=max {
+        let mut relation = FpVector::new(p, algebra.dimension(i));
+        if let Some(idx) = sq(i) {
+            algebra.antipode_on_basis(relation.as_slice_mut(), 1, i, idx);
+        }
+        relations.push(relation);
+    }
+
+    let mut ideal = Vec::with_capacity(max as usize + 1);
+    for t in 0..=max {
+        let dim = algebra.dimension(t);
+        let mut subspace = Subspace::new(p, dim + 1, dim);
+        for (i, relation) in (n as i32 + 1..=t).zip(&relations) {
+            for op_idx in 0..algebra.dimension(t - i) {
+                let mut v = FpVector::new(p, dim);
+                algebra.multiply_basis_element_by_element(
+                    v.as_slice_mut(),
+                    1,
+                    t - i,
+                    op_idx,
+                    i,
+                    relation.as_slice(),
+                );
+                subspace.add_vector(v.as_slice());
+            }
+        }
+        ideal.push(subspace);
+    }
+    let basis: Vec<Vec<usize>> = ideal
+        .iter()
+        .map(|subspace| {
+            (0..subspace.ambient_dimension())
+                .filter(|&i| subspace.pivots()[i] < 0)
+                .collect()
+        })
+        .collect();
+
+    let graded_dimension = BiVec::from_vec(0, basis.iter().map(Vec::len).collect());
+    let mut module = FDModule::new(Arc::clone(&algebra), format!("B({n})"), graded_dimension);
+    for (t, basis) in basis.iter().enumerate() {
+        for (idx, &elt) in basis.iter().enumerate() {
+            let name = algebra
+                .basis_element_to_string(t as i32, elt)
+                .replace(' ', "");
+            module.set_basis_element_name(t as i32, idx, name);
+        }
+    }
+
+    for input_deg in 0..=max {
+        for output_deg in input_deg + 1..=max {
+            let op_deg = output_deg - input_deg;
+            let mut product = FpVector::new(p, algebra.dimension(output_deg));
+            for (input_idx, &elt) in basis[input_deg as usize].iter().enumerate() {
+                for op_idx in 0..algebra.dimension(op_deg) {
+                    product.set_to_zero();
+                    algebra.multiply_basis_elements(
+                        product.as_slice_mut(),
+                        1,
+                        op_deg,
+                        op_idx,
+                        input_deg,
+                        elt,
+                    );
+                    ideal[output_deg as usize].reduce(product.as_slice_mut());
+
+                    let output = module.action_mut(op_deg, op_idx, input_deg, input_idx);
+                    for (output_idx, &i) in basis[output_deg as usize].iter().enumerate() {
+                        output.add_basis_element(output_idx, product.entry(i));
+                    }
+                }
+            }
+        }
+    }
+    Ok(module)
+}
+
+#[cfg(feature = "json")]
+#[derive(Deserialize, Debug)]
+struct BrownGitlerSpec {
+    n: u32,
+}
+
+/// Constructs a [`brown_gitler_module`] from a json specification of the form `{"n": 5}`.
+#[cfg(feature = "json")]
+pub fn brown_gitler_module_from_json(
+    algebra: Arc<SteenrodAlgebra>,
+    json: &Value,
+) -> anyhow::Result<FDModule<SteenrodAlgebra>> {
+    let spec = BrownGitlerSpec::deserialize(json)?;
+    brown_gitler_module(algebra, spec.n)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algebra::{AdemAlgebra, MilnorAlgebra};
+    use crate::module::projective_space::tests::check_associativity;
+    use crate::module::Module;
+    use fp::prime::ValidPrime;
+
+    #[test]
+    fn test_brown_gitler() {
+        let p = ValidPrime::new(2);
+        for n in 0..8 {
+            // Count the monomials in ξ_i of weight at most n by degree
+            let mut expected = vec![0; std::cmp::max(2 * n as usize, 1)];
+            let mut monomials = vec![(0, 0, 1)]; // (weight, degree, smallest allowed i)
+            while let Some((weight, degree, i)) = monomials.pop() {
+                expected[degree] += 1;
+                for j in i..5 {
+                    if weight + (1 << (j - 1)) <= n {
+                        monomials.push((weight + (1 << (j - 1)), degree + (1 << j) - 1, j));
+                    }
+                }
+            }
+
+            for algebra in [
+                SteenrodAlgebra::AdemAlgebra(AdemAlgebra::new(p, false, false, false)),
+                SteenrodAlgebra::MilnorAlgebra(MilnorAlgebra::new(p)),
+            ] {
+                let module = brown_gitler_module(Arc::new(algebra), n as u32).unwrap();
+                let dimensions: Vec<usize> = (0..expected.len() as i32)
+                    .map(|t| module.dimension(t))
+                    .collect();
+                assert_eq!(dimensions, expected, "B({n})");
+                check_associativity(&module, expected.len() as i32 - 1);
+            }
+        }
+    }
+}
@@ -0,0 +1,503 @@
+use crate::algebra::{
+    milnor_algebra::PPartEntry, Algebra, SteenrodAlgebra, SteenrodAlgebraBorrow, SteenrodAlgebraT,
+};
+use crate::module::projective_space::power_coefficient;
+use crate::module::{FDModule, LazyModule, Module};
+use fp::matrix::Subspace;
+use fp::prime::ValidPrime;
+use fp::vector::FpVector;
+use itertools::Itertools;
+use once::OnceBiVec;
+use rustc_hash::FxHashMap as HashMap;
+
+use std::sync::Arc;
+
+use anyhow::anyhow;
+
+#[cfg(feature = "json")]
+use {serde::Deserialize, serde_json::Value};
+
+/// A compact Lie group $G$ whose classifying space can be constructed by [`classifying_space`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum LieGroup {
+    O,
+    SO,
+    U,
+    Sp,
+}
+
+impl std::fmt::Display for LieGroup {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let name = match self {
+            Self::O => "O",
+            Self::SO => "SO",
+            Self::U => "U",
+            Self::Sp => "Sp",
+        };
+        f.write_str(name)
+    }
+}
+
+impl std::str::FromStr for LieGroup {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> anyhow::Result<Self> {
+        match s {
+            "O" => Ok(Self::O),
+            "SO" => Ok(Self::SO),
+            "U" => Ok(Self::U),
+            "Sp" => Ok(Self::Sp),
+            _ => Err(anyhow::anyhow!("Unknown group: {}", s)),
+        }
+    }
+}
+
+/// The splitting principle description of $H^*(BG)$. This is the ring of symmetric functions in
+/// variables $y_i$ of degree `unit` whose exponents are divisible by `step`, with basis the
+/// monomial symmetric functions $m_\lambda$. The Steenrod algebra acts via the coaction
+/// $y \mapsto \sum_i y^{p^i} \xi_i$ (with $\xi_i$ replaced by $\xi_i^2$ if $p = 2$ and
+/// `unit = 2`).
+///
+/// The Thom class of the universal bundle restricts to $\prod_i y_i^{\mathrm{twist}}$, which we
+/// use to describe the Thom spectra.
+struct SplittingModel {
+    p: ValidPrime,
+    unit: i32,
+    step: u32,
+    twist: i32,
+}
+
+impl SplittingModel {
+    fn new(p: ValidPrime, group: LieGroup, thom: i32) -> Option<Self> {
+        let (unit, step, twist) = match (group, *p == 2) {
+            (LieGroup::O | LieGroup::SO, true) => (1, 1, thom),
+            (LieGroup::O, false) => return None,
+            (LieGroup::SO, false) => (2, 2, thom),
+            (LieGroup::U, _) => (2, 1, thom),
+            (LieGroup::Sp, _) => (2, 2, 2 * thom),
+        };
+        Some(Self {
+            p,
+            unit,
+            step,
+            twist,
+        })
+    }
+
+    /// The partitions indexing the basis in degree `degree`, with parts in decreasing order.
+    fn basis(&self, degree: i32) -> Vec<Vec<u32>> {
+        if degree % self.unit != 0 || !((degree / self.unit) as u32).is_multiple_of(self.step) {
+            return Vec::new();
+        }
+        let mut result = Vec::new();
+        partitions(
+            (degree / self.unit) as u32,
+            u32::MAX,
+            self.step,
+            &mut Vec::new(),
+            &mut result,
+        );
+        result
+    }
+
+    fn basis_element_to_string(&self, thom: i32, lambda: &[u32]) -> String {
+        let u = if thom == 0 { "" } else { "U" };
+        if lambda.is_empty() {
+            return if thom == 0 { "1" } else { u }.to_string();
+        }
+        format!(
+            "{u}m_{{{}}}",
+            lambda.iter().map(|&x| x / self.step).format(",")
+        )
+    }
+
+    /// Converts a Milnor basis element to the exponents $S$ such that it acts on the variables as
+    /// the dual of $\xi^S$. Returns `None` if the element acts trivially.
+    fn exponents(&self, q_part: u32, p_part: &[PPartEntry]) -> Option<Vec<u32>> {
+        if q_part != 0 {
+            return None;
+        }
+        // PPartEntry is u8 if the odd-primes feature is disabled
+        #[allow(clippy::unnecessary_cast)]
+        let mut s: Vec<u32> = p_part.iter().map(|&r| r as u32).collect();
+        if *self.p == 2 && self.unit == 2 {
+            if s.iter().any(|&r| r % 2 == 1) {
+                return None;
+            }
+            for r in &mut s {
+                *r /= 2;
+            }
+        }
+        Some(s)
+    }
+
+    /// Calls `f` on every vector `v` with `v[i] <= bound[i]` and $\sum_i v_i (p^{i + 1} - 1) =$
+    /// `weight`.
+    fn for_each_vector(&self, bound: &[u32], weight: u32, f: &mut impl FnMut(&[u32])) {
+        fn recurse(
+            p: u32,
+            bound: &[u32],
+            weight: u32,
+            current: &mut Vec<u32>,
+            f: &mut impl FnMut(&[u32]),
+        ) {
+            let i = current.len();
+            if i == bound.len() {
+                if weight == 0 {
+                    f(current);
+                }
+                return;
+            }
+            let w = p.saturating_pow(i as u32 + 1) - 1;
+            for v in 0..=std::cmp::min(bound[i], weight / w) {
+                current.push(v);
+                recurse(p, bound, weight - v * w, current, f);
+                current.pop();
+            }
+        }
+        recurse(*self.p, bound, weight, &mut Vec::new(), f);
+    }
+
+    /// Computes the coefficients of $m_\nu$ in the dual of $\xi^S$ acting on the $m_\lambda$ for
+    /// all $\lambda$. This is the coefficient of $y^\nu$, to which the monomials $y^\mu$ in
+    /// $m_\lambda$ with $\mu \leq \nu$ contribute. The result is indexed by $\lambda$.
+    fn coefficients(&self, s: &[u32], nu: &[u32]) -> HashMap<Vec<u32>, u32> {
+        let p = *self.p;
+        // The partial sums of the exponents of ξ and the exponents of y used so far
+        let mut terms: HashMap<(Vec<u32>, Vec<u32>), u32> = HashMap::default();
+        terms.insert((vec![0; s.len()], Vec::new()), 1);
+
+        for &n in nu {
+            let mut new_terms: HashMap<(Vec<u32>, Vec<u32>), u32> = HashMap::default();
+            for ((t, mu), &c) in &terms {
+                let bound: Vec<u32> = s.iter().zip(t).map(|(s, t)| s - t).collect();
+                for m in (0..=n).step_by(self.step as usize) {
+                    self.for_each_vector(&bound, n - m, &mut |v| {
+                        let d = power_coefficient(self.p, m as i32 + self.twist, v);
+                        if d == 0 {
+                            return;
+                        }
+                        let t = t.iter().zip(v).map(|(t, v)| t + v).collect();
+                        let mut mu = mu.clone();
+                        if m > 0 {
+                            let i = mu.iter().position(|&x| x < m).unwrap_or(mu.len());
+                            mu.insert(i, m);
+                        }
+                        let entry = new_terms.entry((t, mu)).or_insert(0);
+                        *entry = (*entry + c * d) % p;
+                    });
+                }
+            }
+            terms = new_terms;
+        }
+
+        let mut result: HashMap<Vec<u32>, u32> = HashMap::default();
+        for ((t, mu), c) in terms {
+            if t == s {
+                let entry = result.entry(mu).or_insert(0);
+                *entry = (*entry + c) % p;
+            }
+        }
+        result
+    }
+}
+
+/// Lists the partitions of `n` into parts that are at most `max` and divisible by `step`.
+fn partitions(n: u32, max: u32, step: u32, current: &mut Vec<u32>, result: &mut Vec<Vec<u32>>) {
+    if n == 0 {
+        result.push(current.clone());
+        return;
+    }
+    let mut part = std::cmp::min(n, max) / step * step;
+    while part > 0 {
+        current.push(part);
+        partitions(n - part, part, step, current, result);
+        current.pop();
+        part -= step;
+    }
+}
+
+/// The data shared by the callbacks of [`lazy_classifying_space`].
+struct LazyClassifyingSpace {
+    algebra: Arc<SteenrodAlgebra>,
+    model: SplittingModel,
+    thom: i32,
+    /// The partitions indexing the monomial symmetric functions in each degree.
+    partitions: OnceBiVec<Vec<Vec<u32>>>,
+    /// The index of each partition in `partitions`.
+    indices: OnceBiVec<HashMap<Vec<u32>, usize>>,
+    /// The ideal we quotient by in each degree, which is non-zero only for $BSO$ at the prime 2.
+    ideal: OnceBiVec<Subspace>,
+    /// The partitions that are not pivots of `ideal`, which form the basis of the quotient.
+    basis: OnceBiVec<Vec<usize>>,
+}
+
+impl LazyClassifyingSpace {
+    fn compute_basis(&self, t: i32, quotient: bool) -> Vec<String> {
+        let p = self.model.p;
+        let partitions = self.model.basis(t);
+        let dim = partitions.len();
+        let mut ideal = Subspace::new(p, dim + 1, dim);
+
+        // H^*(BSO) is the quotient of H^*(BO) by the ideal generated by w_1 = m_1.
+        if quotient && t > 0 {
+            let mut result = FpVector::new(p, dim);
+            for lambda in &self.partitions[t - 1] {
+                // m_1 m_λ is the sum of the m_ν, where ν is obtained by increasing a part of λ by
+                // one. The coefficient is the number of parts of ν of that size.
+                for a in lambda.iter().copied().chain([0]).dedup() {
+                    let mut nu = lambda.clone();
+                    match nu.iter().position(|&x| x == a) {
+                        Some(i) => nu[i] += 1,
+                        None => nu.push(1),
+                    }
+                    let c = nu.iter().filter(|&&x| x == a + 1).count() as u32;
+                    let idx = partitions.iter().position(|x| *x == nu).unwrap();
+                    result.add_basis_element(idx, c % *p);
+                }
+                ideal.add_vector(result.as_slice());
+                result.set_to_zero();
+            }
+        }
+
+        let basis: Vec<usize> = (0..dim).filter(|&i| ideal.pivots()[i] < 0).collect();
+        let names = basis
+            .iter()
+            .map(|&i| {
+                self.model
+                    .basis_element_to_string(self.thom, &partitions[i])
+            })
+            .collect();
+
+        self.indices.push(
+            partitions
+                .iter()
+                .enumerate()
+                .map(|(i, x)| (x.clone(), i))
+                .collect(),
+        );
+        self.partitions.push(partitions);
+        self.ideal.push(ideal);
+        self.basis.push(basis);
+        names
+    }
+
+    fn act(&self, result: &mut [FpVector], op_deg: i32, op_idx: usize, input_deg: i32) {
+        let s = match self.algebra.steenrod_algebra() {
+            SteenrodAlgebraBorrow::BorrowMilnor(a) => {
+                let elt = a.basis_element_from_index(op_deg, op_idx);
+                self.model.exponents(elt.q_part, &elt.p_part)
+            }
+            SteenrodAlgebraBorrow::BorrowAdem(a) => {
+                // The Bockstein acts trivially since everything is in even degrees
+                let elt = a.basis_element_from_index(op_deg, op_idx);
+                if elt.bocksteins != 0 {
+                    return;
+                }
+                self.model.exponents(0, &[elt.ps[0] as PPartEntry])
+            }
+        };
+        let s = match s {
+            Some(s) => s,
+            None => return,
+        };
+
+        let output_deg = input_deg + op_deg;
+        let input_basis = &self.basis[input_deg];
+        let output_basis = &self.basis[output_deg];
+        let mut products =
+            vec![FpVector::new(self.model.p, self.partitions[output_deg].len()); input_basis.len()];
+        for (output_idx, nu) in self.partitions[output_deg].iter().enumerate() {
+            for (lambda, c) in self.model.coefficients(&s, nu) {
+                let input_idx = self.indices[input_deg][&lambda];
+                if let Ok(i) = input_basis.binary_search(&input_idx) {
+                    products[i].add_basis_element(output_idx, c);
+                }
+            }
+        }
+        for (result, mut product) in result.iter_mut().zip(products) {
+            self.ideal[output_deg].reduce(product.as_slice_mut());
+            for (i, &idx) in output_basis.iter().enumerate() {
+                result.set_entry(i, product.entry(idx));
+            }
+        }
+    }
+}
+
+fn module_name(group: LieGroup, thom: i32) -> String {
+    match thom {
+        0 => format!("B{group}"),
+        1 => format!("M{group}"),
+        _ => format!("B{group}^{{{thom}γ}}"),
+    }
+}
+
+/// Constructs the cohomology of the Thom spectrum of `thom` copies of the universal bundle over
+/// $BG$ as a [`LazyModule`]. When `thom` is zero, this is $H^*(BG_+)$. Here the universal bundle is
+/// the virtual bundle of dimension zero, so the Thom class is in degree 0.
+///
+/// The basis is given by the monomial symmetric functions $m_\lambda$ in the Chern roots (or
+/// their squares for $Sp$ and, at odd primes, $SO$), which are dual to the monomials in the
+/// standard generators of the homology. $BO$ is only available at the prime 2, and at odd primes
+/// $BSO$ has the same cohomology as $BSp$. At the prime 2, $H^*(BSO)$ is the quotient of $H^*(BO)$
+/// by the ideal generated by $w_1$.
+pub fn lazy_classifying_space(
+    algebra: Arc<SteenrodAlgebra>,
+    group: LieGroup,
+    thom: i32,
+) -> anyhow::Result<LazyModule<SteenrodAlgebra>> {
+    let p = algebra.prime();
+    let quotient = group == LieGroup::SO && *p == 2;
+    let model_group = if quotient { LieGroup::O } else { group };
+    let model = SplittingModel::new(p, model_group, thom)
+        .ok_or_else(|| anyhow!("B{} is only supported at the prime 2", group))?;
+
+    let data = Arc::new(LazyClassifyingSpace {
+        algebra: Arc::clone(&algebra),
+        model,
+        thom,
+        partitions: OnceBiVec::new(0),
+        indices: OnceBiVec::new(0),
+        ideal: OnceBiVec::new(0),
+        basis: OnceBiVec::new(0),
+    });
+    let data_ = Arc::clone(&data);
+    Ok(LazyModule::new(
+        algebra,
+        module_name(group, thom),
+        0,
+        move |t| data.compute_basis(t, quotient),
+        move |result, op_deg, op_idx, input_deg| data_.act(result, op_deg, op_idx, input_deg),
+    ))
+}
+
+/// Constructs the [`lazy_classifying_space`] truncated above degree `max`.
+pub fn classifying_space(
+    algebra: Arc<SteenrodAlgebra>,
+    group: LieGroup,
+    thom: i32,
+    max: i32,
+) -> anyhow::Result<FDModule<SteenrodAlgebra>> {
+    Ok(Arc::new(lazy_classifying_space(algebra, group, thom)?).truncate_to_fd_module(max))
+}
+
+#[cfg(feature = "json")]
+#[derive(Deserialize, Debug)]
+struct ClassifyingSpaceSpec {
+    group: String,
+    max: Option<i32>,
+    thom: Option<i32>,
+}
+
+/// Constructs a [`classifying_space`] from a json specification of the form `{"group": "SO",
+/// "max": 20, "thom": 1}`, where `thom` is optional and defaults to 0.
+#[cfg(feature = "json")]
+pub fn classifying_space_from_json(
+    algebra: Arc<SteenrodAlgebra>,
+    json: &Value,
+) -> anyhow::Result<FDModule<SteenrodAlgebra>> {
+    let spec = ClassifyingSpaceSpec::deserialize(json)?;
+    let max = spec.max.ok_or_else(|| anyhow!("Missing max"))?;
+    if max < 0 {
+        return Err(anyhow!("max must be non-negative"));
+    }
+    classifying_space(algebra, spec.group.parse()?, spec.thom.unwrap_or(0), max)
+}
+
+/// Constructs a [`lazy_classifying_space`] from a json specification of the form `{"group": "SO",
+/// "thom": 1}`, where `thom` is optional and defaults to 0. This is the built-in `"classifying
+/// space"` generator of [`LazyModule`]s.
+#[cfg(feature = "json")]
+pub fn lazy_classifying_space_from_json(
+    algebra: Arc<SteenrodAlgebra>,
+    json: &Value,
+) -> anyhow::Result<LazyModule<SteenrodAlgebra>> {
+    let spec = ClassifyingSpaceSpec::deserialize(json)?;
+    lazy_classifying_space(algebra, spec.group.parse()?, spec.thom.unwrap_or(0))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algebra::{AdemAlgebra, MilnorAlgebra};
+    use crate::module::projective_space::tests::check_associativity;
+    use rstest::rstest;
+
+    fn algebras(p: u32) -> [Arc<SteenrodAlgebra>; 2] {
+        let p = ValidPrime::new(p);
+        [
+            Arc::new(SteenrodAlgebra::AdemAlgebra(AdemAlgebra::new(
+                p,
+                *p != 2,
+                false,
+                false,
+            ))),
+            Arc::new(SteenrodAlgebra::MilnorAlgebra(MilnorAlgebra::new(p))),
+        ]
+    }
+
+    #[rstest]
+    #[case(2, LieGroup::O, 0, 12)]
+    #[case(2, LieGroup::O, 1, 12)]
+    #[case(2, LieGroup::SO, 0, 12)]
+    #[case(2, LieGroup::SO, -1, 12)]
+    #[case(2, LieGroup::U, 0, 20)]
+    #[case(2, LieGroup::U, 3, 20)]
+    #[case(2, LieGroup::Sp, 1, 24)]
+    #[case(3, LieGroup::U, 1, 24)]
+    #[case(3, LieGroup::SO, 1, 32)]
+    #[case(3, LieGroup::Sp, -1, 32)]
+    fn test_associativity(
+        #[case] p: u32,
+        #[case] group: LieGroup,
+        #[case] thom: i32,
+        #[case] max: i32,
+    ) {
+        for algebra in algebras(p) {
+            let module = classifying_space(algebra, group, thom, max).unwrap();
+            check_associativity(&module, max);
+        }
+    }
+
+    #[test]
+    fn test_dimensions() {
+        let [_, algebra] = algebras(2);
+        // The number of partitions of n, and the number of partitions of n without 1s
+        let partitions = [1, 1, 2, 3, 5, 7, 11, 15, 22, 30, 42];
+        let bo = classifying_space(Arc::clone(&algebra), LieGroup::O, 0, 10).unwrap();
+        let bso = classifying_space(Arc::clone(&algebra), LieGroup::SO, 0, 10).unwrap();
+        let bu = classifying_space(algebra, LieGroup::U, 0, 10).unwrap();
+        for t in 0..=10 {
+            assert_eq!(bo.dimension(t), partitions[t as usize]);
+            let no_ones =
+                partitions[t as usize] - if t > 0 { partitions[t as usize - 1] } else { 0 };
+            assert_eq!(bso.dimension(t), no_ones);
+            let bu_dim = if t % 2 == 0 {
+                partitions[t as usize / 2]
+            } else {
+                0
+            };
+            assert_eq!(bu.dimension(t), bu_dim);
+        }
+    }
+
+    #[test]
+    fn test_wu_formula() {
+        let [adem, _] = algebras(2);
+        let module = classifying_space(adem, LieGroup::O, 0, 4).unwrap();
+        let p = module.prime();
+
+        // Sq^1 w_2 = w_1 w_2 + w_3 = m_{2,1} and Sq^2 w_2 = w_2^2 = m_{2,2}
+        let w2 = module.basis_element_to_string(2, 1);
+        assert_eq!(w2, "m_{1,1}");
+        for (op_deg, expected) in [(1, "m_{2,1}"), (2, "m_{2,2}")] {
+            let mut result = FpVector::new(p, module.dimension(2 + op_deg));
+            module.act_on_basis(result.as_slice_mut(), 1, op_deg, 0, 2, 1);
+            let terms: Vec<_> = result
+                .iter_nonzero()
+                .map(|(i, _)| module.basis_element_to_string(2 + op_deg, i))
+                .collect();
+            assert_eq!(terms, [expected]);
+        }
+    }
+}
@@ -0,0 +1,161 @@
+use crate::algebra::{
+    Algebra, EilenbergMacLaneAlgebra, EilenbergMacLaneGroup, SteenrodAlgebra,
+    SteenrodAlgebraBorrow, SteenrodAlgebraT,
+};
+use crate::module::LazyModule;
+
+use std::sync::Arc;
+
+#[cfg(feature = "json")]
+use {serde::Deserialize, serde_json::Value};
+
+/// Constructs the cohomology of the Eilenberg–MacLane space $K(\pi, n)$ as a [`LazyModule`],
+/// where the basis is given by the monomials in the generators of [`EilenbergMacLaneAlgebra`]. If
+/// `reduced` is set, this is the reduced cohomology, which starts in degree $n$.
+pub fn eilenberg_maclane_space(
+    algebra: Arc<SteenrodAlgebra>,
+    group: EilenbergMacLaneGroup,
+    n: u32,
+    reduced: bool,
+) -> anyhow::Result<LazyModule<SteenrodAlgebra>> {
+    if n == 0 {
+        return Err(anyhow::anyhow!("n must be positive"));
+    }
+    let ring = Arc::new(EilenbergMacLaneAlgebra::new(algebra.prime(), group, n));
+    let name = match group {
+        EilenbergMacLaneGroup::Z => format!("K(Z, {n})"),
+        EilenbergMacLaneGroup::ZModP => format!("K(Z/{}, {n})", algebra.prime()),
+    };
+    let min_degree = if reduced { n as i32 } else { 0 };
+
+    let ring_ = Arc::clone(&ring);
+    let steenrod = Arc::clone(&algebra);
+    Ok(LazyModule::new(
+        algebra,
+        name,
+        min_degree,
+        move |t| {
+            ring.compute_basis(t);
+            (0..ring.dimension(t))
+                .map(|i| ring.basis_element_to_string(t, i))
+                .collect()
+        },
+        move |result, op_deg, op_idx, input_deg| {
+            // The generators are the Bockstein and the P^{p^i}, or the Sq^{2^i} at p = 2.
+            let (bockstein, power) = match steenrod.steenrod_algebra() {
+                SteenrodAlgebraBorrow::BorrowAdem(a) => {
+                    let elt = a.basis_element_from_index(op_deg, op_idx);
+                    (elt.bocksteins != 0, elt.ps.first().copied().unwrap_or(0))
+                }
+                SteenrodAlgebraBorrow::BorrowMilnor(a) => {
+                    let elt = a.basis_element_from_index(op_deg, op_idx);
+                    // PPartEntry is u8 if the odd-primes feature is disabled
+                    #[allow(clippy::unnecessary_cast)]
+                    let power = elt.p_part.first().map_or(0, |&r| r as u32);
+                    (elt.q_part != 0, power)
+                }
+            };
+            for (i, v) in result.iter_mut().enumerate() {
+                ring_.steenrod_action_on_basis(v.as_slice_mut(), 1, bockstein, power, input_deg, i);
+            }
+        },
+    ))
+}
+
+#[cfg(feature = "json")]
+#[derive(Deserialize, Debug)]
+struct EilenbergMacLaneSpec {
+    group: String,
+    n: u32,
+    #[serde(default)]
+    reduced: bool,
+}
+
+/// Constructs an [`eilenberg_maclane_space`] from a json specification of the form `{"group":
+/// "Z/p", "n": 3, "reduced": true}`, where `group` is `Z` or `Z/p`, and `reduced` is optional and
+/// defaults to false. This is the built-in `"eilenberg maclane space"` generator of
+/// [`LazyModule`]s.
+#[cfg(feature = "json")]
+pub fn eilenberg_maclane_space_from_json(
+    algebra: Arc<SteenrodAlgebra>,
+    json: &Value,
+) -> anyhow::Result<LazyModule<SteenrodAlgebra>> {
+    let spec = EilenbergMacLaneSpec::deserialize(json)?;
+    eilenberg_maclane_space(algebra, spec.group.parse()?, spec.n, spec.reduced)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algebra::{AdemAlgebra, MilnorAlgebra};
+    use crate::module::projective_space::tests::check_associativity;
+    use crate::module::Module;
+    use fp::prime::ValidPrime;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case(2, EilenbergMacLaneGroup::ZModP, 1, 16)]
+    #[case(2, EilenbergMacLaneGroup::ZModP, 2, 16)]
+    #[case(2, EilenbergMacLaneGroup::ZModP, 3, 16)]
+    #[case(2, EilenbergMacLaneGroup::Z, 2, 16)]
+    #[case(2, EilenbergMacLaneGroup::Z, 3, 16)]
+    #[case(3, EilenbergMacLaneGroup::ZModP, 1, 30)]
+    #[case(3, EilenbergMacLaneGroup::ZModP, 2, 30)]
+    #[case(3, EilenbergMacLaneGroup::Z, 3, 30)]
+    fn test_associativity(
+        #[case] p: u32,
+        #[case] group: EilenbergMacLaneGroup,
+        #[case] n: u32,
+        #[case] max: i32,
+    ) {
+        let p = ValidPrime::new(p);
+        for algebra in [
+            SteenrodAlgebra::AdemAlgebra(AdemAlgebra::new(p, *p != 2, false, false)),
+            SteenrodAlgebra::MilnorAlgebra(MilnorAlgebra::new(p)),
+        ] {
+            let module = eilenberg_maclane_space(Arc::new(algebra), group, n, false).unwrap();
+            check_associativity(&module, max);
+        }
+    }
+
+    #[test]
+    fn test_dimensions() {
+        let p = ValidPrime::new(2);
+        let algebra = Arc::new(SteenrodAlgebra::MilnorAlgebra(MilnorAlgebra::new(p)));
+
+        // K(Z/2, 1) = RP^∞ and K(Z, 2) = CP^∞
+        let rp =
+            eilenberg_maclane_space(Arc::clone(&algebra), EilenbergMacLaneGroup::ZModP, 1, true)
+                .unwrap();
+        let cp = eilenberg_maclane_space(Arc::clone(&algebra), EilenbergMacLaneGroup::Z, 2, true)
+            .unwrap();
+        rp.compute_basis(20);
+        cp.compute_basis(20);
+        for t in 1..=20 {
+            assert_eq!(rp.dimension(t), 1);
+            assert_eq!(cp.dimension(t), (t % 2 == 0) as usize);
+        }
+
+        // H^*(K(Z/2, 2)) is polynomial on ι, Sq^1 ι, Sq^2 Sq^1 ι, Sq^4 Sq^2 Sq^1 ι, ... in degrees
+        // 2, 3, 5, 9, ...
+        let k = eilenberg_maclane_space(algebra, EilenbergMacLaneGroup::ZModP, 2, false).unwrap();
+        k.compute_basis(10);
+        let mut expected = vec![0; 11];
+        for a in 0..=5 {
+            for b in 0..=3 {
+                for c in 0..=2 {
+                    for d in 0..=1 {
+                        let t = 2 * a + 3 * b + 5 * c + 9 * d;
+                        if t <= 10 {
+                            expected[t] += 1;
+                        }
+                    }
+                }
+            }
+        }
+        let dimensions: Vec<usize> = (0..=10).map(|t| k.dimension(t)).collect();
+        assert_eq!(dimensions, expected);
+        assert_eq!(k.basis_element_to_string(6, 1), "Sq1ι_2^2");
+        assert_eq!(k.basis_element_to_string(7, 0), "ι_2^2 Sq1ι_2");
+    }
+}
@@ -1,4 +1,4 @@
-use crate::algebra::Algebra;
+use crate::algebra::{Algebra, HopfAlgebra};
 use crate::module::{BoundedModule, Module, ZeroModule};
 use bivec::BiVec;
 use fp::vector::{FpVector, SliceMut};
@@ -446,6 +446,70 @@ impl<A: Algebra> FiniteDimensionalModule<A> {
     }
 }
 
+impl<A: HopfAlgebra> FiniteDimensionalModule<A> {
+    /// The Spanier–Whitehead dual of the module. This is the linear dual with negated degrees, where
+    /// an element $f$ acts by $(af)(x) = (-1)^{|a||f|} f(\chi(a) x)$ and $\chi$ is the antipode of
+    /// the algebra. The basis element dual to `x` is named `x*`.
+    ///
+    /// The action of every basis element is computed directly, so this works for sub-Hopf algebras
+    /// given by profiles as long as the antipode does.
+    pub fn dual(&self) -> Self {
+        let algebra = self.algebra();
+        let p = *self.prime();
+        let min_degree = self.min_degree();
+        let max_degree = self.max_degree();
+
+        let mut graded_dimension = BiVec::with_capacity(-max_degree, -min_degree + 1);
+        for t in -max_degree..=-min_degree {
+            graded_dimension.push(self.dimension(-t));
+        }
+        let mut result = Self::new(
+            Arc::clone(&algebra),
+            format!("D({})", self.name),
+            graded_dimension,
+        );
+        for t in min_degree..=max_degree {
+            for idx in 0..self.dimension(t) {
+                result.set_basis_element_name(-t, idx, format!("{}*", self.gen_names[t][idx]));
+            }
+        }
+
+        let mut value = FpVector::new(self.prime(), 0);
+        for op_deg in 1..=max_degree - min_degree {
+            for op_idx in 0..algebra.dimension(op_deg) {
+                let mut conjugate = FpVector::new(self.prime(), algebra.dimension(op_deg));
+                algebra.antipode_on_basis(conjugate.as_slice_mut(), 1, op_deg, op_idx);
+
+                for t in min_degree..=max_degree - op_deg {
+                    // The sign (-1)^{|a||f|}, where |f| = -(t + op_deg)
+                    let sign = if (op_deg * (t + op_deg)) % 2 == 0 {
+                        1
+                    } else {
+                        p - 1
+                    };
+                    for j in 0..self.dimension(t) {
+                        value.set_scratch_vector_size(self.dimension(t + op_deg));
+                        self.act_by_element_on_basis(
+                            value.as_slice_mut(),
+                            sign,
+                            op_deg,
+                            conjugate.as_slice(),
+                            t,
+                            j,
+                        );
+                        for (i, v) in value.iter_nonzero() {
+                            result
+                                .action_mut(op_deg, op_idx, -t - op_deg, i)
+                                .add_basis_element(j, v);
+                        }
+                    }
+                }
+            }
+        }
+        result
+    }
+}
+
 #[cfg(feature = "json")]
 impl<A: JsonAlgebra + GeneratedAlgebra> FiniteDimensionalModule<A> {
     fn module_gens_from_json(
@@ -852,4 +916,75 @@ mod tests {
         adem_module.set_action_vector(2, 0, 0, 0, &FpVector::from_slice(p, &[1]));
         adem_module.check_validity(0, 2).unwrap();
     }
+
+    /// Check that the dual is a valid module and that $DDM$ is $M$ with $a$ acting by $(-1)^{|a|}
+    /// a$, which is isomorphic to $M$ via $x \mapsto (-1)^{|x|} x$.
+    #[cfg(feature = "json")]
+    fn check_dual(json: serde_json::Value) {
+        use crate::algebra::{AlgebraType, SteenrodAlgebra};
+
+        for algebra_type in [AlgebraType::Adem, AlgebraType::Milnor] {
+            let algebra = Arc::new(SteenrodAlgebra::from_json(&json, algebra_type).unwrap());
+            let p = *algebra.prime();
+            let module = FiniteDimensionalModule::from_json(algebra, &json).unwrap();
+            let dual = module.dual();
+            assert_eq!(dual.min_degree(), -module.max_degree());
+            assert_eq!(dual.max_degree(), -module.min_degree());
+            for input_deg in dual.min_degree()..=dual.max_degree() {
+                for output_deg in input_deg + 1..=dual.max_degree() {
+                    dual.check_validity(input_deg, output_deg).unwrap();
+                }
+            }
+
+            let double_dual = dual.dual();
+            for input_deg in module.min_degree()..=module.max_degree() {
+                for op_deg in 1..=module.max_degree() - input_deg {
+                    let sign = if op_deg % 2 == 0 { 1 } else { p - 1 };
+                    for op_idx in 0..module.algebra().dimension(op_deg) {
+                        for idx in 0..module.dimension(input_deg) {
+                            let mut expected =
+                                module.action(op_deg, op_idx, input_deg, idx).clone();
+                            expected.scale(sign);
+                            assert_eq!(
+                                double_dual.action(op_deg, op_idx, input_deg, idx),
+                                &expected,
+                                "{algebra_type}: {} acting on {}",
+                                module.algebra().basis_element_to_string(op_deg, op_idx),
+                                module.basis_element_to_string(input_deg, idx)
+                            );
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "json")]
+    fn dual_joker() {
+        check_dual(serde_json::json!({
+            "p": 2,
+            "gens": {"x0": 0, "x1": 1, "x2": 2, "x3": 3, "x4": 4},
+            "actions": ["Sq1 x0 = x1", "Sq2 x0 = x2", "Sq2 x1 = x3", "Sq2 x2 = x4", "Sq1 x3 = x4"],
+        }));
+    }
+
+    #[test]
+    #[cfg(all(feature = "json", feature = "odd-primes"))]
+    fn dual_odd_prime() {
+        check_dual(serde_json::json!({
+            "p": 3,
+            "generic": true,
+            "gens": {"x0": 0, "x1": 1, "x4": 4, "x5": 5, "x8": 8, "x9": 9},
+            "actions": [
+                "b x0 = x1",
+                "P1 x0 = x4",
+                "P1 x1 = x5",
+                "b x4 = x5",
+                "P1 x4 = 2 x8",
+                "P1 x5 = 2 x9",
+                "b x8 = x9",
+            ],
+        }));
+    }
 }
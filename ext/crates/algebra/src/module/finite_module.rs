@@ -1,5 +1,5 @@
 use crate::algebra::{Algebra, SteenrodAlgebra};
-use crate::module::{FDModule, FPModule, Module, RealProjectiveSpace};
+use crate::module::{FDModule, FPModule, LazyModule, Module, ProjectiveSpace, RealProjectiveSpace};
 use fp::prime::ValidPrime;
 use fp::vector::{FpVector, Slice, SliceMut};
 use std::sync::Arc;
@@ -12,6 +12,8 @@ pub enum FiniteModule {
     FDModule(FDModule<SteenrodAlgebra>),
     FPModule(FPModule<SteenrodAlgebra>),
     RealProjectiveSpace(RealProjectiveSpace<SteenrodAlgebra>),
+    ProjectiveSpace(ProjectiveSpace<SteenrodAlgebra>),
+    LazyModule(LazyModule<SteenrodAlgebra>),
 }
 
 macro_rules! dispatch {
@@ -22,6 +24,8 @@ macro_rules! dispatch {
                 FiniteModule::FDModule(m) => m.$method($($arg),*),
                 FiniteModule::FPModule(m) => m.$method($($arg),*),
                 FiniteModule::RealProjectiveSpace(m) => m.$method($($arg),*),
+                FiniteModule::ProjectiveSpace(m) => m.$method($($arg),*),
+                FiniteModule::LazyModule(m) => m.$method($($arg),*),
             }
         }
         dispatch!{$($tail)*}
@@ -63,7 +67,7 @@ impl Module for FiniteModule {
 
     /// Whether act_on_basis_borrow is available.
     fn borrow_output(&self) -> bool {
-        self.is_fd_module()
+        self.is_fd_module() || self.is_lazy_module()
     }
 
     fn act_on_basis_borrow(
@@ -77,6 +81,9 @@ impl Module for FiniteModule {
             FiniteModule::FDModule(m) => {
                 m.act_on_basis_borrow(op_degree, op_index, mod_degree, mod_index)
             }
+            FiniteModule::LazyModule(m) => {
+                m.act_on_basis_borrow(op_degree, op_index, mod_degree, mod_index)
+            }
             _ => unimplemented!(),
         }
     }
@@ -97,6 +104,16 @@ impl From<RealProjectiveSpace<SteenrodAlgebra>> for FiniteModule {
         Self::RealProjectiveSpace(m)
     }
 }
+impl From<ProjectiveSpace<SteenrodAlgebra>> for FiniteModule {
+    fn from(m: ProjectiveSpace<SteenrodAlgebra>) -> Self {
+        Self::ProjectiveSpace(m)
+    }
+}
+impl From<LazyModule<SteenrodAlgebra>> for FiniteModule {
+    fn from(m: LazyModule<SteenrodAlgebra>) -> Self {
+        Self::LazyModule(m)
+    }
+}
 
 #[cfg(feature = "json")]
 impl FiniteModule {
@@ -108,6 +125,18 @@ impl FiniteModule {
             Some("real projective space") => Ok(FiniteModule::from(
                 RealProjectiveSpace::from_json(algebra, json)?,
             )),
+            Some("complex projective space" | "quaternionic projective space" | "lens space") => {
+                Ok(FiniteModule::from(ProjectiveSpace::from_json(
+                    algebra, json,
+                )?))
+            }
+            Some("classifying space") => Ok(FiniteModule::from(
+                crate::module::classifying_space_from_json(algebra, json)?,
+            )),
+            Some("brown gitler module") => Ok(FiniteModule::from(
+                crate::module::brown_gitler_module_from_json(algebra, json)?,
+            )),
+            Some("lazy module") => Ok(FiniteModule::from(LazyModule::from_json(algebra, json)?)),
             Some("finite dimensional module") => {
                 Ok(FiniteModule::from(FDModule::from_json(algebra, json)?))
             }
@@ -128,6 +157,8 @@ impl FiniteModule {
             Self::FDModule(_) => "finite dimensional module",
             Self::FPModule(_) => "finitely presented module",
             Self::RealProjectiveSpace(_) => "real projective space",
+            Self::ProjectiveSpace(m) => m.kind.type_(),
+            Self::LazyModule(_) => "lazy module",
         }
     }
 
@@ -135,6 +166,14 @@ impl FiniteModule {
         matches!(self, FiniteModule::RealProjectiveSpace(_))
     }
 
+    pub fn is_projective_space(&self) -> bool {
+        matches!(self, FiniteModule::ProjectiveSpace(_))
+    }
+
+    pub fn is_lazy_module(&self) -> bool {
+        matches!(self, FiniteModule::LazyModule(_))
+    }
+
     pub fn is_fp_module(&self) -> bool {
         matches!(self, FiniteModule::FPModule(_))
     }
@@ -150,6 +189,20 @@ impl FiniteModule {
         }
     }
 
+    pub fn into_projective_space(self) -> Option<ProjectiveSpace<SteenrodAlgebra>> {
+        match self {
+            FiniteModule::ProjectiveSpace(m) => Some(m),
+            _ => None,
+        }
+    }
+
+    pub fn into_lazy_module(self) -> Option<LazyModule<SteenrodAlgebra>> {
+        match self {
+            FiniteModule::LazyModule(m) => Some(m),
+            _ => None,
+        }
+    }
+
     pub fn into_fp_module(self) -> Option<FPModule<SteenrodAlgebra>> {
         match self {
             FiniteModule::FPModule(m) => Some(m),
@@ -171,6 +224,20 @@ impl FiniteModule {
         }
     }
 
+    pub fn as_projective_space(&self) -> Option<&ProjectiveSpace<SteenrodAlgebra>> {
+        match self {
+            FiniteModule::ProjectiveSpace(m) => Some(m),
+            _ => None,
+        }
+    }
+
+    pub fn as_lazy_module(&self) -> Option<&LazyModule<SteenrodAlgebra>> {
+        match self {
+            FiniteModule::LazyModule(m) => Some(m),
+            _ => None,
+        }
+    }
+
     pub fn as_fp_module(&self) -> Option<&FPModule<SteenrodAlgebra>> {
         match self {
             FiniteModule::FPModule(m) => Some(m),
@@ -191,7 +258,8 @@ impl crate::module::BoundedModule for FiniteModule {
         match self {
             FiniteModule::FDModule(m) => m.max_degree(),
             FiniteModule::RealProjectiveSpace(m) => m.max_degree(),
-            FiniteModule::FPModule(_) => i32::MAX,
+            FiniteModule::ProjectiveSpace(m) => m.max_degree(),
+            FiniteModule::FPModule(_) | FiniteModule::LazyModule(_) => i32::MAX,
         }
     }
 }
@@ -0,0 +1,111 @@
+use std::sync::Arc;
+
+use bivec::BiVec;
+
+use crate::algebra::Field;
+use crate::module::block_structure::BlockStructure;
+use crate::module::{FreeModule, Module, RightModule};
+use fp::vector::SliceMut;
+use once::OnceBiVec;
+
+/// The vector space $N \otimes_A F$, where $N$ is a right module and $F$ is a free (left) module.
+/// If $F$ has generators $g_i$, then this is isomorphic to $\bigoplus_i \Sigma^{|g_i|} N$, and the
+/// basis in each degree is divided into one block for each generator of $F$.
+pub struct FreeTensorModule<N: RightModule> {
+    algebra: Arc<Field>,
+    left: Arc<N>,
+    right: Arc<FreeModule<N::Algebra>>,
+    pub block_structures: OnceBiVec<BlockStructure>,
+}
+
+impl<N: RightModule> std::fmt::Display for FreeTensorModule<N> {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{} ⊗ {}", self.left, self.right)
+    }
+}
+
+impl<N: RightModule> FreeTensorModule<N> {
+    pub fn new(left: Arc<N>, right: Arc<FreeModule<N::Algebra>>) -> Self {
+        let algebra = Arc::new(Field::new(left.prime()));
+        let min_degree = left.min_degree() + right.min_degree();
+        Self {
+            algebra,
+            left,
+            right,
+            block_structures: OnceBiVec::new(min_degree),
+        }
+    }
+
+    pub fn left(&self) -> Arc<N> {
+        Arc::clone(&self.left)
+    }
+
+    pub fn right(&self) -> Arc<FreeModule<N::Algebra>> {
+        Arc::clone(&self.right)
+    }
+}
+
+impl<N: RightModule> Module for FreeTensorModule<N> {
+    type Algebra = Field;
+
+    fn algebra(&self) -> Arc<Self::Algebra> {
+        Arc::clone(&self.algebra)
+    }
+
+    fn min_degree(&self) -> i32 {
+        self.block_structures.min_degree()
+    }
+
+    fn max_computed_degree(&self) -> i32 {
+        self.block_structures.max_degree()
+    }
+
+    /// This requires the generators of the free module to have been computed through `degree -
+    /// left.min_degree()`.
+    fn compute_basis(&self, degree: i32) {
+        let right_min = self.right.min_degree();
+        self.left.compute_basis(degree - right_min);
+        for d in self.block_structures.max_degree() + 1..=degree {
+            let max_gen_degree = d - self.left.min_degree();
+            let mut block_sizes = BiVec::with_capacity(right_min, max_gen_degree + 1);
+            for gen_deg in right_min..=max_gen_degree {
+                let num_gens = self.right.number_of_gens_in_degree(gen_deg);
+                block_sizes.push(vec![self.left.dimension(d - gen_deg); num_gens]);
+            }
+            self.block_structures
+                .push(BlockStructure::new(&block_sizes));
+        }
+    }
+
+    fn dimension(&self, degree: i32) -> usize {
+        self.block_structures[degree].total_dimension
+    }
+
+    fn act_on_basis(
+        &self,
+        mut result: SliceMut,
+        coeff: u32,
+        op_degree: i32,
+        op_index: usize,
+        _mod_degree: i32,
+        mod_index: usize,
+    ) {
+        assert!(op_degree == 0);
+        assert!(op_index == 0);
+        result.add_basis_element(mod_index, coeff);
+    }
+
+    fn basis_element_to_string(&self, degree: i32, idx: usize) -> String {
+        let gen_basis_elt = self.block_structures[degree].index_to_generator_basis_elt(idx);
+        let gen_deg = gen_basis_elt.generator_degree;
+        let gen_mod_idx =
+            self.right
+                .operation_generator_to_index(0, 0, gen_deg, gen_basis_elt.generator_index);
+        format!(
+            "{} ⊗ {}",
+            self.left
+                .basis_element_to_string(degree - gen_deg, gen_basis_elt.basis_index),
+            self.right.basis_element_to_string(gen_deg, gen_mod_idx)
+        )
+    }
+}
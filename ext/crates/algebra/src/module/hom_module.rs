@@ -48,7 +48,8 @@ impl<M: BoundedModule> HomModule<M> {
     pub fn element_to_homomorphism(&self, degree: i32, x: Slice) -> FreeModuleHomomorphism<M> {
         let result =
             FreeModuleHomomorphism::new(Arc::clone(&self.source), Arc::clone(&self.target), degree);
-        let min_nonzero_degree = degree + self.target.min_degree();
+        let min_nonzero_degree =
+            std::cmp::max(degree + self.target.min_degree(), self.source.min_degree());
         let max_nonzero_degree = degree + self.target.max_degree();
         result.extend_by_zero(min_nonzero_degree - 1);
         let mut used_entries = 0;
@@ -122,13 +123,13 @@ impl<M: BoundedModule> Module for HomModule<M> {
     }
 
     fn max_computed_degree(&self) -> i32 {
-        unimplemented!()
+        self.block_structures.max_degree()
     }
 
     fn compute_basis(&self, degree: i32) {
         // assertion about source:
         // self.source.compute_basis(degree + self.target.max_degree());
-        for d in self.min_degree()..=degree {
+        for d in self.block_structures.max_degree() + 1..=degree {
             let mut block_sizes = BiVec::with_capacity(
                 self.target.min_degree() + d,
                 self.target.max_degree() + d + 1,
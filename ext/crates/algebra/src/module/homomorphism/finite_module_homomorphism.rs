@@ -139,13 +139,13 @@ impl<M: SteenrodModule> ZeroHomomorphism<FiniteModule, M> for FiniteModuleHomomo
                 Arc::clone(&target),
                 degree_shift,
             )),
-            FiniteModule::RealProjectiveSpace(_) => {
-                FMHI::RP(GenericZeroHomomorphism::zero_homomorphism(
-                    Arc::clone(&source),
-                    Arc::clone(&target),
-                    degree_shift,
-                ))
-            }
+            FiniteModule::RealProjectiveSpace(_)
+            | FiniteModule::ProjectiveSpace(_)
+            | FiniteModule::LazyModule(_) => FMHI::RP(GenericZeroHomomorphism::zero_homomorphism(
+                Arc::clone(&source),
+                Arc::clone(&target),
+                degree_shift,
+            )),
             FiniteModule::FPModule(_) => FMHI::FP(FPModuleHomomorphism::zero_homomorphism(
                 Arc::clone(&source),
                 Arc::clone(&target),
@@ -169,6 +169,12 @@ impl IdentityHomomorphism<FiniteModule> for FiniteModuleHomomorphism<FiniteModul
             FiniteModule::RealProjectiveSpace(_) => {
                 panic!("Identity morphism not supported for RealProjectiveSpace")
             }
+            FiniteModule::ProjectiveSpace(_) => {
+                panic!("Identity morphism not supported for ProjectiveSpace")
+            }
+            FiniteModule::LazyModule(_) => {
+                panic!("Identity morphism not supported for LazyModule")
+            }
             FiniteModule::FPModule(_) => FMHI::FP(FPModuleHomomorphism::identity_homomorphism(
                 Arc::clone(&source),
             )),
@@ -0,0 +1,121 @@
+use std::sync::Arc;
+
+use crate::module::block_structure::BlockStart;
+use crate::module::homomorphism::{FreeModuleHomomorphism, ModuleHomomorphism};
+use crate::module::{FreeModule, FreeTensorModule, Module, RightModule};
+use fp::matrix::{QuasiInverse, Subspace};
+use fp::vector::SliceMut;
+use once::OnceBiVec;
+
+/// Given a map `map`: F -> G of free modules and `source` = N ⊗ F, `target` = N ⊗ G, produce the
+/// induced map N ⊗ `map`: N ⊗ F -> N ⊗ G.
+pub struct FreeTensorHomomorphism<N: RightModule> {
+    source: Arc<FreeTensorModule<N>>,
+    target: Arc<FreeTensorModule<N>>,
+    map: Arc<FreeModuleHomomorphism<FreeModule<N::Algebra>>>,
+    images: OnceBiVec<Subspace>,
+    kernels: OnceBiVec<Subspace>,
+    quasi_inverses: OnceBiVec<QuasiInverse>,
+}
+
+impl<N: RightModule> FreeTensorHomomorphism<N> {
+    pub fn new(
+        source: Arc<FreeTensorModule<N>>,
+        target: Arc<FreeTensorModule<N>>,
+        map: Arc<FreeModuleHomomorphism<FreeModule<N::Algebra>>>,
+    ) -> Self {
+        let min_degree = source.min_degree();
+        Self {
+            source,
+            target,
+            map,
+            images: OnceBiVec::new(min_degree),
+            kernels: OnceBiVec::new(min_degree),
+            quasi_inverses: OnceBiVec::new(min_degree),
+        }
+    }
+}
+
+impl<N: RightModule> ModuleHomomorphism for FreeTensorHomomorphism<N> {
+    type Source = FreeTensorModule<N>;
+    type Target = FreeTensorModule<N>;
+
+    fn source(&self) -> Arc<Self::Source> {
+        Arc::clone(&self.source)
+    }
+
+    fn target(&self) -> Arc<Self::Target> {
+        Arc::clone(&self.target)
+    }
+
+    fn degree_shift(&self) -> i32 {
+        self.map.degree_shift()
+    }
+
+    fn min_degree(&self) -> i32 {
+        self.source().min_degree()
+    }
+
+    /// The basis element $n \otimes g$ is sent to $\sum_i (n \cdot a_i) \otimes g_i$, where
+    /// $\mathrm{map}(g) = \sum_i a_i g_i$.
+    fn apply_to_basis_element(
+        &self,
+        mut result: SliceMut,
+        coeff: u32,
+        input_degree: i32,
+        input_idx: usize,
+    ) {
+        let gen_basis_elt =
+            self.source.block_structures[input_degree].index_to_generator_basis_elt(input_idx);
+        let gen_deg = gen_basis_elt.generator_degree;
+        let mod_deg = input_degree - gen_deg;
+        let mod_idx = gen_basis_elt.basis_index;
+
+        let output_degree = input_degree - self.degree_shift();
+        let free_target = self.map.target();
+        let left = self.source.left();
+        let p = *self.prime();
+
+        let dx = self.map.output(gen_deg, gen_basis_elt.generator_index);
+        for (i, v) in dx.iter_nonzero() {
+            let opgen = free_target.index_to_op_gen(gen_deg - self.degree_shift(), i);
+            let BlockStart {
+                block_start_index,
+                block_size,
+            } = self.target.block_structures[output_degree]
+                .generator_to_block(opgen.generator_degree, opgen.generator_index);
+            if *block_size == 0 {
+                continue;
+            }
+            left.right_act_on_basis(
+                result.slice_mut(*block_start_index, *block_start_index + block_size),
+                (coeff * v) % p,
+                mod_deg,
+                mod_idx,
+                opgen.operation_degree,
+                opgen.operation_index,
+            );
+        }
+    }
+
+    fn compute_auxiliary_data_through_degree(&self, degree: i32) {
+        self.kernels.extend(degree, |i| {
+            let (image, kernel, qi) = self.auxiliary_data(i);
+            self.images.push_checked(image, i);
+            self.quasi_inverses.push_checked(qi, i);
+            kernel
+        });
+    }
+
+    fn quasi_inverse(&self, degree: i32) -> Option<&QuasiInverse> {
+        self.quasi_inverses.get(degree)
+    }
+
+    fn kernel(&self, degree: i32) -> Option<&Subspace> {
+        self.kernels.get(degree)
+    }
+
+    fn image(&self, degree: i32) -> Option<&Subspace> {
+        self.images.get(degree)
+    }
+}
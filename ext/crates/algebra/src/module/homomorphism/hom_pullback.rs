@@ -66,9 +66,11 @@ impl<M: BoundedModule> ModuleHomomorphism for HomPullback<M> {
         fn_degree: i32,
         fn_idx: usize,
     ) {
-        println!("fn_deg : {}, fn_idx : {}", fn_degree, fn_idx);
         let target_module = self.target.target();
         for out_deg in target_module.min_degree()..=target_module.max_degree() {
+            if target_module.dimension(out_deg) == 0 {
+                continue;
+            }
             let x_degree = fn_degree + out_deg;
             let num_gens = self.map.source().number_of_gens_in_degree(x_degree);
             for i in 0..num_gens {
@@ -76,8 +78,8 @@ impl<M: BoundedModule> ModuleHomomorphism for HomPullback<M> {
                 let BlockStart {
                     block_start_index,
                     block_size,
-                } = self.source.block_structures[fn_degree].generator_to_block(x_degree, i);
-                self.target.evaluate_basis_map_on_element(
+                } = self.target.block_structures[fn_degree].generator_to_block(x_degree, i);
+                self.source.evaluate_basis_map_on_element(
                     result.slice_mut(*block_start_index, *block_start_index + block_size),
                     coeff,
                     fn_degree,
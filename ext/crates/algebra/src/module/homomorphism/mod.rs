@@ -12,6 +12,7 @@ mod bounded_module_homomorphism;
 mod finite_module_homomorphism;
 mod fp_module_homomorphism;
 mod free_module_homomorphism;
+mod free_tensor_homomorphism;
 mod generic_zero_homomorphism;
 mod hom_pullback;
 mod quotient_homomorphism;
@@ -21,6 +22,7 @@ pub use bounded_module_homomorphism::BoundedModuleHomomorphism;
 pub use finite_module_homomorphism::FiniteModuleHomomorphism;
 pub use fp_module_homomorphism::{FPModuleHomomorphism, FPModuleT};
 pub use free_module_homomorphism::FreeModuleHomomorphism;
+pub use free_tensor_homomorphism::FreeTensorHomomorphism;
 pub use generic_zero_homomorphism::GenericZeroHomomorphism;
 pub use hom_pullback::HomPullback;
 pub use quotient_homomorphism::{QuotientHomomorphism, QuotientHomomorphismSource};
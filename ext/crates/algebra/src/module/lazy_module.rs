@@ -0,0 +1,310 @@
+use crate::algebra::{Algebra, GeneratedAlgebra};
+use crate::module::Module;
+use bivec::BiVec;
+use fp::vector::{FpVector, SliceMut};
+use once::OnceBiVec;
+
+use std::sync::{Arc, Mutex};
+
+#[cfg(feature = "json")]
+use {
+    crate::algebra::SteenrodAlgebra, anyhow::anyhow, serde_json::Value, std::collections::BTreeMap,
+};
+
+type BasisFn = dyn Fn(i32) -> Vec<String> + Send + Sync;
+type ActionFn = dyn Fn(&mut [FpVector], i32, usize, i32) + Send + Sync;
+
+/// A bounded below module whose structure is computed degree by degree by user-supplied
+/// callbacks, and memoized as it is computed. This is useful for modules that are infinite and not
+/// of a simple closed form, such as $H^*(BSO)$.
+///
+/// The module is specified by two callbacks:
+///  * `basis` takes a degree and returns the names of the basis elements in that degree. It is
+///    called on each degree in increasing order.
+///  * `action` takes a list of zero vectors `result`, an operation `(op_degree, op_index)` and an
+///    `input_degree`, and sets `result[i]` to the action of the operation on the `i`th basis
+///    element of degree `input_degree`. It is only called when `op_index` is a generator of the
+///    algebra, and only after `basis` has been called on the degree `op_degree + input_degree`.
+///    The actions of the other basis elements are computed by decomposing them into generators.
+pub struct LazyModule<A: Algebra> {
+    algebra: Arc<A>,
+    name: String,
+    min_degree: i32,
+    basis_fn: Box<BasisFn>,
+    action_fn: Box<ActionFn>,
+    lock: Mutex<()>,
+    /// The names of the basis elements in each degree.
+    basis: OnceBiVec<Vec<String>>,
+    /// `actions[t][s][op_idx][idx]` is the action of the `op_idx`th basis element of the algebra
+    /// on the `idx`th basis element in degree `s < t`.
+    actions: OnceBiVec<BiVec<Vec<Vec<FpVector>>>>,
+    /// The json specification this module was constructed from, if any.
+    #[cfg(feature = "json")]
+    pub(crate) spec: Option<Value>,
+}
+
+impl<A: Algebra> std::fmt::Display for LazyModule<A> {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}", self.name)
+    }
+}
+
+impl<A: Algebra> PartialEq for LazyModule<A> {
+    #[cfg(feature = "json")]
+    fn eq(&self, other: &Self) -> bool {
+        std::ptr::eq(self, other) || (self.spec.is_some() && self.spec == other.spec)
+    }
+
+    #[cfg(not(feature = "json"))]
+    fn eq(&self, other: &Self) -> bool {
+        std::ptr::eq(self, other)
+    }
+}
+
+impl<A: Algebra> Eq for LazyModule<A> {}
+
+impl<A: Algebra> LazyModule<A> {
+    pub fn new(
+        algebra: Arc<A>,
+        name: String,
+        min_degree: i32,
+        basis: impl Fn(i32) -> Vec<String> + Send + Sync + 'static,
+        action: impl Fn(&mut [FpVector], i32, usize, i32) + Send + Sync + 'static,
+    ) -> Self {
+        Self {
+            algebra,
+            name,
+            min_degree,
+            basis_fn: Box::new(basis),
+            action_fn: Box::new(action),
+            lock: Mutex::new(()),
+            basis: OnceBiVec::new(min_degree),
+            actions: OnceBiVec::new(min_degree),
+            #[cfg(feature = "json")]
+            spec: None,
+        }
+    }
+}
+
+impl<A: GeneratedAlgebra> LazyModule<A> {
+    /// Computes the action of everything landing in degree `t`, assuming it has been computed for
+    /// all smaller degrees.
+    fn compute_actions(&self, t: i32) -> BiVec<Vec<Vec<FpVector>>> {
+        let p = self.prime();
+        let dim = self.dimension(t);
+
+        // We fill this in by decreasing input degree, so that when we decompose an operation, the
+        // action of the second factor is already known.
+        let mut table: Vec<Vec<Vec<FpVector>>> = vec![Vec::new(); (t - self.min_degree) as usize];
+        for input_deg in (self.min_degree..t).rev() {
+            let op_deg = t - input_deg;
+            let input_dim = self.dimension(input_deg);
+            let mut actions: Vec<Vec<FpVector>> = (0..self.algebra.dimension(op_deg))
+                .map(|_| vec![FpVector::new(p, dim); input_dim])
+                .collect();
+
+            if dim > 0 && input_dim > 0 {
+                let generators = self.algebra.generators(op_deg);
+                for &op_idx in &generators {
+                    (self.action_fn)(&mut actions[op_idx], op_deg, op_idx, input_deg);
+                }
+                for (op_idx, output) in actions.iter_mut().enumerate() {
+                    if generators.contains(&op_idx) {
+                        continue;
+                    }
+                    let decomposition = self.algebra.decompose_basis_element(op_deg, op_idx);
+                    for (coef, (_, idx_1), (deg_2, idx_2)) in decomposition {
+                        let middle_deg = input_deg + deg_2;
+                        let first = &table[(middle_deg - self.min_degree) as usize][idx_1];
+                        let second = &self.actions[middle_deg][input_deg][idx_2];
+                        for (result, intermediate) in output.iter_mut().zip(second) {
+                            for (i, c) in intermediate.iter_nonzero() {
+                                result.add(&first[i], (coef * c) % *p);
+                            }
+                        }
+                    }
+                }
+            }
+            table[(input_deg - self.min_degree) as usize] = actions;
+        }
+        BiVec::from_vec(self.min_degree, table)
+    }
+}
+
+impl<A: GeneratedAlgebra> Module for LazyModule<A> {
+    type Algebra = A;
+
+    fn algebra(&self) -> Arc<A> {
+        Arc::clone(&self.algebra)
+    }
+
+    fn min_degree(&self) -> i32 {
+        self.min_degree
+    }
+
+    fn compute_basis(&self, degree: i32) {
+        self.algebra.compute_basis(degree - self.min_degree);
+
+        let _lock = self.lock.lock().unwrap();
+        for t in self.basis.len()..=degree {
+            self.basis.push((self.basis_fn)(t));
+            self.actions.push(self.compute_actions(t));
+        }
+    }
+
+    fn max_computed_degree(&self) -> i32 {
+        self.basis.max_degree()
+    }
+
+    fn dimension(&self, degree: i32) -> usize {
+        if degree < self.min_degree {
+            return 0;
+        }
+        self.basis[degree].len()
+    }
+
+    fn act_on_basis(
+        &self,
+        mut result: SliceMut,
+        coeff: u32,
+        op_degree: i32,
+        op_index: usize,
+        mod_degree: i32,
+        mod_index: usize,
+    ) {
+        assert!(op_index < self.algebra().dimension(op_degree));
+        assert!(mod_index < self.dimension(mod_degree));
+        if op_degree == 0 {
+            // We assume our algebras are connected so just add input to output.
+            result.add_basis_element(mod_index, coeff);
+            return;
+        }
+        let output = self.act_on_basis_borrow(op_degree, op_index, mod_degree, mod_index);
+        result.add(output.as_slice(), coeff);
+    }
+
+    fn basis_element_to_string(&self, degree: i32, idx: usize) -> String {
+        self.basis[degree][idx].clone()
+    }
+
+    fn borrow_output(&self) -> bool {
+        true
+    }
+
+    fn act_on_basis_borrow(
+        &self,
+        op_degree: i32,
+        op_index: usize,
+        mod_degree: i32,
+        mod_index: usize,
+    ) -> &FpVector {
+        &self.actions[op_degree + mod_degree][mod_degree][op_index][mod_index]
+    }
+}
+
+/// A function that constructs a [`LazyModule`] from its json specification. This is registered
+/// under a name via [`register_lazy_module`], and the module is then specified by `{"type": "lazy
+/// module", "generator": name, ...}`, where the remaining fields are passed to the generator.
+#[cfg(feature = "json")]
+pub type LazyModuleGenerator =
+    fn(Arc<SteenrodAlgebra>, &Value) -> anyhow::Result<LazyModule<SteenrodAlgebra>>;
+
+#[cfg(feature = "json")]
+static GENERATORS: Mutex<BTreeMap<String, LazyModuleGenerator>> = Mutex::new(BTreeMap::new());
+
+/// Registers a [`LazyModuleGenerator`] under the name `name`, replacing any existing generator of
+/// the same name. The built-in generators cannot be replaced.
+#[cfg(feature = "json")]
+pub fn register_lazy_module(name: &str, generator: LazyModuleGenerator) {
+    GENERATORS
+        .lock()
+        .unwrap()
+        .insert(name.to_string(), generator);
+}
+
+#[cfg(feature = "json")]
+fn lazy_module_generator(name: &str) -> Option<LazyModuleGenerator> {
+    match name {
+        "classifying space" => Some(crate::module::lazy_classifying_space_from_json),
+        "eilenberg maclane space" => Some(crate::module::eilenberg_maclane_space_from_json),
+        _ => GENERATORS.lock().unwrap().get(name).copied(),
+    }
+}
+
+#[cfg(feature = "json")]
+impl LazyModule<SteenrodAlgebra> {
+    pub fn from_json(algebra: Arc<SteenrodAlgebra>, json: &Value) -> anyhow::Result<Self> {
+        let name = json["generator"]
+            .as_str()
+            .ok_or_else(|| anyhow!("Missing generator"))?;
+        let generator =
+            lazy_module_generator(name).ok_or_else(|| anyhow!("Unknown generator: {}", name))?;
+        let mut module = generator(algebra, json)?;
+        module.spec = Some(json.clone());
+        Ok(module)
+    }
+
+    /// Writes the specification the module was constructed from. This panics if the module was
+    /// not constructed by [`LazyModule::from_json`], since there is no way to serialize the
+    /// callbacks.
+    pub fn to_json(&self, json: &mut Value) {
+        let spec = self
+            .spec
+            .as_ref()
+            .expect("Cannot serialize lazy module without a generator");
+        for (key, value) in spec.as_object().unwrap() {
+            json[key] = value.clone();
+        }
+        json["name"] = Value::String(self.name.clone());
+        json["type"] = Value::from("lazy module");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algebra::{AdemAlgebra, MilnorAlgebra, SteenrodAlgebra};
+    use crate::module::projective_space::tests::check_associativity;
+    use crate::module::RealProjectiveSpace;
+    use fp::prime::{Binomial, ValidPrime};
+
+    #[test]
+    fn test_lazy_rp() {
+        let p = ValidPrime::new(2);
+        for algebra in [
+            SteenrodAlgebra::AdemAlgebra(AdemAlgebra::new(p, false, false, false)),
+            SteenrodAlgebra::MilnorAlgebra(MilnorAlgebra::new(p)),
+        ] {
+            let algebra = Arc::new(algebra);
+            // Sq^k x^n = binom(n, k) x^{n + k}, where we only need the generators Sq^{2^i}
+            let lazy = LazyModule::new(
+                Arc::clone(&algebra),
+                "RP_1".to_string(),
+                1,
+                |t| vec![format!("x^{t}")],
+                |result, op_deg, _, input_deg| {
+                    result[0].set_entry(
+                        0,
+                        i32::binomial(ValidPrime::new(2), input_deg, op_deg) as u32,
+                    )
+                },
+            );
+            let rp = RealProjectiveSpace::new(Arc::clone(&algebra), 1, None, false);
+            lazy.compute_basis(20);
+            rp.compute_basis(20);
+            check_associativity(&lazy, 20);
+
+            for input_deg in 1..20 {
+                for op_deg in 1..20 - input_deg {
+                    for op_idx in 0..algebra.dimension(op_deg) {
+                        let mut a = FpVector::new(p, 1);
+                        let mut b = FpVector::new(p, 1);
+                        lazy.act_on_basis(a.as_slice_mut(), 1, op_deg, op_idx, input_deg, 0);
+                        rp.act_on_basis(b.as_slice_mut(), 1, op_deg, op_idx, input_deg, 0);
+                        assert_eq!(a, b);
+                    }
+                }
+            }
+        }
+    }
+}
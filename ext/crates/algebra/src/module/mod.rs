@@ -1,9 +1,16 @@
 mod bounded_module;
+mod brown_gitler;
+mod classifying_space;
+mod eilenberg_maclane_space;
 mod finite_dimensional_module;
 mod finite_module;
 mod finitely_presented_module;
 mod free_module;
+mod free_tensor_module;
+mod lazy_module;
 mod module_trait;
+mod projective_space;
+mod right_module;
 mod rpn;
 mod zero_module;
 
@@ -18,11 +25,26 @@ pub mod block_structure;
 pub mod homomorphism;
 
 pub use bounded_module::BoundedModule;
+pub use brown_gitler::brown_gitler_module;
+#[cfg(feature = "json")]
+pub use brown_gitler::brown_gitler_module_from_json;
+pub use classifying_space::{classifying_space, lazy_classifying_space, LieGroup};
+#[cfg(feature = "json")]
+pub use classifying_space::{classifying_space_from_json, lazy_classifying_space_from_json};
+pub use eilenberg_maclane_space::eilenberg_maclane_space;
+#[cfg(feature = "json")]
+pub use eilenberg_maclane_space::eilenberg_maclane_space_from_json;
 pub use finite_dimensional_module::FiniteDimensionalModule as FDModule;
 pub use finite_module::FiniteModule;
 pub use finitely_presented_module::FinitelyPresentedModule as FPModule;
 pub use free_module::{FreeModule, OperationGeneratorPair};
+pub use free_tensor_module::FreeTensorModule;
+pub use lazy_module::LazyModule;
+#[cfg(feature = "json")]
+pub use lazy_module::{register_lazy_module, LazyModuleGenerator};
 pub use module_trait::{Module, ModuleFailedRelationError};
+pub use projective_space::{ProjectiveSpace, ProjectiveSpaceKind};
+pub use right_module::RightModule;
 pub use rpn::RealProjectiveSpace;
 pub use zero_module::ZeroModule;
 
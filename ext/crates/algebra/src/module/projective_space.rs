@@ -0,0 +1,452 @@
+use crate::algebra::{
+    adem_algebra::AdemBasisElement, milnor_algebra::MilnorBasisElement, AdemAlgebra, Algebra,
+    MilnorAlgebra, SteenrodAlgebraBorrow, SteenrodAlgebraT,
+};
+use crate::module::{BoundedModule, Module, ZeroModule};
+use fp::prime::{Binomial, ValidPrime};
+use fp::vector::SliceMut;
+
+use std::sync::Arc;
+
+#[cfg(feature = "json")]
+use {anyhow::anyhow, serde::Deserialize, serde_json::Value};
+
+/// The projective spaces whose cohomology is (a subquotient of) a polynomial algebra on a single
+/// even dimensional class, possibly tensored with an exterior class.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ProjectiveSpaceKind {
+    /// $\mathbb{CP}^\infty$, whose cohomology is $\mathbb{F}_p[y]$ with $|y| = 2$.
+    Complex,
+    /// $\mathbb{HP}^\infty$, whose cohomology is $\mathbb{F}_p[y^2] \subseteq \mathbb{F}_p[y]$.
+    Quaternionic,
+    /// $B\mathbb{Z}/p$ for $p$ odd, whose cohomology is $\Lambda[x] \otimes \mathbb{F}_p[y]$ with
+    /// $|x| = 1$ and $\beta x = y$. Its skeleta are the lens spaces.
+    Lens,
+}
+
+impl ProjectiveSpaceKind {
+    /// The dimension of the cells, i.e. the degrees in which the cohomology is non-zero are the
+    /// multiples of this number.
+    pub fn cell_dimension(self) -> i32 {
+        match self {
+            Self::Complex => 2,
+            Self::Quaternionic => 4,
+            Self::Lens => 1,
+        }
+    }
+
+    pub fn type_(self) -> &'static str {
+        match self {
+            Self::Complex => "complex projective space",
+            Self::Quaternionic => "quaternionic projective space",
+            Self::Lens => "lens space",
+        }
+    }
+
+    fn symbol(self) -> &'static str {
+        match self {
+            Self::Complex => "CP",
+            Self::Quaternionic => "HP",
+            Self::Lens => "L",
+        }
+    }
+}
+
+/// This is a stunted complex or quaternionic projective space, or a stunted lens space. The
+/// cohomology is the subquotient of $\Lambda[x] \otimes \mathbb{F}_p[y^\pm]$ given by the
+/// elements of [`ProjectiveSpaceKind`] with degree between `min` and `max` (inclusive).
+///
+/// As for [`RealProjectiveSpace`](crate::module::RealProjectiveSpace), negative values of `min`
+/// are allowed. The Thom spectrum of $k$ copies of the canonical line bundle over $X^n$ is
+/// $X^{n + k}_k$, so these are the Thom spectra of multiples of the canonical bundle, with the
+/// bottom and top cells in degrees `min` and `max`.
+pub struct ProjectiveSpace<A: SteenrodAlgebraT> {
+    algebra: Arc<A>,
+    pub kind: ProjectiveSpaceKind,
+    pub min: i32,
+    pub max: Option<i32>, // If None, then the infinite projective space
+}
+
+impl<A: SteenrodAlgebraT> std::fmt::Display for ProjectiveSpace<A> {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        // The usual indexing is by complex/quaternionic dimension
+        let index = match self.kind {
+            ProjectiveSpaceKind::Lens => 1,
+            kind => kind.cell_dimension(),
+        };
+        let symbol = self.kind.symbol();
+
+        if let Some(max) = self.max {
+            write!(f, "{}^{}_{}", symbol, max / index, self.min / index)
+        } else {
+            write!(f, "{}_{}", symbol, self.min / index)
+        }
+    }
+}
+
+impl<A: SteenrodAlgebraT> PartialEq for ProjectiveSpace<A> {
+    fn eq(&self, other: &Self) -> bool {
+        self.kind == other.kind && self.min == other.min && self.max == other.max
+    }
+}
+
+impl<A: SteenrodAlgebraT> Eq for ProjectiveSpace<A> {}
+
+impl<A: SteenrodAlgebraT> Module for ProjectiveSpace<A> {
+    type Algebra = A;
+
+    fn algebra(&self) -> Arc<A> {
+        Arc::clone(&self.algebra)
+    }
+
+    fn min_degree(&self) -> i32 {
+        self.min
+    }
+
+    fn max_computed_degree(&self) -> i32 {
+        i32::MAX
+    }
+
+    fn dimension(&self, degree: i32) -> usize {
+        if degree < self.min || self.max.is_some_and(|m| degree > m) {
+            return 0;
+        }
+        if degree % self.kind.cell_dimension() != 0 {
+            return 0;
+        }
+        1
+    }
+
+    fn basis_element_to_string(&self, degree: i32, _idx: usize) -> String {
+        // It is an error to call the function if self.dimension(degree) == 0
+        match self.kind {
+            ProjectiveSpaceKind::Complex => format!("y^{{{}}}", degree / 2),
+            ProjectiveSpaceKind::Quaternionic => format!("z^{{{}}}", degree / 4),
+            ProjectiveSpaceKind::Lens if degree % 2 == 0 => format!("y^{{{}}}", degree / 2),
+            ProjectiveSpaceKind::Lens => format!("xy^{{{}}}", degree.div_euclid(2)),
+        }
+    }
+
+    fn act_on_basis(
+        &self,
+        mut result: SliceMut,
+        coeff: u32,
+        op_degree: i32,
+        op_index: usize,
+        mod_degree: i32,
+        mod_index: usize,
+    ) {
+        assert!(op_index < self.algebra().dimension(op_degree));
+        assert!(mod_index < self.dimension(mod_degree));
+
+        let output_degree = mod_degree + op_degree;
+
+        if coeff == 0 || self.dimension(output_degree) == 0 {
+            return;
+        }
+        if op_degree == 0 {
+            result.add_basis_element(0, coeff);
+            return;
+        }
+
+        let c = match self.algebra.steenrod_algebra() {
+            SteenrodAlgebraBorrow::BorrowAdem(a) => coef_adem(a, op_degree, op_index, mod_degree),
+            SteenrodAlgebraBorrow::BorrowMilnor(a) => {
+                coef_milnor(a, op_degree, op_index, mod_degree)
+            }
+        };
+        result.add_basis_element(0, (c * coeff) % *self.prime());
+    }
+}
+
+/// The coefficient of $y^{j + \sum r_i (p^i - 1)}$ in $P(R) y^j$. This is the coefficient of
+/// $\xi^R$ in $(1 + \xi_1 + \xi_2 + \cdots)^j$, which makes sense for negative $j$ as well.
+pub(crate) fn power_coefficient(p: ValidPrime, j: i32, r: &[u32]) -> u32 {
+    let total: u32 = r.iter().sum();
+    let mut list = Vec::with_capacity(r.len() + 1);
+    if j >= 0 {
+        if total > j as u32 {
+            return 0;
+        }
+        list.push(j as u32 - total);
+    } else {
+        list.push((-j - 1) as u32);
+    }
+    list.extend_from_slice(r);
+
+    let c = u32::multinomial(p, &mut list);
+    if j < 0 && total % 2 == 1 {
+        (*p - c) % *p
+    } else {
+        c
+    }
+}
+
+/// Split a class of degree `degree` into the exponents of $x$ and $y$.
+fn exponents(degree: i32) -> (i32, i32) {
+    (degree.rem_euclid(2), degree.div_euclid(2))
+}
+
+// Compute the coefficient of the operation on the class of degree mod_degree.
+fn coef_adem(algebra: &AdemAlgebra, op_deg: i32, op_idx: usize, mod_degree: i32) -> u32 {
+    let p = algebra.prime();
+    let elt: &AdemBasisElement = algebra.basis_element_from_index(op_deg, op_idx);
+    let (mut x, mut j) = exponents(mod_degree);
+
+    // The element is β^{e_0} P^{i_0} β^{e_1} ... P^{i_{n - 1}} β^{e_n}, where e_k is the kth bit
+    // of elt.bocksteins. We apply the factors from the right.
+    let mut c = 1;
+    for k in (0..=elt.ps.len()).rev() {
+        if elt.bocksteins >> k & 1 == 1 {
+            if x == 0 {
+                return 0;
+            }
+            x = 0;
+            j += 1;
+        }
+        if k == 0 {
+            break;
+        }
+        let mut i = elt.ps[k - 1];
+        if *p == 2 {
+            // Only the even squares act non-trivially on y
+            if i % 2 == 1 {
+                return 0;
+            }
+            i /= 2;
+        }
+        c = (c * power_coefficient(p, j, &[i])) % *p;
+        if c == 0 {
+            return 0;
+        }
+        j += (i * (*p - 1)) as i32;
+    }
+    c
+}
+
+fn coef_milnor(algebra: &MilnorAlgebra, op_deg: i32, op_idx: usize, mod_degree: i32) -> u32 {
+    let p = algebra.prime();
+    let elt: &MilnorBasisElement = algebra.basis_element_from_index(op_deg, op_idx);
+    let (x, j) = exponents(mod_degree);
+
+    // PPartEntry is u8 if the odd-primes feature is disabled
+    #[allow(clippy::unnecessary_cast)]
+    let mut r: Vec<u32> = elt.p_part.iter().map(|&r| r as u32).collect();
+    if *p == 2 {
+        // Sq(R) acts on y as P(R / 2) if R is even, and trivially otherwise
+        if r.iter().any(|&r| r % 2 == 1) {
+            return 0;
+        }
+        for r in &mut r {
+            *r /= 2;
+        }
+    }
+
+    // Q(E) P(R) x y^j = Q(E) (x P(R) y^j), and Q_i x = y^{p^i} while Q_i y = 0.
+    match elt.q_part {
+        0 => power_coefficient(p, j, &r),
+        q if x == 1 && q.is_power_of_two() => power_coefficient(p, j, &r),
+        _ => 0,
+    }
+}
+
+impl<A: SteenrodAlgebraT> ZeroModule for ProjectiveSpace<A> {
+    fn zero_module(algebra: Arc<A>, min_degree: i32) -> Self {
+        Self {
+            algebra,
+            kind: ProjectiveSpaceKind::Complex,
+            min: min_degree,
+            max: Some(min_degree - 1),
+        }
+    }
+}
+
+impl<A: SteenrodAlgebraT> ProjectiveSpace<A> {
+    /// Construct the projective space with cells in degrees between `min` and `max`. These are
+    /// rounded inwards to multiples of the cell dimension.
+    pub fn new(algebra: Arc<A>, kind: ProjectiveSpaceKind, min: i32, max: Option<i32>) -> Self {
+        assert!(
+            kind != ProjectiveSpaceKind::Lens || *algebra.prime() != 2,
+            "Lens spaces at p = 2 are real projective spaces"
+        );
+        let d = kind.cell_dimension();
+        let min = -(-min).div_euclid(d) * d;
+        let max = max.map(|max| max.div_euclid(d) * d);
+        if let Some(max) = max {
+            assert!(max >= min);
+        }
+        Self {
+            algebra,
+            kind,
+            min,
+            max,
+        }
+    }
+}
+
+#[cfg(feature = "json")]
+#[derive(Deserialize, Debug)]
+struct ProjectiveSpaceSpec {
+    min: i32,
+    max: Option<i32>,
+}
+
+#[cfg(feature = "json")]
+impl<A: SteenrodAlgebraT> ProjectiveSpace<A> {
+    pub fn from_json(algebra: Arc<A>, json: &Value) -> anyhow::Result<Self> {
+        let kind = match json["type"].as_str() {
+            Some("complex projective space") => ProjectiveSpaceKind::Complex,
+            Some("quaternionic projective space") => ProjectiveSpaceKind::Quaternionic,
+            Some("lens space") => ProjectiveSpaceKind::Lens,
+            _ => return Err(anyhow!("Not a projective space: {}", json["type"])),
+        };
+        if kind == ProjectiveSpaceKind::Lens && *algebra.prime() == 2 {
+            return Err(anyhow!(
+                "Lens spaces are only defined at odd primes. Use real projective space instead"
+            ));
+        }
+
+        let spec: ProjectiveSpaceSpec = ProjectiveSpaceSpec::deserialize(json)?;
+        if spec.max.is_some_and(|max| max < spec.min) {
+            return Err(anyhow!("max must be at least min"));
+        }
+        Ok(Self::new(algebra, kind, spec.min, spec.max))
+    }
+
+    pub fn to_json(&self, json: &mut Value) {
+        json["name"] = Value::String(self.to_string());
+        json["type"] = Value::from(self.kind.type_());
+        json["min"] = Value::from(self.min);
+        if let Some(max) = self.max {
+            json["max"] = Value::from(max);
+        }
+    }
+}
+
+impl<A: SteenrodAlgebraT> BoundedModule for ProjectiveSpace<A> {
+    /// `max_degree` is the a degree such that if t > `max_degree`, then `self.dimension(t) = 0`.
+    fn max_degree(&self) -> i32 {
+        self.max.unwrap_or(i32::MAX)
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use crate::algebra::SteenrodAlgebra;
+    use fp::vector::FpVector;
+    use rstest::rstest;
+
+    /// Check that $a(bm) = (ab)m$ for all basis elements with $|a| + |b| + |m| \leq$ `max`.
+    pub(crate) fn check_associativity<M: Module>(module: &M, max: i32) {
+        let algebra = module.algebra();
+        let p = module.prime();
+        let min = module.min_degree();
+        algebra.compute_basis(max - min);
+        module.compute_basis(max);
+
+        for m_deg in min..=max {
+            for m_idx in 0..module.dimension(m_deg) {
+                for b_deg in 0..=max - m_deg {
+                    for a_deg in 0..=max - m_deg - b_deg {
+                        let out_deg = a_deg + b_deg + m_deg;
+                        for b_idx in 0..algebra.dimension(b_deg) {
+                            let mut bm = FpVector::new(p, module.dimension(b_deg + m_deg));
+                            module.act_on_basis(bm.as_slice_mut(), 1, b_deg, b_idx, m_deg, m_idx);
+                            for a_idx in 0..algebra.dimension(a_deg) {
+                                let mut left = FpVector::new(p, module.dimension(out_deg));
+                                module.act(
+                                    left.as_slice_mut(),
+                                    1,
+                                    a_deg,
+                                    a_idx,
+                                    b_deg + m_deg,
+                                    bm.as_slice(),
+                                );
+
+                                let mut ab = FpVector::new(p, algebra.dimension(a_deg + b_deg));
+                                algebra.multiply_basis_elements(
+                                    ab.as_slice_mut(),
+                                    1,
+                                    a_deg,
+                                    a_idx,
+                                    b_deg,
+                                    b_idx,
+                                );
+                                let mut right = FpVector::new(p, module.dimension(out_deg));
+                                module.act_by_element_on_basis(
+                                    right.as_slice_mut(),
+                                    1,
+                                    a_deg + b_deg,
+                                    ab.as_slice(),
+                                    m_deg,
+                                    m_idx,
+                                );
+                                assert_eq!(
+                                    left,
+                                    right,
+                                    "{}: a = {}, b = {}, m = {}",
+                                    module,
+                                    algebra.basis_element_to_string(a_deg, a_idx),
+                                    algebra.basis_element_to_string(b_deg, b_idx),
+                                    module.basis_element_to_string(m_deg, m_idx),
+                                );
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    #[rstest]
+    #[case(2, ProjectiveSpaceKind::Complex, 2, 24)]
+    #[case(2, ProjectiveSpaceKind::Complex, -10, 14)]
+    #[case(2, ProjectiveSpaceKind::Quaternionic, -12, 28)]
+    #[case(3, ProjectiveSpaceKind::Complex, -12, 30)]
+    #[case(3, ProjectiveSpaceKind::Quaternionic, 4, 40)]
+    #[case(3, ProjectiveSpaceKind::Lens, 1, 30)]
+    #[case(3, ProjectiveSpaceKind::Lens, -9, 24)]
+    #[case(5, ProjectiveSpaceKind::Lens, -5, 40)]
+    fn test_associativity(
+        #[case] p: u32,
+        #[case] kind: ProjectiveSpaceKind,
+        #[case] min: i32,
+        #[case] max: i32,
+    ) {
+        let p = ValidPrime::new(p);
+        for algebra in [
+            SteenrodAlgebra::AdemAlgebra(AdemAlgebra::new(p, *p != 2, false, false)),
+            SteenrodAlgebra::MilnorAlgebra(MilnorAlgebra::new(p)),
+        ] {
+            let module = ProjectiveSpace::new(Arc::new(algebra), kind, min, None);
+            check_associativity(&module, max);
+        }
+    }
+
+    #[test]
+    fn test_cp_actions() {
+        let p = ValidPrime::new(2);
+        let adem = AdemAlgebra::new(p, false, false, false);
+        adem.compute_basis(10);
+        let sq: Vec<usize> = (0..=4).map(|i| adem.beps_pn(0, i).1).collect();
+        let algebra = Arc::new(SteenrodAlgebra::AdemAlgebra(adem));
+        let module = ProjectiveSpace::new(algebra, ProjectiveSpaceKind::Complex, 2, Some(10));
+        assert_eq!(module.to_string(), "CP^5_1");
+
+        let mut result = FpVector::new(p, 1);
+        // Sq^2 y = y^2, Sq^2 y^2 = 0, Sq^4 y^2 = y^4 and Sq^4 y^3 = y^5
+        for (op_deg, mod_deg, value) in [(2, 2, 1), (2, 4, 0), (4, 4, 1), (4, 6, 1), (4, 8, 0)] {
+            result.set_to_zero();
+            module.act_on_basis(
+                result.as_slice_mut(),
+                1,
+                op_deg,
+                sq[op_deg as usize],
+                mod_deg,
+                0,
+            );
+            assert_eq!(result.entry(0), value, "Sq{op_deg} y^{}", mod_deg / 2);
+        }
+    }
+}
@@ -0,0 +1,111 @@
+use crate::algebra::Algebra;
+use crate::module::{FreeModule, Module, TruncatedModule};
+use fp::vector::SliceMut;
+
+/// A module that additionally carries a right action of its algebra. The underlying graded vector
+/// space is given by the [`Module`] implementation, whose left action is not used by anything that
+/// only needs a right module.
+///
+/// The algebra itself is a right module over itself, namely the free module on one generator in
+/// degree 0, so the Milnor and Adem algebras are covered by [`FreeModule`].
+pub trait RightModule: Module {
+    /// Add `coeff` times the basis element `(mod_degree, mod_index)` acted on the right by the
+    /// operation `(op_degree, op_index)` to `result`.
+    fn right_act_on_basis(
+        &self,
+        result: SliceMut,
+        coeff: u32,
+        mod_degree: i32,
+        mod_index: usize,
+        op_degree: i32,
+        op_index: usize,
+    );
+}
+
+/// A free module is also a free right module on the same generators, with the algebra acting on
+/// the operation, i.e. $(a g) \cdot b = (ab) g$.
+impl<A: Algebra> RightModule for FreeModule<A> {
+    fn right_act_on_basis(
+        &self,
+        mut result: SliceMut,
+        coeff: u32,
+        mod_degree: i32,
+        mod_index: usize,
+        op_degree: i32,
+        op_index: usize,
+    ) {
+        let opgen = self.index_to_op_gen(mod_degree, mod_index);
+        let out_op_degree = opgen.operation_degree + op_degree;
+        let start = self.operation_generator_to_index(
+            out_op_degree,
+            0,
+            opgen.generator_degree,
+            opgen.generator_index,
+        );
+        let end = start + self.algebra().dimension(out_op_degree);
+        self.algebra().multiply_basis_elements(
+            result.slice_mut(start, end),
+            coeff,
+            opgen.operation_degree,
+            opgen.operation_index,
+            op_degree,
+            op_index,
+        );
+    }
+}
+
+impl<M: RightModule + ?Sized> RightModule for TruncatedModule<M> {
+    fn right_act_on_basis(
+        &self,
+        result: SliceMut,
+        coeff: u32,
+        mod_degree: i32,
+        mod_index: usize,
+        op_degree: i32,
+        op_index: usize,
+    ) {
+        if op_degree + mod_degree <= self.truncation {
+            self.module
+                .right_act_on_basis(result, coeff, mod_degree, mod_index, op_degree, op_index);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algebra::{MilnorAlgebra, SteenrodAlgebra};
+    use fp::prime::ValidPrime;
+    use fp::vector::FpVector;
+    use std::sync::Arc;
+
+    #[test]
+    fn free_right_action() {
+        let p = ValidPrime::new(2);
+        let algebra: Arc<SteenrodAlgebra> = Arc::new(MilnorAlgebra::new(p).into());
+        algebra.compute_basis(10);
+
+        let module = FreeModule::new(Arc::clone(&algebra), String::from("F"), 0);
+        module.add_generators(0, 1, None);
+        module.add_generators(1, 1, None);
+        module.extend_by_zero(10);
+
+        // (Sq(2) x_1) Sq(1) = Sq(3) x_1 + Sq(0, 1) x_1
+        let mut result = FpVector::new(p, module.dimension(4));
+        let input = module.operation_generator_to_index(2, 0, 1, 0);
+        module.right_act_on_basis(result.as_slice_mut(), 1, 3, input, 1, 0);
+
+        let mut expected = FpVector::new(p, module.dimension(4));
+        let start = module.operation_generator_to_index(3, 0, 1, 0);
+        algebra.multiply_basis_elements(
+            expected.slice_mut(start, start + algebra.dimension(3)),
+            1,
+            2,
+            0,
+            1,
+            0,
+        );
+        assert_eq!(result, expected);
+        assert_eq!(result.iter_nonzero().count(), 2);
+    }
+}
@@ -1,5 +1,5 @@
 use crate::algebra::adem_algebra::AdemBasisElement;
-use crate::algebra::{AdemAlgebra, Algebra, MilnorAlgebra};
+use crate::algebra::{AdemAlgebra, Algebra, HopfAlgebra, MilnorAlgebra};
 use crate::change_of_basis;
 use crate::module::Module;
 use crate::steenrod_parser::BocksteinOrSq;
@@ -92,6 +92,13 @@ fn evaluate_algebra_tree_helper(
             );
             Ok((degree, result))
         }
+        AlgebraParseNode::Antipode(inner) => {
+            let (degree, input) =
+                evaluate_algebra_tree_helper(adem_algebra, milnor_algebra, output_degree, *inner)?;
+            let mut result = FpVector::new(p, adem_algebra.dimension(degree));
+            adem_algebra.antipode(result.as_slice_mut(), 1, degree, input.as_slice());
+            Ok((degree, result))
+        }
         AlgebraParseNode::BasisElt(basis_elt) => {
             evaluate_basis_element(adem_algebra, milnor_algebra, output_degree, basis_elt)
         }
@@ -455,6 +462,17 @@ mod tests {
             expect![[r#"Sq6 Sq3 Sq1"#]],
             expect![[r#"P(7, 1) + P(3, 0, 1) + P(0, 1, 1)"#]],
         );
+        check(
+            "chi(Sq4)",
+            expect![[r#"Sq4 + Sq3 Sq1"#]],
+            expect![[r#"P(4) + P(1, 1)"#]],
+        );
+        check("chi(Sq2 * Sq1)", expect![[r#"Sq3"#]], expect![[r#"P(3)"#]]);
+        check(
+            "Sq1 * χ(Sq3 + Sq2 * Sq1)",
+            expect![[r#"Sq3 Sq1"#]],
+            expect![[r#"P(1, 1)"#]],
+        );
     }
 
     #[test]
@@ -492,5 +510,11 @@ mod tests {
             expect![[r#"P6 P2"#]],
             expect![[r#"P(8) + P(4, 1) + P(0, 2)"#]],
         );
+        check("chi(P1)", expect![[r#"2 * P1"#]], expect![[r#"2 * P(1)"#]]);
+        check(
+            "χ(Q0 * P1)",
+            expect![[r#"P1 b"#]],
+            expect![[r#"Q_0 P(1) + Q_1"#]],
+        );
     }
 }
@@ -14,7 +14,7 @@ use nom::{
 use crate::algebra::milnor_algebra::PPart;
 use std::str::FromStr;
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum AlgebraBasisElt {
     AList(Vec<BocksteinOrSq>), // Admissible list.
     PList(PPart),
@@ -22,10 +22,11 @@ pub enum AlgebraBasisElt {
     Q(u32),
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum AlgebraParseNode {
     Product(Box<AlgebraParseNode>, Box<AlgebraParseNode>),
     Sum(Box<AlgebraParseNode>, Box<AlgebraParseNode>),
+    Antipode(Box<AlgebraParseNode>),
     BasisElt(AlgebraBasisElt),
     Scalar(i32),
 }
@@ -61,7 +62,7 @@ fn space_separated_sequence<T: FromStr + Copy>(i: &str) -> IResult<&str, Vec<T>>
     delimited(tag("("), space_separated_integer_list, tag(")"))(i)
 }
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq, Eq)]
 pub enum BocksteinOrSq {
     Bockstein,
     Sq(u32),
@@ -136,8 +137,15 @@ fn algebra_parens(i: &str) -> IResult<&str, AlgebraParseNode> {
     delimited(space, delimited(tag("("), algebra_expr, tag(")")), space)(i)
 }
 
+/// The antipode, written as `chi(...)` or `χ(...)`.
+fn algebra_antipode(i: &str) -> IResult<&str, AlgebraParseNode> {
+    let (rest, (_, _, expr)) = tuple((space, alt((tag("chi"), tag("χ"))), algebra_parens))(i)?;
+    Ok((rest, AlgebraParseNode::Antipode(Box::new(expr))))
+}
+
 fn algebra_factor(i: &str) -> IResult<&str, AlgebraParseNode> {
     alt((
+        algebra_antipode,
         delimited(space, algebra_generator, space),
         scalar,
         algebra_parens,
@@ -292,4 +300,19 @@ mod tests {
 
         println!();
     }
+
+    #[test]
+    fn test_parse_antipode() {
+        use AlgebraBasisElt::P;
+        use AlgebraParseNode::*;
+
+        let sq = |i| Box::new(BasisElt(P(i)));
+        assert_eq!(
+            parse_algebra("chi(Sq2 * Sq1) + χ(Sq3)").unwrap(),
+            Sum(
+                Box::new(Antipode(Box::new(Product(sq(2), sq(1))))),
+                Box::new(Antipode(sq(3)))
+            )
+        );
+    }
 }
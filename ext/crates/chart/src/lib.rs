@@ -47,6 +47,9 @@ pub trait Backend {
     ) -> Result<(), Self::Error>;
     fn node(&mut self, x: i32, y: i32, n: usize) -> Result<(), Self::Error>;
 
+    /// Label the `i`th node at `(x, y)`. The nodes at `(x, y)` must already have been drawn.
+    fn label(&mut self, node: (i32, i32, usize), content: impl Display) -> Result<(), Self::Error>;
+
     fn structline(
         &mut self,
         source: (i32, i32, usize),
@@ -141,6 +144,10 @@ impl<T: Write> SvgBackend<T> {
      text-anchor: end;
      dominant-baseline: middle;
     }
+    .label {
+     font-size: 5px;
+     dominant-baseline: middle;
+    }
     "#;
 
     const GRID_WIDTH: i32 = 20;
@@ -199,6 +206,23 @@ impl<T: Write> SvgBackend<T> {
     }
 }
 
+/// Escape the characters of `content` that are special in XML text and attributes.
+fn escape_xml(content: impl Display) -> String {
+    let content = content.to_string();
+    let mut result = String::with_capacity(content.len());
+    for c in content.chars() {
+        match c {
+            '&' => result.push_str("&amp;"),
+            '<' => result.push_str("&lt;"),
+            '>' => result.push_str("&gt;"),
+            '"' => result.push_str("&quot;"),
+            '\'' => result.push_str("&apos;"),
+            _ => result.push(c),
+        }
+    }
+    result
+}
+
 impl<T: Write> Backend for SvgBackend<T> {
     type Error = std::io::Error;
     const EXT: &'static str = "svg";
@@ -259,7 +283,7 @@ impl<T: Write> Backend for SvgBackend<T> {
             r#"<text class="{class}" x="{x}" y="{y}">{text}</text>"#,
             x = Self::MARGIN + x * Self::GRID_WIDTH + offset.0,
             y = Self::MARGIN + (self.max_y - y) * Self::GRID_WIDTH + offset.1,
-            text = content,
+            text = escape_xml(content),
             class = class,
         )
     }
@@ -283,6 +307,20 @@ impl<T: Write> Backend for SvgBackend<T> {
         Ok(())
     }
 
+    fn label(&mut self, node: (i32, i32, usize), content: impl Display) -> Result<(), Self::Error> {
+        if node.0 > self.max_x || node.1 > self.max_y {
+            return Ok(());
+        }
+        let (r, x, y) = self.get_coords(node.0, node.1, node.2);
+
+        writeln!(
+            self.out,
+            r#"<text class="label" x="{x}" y="{y}">{content}</text>"#,
+            x = x + r + 1.0,
+            content = escape_xml(content),
+        )
+    }
+
     fn structline(
         &mut self,
         source: (i32, i32, usize),
@@ -428,6 +466,18 @@ impl<T: Write> Backend for TikzBackend<T> {
         Ok(())
     }
 
+    fn label(&mut self, node: (i32, i32, usize), content: impl Display) -> Result<(), Self::Error> {
+        if node.0 > self.max_x || node.1 > self.max_y {
+            return Ok(());
+        }
+        let (_, x, y) = self.get_coords(node.0, node.1, node.2);
+
+        writeln!(
+            self.out,
+            r#"\node [right, font=\tiny] at ({x}, {y}) {{${content}$}};"#,
+        )
+    }
+
     fn structline(
         &mut self,
         source: (i32, i32, usize),
@@ -477,4 +527,18 @@ mod test {
 
         expect_file!["../legend.svg"].assert_eq(std::str::from_utf8(&res).unwrap());
     }
+
+    #[test]
+    fn test_label_escape() {
+        let mut res: Vec<u8> = Vec::new();
+        {
+            let mut backend = SvgBackend::new(&mut res);
+            backend.header(1, 1).unwrap();
+            backend.node(0, 0, 1).unwrap();
+            backend.label((0, 0, 0), "<h_0 & h_1>").unwrap();
+        }
+        let res = std::str::from_utf8(&res).unwrap();
+        assert!(res.contains(">&lt;h_0 &amp; h_1&gt;</text>"));
+        assert!(!res.contains("<h_0"));
+    }
 }
@@ -20,11 +20,13 @@ fn main() -> Result<(), Error> {
 
     let mut writer = ConstWriter::for_build("constants")?.finish_dependencies();
 
-    writer.add_raw("/// The number of primes that are supported.");
+    writer.add_raw("/// The number of primes for which we precompute lookup tables.");
     writer.add_value("NUM_PRIMES", "usize", num_primes);
-    writer.add_raw("/// The `MAX_PRIME`th prime number. Constructing a `ValidPrime` using any number larger than");
-    writer.add_raw("/// this value will cause a panic.");
-    writer.add_value("MAX_PRIME", "usize", max_prime);
+    writer.add_raw(
+        "/// The `NUM_PRIMES`th prime number. Larger primes are supported, but do not have lookup",
+    );
+    writer.add_raw("/// tables.");
+    writer.add_value("MAX_TABLE_PRIME", "usize", max_prime);
     // `NOT_A_PRIME` is never used if odd-primes is disabled.
     writer.add_raw("#[allow(dead_code)]");
     writer.add_raw(
@@ -33,10 +35,11 @@ fn main() -> Result<(), Error> {
     writer.add_raw("/// a prime number.");
     writer.add_value("NOT_A_PRIME", "usize", not_a_prime);
     writer.add_value("MAX_MULTINOMIAL_LEN", "usize", max_multinomial_len);
-    writer.add_raw("/// An array containing the first `NUM_PRIMES` prime numbers.");
+    writer.add_raw("/// An array containing the first `NUM_PRIMES` prime numbers, which are the primes with lookup");
+    writer.add_raw("/// tables.");
     writer.add_array("PRIMES", "u32", &primes);
     writer.add_raw(
-        "/// For any integer `i` less than or equal to `MAX_PRIME`, `PRIME_TO_INDEX_MAP[i]` is",
+        "/// For any integer `i` less than or equal to `MAX_TABLE_PRIME`, `PRIME_TO_INDEX_MAP[i]` is",
     );
     writer.add_raw(
         "/// the index of `i` in `PRIMES` if `i` is prime; otherwise, it is `NOT_A_PRIME`.",
@@ -13,8 +13,8 @@ macro_rules! const_for {
     };
 }
 
-pub(crate) const INVERSE_TABLE: [[u32; MAX_PRIME]; NUM_PRIMES] = {
-    let mut result = [[0; MAX_PRIME]; NUM_PRIMES];
+pub(crate) const INVERSE_TABLE: [[u32; MAX_TABLE_PRIME]; NUM_PRIMES] = {
+    let mut result = [[0; MAX_TABLE_PRIME]; NUM_PRIMES];
     const_for! { i in 0 .. NUM_PRIMES {
         let p = PRIMES[i];
         const_for! { k in 1 .. p {
@@ -43,8 +43,8 @@ pub(crate) const BINOMIAL4_TABLE: [[u32; BINOMIAL4_TABLE_SIZE]; BINOMIAL4_TABLE_
     res
 };
 
-pub(crate) static BINOMIAL_TABLE: [[[u32; MAX_PRIME]; MAX_PRIME]; NUM_PRIMES] = {
-    let mut result = [[[0; MAX_PRIME]; MAX_PRIME]; NUM_PRIMES];
+pub(crate) static BINOMIAL_TABLE: [[[u32; MAX_TABLE_PRIME]; MAX_TABLE_PRIME]; NUM_PRIMES] = {
+    let mut result = [[[0; MAX_TABLE_PRIME]; MAX_TABLE_PRIME]; NUM_PRIMES];
     const_for! { i in 0 .. NUM_PRIMES {
         let p = PRIMES[i];
         let pu = p as usize;
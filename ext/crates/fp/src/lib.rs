@@ -1,13 +1,16 @@
 mod constants;
 mod limb;
 
-pub use constants::{MAX_MULTINOMIAL_LEN, NUM_PRIMES, PRIMES, PRIME_TO_INDEX_MAP};
+pub use constants::MAX_MULTINOMIAL_LEN;
 
 pub mod matrix;
 pub mod prime;
 #[cfg(feature = "odd-primes")]
 pub mod vector;
 pub mod vector_2;
+#[cfg(feature = "odd-primes")]
+pub mod vector_big;
+pub mod zpk;
 #[cfg(not(feature = "odd-primes"))]
 pub use vector_2 as vector;
 
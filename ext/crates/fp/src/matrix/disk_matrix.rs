@@ -0,0 +1,400 @@
+use super::{Matrix, QuasiInverse, Subspace};
+use crate::limb::Limb;
+use crate::prime::ValidPrime;
+use crate::vector::FpVector;
+
+use byteorder::{LittleEndian, WriteBytesExt};
+use std::fs::{File, OpenOptions};
+use std::io::{BufReader, BufWriter, Seek, SeekFrom, Write};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+/// Used to generate unique names for scratch files within a process.
+static SCRATCH_COUNTER: AtomicUsize = AtomicUsize::new(0);
+
+/// An out-of-core variant of [`Matrix`]. The rows are stored in a scratch file on disk, and only
+/// `block_size` rows (times two) are loaded into memory at any point. This is intended for
+/// matrices that are too large to fit in memory, and is otherwise much slower than [`Matrix`].
+///
+/// The supported operations mirror those of [`Matrix`]: one first fills in the rows, then calls
+/// [`DiskMatrix::row_reduce`], and then extracts the kernel or quasi-inverse. The quasi-inverse
+/// can be written directly to a [`Write`] in the format of [`QuasiInverse::to_bytes`], so that it
+/// never has to be held in memory either.
+///
+/// Unlike [`Matrix`], the rows of the row reduced matrix are not sorted by their pivot columns,
+/// and [`DiskMatrix::pivots`] records the (global) row index of each pivot. The scratch file is
+/// deleted when the `DiskMatrix` is dropped.
+pub struct DiskMatrix {
+    p: ValidPrime,
+    columns: usize,
+    rows: usize,
+    block_size: usize,
+    /// The number of bytes each row occupies in the scratch file.
+    row_bytes: u64,
+    file: File,
+    path: PathBuf,
+    pivots: Vec<isize>,
+}
+
+impl DiskMatrix {
+    /// Create an empty matrix with `columns` columns, whose rows are stored in a scratch file in
+    /// `scratch_dir`. The row reduction loads `block_size` rows into memory at a time.
+    pub fn new(
+        p: ValidPrime,
+        columns: usize,
+        scratch_dir: &Path,
+        block_size: usize,
+    ) -> std::io::Result<Self> {
+        assert!(block_size > 0);
+        let path = scratch_dir.join(format!(
+            "disk_matrix_{}_{}.scratch",
+            std::process::id(),
+            SCRATCH_COUNTER.fetch_add(1, Ordering::Relaxed)
+        ));
+        let file = OpenOptions::new()
+            .read(true)
+            .write(true)
+            .create_new(true)
+            .open(&path)?;
+
+        Ok(Self {
+            p,
+            columns,
+            rows: 0,
+            block_size,
+            row_bytes: (FpVector::num_limbs(p, columns) * std::mem::size_of::<Limb>()) as u64,
+            file,
+            path,
+            pivots: Vec::new(),
+        })
+    }
+
+    /// Copy an in-memory matrix into a `DiskMatrix`.
+    pub fn from_matrix(
+        matrix: &Matrix,
+        scratch_dir: &Path,
+        block_size: usize,
+    ) -> std::io::Result<Self> {
+        let mut result = Self::new(matrix.prime(), matrix.columns(), scratch_dir, block_size)?;
+        let mut f = BufWriter::new(&result.file);
+        for row in matrix.iter() {
+            row.to_bytes(&mut f)?;
+        }
+        f.flush()?;
+        drop(f);
+        result.rows = matrix.rows();
+        Ok(result)
+    }
+
+    pub fn prime(&self) -> ValidPrime {
+        self.p
+    }
+
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    pub fn columns(&self) -> usize {
+        self.columns
+    }
+
+    /// The pivot columns of the matrix. `pivots[n]` is the index of the row whose pivot is in
+    /// column `n`, and `-1` if there is no such row. This is only meaningful after calling
+    /// [`DiskMatrix::row_reduce`].
+    pub fn pivots(&self) -> &[isize] {
+        &self.pivots
+    }
+
+    /// Append a row to the end of the matrix.
+    pub fn push_row(&mut self, row: &FpVector) -> std::io::Result<()> {
+        assert_eq!(row.len(), self.columns);
+        let mut f = &self.file;
+        f.seek(SeekFrom::Start(self.rows as u64 * self.row_bytes))?;
+        row.to_bytes(&mut f)?;
+        self.rows += 1;
+        Ok(())
+    }
+
+    /// Read the `i`th row of the matrix.
+    pub fn row(&self, i: usize) -> std::io::Result<FpVector> {
+        assert!(i < self.rows);
+        let mut f = &self.file;
+        f.seek(SeekFrom::Start(i as u64 * self.row_bytes))?;
+        FpVector::from_bytes(self.p, self.columns, &mut f)
+    }
+
+    fn read_block(&self, start: usize, end: usize) -> std::io::Result<Matrix> {
+        let mut f = &self.file;
+        f.seek(SeekFrom::Start(start as u64 * self.row_bytes))?;
+        let mut f = BufReader::new(f);
+        Matrix::from_bytes(self.p, end - start, self.columns, &mut f)
+    }
+
+    fn write_block(&self, start: usize, block: &Matrix) -> std::io::Result<()> {
+        let mut f = &self.file;
+        f.seek(SeekFrom::Start(start as u64 * self.row_bytes))?;
+        let mut f = BufWriter::new(f);
+        block.to_bytes(&mut f)?;
+        f.flush()
+    }
+
+    fn block_range(&self, block: usize) -> (usize, usize) {
+        let start = block * self.block_size;
+        (start, std::cmp::min(start + self.block_size, self.rows))
+    }
+
+    /// Reduce the rows of `target` using the pivot rows of the block starting at `source_start`.
+    fn reduce_by_block(&self, target: &mut Matrix, source_start: usize, source: &Matrix) {
+        let p = *self.p;
+        let source_end = source_start + source.rows();
+        for (column, &row) in self.pivots.iter().enumerate() {
+            if row < source_start as isize || row >= source_end as isize {
+                continue;
+            }
+            let pivot_row = &source[row as usize - source_start];
+            for target_row in target.iter_mut() {
+                let c = target_row.entry(column);
+                if c != 0 {
+                    target_row.add_offset(pivot_row, p - c, column);
+                }
+            }
+        }
+    }
+
+    /// Row reduce the matrix to reduced row echelon form, and record the pivots.
+    ///
+    /// This is a blocked Gaussian elimination. In the forward pass, each block is reduced by
+    /// all previous blocks and then row reduced internally, so that its pivots are distinct from
+    /// those of the previous blocks. In the backward pass, each block is reduced by all later
+    /// blocks, which are already in their final form.
+    ///
+    /// # Returns
+    /// The number of non-zero rows in the matrix.
+    pub fn row_reduce(&mut self) -> std::io::Result<usize> {
+        self.pivots = vec![-1; self.columns];
+        let num_blocks = self.rows.div_ceil(self.block_size);
+
+        for i in 0..num_blocks {
+            let (start, end) = self.block_range(i);
+            let mut block = self.read_block(start, end)?;
+            for j in 0..i {
+                let (source_start, source_end) = self.block_range(j);
+                let source = self.read_block(source_start, source_end)?;
+                self.reduce_by_block(&mut block, source_start, &source);
+            }
+            block.row_reduce();
+            for (column, &row) in block.pivots().iter().enumerate() {
+                if row >= 0 {
+                    self.pivots[column] = start as isize + row;
+                }
+            }
+            self.write_block(start, &block)?;
+        }
+
+        for i in (0..num_blocks).rev() {
+            let (start, end) = self.block_range(i);
+            let mut block = self.read_block(start, end)?;
+            for j in i + 1..num_blocks {
+                let (source_start, source_end) = self.block_range(j);
+                let source = self.read_block(source_start, source_end)?;
+                self.reduce_by_block(&mut block, source_start, &source);
+            }
+            self.write_block(start, &block)?;
+        }
+
+        Ok(self.pivots.iter().filter(|&&x| x >= 0).count())
+    }
+
+    /// Computes the kernel from an augmented matrix in rref. This is the analogue of
+    /// [`Matrix::compute_kernel`], and the kernel is held in memory.
+    pub fn compute_kernel(&self, first_source_column: usize) -> std::io::Result<Subspace> {
+        let source_dimension = self.columns - first_source_column;
+        let kernel_rows: Vec<usize> = self.pivots[first_source_column..]
+            .iter()
+            .filter(|&&x| x >= 0)
+            .map(|&x| x as usize)
+            .collect();
+
+        let mut kernel = Subspace::new(self.p, kernel_rows.len(), source_dimension);
+        let mut next_row = 0;
+        for (i, &row) in self.pivots[first_source_column..].iter().enumerate() {
+            if row >= 0 {
+                kernel.pivots_mut()[i] = next_row;
+                next_row += 1;
+            }
+        }
+        for (target, &row) in kernel.matrix.iter_mut().zip(&kernel_rows) {
+            let row = self.row(row)?;
+            target
+                .as_slice_mut()
+                .assign(row.slice(first_source_column, self.columns));
+        }
+        Ok(kernel)
+    }
+
+    /// Write the kernel of an augmented matrix in rref to `buffer`, in the format of
+    /// [`Subspace::to_bytes`]. This reads in one row at a time, so that the kernel is never held
+    /// in memory.
+    pub fn write_kernel(
+        &self,
+        first_source_column: usize,
+        buffer: &mut impl Write,
+    ) -> std::io::Result<()> {
+        let source_dimension = self.columns - first_source_column;
+        let mut pivots = Vec::with_capacity(source_dimension);
+        let mut kernel_rows = Vec::new();
+        for &row in &self.pivots[first_source_column..] {
+            if row >= 0 {
+                pivots.push(kernel_rows.len() as isize);
+                kernel_rows.push(row as usize);
+            } else {
+                pivots.push(-1);
+            }
+        }
+
+        buffer.write_u64::<LittleEndian>(kernel_rows.len() as u64)?;
+        buffer.write_u64::<LittleEndian>(source_dimension as u64)?;
+
+        let mut kernel_row = FpVector::new(self.p, source_dimension);
+        for &row in &kernel_rows {
+            let row = self.row(row)?;
+            kernel_row
+                .as_slice_mut()
+                .assign(row.slice(first_source_column, self.columns));
+            kernel_row.to_bytes(buffer)?;
+        }
+        Matrix::write_pivot(&pivots, buffer)
+    }
+
+    /// The rows of the image part of the matrix, in order of pivot columns, and the
+    /// corresponding image pivots.
+    fn image_rows(&self, last_target_col: usize) -> (Vec<isize>, Vec<usize>) {
+        let mut image = Vec::with_capacity(last_target_col);
+        let mut rows = Vec::new();
+        for &row in &self.pivots[..last_target_col] {
+            if row >= 0 {
+                image.push(rows.len() as isize);
+                rows.push(row as usize);
+            } else {
+                image.push(-1);
+            }
+        }
+        (image, rows)
+    }
+
+    /// Computes the quasi-inverse of a matrix given a rref of [A|0|I]. This is the analogue of
+    /// [`Matrix::compute_quasi_inverse`], and the quasi-inverse is held in memory. Use
+    /// [`DiskMatrix::write_quasi_inverse`] to avoid this.
+    pub fn compute_quasi_inverse(
+        &self,
+        last_target_col: usize,
+        first_source_col: usize,
+    ) -> std::io::Result<QuasiInverse> {
+        let (image, rows) = self.image_rows(last_target_col);
+        let mut preimage = Matrix::new(self.p, rows.len(), self.columns - first_source_col);
+        for (target, &row) in preimage.iter_mut().zip(&rows) {
+            let row = self.row(row)?;
+            target
+                .as_slice_mut()
+                .assign(row.slice(first_source_col, self.columns));
+        }
+        Ok(QuasiInverse::new(Some(image), preimage))
+    }
+
+    /// Write the quasi-inverse of a matrix given a rref of [A|0|I] to `buffer`, in the format of
+    /// [`QuasiInverse::to_bytes`]. This reads in one row at a time, so that the quasi-inverse is
+    /// never held in memory. The result can be read using [`QuasiInverse::from_bytes`] or applied
+    /// directly with [`QuasiInverse::stream_quasi_inverse`].
+    pub fn write_quasi_inverse(
+        &self,
+        last_target_col: usize,
+        first_source_col: usize,
+        buffer: &mut impl Write,
+    ) -> std::io::Result<()> {
+        let (image, rows) = self.image_rows(last_target_col);
+        let source_dimension = self.columns - first_source_col;
+
+        buffer.write_u64::<LittleEndian>(source_dimension as u64)?;
+        buffer.write_u64::<LittleEndian>(last_target_col as u64)?;
+        buffer.write_u64::<LittleEndian>(rows.len() as u64)?;
+        Matrix::write_pivot(&image, buffer)?;
+
+        let mut preimage_row = FpVector::new(self.p, source_dimension);
+        for &row in &rows {
+            let row = self.row(row)?;
+            preimage_row
+                .as_slice_mut()
+                .assign(row.slice(first_source_col, self.columns));
+            preimage_row.to_bytes(buffer)?;
+        }
+        Ok(())
+    }
+}
+
+impl Drop for DiskMatrix {
+    fn drop(&mut self) {
+        // There is nothing useful we can do if this fails
+        std::fs::remove_file(&self.path).ok();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::Rng;
+
+    fn random_matrix(p: ValidPrime, rows: usize, columns: usize) -> Matrix {
+        let mut rng = rand::thread_rng();
+        let mut m = Matrix::new(p, rows, columns);
+        for row in m.iter_mut() {
+            for i in 0..columns {
+                // Make the matrix sparse-ish so that it is not of full rank
+                if rng.gen_bool(0.3) {
+                    row.set_entry(i, rng.gen_range(0..*p));
+                }
+            }
+        }
+        m
+    }
+
+    #[test]
+    fn test_disk_matrix() {
+        let dir = std::env::temp_dir();
+        for &p in &[2, 3, 5] {
+            let p = ValidPrime::new(p);
+            for &(rows, cols, block_size) in &[(10, 8, 3), (30, 20, 7), (25, 40, 100), (1, 5, 2)] {
+                let input = random_matrix(p, rows, cols);
+                let (padded_cols, mut m) = Matrix::augmented_from_vec(p, &input.to_vec());
+                let mut disk = DiskMatrix::from_matrix(&m, &dir, block_size).unwrap();
+
+                let rank = m.row_reduce();
+                assert_eq!(disk.row_reduce().unwrap(), rank);
+
+                for (column, &row) in m.pivots().iter().enumerate() {
+                    assert_eq!(row >= 0, disk.pivots()[column] >= 0);
+                    if row >= 0 {
+                        let disk_row = disk.row(disk.pivots()[column] as usize).unwrap();
+                        assert_eq!(disk_row, m[row as usize]);
+                    }
+                }
+
+                let kernel = m.compute_kernel(padded_cols);
+                assert_eq!(disk.compute_kernel(padded_cols).unwrap(), kernel);
+
+                let mut buffer = Vec::new();
+                disk.write_kernel(padded_cols, &mut buffer).unwrap();
+                let streamed = Subspace::from_bytes(p, &mut &buffer[..]).unwrap();
+                assert_eq!(streamed, kernel);
+
+                let qi = m.compute_quasi_inverse(cols, padded_cols);
+                assert_eq!(disk.compute_quasi_inverse(cols, padded_cols).unwrap(), qi);
+
+                let mut buffer = Vec::new();
+                disk.write_quasi_inverse(cols, padded_cols, &mut buffer)
+                    .unwrap();
+                let streamed = QuasiInverse::from_bytes(p, &mut &buffer[..]).unwrap();
+                assert_eq!(streamed, qi);
+            }
+        }
+    }
+}
@@ -1,4 +1,5 @@
 // mod basis;
+mod disk_matrix;
 mod matrix_inner;
 mod quasi_inverse;
 mod subquotient;
@@ -7,6 +8,7 @@ mod subspace;
 mod m4ri;
 
 // pub use basis::Basis;
+pub use disk_matrix::DiskMatrix;
 pub use matrix_inner::{AugmentedMatrix, Matrix, MatrixSliceMut};
 pub use quasi_inverse::QuasiInverse;
 pub use subquotient::Subquotient;
@@ -6,9 +6,13 @@ use crate::constants::{
     BINOMIAL4_TABLE, BINOMIAL4_TABLE_SIZE, BINOMIAL_TABLE, INVERSE_TABLE, PRIME_TO_INDEX_MAP,
 };
 #[allow(unused_imports)]
-use crate::constants::{MAX_PRIME, NOT_A_PRIME, NUM_PRIMES};
+use crate::constants::{MAX_TABLE_PRIME, NOT_A_PRIME};
 
-#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+/// All primes must be less than `PRIME_BOUND`. This ensures that the product of two elements of
+/// $\mathbb{F}_p$ fits in a `u32`.
+pub const PRIME_BOUND: u32 = 1 << 16;
+
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq)]
 pub struct ValidPrime {
     #[cfg(feature = "odd-primes")]
     p: u32,
@@ -59,7 +63,7 @@ impl std::ops::Deref for ValidPrime {
     fn deref(&self) -> &Self::Target {
         let p = self.p;
         unsafe {
-            if !is_valid_prime(p) || p == 0 || PRIME_TO_INDEX_MAP[p as usize] >= NUM_PRIMES {
+            if !(2..PRIME_BOUND).contains(&p) {
                 std::hint::unreachable_unchecked()
             }
         }
@@ -132,9 +136,32 @@ impl<'de> Deserialize<'de> for ValidPrime {
     }
 }
 
+/// Whether `p` is a prime less than [`PRIME_BOUND`]. Small primes are looked up in a table, and
+/// larger ones are checked by trial division.
 #[cfg(feature = "odd-primes")]
 pub const fn is_valid_prime(p: u32) -> bool {
-    (p as usize) <= MAX_PRIME && PRIME_TO_INDEX_MAP[p as usize] != NOT_A_PRIME
+    if (p as usize) <= MAX_TABLE_PRIME {
+        return PRIME_TO_INDEX_MAP[p as usize] != NOT_A_PRIME;
+    }
+    if p >= PRIME_BOUND {
+        return false;
+    }
+    let mut k = 2;
+    while k * k <= p {
+        if p.is_multiple_of(k) {
+            return false;
+        }
+        k += 1;
+    }
+    true
+}
+
+/// The index of `p` in the lookup tables, if `p` is small enough to have one.
+fn table_index(p: ValidPrime) -> Option<usize> {
+    PRIME_TO_INDEX_MAP
+        .get(*p as usize)
+        .copied()
+        .filter(|&i| i != NOT_A_PRIME)
 }
 
 #[cfg(not(feature = "odd-primes"))]
@@ -142,11 +169,15 @@ pub const fn is_valid_prime(p: u32) -> bool {
     p == 2
 }
 
-// Uses a the lookup table we initialized.
+/// Compute the inverse of `k` mod `p`. This uses a lookup table for small primes, and Fermat's
+/// little theorem otherwise.
 pub fn inverse(p: ValidPrime, k: u32) -> u32 {
     assert!(k > 0 && k < *p);
-    // LLVM doesn't understand the inequality is transitive
-    unsafe { *INVERSE_TABLE[PRIME_TO_INDEX_MAP[*p as usize]].get_unchecked(k as usize) }
+    match table_index(p) {
+        // LLVM doesn't understand the inequality is transitive
+        Some(i) => unsafe { *INVERSE_TABLE[i].get_unchecked(k as usize) },
+        None => power_mod(*p, k, *p - 2),
+    }
 }
 
 pub const fn minus_one_to_the_n(p: u32, i: i32) -> u32 {
@@ -157,17 +188,36 @@ pub const fn minus_one_to_the_n(p: u32, i: i32) -> u32 {
     }
 }
 
-/// This uses a lookup table for n choose k when n and k are both less than p.
-/// Lucas's theorem reduces general binomial coefficients to this case.
+/// This uses a lookup table for n choose k when n and k are both less than p. Lucas's theorem
+/// reduces general binomial coefficients to this case. Primes that are too large to have a lookup
+/// table fall back to computing the coefficient directly.
 ///
 /// Calling this function safely requires that `k, n < p`.  These invariants are often known
 /// apriori because k and n are obtained by reducing mod p, so it is better to expose an unsafe
 /// interface that avoids these checks.
 unsafe fn direct_binomial(p: ValidPrime, n: usize, k: usize) -> u32 {
-    *BINOMIAL_TABLE
-        .get_unchecked(PRIME_TO_INDEX_MAP[*p as usize])
-        .get_unchecked(n)
-        .get_unchecked(k)
+    match table_index(p) {
+        Some(i) => *BINOMIAL_TABLE
+            .get_unchecked(i)
+            .get_unchecked(n)
+            .get_unchecked(k),
+        None => direct_binomial_fallback(p, n as u32, k as u32),
+    }
+}
+
+/// Compute n choose k mod p as n (n - 1) ... (n - k + 1) / k!. This requires `k, n < p`, so that
+/// k! is invertible.
+fn direct_binomial_fallback(p: ValidPrime, n: u32, k: u32) -> u32 {
+    if k > n {
+        return 0;
+    }
+    let mut numerator = 1;
+    let mut denominator = 1;
+    for i in 0..k {
+        numerator = numerator * (n - i) % *p;
+        denominator = denominator * (i + 1) % *p;
+    }
+    numerator * inverse(p, denominator) % *p
 }
 
 /// Computes b^e.
@@ -299,7 +349,9 @@ macro_rules! impl_binomial {
 
             #[inline]
             fn binomial2(n: Self, k: Self) -> Self {
-                if n < k {
+                // We have both signed and unsigned types
+                #[allow(unused_comparisons)]
+                if n < k || k < 0 {
                     0
                 } else if (n - k) & k == 0 {
                     1
@@ -333,17 +385,16 @@ macro_rules! impl_binomial {
                         partial_sum += entry;
                         if partial_sum > total_entry {
                             // This early return is necessary because direct_binomial only works when
-                            // partial_sum < 19
+                            // partial_sum < p
                             return 0;
                         }
-                        // This is safe because p < 20, partial_sum <= total_entry < p and entry < p.
-                        multi *=
-                            unsafe { direct_binomial(p_, partial_sum as usize, entry as usize) }
-                                as Self;
-                        multi %= p;
+                        // This is safe because partial_sum <= total_entry < p and entry < p.
+                        let c =
+                            unsafe { direct_binomial(p_, partial_sum as usize, entry as usize) };
+                        // The product fits in a u32 since p < PRIME_BOUND.
+                        multi = (multi as u32 * c % *p_) as Self;
                     }
-                    answer *= multi;
-                    answer %= p;
+                    answer = (answer as u32 * multi as u32 % *p_) as Self;
                 }
                 answer
             }
@@ -361,10 +412,10 @@ macro_rules! impl_binomial {
                 let mut answer = 1;
 
                 while n > 0 {
-                    // This is safe because p < 20 and anything mod p is < p.
-                    answer *=
-                        unsafe { direct_binomial(p_, (n % p) as usize, (k % p) as usize) } as Self;
-                    answer %= p;
+                    // This is safe because anything mod p is < p.
+                    let c = unsafe { direct_binomial(p_, (n % p) as usize, (k % p) as usize) };
+                    // The product fits in a u32 since p < PRIME_BOUND.
+                    answer = (answer as u32 * c % *p_) as Self;
                     n /= p;
                     k /= p;
                 }
@@ -494,9 +545,19 @@ mod tests {
     use super::*;
     use crate::constants::PRIMES;
 
+    #[test]
+    fn valid_prime_test() {
+        for &p in PRIMES.iter().chain(&[23, 31, 257, 65521]) {
+            assert!(is_valid_prime(p), "{p} should be a valid prime");
+        }
+        for p in [0, 1, 4, 25, 961, 65535, 65537] {
+            assert!(!is_valid_prime(p), "{p} should not be a valid prime");
+        }
+    }
+
     #[test]
     fn inverse_test() {
-        for &p in PRIMES.iter() {
+        for &p in PRIMES.iter().chain(&[23, 31, 65521]) {
             let p = ValidPrime::new(p);
             for k in 1..*p {
                 assert_eq!((inverse(p, k) * k) % *p, 1);
@@ -514,11 +575,17 @@ mod tests {
                 u32::binomial(ValidPrime::new(entry[0] as u32), entry[1], entry[2])
             );
         }
+
+        for p in [2, 3] {
+            let p = ValidPrime::new(p);
+            assert_eq!(i32::binomial(p, -1, -1), 0);
+            assert_eq!(i32::binomial(p, 3, -2), 0);
+        }
     }
 
     #[test]
     fn binomial_vs_monomial() {
-        for &p in &[2, 3, 5, 7, 11] {
+        for &p in &[2, 3, 5, 7, 11, 23, 31] {
             let p = ValidPrime::new(p);
             for l in 0..20 {
                 for m in 0..20 {
@@ -544,7 +611,7 @@ mod tests {
         for n in 0..12 {
             for j in 0..=n {
                 let ans = binomial_full(n, j);
-                for &p in &[2, 3, 5, 7, 11] {
+                for &p in &[2, 3, 5, 7, 11, 23, 31] {
                     assert_eq!(
                         u32::binomial(ValidPrime::new(p), n, j),
                         ans % p,
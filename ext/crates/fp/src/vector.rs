@@ -1,6 +1,7 @@
 //! This module is provides wrappers around the contents of [`crate::vector_inner`]. The main
 //! purpose is to put [`FpVectorP`] for different `p` into a single enum. It does the same for the
-//! various slice structs.
+//! various slice structs. Primes without a dedicated `FpVectorP` variant are handled by
+//! [`FpVectorBig`], which stores the prime at runtime.
 //!
 //! The main magic occurs in the macro `dispatch_vector_inner`, which we use to provide wrapper
 //! functions around the `FpVectorP` functions.
@@ -9,6 +10,7 @@
 
 use crate::limb::{entries_per_limb, Limb};
 use crate::prime::ValidPrime;
+use crate::vector_big::{FpVectorBig, FpVectorNonZeroIteratorBig, SliceBig, SliceMutBig};
 use crate::vector_inner::{
     FpVectorIterator, FpVectorNonZeroIteratorP, FpVectorP, SliceMutP, SliceP,
 };
@@ -29,6 +31,7 @@ macro_rules! dispatch_vector_inner {
                 (Self::_3(ref x), $other::_3(ref y)) => x.$method(y, $($arg),*),
                 (Self::_5(ref x), $other::_5(ref y)) => x.$method(y, $($arg),*),
                 (Self::_7(ref x), $other::_7(ref y)) => x.$method(y, $($arg),*),
+                (Self::Big(ref x), $other::Big(ref y)) => x.$method(y, $($arg),*),
                 (l, r) => {
                     panic!("Applying {} to vectors over different primes ({} and {})", stringify!($method), l.prime(), r.prime());
                 }
@@ -43,6 +46,7 @@ macro_rules! dispatch_vector_inner {
                 (Self::_3(ref mut x), $other::_3(ref y)) => x.$method(y, $($arg),*),
                 (Self::_5(ref mut x), $other::_5(ref y)) => x.$method(y, $($arg),*),
                 (Self::_7(ref mut x), $other::_7(ref y)) => x.$method(y, $($arg),*),
+                (Self::Big(ref mut x), $other::Big(ref y)) => x.$method(y, $($arg),*),
                 (l, r) => {
                     panic!("Applying {} to vectors over different primes ({} and {})", stringify!($method), l.prime(), r.prime());
                 }
@@ -56,6 +60,7 @@ macro_rules! dispatch_vector_inner {
                 (Self::_3(ref mut x), $other::_3(y)) => x.$method(y, $($arg),*),
                 (Self::_5(ref mut x), $other::_5(y)) => x.$method(y, $($arg),*),
                 (Self::_7(ref mut x), $other::_7(y)) => x.$method(y, $($arg),*),
+                (Self::Big(ref mut x), $other::Big(y)) => x.$method(y, $($arg),*),
                 (l, r) => {
                     panic!("Applying {} to vectors over different primes ({} and {})", stringify!($method), l.prime(), r.prime());
                 }
@@ -70,6 +75,7 @@ macro_rules! dispatch_vector_inner {
                 Self::_3(ref mut x) => $ret::_3(x.$method($($arg),*)),
                 Self::_5(ref mut x) => $ret::_5(x.$method($($arg),*)),
                 Self::_7(ref mut x) => $ret::_7(x.$method($($arg),*)),
+                Self::Big(ref mut x) => $ret::Big(x.$method($($arg),*)),
             }
         }
     };
@@ -81,6 +87,7 @@ macro_rules! dispatch_vector_inner {
                 Self::_3(ref x) => $ret::_3(x.$method($($arg),*)),
                 Self::_5(ref x) => $ret::_5(x.$method($($arg),*)),
                 Self::_7(ref x) => $ret::_7(x.$method($($arg),*)),
+                Self::Big(ref x) => $ret::Big(x.$method($($arg),*)),
             }
         }
     };
@@ -92,6 +99,7 @@ macro_rules! dispatch_vector_inner {
                 Self::_3(x) => $ret::_3(x.$method($($arg),*)),
                 Self::_5(x) => $ret::_5(x.$method($($arg),*)),
                 Self::_7(x) => $ret::_7(x.$method($($arg),*)),
+                Self::Big(x) => $ret::Big(x.$method($($arg),*)),
             }
         }
     };
@@ -104,6 +112,7 @@ macro_rules! dispatch_vector_inner {
                 Self::_3(x) => $ret::_3(x.$method($($arg),*)),
                 Self::_5(x) => $ret::_5(x.$method($($arg),*)),
                 Self::_7(x) => $ret::_7(x.$method($($arg),*)),
+                Self::Big(x) => $ret::Big(x.$method($($arg),*)),
             }
         }
     };
@@ -116,6 +125,7 @@ macro_rules! dispatch_vector_inner {
                 Self::_3(ref mut x) => x.$method($($arg),*),
                 Self::_5(ref mut x) => x.$method($($arg),*),
                 Self::_7(ref mut x) => x.$method($($arg),*),
+                Self::Big(ref mut x) => x.$method($($arg),*),
             }
         }
     };
@@ -127,6 +137,7 @@ macro_rules! dispatch_vector_inner {
                 Self::_3(ref x) => x.$method($($arg),*),
                 Self::_5(ref x) => x.$method($($arg),*),
                 Self::_7(ref x) => x.$method($($arg),*),
+                Self::Big(ref x) => x.$method($($arg),*),
             }
         }
     };
@@ -138,6 +149,7 @@ macro_rules! dispatch_vector_inner {
                 Self::_3(x) => x.$method($($arg),*),
                 Self::_5(x) => x.$method($($arg),*),
                 Self::_7(x) => x.$method($($arg),*),
+                Self::Big(x) => x.$method($($arg),*),
             }
         }
     }
@@ -154,13 +166,13 @@ macro_rules! dispatch_vector {
 }
 
 macro_rules! match_p {
-    ($p:ident, $($val:tt)*) => {
+    ($p:ident, $big:expr, $($val:tt)*) => {
         match *$p {
             2 => Self::_2($($val)*),
             3 => Self::_3($($val)*),
             5 => Self::_5($($val)*),
             7 => Self::_7($($val)*),
-            _ => panic!("Prime not supported: {}", *$p)
+            _ => Self::Big($big),
         }
     }
 }
@@ -171,6 +183,7 @@ pub enum FpVector {
     _3(FpVectorP<3>),
     _5(FpVectorP<5>),
     _7(FpVectorP<7>),
+    Big(FpVectorBig),
 }
 
 #[derive(Debug, Copy, Clone)]
@@ -179,6 +192,7 @@ pub enum Slice<'a> {
     _3(SliceP<'a, 3>),
     _5(SliceP<'a, 5>),
     _7(SliceP<'a, 7>),
+    Big(SliceBig<'a>),
 }
 
 #[derive(Debug)]
@@ -187,6 +201,7 @@ pub enum SliceMut<'a> {
     _3(SliceMutP<'a, 3>),
     _5(SliceMutP<'a, 5>),
     _7(SliceMutP<'a, 7>),
+    Big(SliceMutBig<'a>),
 }
 
 pub enum FpVectorNonZeroIterator<'a> {
@@ -194,19 +209,28 @@ pub enum FpVectorNonZeroIterator<'a> {
     _3(FpVectorNonZeroIteratorP<'a, 3>),
     _5(FpVectorNonZeroIteratorP<'a, 5>),
     _7(FpVectorNonZeroIteratorP<'a, 7>),
+    Big(FpVectorNonZeroIteratorBig<'a>),
 }
 
 impl FpVector {
     pub fn new(p: ValidPrime, len: usize) -> FpVector {
-        match_p!(p, FpVectorP::new_(len))
+        match_p!(p, FpVectorBig::new_(p, len), FpVectorP::new_(len))
     }
 
     pub fn new_with_capacity(p: ValidPrime, len: usize, capacity: usize) -> FpVector {
-        match_p!(p, FpVectorP::new_with_capacity_(len, capacity))
+        match_p!(
+            p,
+            FpVectorBig::new_with_capacity_(p, len, capacity),
+            FpVectorP::new_with_capacity_(len, capacity)
+        )
     }
 
     pub fn from_slice(p: ValidPrime, slice: &[u32]) -> Self {
-        match_p!(p, FpVectorP::from(&slice))
+        match_p!(
+            p,
+            FpVectorBig::from_slice(p, slice),
+            FpVectorP::from(&slice)
+        )
     }
 
     pub fn num_limbs(p: ValidPrime, len: usize) -> usize {
@@ -239,7 +263,11 @@ impl FpVector {
             }
             limbs
         };
-        Ok(match_p!(p, FpVectorP::from_raw_parts(len, limbs)))
+        Ok(match_p!(
+            p,
+            FpVectorBig::from_raw_parts(p, len, limbs),
+            FpVectorP::from_raw_parts(len, limbs)
+        ))
     }
 
     pub fn to_bytes(&self, buffer: &mut impl Write) -> std::io::Result<()> {
@@ -343,6 +371,9 @@ impl<'a> SliceMut<'a> {
             (SliceMut::_7(ref mut x), Slice::_7(y), Slice::_7(z)) => {
                 x.add_tensor(offset, coeff, y, z)
             }
+            (SliceMut::Big(ref mut x), Slice::Big(y), Slice::Big(z)) => {
+                x.add_tensor(offset, coeff, y, z)
+            }
             _ => {
                 panic!("Applying add_tensor to vectors over different primes");
             }
@@ -399,11 +430,11 @@ impl<'a> IntoIterator for &'a FpVector {
 }
 
 macro_rules! impl_try_into {
-    ($var:tt, $p:literal) => {
-        impl<'a> TryInto<&'a mut FpVectorP<$p>> for &'a mut FpVector {
+    ($var:tt, $t:ty) => {
+        impl<'a> TryInto<&'a mut $t> for &'a mut FpVector {
             type Error = ();
 
-            fn try_into(self) -> Result<&'a mut FpVectorP<$p>, ()> {
+            fn try_into(self) -> Result<&'a mut $t, ()> {
                 match self {
                     FpVector::$var(ref mut x) => Ok(x),
                     _ => Err(()),
@@ -413,10 +444,11 @@ macro_rules! impl_try_into {
     };
 }
 
-impl_try_into!(_2, 2);
-impl_try_into!(_3, 3);
-impl_try_into!(_5, 5);
-impl_try_into!(_7, 7);
+impl_try_into!(_2, FpVectorP<2>);
+impl_try_into!(_3, FpVectorP<3>);
+impl_try_into!(_5, FpVectorP<5>);
+impl_try_into!(_7, FpVectorP<7>);
+impl_try_into!(Big, FpVectorBig);
 
 #[cfg(feature = "json")]
 impl Serialize for FpVector {
@@ -561,7 +593,7 @@ mod test {
         (fn $name:ident($p:ident: ValidPrime) $body:tt $($rest:tt)*) => {
             #[rstest]
             #[trace]
-            fn $name(#[values(2, 3, 5, 7)] p: u32) {
+            fn $name(#[values(2, 3, 5, 7, 31, 65521)] p: u32) {
                 let $p = ValidPrime::new(p);
 
                 $body
@@ -571,7 +603,7 @@ mod test {
         (fn $name:ident($p:ident: ValidPrime, $dim:ident: usize) $body:tt $($rest:tt)*) => {
             #[rstest]
             #[trace]
-            fn $name(#[values(2, 3, 5, 7)] p: u32, #[values(10, 20, 70, 100, 1000)] $dim: usize) {
+            fn $name(#[values(2, 3, 5, 7, 31, 65521)] p: u32, #[values(10, 20, 70, 100, 1000)] $dim: usize) {
                 let $p = ValidPrime::new(p);
 
                 $body
@@ -581,7 +613,7 @@ mod test {
         (fn $name:ident($p:ident: ValidPrime, $dim:ident: usize, $slice_start:ident: usize, $slice_end:ident: usize) $body:tt $($rest:tt)*) => {
             #[rstest]
             #[trace]
-            fn $name(#[values(2, 3, 5, 7)] p: u32, #[values(10, 20, 70, 100, 1000)] $dim: usize) {
+            fn $name(#[values(2, 3, 5, 7, 31, 65521)] p: u32, #[values(10, 20, 70, 100, 1000)] $dim: usize) {
                 let $p = ValidPrime::new(p);
 
                 let $slice_start = match $dim {
@@ -855,6 +887,10 @@ mod test {
         fn test_iterator_slice(p: ValidPrime) {
             let ep = entries_per_limb(p);
             for &dim in &[5, 10, ep, ep - 1, ep + 1, 3 * ep, 3 * ep - 1, 3 * ep + 1] {
+                // Large primes have too few entries per limb for the slice to make sense
+                if dim < 4 {
+                    continue;
+                }
                 let v_arr = random_vector(p, dim);
                 let v = FpVector::from_slice(p, &v_arr);
                 let v = v.slice(3, dim - 1);
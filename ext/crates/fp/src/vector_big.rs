@@ -0,0 +1,468 @@
+//! Vectors over $\mathbb{F}_p$ for primes that do not have a dedicated [`FpVectorP`]
+//! implementation.
+//!
+//! Entries are packed into limbs exactly as in [`FpVectorP`], so the two share the same
+//! serialization format. However, the prime is only known at runtime and all operations are
+//! performed one entry at a time. This is much slower than [`FpVectorP`], and is intended for the
+//! occasional computation at a large prime.
+//!
+//! [`FpVectorP`]: crate::vector_inner::FpVectorP
+
+use crate::limb::{bit_length, entries_per_limb, Limb};
+use crate::prime::ValidPrime;
+use crate::vector_inner::FpVectorIterator;
+
+#[derive(Debug, Hash, Eq, PartialEq, Clone)]
+pub struct FpVectorBig {
+    p: ValidPrime,
+    len: usize,
+    limbs: Vec<Limb>,
+}
+
+#[derive(Debug, Copy, Clone)]
+pub struct SliceBig<'a> {
+    p: ValidPrime,
+    limbs: &'a [Limb],
+    start: usize,
+    end: usize,
+}
+
+#[derive(Debug)]
+pub struct SliceMutBig<'a> {
+    p: ValidPrime,
+    limbs: &'a mut [Limb],
+    start: usize,
+    end: usize,
+}
+
+pub struct FpVectorNonZeroIteratorBig<'a> {
+    slice: SliceBig<'a>,
+    idx: usize,
+}
+
+fn number(p: ValidPrime, len: usize) -> usize {
+    len.div_ceil(entries_per_limb(p))
+}
+
+fn get(p: ValidPrime, limbs: &[Limb], idx: usize) -> u32 {
+    let entries_per_limb = entries_per_limb(p);
+    let bit_length = bit_length(p);
+    let bit_mask = (1 << bit_length) - 1;
+    ((limbs[idx / entries_per_limb] >> (idx % entries_per_limb * bit_length)) & bit_mask) as u32
+}
+
+fn set(p: ValidPrime, limbs: &mut [Limb], idx: usize, value: u32) {
+    let entries_per_limb = entries_per_limb(p);
+    let bit_length = bit_length(p);
+    let bit_mask: Limb = (1 << bit_length) - 1;
+    let bit_index = idx % entries_per_limb * bit_length;
+    let limb = &mut limbs[idx / entries_per_limb];
+    *limb &= !(bit_mask << bit_index);
+    *limb |= (value as Limb) << bit_index;
+}
+
+/// Compute `a + c * b` mod p without overflowing.
+fn add_mul(p: ValidPrime, a: u32, b: u32, c: u32) -> u32 {
+    ((a as u64 + b as u64 * c as u64) % *p as u64) as u32
+}
+
+impl FpVectorBig {
+    pub fn new_(p: ValidPrime, len: usize) -> Self {
+        Self {
+            p,
+            len,
+            limbs: vec![0; number(p, len)],
+        }
+    }
+
+    pub fn from_raw_parts(p: ValidPrime, len: usize, limbs: Vec<Limb>) -> Self {
+        debug_assert_eq!(limbs.len(), number(p, len));
+        Self { p, len, limbs }
+    }
+
+    pub fn new_with_capacity_(p: ValidPrime, len: usize, capacity: usize) -> Self {
+        let mut limbs = Vec::with_capacity(number(p, capacity));
+        limbs.resize(number(p, len), 0);
+        Self { p, len, limbs }
+    }
+
+    pub fn from_slice(p: ValidPrime, slice: &[u32]) -> Self {
+        let mut v = Self::new_(p, slice.len());
+        v.copy_from_slice(slice);
+        v
+    }
+
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub const fn prime(&self) -> ValidPrime {
+        self.p
+    }
+
+    #[must_use]
+    pub fn slice(&self, start: usize, end: usize) -> SliceBig<'_> {
+        assert!(start <= end && end <= self.len);
+        SliceBig {
+            p: self.p,
+            limbs: &self.limbs,
+            start,
+            end,
+        }
+    }
+
+    #[must_use]
+    pub fn slice_mut(&mut self, start: usize, end: usize) -> SliceMutBig<'_> {
+        assert!(start <= end && end <= self.len);
+        SliceMutBig {
+            p: self.p,
+            limbs: &mut self.limbs,
+            start,
+            end,
+        }
+    }
+
+    #[must_use]
+    pub fn as_slice(&self) -> SliceBig<'_> {
+        self.slice(0, self.len)
+    }
+
+    #[must_use]
+    pub fn as_slice_mut(&mut self) -> SliceMutBig<'_> {
+        self.slice_mut(0, self.len)
+    }
+
+    pub fn add_basis_element(&mut self, index: usize, value: u32) {
+        self.as_slice_mut().add_basis_element(index, value);
+    }
+
+    pub fn entry(&self, index: usize) -> u32 {
+        self.as_slice().entry(index)
+    }
+
+    pub fn set_entry(&mut self, index: usize, value: u32) {
+        self.as_slice_mut().set_entry(index, value);
+    }
+
+    pub fn iter(&self) -> FpVectorIterator<'_> {
+        self.as_slice().iter()
+    }
+
+    pub fn iter_nonzero(&self) -> FpVectorNonZeroIteratorBig<'_> {
+        self.as_slice().iter_nonzero()
+    }
+
+    pub fn set_to_zero(&mut self) {
+        for limb in &mut self.limbs {
+            *limb = 0;
+        }
+    }
+
+    pub fn scale(&mut self, c: u32) {
+        self.as_slice_mut().scale(c);
+    }
+
+    pub fn add_offset(&mut self, other: &Self, c: u32, offset: usize) {
+        debug_assert_eq!(self.len(), other.len());
+        self.slice_mut(offset, self.len)
+            .add(other.slice(offset, other.len), c);
+    }
+
+    pub fn add_offset_nosimd(&mut self, other: &Self, c: u32, offset: usize) {
+        self.add_offset(other, c, offset);
+    }
+
+    pub fn add(&mut self, other: &Self, c: u32) {
+        self.add_offset(other, c, 0);
+    }
+
+    pub fn add_nosimd(&mut self, other: &Self, c: u32) {
+        self.add_offset(other, c, 0);
+    }
+
+    pub fn assign(&mut self, other: &Self) {
+        debug_assert_eq!(self.len(), other.len());
+        self.limbs.copy_from_slice(&other.limbs)
+    }
+
+    /// A version of [`FpVectorBig::assign`] that allows `other` to be shorter than `self`.
+    pub fn assign_partial(&mut self, other: &Self) {
+        debug_assert!(other.len() <= self.len());
+        self.limbs[0..other.limbs.len()].copy_from_slice(&other.limbs);
+        for limb in self.limbs[other.limbs.len()..].iter_mut() {
+            *limb = 0;
+        }
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.limbs.iter().all(|&x| x == 0)
+    }
+
+    pub(crate) fn limbs(&self) -> &[Limb] {
+        &self.limbs
+    }
+
+    pub(crate) fn limbs_mut(&mut self) -> &mut [Limb] {
+        &mut self.limbs
+    }
+
+    /// This function ensures the length of the vector is at least `len`. See also
+    /// `set_scratch_vector_size`.
+    pub fn extend_len(&mut self, len: usize) {
+        if self.len >= len {
+            return;
+        }
+        self.len = len;
+        self.limbs.resize(number(self.p, len), 0);
+    }
+
+    /// This clears the vector and sets the length to `len`. This is useful for reusing
+    /// allocations of temporary vectors.
+    pub fn set_scratch_vector_size(&mut self, len: usize) {
+        self.limbs.clear();
+        self.limbs.resize(number(self.p, len), 0);
+        self.len = len;
+    }
+
+    /// This replaces the contents of the vector with the contents of the slice. The two must have
+    /// the same length.
+    pub fn copy_from_slice(&mut self, slice: &[u32]) {
+        assert_eq!(self.len, slice.len());
+        for (i, &x) in slice.iter().enumerate() {
+            set(self.p, &mut self.limbs, i, x);
+        }
+    }
+
+    /// Permanently remove the first `n` elements in the vector. `n` must be a multiple of
+    /// the number of entries per limb
+    pub(crate) fn trim_start(&mut self, n: usize) {
+        assert!(n <= self.len);
+        let entries_per = entries_per_limb(self.p);
+        assert_eq!(n % entries_per, 0);
+        let num_limbs = n / entries_per;
+        self.limbs.drain(0..num_limbs);
+        self.len -= n;
+    }
+
+    pub fn sign_rule(&self, _other: &Self) -> bool {
+        panic!("sign_rule is only defined at the prime 2");
+    }
+
+    pub fn add_truncate(&mut self, other: &Self, c: u32) -> Option<()> {
+        for i in 0..self.len {
+            let sum = self.entry(i) as u64 + c as u64 * other.entry(i) as u64;
+            if sum >= *self.p as u64 {
+                return None;
+            }
+            self.set_entry(i, sum as u32);
+        }
+        Some(())
+    }
+
+    pub fn add_carry<T>(&mut self, _other: &Self, _c: u32, _rest: &mut [T]) -> bool {
+        panic!("add_carry is only defined at the prime 2");
+    }
+
+    /// Find the index and value of the first non-zero entry of the vector. `None` if the vector is zero.
+    pub fn first_nonzero(&self) -> Option<(usize, u32)> {
+        self.iter_nonzero().next()
+    }
+}
+
+impl<'a> SliceBig<'a> {
+    pub fn prime(&self) -> ValidPrime {
+        self.p
+    }
+
+    pub fn len(&self) -> usize {
+        self.end - self.start
+    }
+
+    pub const fn is_empty(&self) -> bool {
+        self.start == self.end
+    }
+
+    pub fn entry(&self, index: usize) -> u32 {
+        debug_assert!(index < self.len());
+        get(self.p, self.limbs, index + self.start)
+    }
+
+    pub fn iter(self) -> FpVectorIterator<'a> {
+        FpVectorIterator::new_runtime(
+            self.limbs,
+            self.start,
+            self.len(),
+            bit_length(self.p),
+            entries_per_limb(self.p),
+        )
+    }
+
+    pub fn iter_nonzero(self) -> FpVectorNonZeroIteratorBig<'a> {
+        FpVectorNonZeroIteratorBig {
+            slice: self,
+            idx: 0,
+        }
+    }
+
+    pub fn is_zero(&self) -> bool {
+        (0..self.len()).all(|i| self.entry(i) == 0)
+    }
+
+    #[must_use]
+    pub fn slice(self, start: usize, end: usize) -> SliceBig<'a> {
+        assert!(start <= end && end <= self.len());
+        SliceBig {
+            p: self.p,
+            limbs: self.limbs,
+            start: self.start + start,
+            end: self.start + end,
+        }
+    }
+
+    #[must_use]
+    pub fn to_owned(self) -> FpVectorBig {
+        let mut new = FpVectorBig::new_(self.p, self.len());
+        for i in 0..self.len() {
+            new.set_entry(i, self.entry(i));
+        }
+        new
+    }
+}
+
+impl<'a> SliceMutBig<'a> {
+    pub fn prime(&self) -> ValidPrime {
+        self.p
+    }
+
+    #[must_use]
+    pub fn slice_mut(&mut self, start: usize, end: usize) -> SliceMutBig<'_> {
+        assert!(start <= end && end <= self.as_slice().len());
+        SliceMutBig {
+            p: self.p,
+            limbs: self.limbs,
+            start: self.start + start,
+            end: self.start + end,
+        }
+    }
+
+    #[must_use]
+    pub fn as_slice(&self) -> SliceBig<'_> {
+        SliceBig {
+            p: self.p,
+            limbs: self.limbs,
+            start: self.start,
+            end: self.end,
+        }
+    }
+
+    /// Generates a version of itself with a shorter lifetime
+    #[must_use]
+    pub fn copy(&mut self) -> SliceMutBig<'_> {
+        SliceMutBig {
+            p: self.p,
+            limbs: self.limbs,
+            start: self.start,
+            end: self.end,
+        }
+    }
+
+    pub fn add_basis_element(&mut self, index: usize, value: u32) {
+        let x = add_mul(self.p, self.as_slice().entry(index), value, 1);
+        self.set_entry(index, x);
+    }
+
+    pub fn set_entry(&mut self, index: usize, value: u32) {
+        debug_assert!(index < self.as_slice().len());
+        set(self.p, self.limbs, index + self.start, value);
+    }
+
+    pub fn scale(&mut self, c: u32) {
+        for i in 0..self.as_slice().len() {
+            let x = add_mul(self.p, 0, self.as_slice().entry(i), c);
+            self.set_entry(i, x);
+        }
+    }
+
+    pub fn set_to_zero(&mut self) {
+        for i in 0..self.as_slice().len() {
+            self.set_entry(i, 0);
+        }
+    }
+
+    pub fn add(&mut self, other: SliceBig<'_>, c: u32) {
+        debug_assert_eq!(self.as_slice().len(), other.len());
+        if c == 0 {
+            return;
+        }
+        for (i, v) in other.iter_nonzero() {
+            let x = add_mul(self.p, self.as_slice().entry(i), v, c);
+            self.set_entry(i, x);
+        }
+    }
+
+    /// `coeff` need not be reduced mod p.
+    /// Adds v otimes w to self.
+    pub fn add_tensor(
+        &mut self,
+        offset: usize,
+        coeff: u32,
+        left: SliceBig<'_>,
+        right: SliceBig<'_>,
+    ) {
+        let right_dim = right.len();
+
+        for (i, v) in left.iter_nonzero() {
+            let entry = add_mul(self.p, 0, v, coeff);
+            self.slice_mut(offset + i * right_dim, offset + (i + 1) * right_dim)
+                .add(right, entry);
+        }
+    }
+
+    pub fn assign(&mut self, other: SliceBig<'_>) {
+        debug_assert_eq!(self.as_slice().len(), other.len());
+        for i in 0..other.len() {
+            self.set_entry(i, other.entry(i));
+        }
+    }
+
+    /// Given a mask v, add the `v[i]`th entry of `other` to the `i`th entry of `self`.
+    pub fn add_masked(&mut self, other: SliceBig<'_>, c: u32, mask: &[usize]) {
+        assert_eq!(self.as_slice().len(), mask.len());
+        for (i, &x) in mask.iter().enumerate() {
+            let entry = other.entry(x);
+            if entry != 0 {
+                let x = add_mul(self.p, self.as_slice().entry(i), entry, c);
+                self.set_entry(i, x);
+            }
+        }
+    }
+
+    /// Given a mask v, add the `i`th entry of `other` to the `v[i]`th entry of `self`.
+    pub fn add_unmasked(&mut self, other: SliceBig<'_>, c: u32, mask: &[usize]) {
+        assert_eq!(other.len(), mask.len());
+        for (i, v) in other.iter_nonzero() {
+            let x = add_mul(self.p, self.as_slice().entry(mask[i]), v, c);
+            self.set_entry(mask[i], x);
+        }
+    }
+}
+
+impl<'a> Iterator for FpVectorNonZeroIteratorBig<'a> {
+    type Item = (usize, u32);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.idx < self.slice.len() {
+            let idx = self.idx;
+            self.idx += 1;
+            let entry = self.slice.entry(idx);
+            if entry != 0 {
+                return Some((idx, entry));
+            }
+        }
+        None
+    }
+}
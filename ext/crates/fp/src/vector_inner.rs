@@ -1035,8 +1035,25 @@ pub struct FpVectorIterator<'a> {
 
 impl<'a> FpVectorIterator<'a> {
     fn new<const P: u32>(vec: SliceP<'a, P>) -> Self {
-        let counter = vec.len();
-        let limbs = &vec.limbs;
+        Self::new_runtime(
+            vec.limbs,
+            vec.start,
+            vec.len(),
+            limb::bit_length_const::<P>(),
+            limb::entries_per_limb_const::<P>(),
+        )
+    }
+
+    /// Iterate over `len` entries of `limbs` starting at `start`, where each entry occupies
+    /// `bit_length` bits and each limb holds `entries_per_limb` entries.
+    pub(crate) fn new_runtime(
+        limbs: &'a [Limb],
+        start: usize,
+        len: usize,
+        bit_length: usize,
+        entries_per_limb: usize,
+    ) -> Self {
+        let counter = len;
 
         if counter == 0 {
             return Self {
@@ -1050,19 +1067,18 @@ impl<'a> FpVectorIterator<'a> {
                 counter,
             };
         }
-        let pair = limb::limb_bit_index_pair::<P>(vec.start);
+        let limb_index = start / entries_per_limb;
+        let bit_index = start % entries_per_limb * bit_length;
 
-        let bit_length = limb::bit_length_const::<P>();
-        let cur_limb = limbs[pair.limb] >> pair.bit_index;
+        let cur_limb = limbs[limb_index] >> bit_index;
 
-        let entries_per_limb = limb::entries_per_limb_const::<P>();
         Self {
             limbs,
             bit_length,
             entries_per_limb_m_1: entries_per_limb - 1,
-            bit_mask: limb::bitmask::<P>(),
-            limb_index: pair.limb,
-            entries_left: entries_per_limb - (vec.start % entries_per_limb),
+            bit_mask: (1 << bit_length) - 1,
+            limb_index,
+            entries_left: entries_per_limb - (start % entries_per_limb),
             cur_limb,
             counter,
         }
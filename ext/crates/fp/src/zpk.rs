@@ -0,0 +1,425 @@
+//! Linear algebra over $\mathbb{Z}/p^k$.
+//!
+//! Unlike the rest of the crate, this is not optimized at all. It is intended for computing the
+//! homology of small chain complexes with torsion information, where the matrices involved are
+//! tiny. Matrices are stored densely as lists of rows, and as in [`crate::matrix::Matrix`], they
+//! act on the right of row vectors.
+
+use crate::prime::ValidPrime;
+
+use std::fmt;
+
+/// The ring $\mathbb{Z}/p^k$. Elements are represented by `u32` in the range `0..p^k`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Zpk {
+    p: ValidPrime,
+    k: u32,
+    modulus: u32,
+}
+
+impl Zpk {
+    /// Create the ring $\mathbb{Z}/p^k$. This panics if $k = 0$ or $p^k$ does not fit in a `u32`.
+    pub fn new(p: ValidPrime, k: u32) -> Self {
+        assert!(k > 0, "Z/p^0 is the zero ring");
+        let modulus = (*p)
+            .checked_pow(k)
+            .unwrap_or_else(|| panic!("{}^{} does not fit in a u32", p, k));
+        Self { p, k, modulus }
+    }
+
+    pub fn prime(&self) -> ValidPrime {
+        self.p
+    }
+
+    pub fn k(&self) -> u32 {
+        self.k
+    }
+
+    /// The number $p^k$.
+    pub fn modulus(&self) -> u32 {
+        self.modulus
+    }
+
+    /// Reduce an arbitrary integer into the range `0..p^k`.
+    pub fn reduce(&self, x: i64) -> u32 {
+        x.rem_euclid(self.modulus as i64) as u32
+    }
+
+    pub fn add(&self, a: u32, b: u32) -> u32 {
+        ((a as u64 + b as u64) % self.modulus as u64) as u32
+    }
+
+    pub fn mul(&self, a: u32, b: u32) -> u32 {
+        ((a as u64 * b as u64) % self.modulus as u64) as u32
+    }
+
+    pub fn neg(&self, a: u32) -> u32 {
+        (self.modulus - a) % self.modulus
+    }
+
+    /// The $p$-adic valuation of `a`. This is `k` if `a` is zero.
+    pub fn valuation(&self, mut a: u32) -> u32 {
+        if a == 0 {
+            return self.k;
+        }
+        let mut v = 0;
+        while a.is_multiple_of(*self.p) {
+            a /= *self.p;
+            v += 1;
+        }
+        v
+    }
+
+    /// $p^e$, reduced mod $p^k$.
+    pub fn p_power(&self, e: u32) -> u32 {
+        if e >= self.k {
+            0
+        } else {
+            (*self.p).pow(e)
+        }
+    }
+
+    /// The inverse of a unit. This panics if `a` is divisible by $p$.
+    pub fn inverse(&self, a: u32) -> u32 {
+        assert!(
+            !a.is_multiple_of(*self.p),
+            "{} is not a unit mod {}",
+            a,
+            self.modulus
+        );
+        // Since a^{φ(p^k)} = 1, the inverse is a^{φ(p^k) - 1}.
+        let phi = self.modulus / *self.p * (*self.p - 1);
+        let mut result = 1;
+        let mut b = a;
+        let mut e = phi - 1;
+        while e > 0 {
+            if e & 1 == 1 {
+                result = self.mul(result, b);
+            }
+            b = self.mul(b, b);
+            e >>= 1;
+        }
+        result
+    }
+
+    /// Write `a` as $p^v u$ where $u$ is a unit, and return $(v, u)$. The unit is not unique
+    /// mod $p^k$; we return one particular choice. This panics if `a` is zero.
+    pub fn split(&self, a: u32) -> (u32, u32) {
+        assert!(a != 0);
+        let v = self.valuation(a);
+        (v, a / (*self.p).pow(v))
+    }
+}
+
+impl fmt::Display for Zpk {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.k == 1 {
+            write!(f, "Z/{}", self.p)
+        } else {
+            write!(f, "Z/{}^{}", self.p, self.k)
+        }
+    }
+}
+
+/// A matrix over $\mathbb{Z}/p^k$.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ZpkMatrix {
+    ring: Zpk,
+    columns: usize,
+    rows: Vec<Vec<u32>>,
+}
+
+/// The result of [`ZpkMatrix::smith_normal_form`].
+///
+/// If the original matrix is $A$, then $U A V = D$, where $U$ and $V$ are invertible and $D$ is
+/// diagonal with entries $p^{e_0}, p^{e_1}, \ldots$, with $e_0 \leq e_1 \leq \cdots$. We record the
+/// exponents $e_i$, where an exponent of $k$ means the diagonal entry is zero. There are
+/// `min(rows, columns)` diagonal entries.
+pub struct SmithNormalForm {
+    pub exponents: Vec<u32>,
+    pub u: ZpkMatrix,
+    pub u_inverse: ZpkMatrix,
+}
+
+impl ZpkMatrix {
+    pub fn new(ring: Zpk, rows: usize, columns: usize) -> Self {
+        Self {
+            ring,
+            columns,
+            rows: vec![vec![0; columns]; rows],
+        }
+    }
+
+    pub fn identity(ring: Zpk, dim: usize) -> Self {
+        let mut result = Self::new(ring, dim, dim);
+        for i in 0..dim {
+            result.rows[i][i] = 1;
+        }
+        result
+    }
+
+    /// Produces a matrix from a list of rows. The entries are reduced mod $p^k$, and negative
+    /// entries are allowed.
+    pub fn from_vec(ring: Zpk, input: &[Vec<i64>], columns: usize) -> Self {
+        let rows = input
+            .iter()
+            .map(|row| {
+                assert_eq!(row.len(), columns);
+                row.iter().map(|&x| ring.reduce(x)).collect()
+            })
+            .collect();
+        Self {
+            ring,
+            columns,
+            rows,
+        }
+    }
+
+    pub fn ring(&self) -> Zpk {
+        self.ring
+    }
+
+    pub fn rows(&self) -> usize {
+        self.rows.len()
+    }
+
+    pub fn columns(&self) -> usize {
+        self.columns
+    }
+
+    pub fn entry(&self, row: usize, column: usize) -> u32 {
+        self.rows[row][column]
+    }
+
+    pub fn set_entry(&mut self, row: usize, column: usize, value: u32) {
+        self.rows[row][column] = value % self.ring.modulus();
+    }
+
+    pub fn row(&self, row: usize) -> &[u32] {
+        &self.rows[row]
+    }
+
+    pub fn to_vec(&self) -> Vec<Vec<u32>> {
+        self.rows.clone()
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.rows.iter().all(|row| row.iter().all(|&x| x == 0))
+    }
+
+    /// Apply the matrix to a row vector, i.e. compute `v * self`.
+    pub fn apply(&self, v: &[u32]) -> Vec<u32> {
+        assert_eq!(v.len(), self.rows());
+        let mut result = vec![0; self.columns];
+        for (&c, row) in v.iter().zip(&self.rows) {
+            if c == 0 {
+                continue;
+            }
+            for (r, &x) in result.iter_mut().zip(row) {
+                *r = self.ring.add(*r, self.ring.mul(c, x));
+            }
+        }
+        result
+    }
+
+    /// `row[target] += c * row[source]`
+    fn add_row(&mut self, target: usize, source: usize, c: u32) {
+        if c == 0 {
+            return;
+        }
+        for i in 0..self.columns {
+            let v = self.ring.mul(c, self.rows[source][i]);
+            self.rows[target][i] = self.ring.add(self.rows[target][i], v);
+        }
+    }
+
+    /// `column[target] += c * column[source]`
+    fn add_column(&mut self, target: usize, source: usize, c: u32) {
+        if c == 0 {
+            return;
+        }
+        for row in &mut self.rows {
+            row[target] = self.ring.add(row[target], self.ring.mul(c, row[source]));
+        }
+    }
+
+    fn scale_row(&mut self, row: usize, c: u32) {
+        for x in &mut self.rows[row] {
+            *x = self.ring.mul(*x, c);
+        }
+    }
+
+    fn scale_column(&mut self, column: usize, c: u32) {
+        for row in &mut self.rows {
+            row[column] = self.ring.mul(row[column], c);
+        }
+    }
+
+    fn swap_columns(&mut self, i: usize, j: usize) {
+        for row in &mut self.rows {
+            row.swap(i, j);
+        }
+    }
+
+    /// Compute the Smith normal form of the matrix. Since $\mathbb{Z}/p^k$ is a local ring, this
+    /// amounts to repeatedly choosing a pivot of minimal valuation and clearing its row and column.
+    /// We keep track of the row operations and their inverses, since these are what one needs to
+    /// compute kernels. The column operations are discarded.
+    pub fn smith_normal_form(&self) -> SmithNormalForm {
+        let ring = self.ring;
+        let mut m = self.clone();
+        let mut u = Self::identity(ring, self.rows());
+        let mut u_inverse = Self::identity(ring, self.rows());
+
+        let num_pivots = std::cmp::min(self.rows(), self.columns());
+        let mut exponents = Vec::with_capacity(num_pivots);
+
+        for i in 0..num_pivots {
+            // Find the entry of minimal valuation in the remaining block
+            let mut best: Option<(u32, usize, usize)> = None;
+            for r in i..m.rows() {
+                for c in i..m.columns() {
+                    let x = m.rows[r][c];
+                    if x == 0 {
+                        continue;
+                    }
+                    let v = ring.valuation(x);
+                    if best.is_none_or(|(bv, _, _)| v < bv) {
+                        best = Some((v, r, c));
+                    }
+                }
+            }
+            let (v, r, c) = match best {
+                Some(x) => x,
+                None => {
+                    exponents.resize(num_pivots, ring.k());
+                    break;
+                }
+            };
+
+            // Move the pivot to (i, i). Row swaps are mirrored as column swaps on u_inverse.
+            m.rows.swap(i, r);
+            u.rows.swap(i, r);
+            u_inverse.swap_columns(i, r);
+            m.swap_columns(i, c);
+
+            // Normalize the pivot to p^v
+            let (_, unit) = ring.split(m.rows[i][i]);
+            let unit_inv = ring.inverse(unit);
+            m.scale_row(i, unit_inv);
+            u.scale_row(i, unit_inv);
+            u_inverse.scale_column(i, unit);
+
+            // Every other entry in the block is divisible by p^v, so we can clear the pivot row
+            // and column.
+            let pv = (*ring.prime()).pow(v);
+            for r in 0..m.rows() {
+                if r == i || m.rows[r][i] == 0 {
+                    continue;
+                }
+                let c = ring.neg(m.rows[r][i] / pv);
+                m.add_row(r, i, c);
+                u.add_row(r, i, c);
+                // The inverse of adding c * row i to row r is subtracting it, which on the right
+                // is the column operation col_i -= c * col_r.
+                u_inverse.add_column(i, r, ring.neg(c));
+            }
+            for c in i + 1..m.columns() {
+                if m.rows[i][c] == 0 {
+                    continue;
+                }
+                let x = ring.neg(m.rows[i][c] / pv);
+                m.add_column(c, i, x);
+            }
+            exponents.push(v);
+        }
+
+        SmithNormalForm {
+            exponents,
+            u,
+            u_inverse,
+        }
+    }
+
+    /// Compute the isomorphism type of the cokernel of the matrix, i.e. the quotient of
+    /// $(\mathbb{Z}/p^k)^{\mathrm{columns}}$ by the span of the rows. The result is a list of
+    /// exponents $e_i$ such that the cokernel is $\bigoplus \mathbb{Z}/p^{e_i}$. Trivial summands
+    /// are omitted, and the list is sorted in increasing order.
+    pub fn cokernel(&self) -> Vec<u32> {
+        let k = self.ring.k();
+        let snf = self.smith_normal_form();
+        let mut result: Vec<u32> = snf.exponents.iter().copied().filter(|&e| e > 0).collect();
+        result.extend(std::iter::repeat_n(k, self.columns - snf.exponents.len()));
+        result.sort_unstable();
+        result
+    }
+}
+
+impl fmt::Display for ZpkMatrix {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for row in &self.rows {
+            writeln!(f, "{:?}", row)?;
+        }
+        Ok(())
+    }
+}
+
+impl std::ops::Mul for &ZpkMatrix {
+    type Output = ZpkMatrix;
+
+    fn mul(self, rhs: Self) -> ZpkMatrix {
+        assert_eq!(self.ring, rhs.ring);
+        assert_eq!(self.columns, rhs.rows());
+        ZpkMatrix {
+            ring: self.ring,
+            columns: rhs.columns,
+            rows: self.rows.iter().map(|row| rhs.apply(row)).collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_inverse() {
+        let ring = Zpk::new(ValidPrime::new(3), 3);
+        for a in 1..27 {
+            if a % 3 != 0 {
+                assert_eq!(ring.mul(a, ring.inverse(a)), 1);
+            }
+        }
+    }
+
+    #[test]
+    fn test_smith_normal_form() {
+        let ring = Zpk::new(ValidPrime::new(2), 4);
+        let input = [vec![2, 4, 6], vec![4, 12, 8], vec![6, 0, 3]];
+        let m = ZpkMatrix::from_vec(ring, &input, 3);
+        let snf = m.smith_normal_form();
+
+        assert_eq!(snf.exponents, vec![0, 1, 2]);
+        assert_eq!(&snf.u * &snf.u_inverse, ZpkMatrix::identity(ring, 3));
+
+        // U A is A with its columns operated on, so its rows have the expected valuations.
+        let ua = &snf.u * &m;
+        for (i, &e) in snf.exponents.iter().enumerate() {
+            let v = ua.row(i).iter().map(|&x| ring.valuation(x)).min().unwrap();
+            assert_eq!(v, e);
+        }
+    }
+
+    #[test]
+    fn test_cokernel() {
+        let ring = Zpk::new(ValidPrime::new(2), 3);
+        // Z/8 / 2 = Z/2
+        assert_eq!(ZpkMatrix::from_vec(ring, &[vec![2]], 1).cokernel(), vec![1]);
+        // (Z/8)^2 / (4, 0), (2, 2) = Z/2 + Z/4
+        assert_eq!(
+            ZpkMatrix::from_vec(ring, &[vec![4, 0], vec![2, 2]], 2).cokernel(),
+            vec![1, 2]
+        );
+        assert_eq!(ZpkMatrix::new(ring, 0, 2).cokernel(), vec![3, 3]);
+    }
+}
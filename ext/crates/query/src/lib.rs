@@ -24,6 +24,15 @@ thread_local! {
     }
 }
 
+/// Discard all remaining command line arguments, so that subsequent queries are always answered
+/// interactively. This is used by programs that parse their own command line arguments and only
+/// fall back to prompting for values that were not supplied.
+///
+/// The arguments are stored per thread, so this only affects queries made from the current thread.
+pub fn clear_args() {
+    ARGV.with(|argv| argv.borrow_mut().by_ref().for_each(drop));
+}
+
 pub fn optional<S, E: Display>(
     prompt: &str,
     mut parser: impl for<'a> FnMut(&'a str) -> Result<S, E>,
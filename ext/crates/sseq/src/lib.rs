@@ -1,6 +1,8 @@
 mod bigraded;
 mod differential;
+mod product;
 mod sseq;
 pub use crate::sseq::*;
 pub use bigraded::*;
 pub use differential::*;
+pub use product::*;
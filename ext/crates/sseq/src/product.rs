@@ -0,0 +1,91 @@
+use std::collections::BTreeMap;
+
+use fp::{
+    matrix::Matrix,
+    vector::{Slice, SliceMut},
+};
+
+/// A linear operation on the classes of a spectral sequence that is homogeneous in each bidegree.
+///
+/// This models multiplication by a fixed class as well as operations such as algebraic Steenrod
+/// operations, whose shift in bidegree depends on the source bidegree. The operation is recorded
+/// as a matrix from each source bidegree to its target bidegree, and is undefined in bidegrees
+/// where no matrix has been set.
+pub struct Product {
+    pub name: String,
+    /// (x, y) -> (target_x, target_y, matrix)
+    matrices: BTreeMap<(i32, i32), (i32, i32, Matrix)>,
+}
+
+impl Product {
+    pub fn new(name: String) -> Self {
+        Self {
+            name,
+            matrices: BTreeMap::new(),
+        }
+    }
+
+    /// Set the matrix of the operation from bidegree `(x, y)` to `(target_x, target_y)`. The rows
+    /// of the matrix are the images of the basis elements of the source.
+    pub fn set_matrix(&mut self, x: i32, y: i32, target_x: i32, target_y: i32, matrix: Matrix) {
+        self.matrices.insert((x, y), (target_x, target_y, matrix));
+    }
+
+    pub fn defined(&self, x: i32, y: i32) -> bool {
+        self.matrices.contains_key(&(x, y))
+    }
+
+    /// The bidegree the operation sends `(x, y)` to, if it is defined.
+    pub fn target(&self, x: i32, y: i32) -> Option<(i32, i32)> {
+        self.matrices.get(&(x, y)).map(|&(tx, ty, _)| (tx, ty))
+    }
+
+    pub fn matrix(&self, x: i32, y: i32) -> Option<&Matrix> {
+        self.matrices.get(&(x, y)).map(|(_, _, m)| m)
+    }
+
+    /// Iterate through the source bidegrees where the operation is defined, together with the
+    /// target bidegree and the matrix.
+    pub fn iter(&self) -> impl Iterator<Item = ((i32, i32), (i32, i32), &Matrix)> + '_ {
+        self.matrices
+            .iter()
+            .map(|(&source, (tx, ty, m))| (source, (*tx, *ty), m))
+    }
+
+    /// Add `coeff` times the image of `source` in bidegree `(x, y)` to `result`.
+    ///
+    /// # Returns
+    ///
+    /// Whether the operation is defined in bidegree `(x, y)`.
+    pub fn apply(&self, mut result: SliceMut, coeff: u32, x: i32, y: i32, source: Slice) -> bool {
+        let Some((_, _, matrix)) = self.matrices.get(&(x, y)) else {
+            return false;
+        };
+        for (i, v) in source.iter_nonzero() {
+            result.add(matrix[i].as_slice(), coeff * v);
+        }
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use fp::{prime::ValidPrime, vector::FpVector};
+
+    #[test]
+    fn test_product_apply() {
+        let p = ValidPrime::new(3);
+        let mut product = Product::new("P^0".to_string());
+        product.set_matrix(2, 1, 10, 1, Matrix::from_vec(p, &[vec![1, 0], vec![2, 1]]));
+
+        assert_eq!(product.target(2, 1), Some((10, 1)));
+        assert_eq!(product.target(1, 1), None);
+
+        let mut result = FpVector::new(p, 2);
+        let source = FpVector::from_slice(p, &[1, 1]);
+        assert!(product.apply(result.as_slice_mut(), 2, 2, 1, source.as_slice()));
+        assert_eq!(result, FpVector::from_slice(p, &[0, 2]));
+        assert!(!product.apply(result.as_slice_mut(), 1, 0, 0, source.as_slice()));
+    }
+}
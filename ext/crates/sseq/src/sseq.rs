@@ -1,5 +1,6 @@
 use crate::bigraded::DenseBigradedModule;
 use crate::differential::Differential;
+use crate::product::Product;
 use bivec::BiVec;
 use fp::{
     matrix::{Matrix, Subquotient, Subspace},
@@ -27,6 +28,21 @@ impl SseqProfile for Adams {
     }
 }
 
+/// The May spectral sequence, indexed by the stem and the homological degree. Every differential
+/// decreases the stem by one and increases the homological degree by one, and the pages are
+/// distinguished by how much they lower the May filtration.
+pub struct May;
+
+impl SseqProfile for May {
+    const MIN_R: i32 = 1;
+    fn profile(_r: i32, x: i32, y: i32) -> (i32, i32) {
+        (x - 1, y + 1)
+    }
+    fn profile_inverse(_r: i32, x: i32, y: i32) -> (i32, i32) {
+        (x + 1, y - 1)
+    }
+}
+
 pub struct Sseq<P: SseqProfile = Adams> {
     p: ValidPrime,
 
@@ -56,6 +72,9 @@ pub struct Sseq<P: SseqProfile = Adams> {
     /// x -> y -> validity. A bidegree is invalid if the page_data is no longer accurate.
     invalid: BiVec<BiVec<bool>>,
 
+    /// Operations on the first page, such as products with fixed classes.
+    products: Vec<Product>,
+
     // Docs: If your struct does not in fact own the data of type T, it is better to use a
     // reference type, like PhantomData<&'a T> (ideally) or PhantomData<*const T> (if no lifetime
     // applies), so as not to indicate ownership.
@@ -71,6 +90,7 @@ impl<P: SseqProfile> Sseq<P> {
             permanent_classes: BiVec::new(min_x),
             page_data: BiVec::new(min_x),
             invalid: BiVec::new(min_x),
+            products: Vec::new(),
             profile: PhantomData,
         }
     }
@@ -358,6 +378,21 @@ impl<P: SseqProfile> Sseq<P> {
     pub fn page_data(&self, x: i32, y: i32) -> &BiVec<Subquotient> {
         &self.page_data[x][y]
     }
+
+    /// Record an operation on the first page. This replaces any existing product with the same
+    /// name.
+    pub fn add_product(&mut self, product: Product) {
+        self.products.retain(|p| p.name != product.name);
+        self.products.push(product);
+    }
+
+    pub fn products(&self) -> &[Product] {
+        &self.products
+    }
+
+    pub fn product(&self, name: &str) -> Option<&Product> {
+        self.products.iter().find(|p| p.name == name)
+    }
 }
 
 #[cfg(test)]
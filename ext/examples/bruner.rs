@@ -6,11 +6,9 @@
 //! 1. Compute our own resolution with the Milnor basis
 //! 2. Create Bruner's resolution as a
 //!    [`FiniteChainComplex`](ext::chain_complex::FiniteChainComplex) object
-//! 3. Use a [`ResolutionHomomorphism`](ext::resolution_homomorphism::ResolutionHomomorphism) to
-//!    lift the identity to a chain map from Bruner's resolution
-//!    to our resolution. We should do it in this direction because we have stored the
-//!    quasi-inverses for our resolution, but not Bruner's.
-//! 4. Read off the transformation matrix we need
+//! 3. Use a [`ChangeOfBasis`](ext::change_of_basis::ChangeOfBasis) to lift the identity to a
+//!    chain map from Bruner's resolution to our resolution and read off the transformation
+//!    matrix we need
 //!
 //! The main extra work to put in is step (2), where we have to parse Bruner's differentials and
 //! interpret it as a chain complex.
@@ -95,10 +93,10 @@ use algebra::{
 use anyhow::{Error, Result};
 use ext::{
     chain_complex::{ChainComplex, FiniteChainComplex as FCC},
-    resolution_homomorphism::ResolutionHomomorphism,
+    change_of_basis::ChangeOfBasis,
     utils::construct,
 };
-use fp::{matrix::Matrix, prime::ValidPrime, vector::FpVector};
+use fp::{prime::ValidPrime, vector::FpVector};
 use std::{
     fs::File,
     io::{BufRead, BufReader},
@@ -302,20 +300,23 @@ fn main() {
 
     let resolution = Arc::new(resolution);
 
-    // Create a ResolutionHomomorphism object
-    let hom = ResolutionHomomorphism::new(String::new(), cc, resolution, 0, 0);
-
-    // We have to explicitly tell it what to do at (0, 0)
-    hom.extend_step(0, 0, Some(&Matrix::from_vec(TWO, &[vec![1]])));
-    hom.extend_through_stem(max_s, max_n);
+    // Lift the identity to a chain map from Bruner's resolution to ours. We should do it in this
+    // direction because we have stored the quasi-inverses for our resolution, but not Bruner's.
+    let change = ChangeOfBasis::new_unit(&*cc, Arc::clone(&resolution));
 
     // Now print the results
     println!("sseq_basis | bruner_basis");
-    for (s, n, t) in hom.target.iter_stem() {
-        let matrix = hom.get_map(s).hom_k(t);
-
-        for (i, row) in matrix.into_iter().enumerate() {
-            println!("x_({},{},{}) = {:?}", n, s, i, row);
+    for (s, n, t) in resolution.iter_stem() {
+        let matrix = change.matrix(s, t);
+
+        for (i, row) in matrix.iter().enumerate() {
+            println!(
+                "x_({},{},{}) = {:?}",
+                n,
+                s,
+                i,
+                row.iter().collect::<Vec<_>>()
+            );
         }
     }
 }
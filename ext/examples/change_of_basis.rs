@@ -0,0 +1,50 @@
+//! This compares the bases of $\Ext$ coming from two resolutions of the same module, e.g. one with
+//! the Milnor basis and one with the Adem basis. See
+//! [`ChangeOfBasis`](ext::change_of_basis::ChangeOfBasis) for details.
+//!
+//! # Usage
+//! This asks for the two modules, which must be the same module up to the choice of basis of the
+//! Steenrod algebra, and the range to compute the change of basis in. For example, the following
+//! session compares the Milnor and Adem bases for the sphere:
+//!
+//! ```text
+//!  $ cargo run --example change_of_basis
+//! Source module (default: S_2): S_2@milnor
+//! Source module save directory (optional):
+//! Target module (default: S_2): S_2@adem
+//! Target module save directory (optional):
+//! Max n (default: 30): 20
+//! Max s (default: 7): 7
+//! ```
+//!
+//! # Output
+//! For each class of the target, this prints a line of the form `x_(n, s, i) = [...]`, which
+//! expresses the class in terms of the basis of the source.
+
+use std::sync::Arc;
+
+use ext::chain_complex::ChainComplex;
+use ext::change_of_basis::ChangeOfBasis;
+use ext::utils::query_module_only;
+
+fn main() -> anyhow::Result<()> {
+    let source = query_module_only("Source module", None)?;
+    let mut target = query_module_only("Target module", None)?;
+    target.load_quasi_inverse = target.save_dir().is_none();
+
+    let max_n: i32 = query::with_default("Max n", "30", str::parse);
+    let max_s: u32 = query::with_default("Max s", "7", str::parse);
+
+    source.compute_through_stem(max_s, max_n);
+    target.compute_through_stem(max_s, max_n);
+    let target = Arc::new(target);
+
+    let change = ChangeOfBasis::new(&source, Arc::clone(&target));
+
+    for (s, n, t) in target.iter_stem() {
+        for (i, row) in change.matrix(s, t).iter().enumerate() {
+            println!("x_({n}, {s}, {i}) = {row}");
+        }
+    }
+    Ok(())
+}
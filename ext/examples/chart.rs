@@ -1,12 +1,14 @@
 use algebra::module::{Module, OperationGeneratorPair};
 use chart::{Backend, SvgBackend};
 use ext::chain_complex::ChainComplex;
+use ext::names::ClassNames;
 use ext::utils::query_module;
 
 fn main() -> anyhow::Result<()> {
     let f = std::io::stdout();
     let mut g = SvgBackend::new(f);
     let resolution = query_module(None, false)?;
+    let names = ClassNames::for_resolution(&resolution)?;
 
     g.init(
         resolution.module(0).max_computed_degree(),
@@ -17,6 +19,11 @@ fn main() -> anyhow::Result<()> {
         for (s, n, t) in resolution.iter_stem() {
             let num_gens = resolution.module(s).number_of_gens_in_degree(t);
             g.node(n as i32, s as i32, num_gens)?;
+            for i in 0..num_gens {
+                if let Some(name) = names.get(n, s, i) {
+                    g.label((n, s as i32, i), name)?;
+                }
+            }
             if s == 0 {
                 continue;
             }
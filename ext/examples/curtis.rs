@@ -0,0 +1,41 @@
+//! Prints the Curtis table of the Lambda algebra, which computes the unstable Adams $E_2$ page of
+//! spheres. See [`ext::curtis`] for the conventions.
+//!
+//! Each line is of the form `n,s: λI [a, b)`, where $\lambda_I$ is the leading term of a cycle
+//! that represents a nonzero class in $\Ext^{s, n + s}$ of $S^m$ for $a \leq m < b$. If $b$ is
+//! `∞`, the class survives to the stable $\Ext$. If `Show cycles` is set, each line is followed by
+//! the full cycle.
+
+use algebra::Algebra;
+use ext::curtis::CurtisTable;
+
+fn main() -> anyhow::Result<()> {
+    let max_n: i32 = query::with_default("Max n", "15", str::parse);
+    let max_s: u32 = query::with_default("Max s", "7", str::parse);
+    let show_cycles: bool = query::yes_no("Show cycles");
+
+    let table = CurtisTable::new(max_n + max_s as i32);
+    let algebra = table.algebra();
+
+    for n in 0..=max_n {
+        for s in 0..=max_s {
+            let t = n + s as i32;
+            for idx in algebra.length_range(t, s) {
+                let (birth, death) = match table.lifetime(t, idx) {
+                    Some(lifetime) => lifetime,
+                    None => continue,
+                };
+                let death = death.map_or_else(|| "∞".to_owned(), |d| d.to_string());
+                println!(
+                    "{n},{s}: {} [{birth}, {death})",
+                    algebra.basis_element_to_string(t, idx)
+                );
+                if show_cycles {
+                    let cycle = table.cycle(t, idx).unwrap();
+                    println!("    {}", algebra.element_to_string(t, cycle.as_slice()));
+                }
+            }
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,59 @@
+//! This computes $\Ext^{s, t}(M, N)$ for a module $M$ and a bounded module $N$, and prints a
+//! cocycle representative of each basis element.
+//!
+//! # Usage
+//! This asks for the module $M$ in the usual way, and then for the coefficient module $N$. The
+//! coefficient module is specified by its name, with an optional shift, and uses the same basis of
+//! the Steenrod algebra as $M$.
+//!
+//! # Output
+//! For each basis element `x_(n, s, i)` of $\Ext^{s, n + s}(M, N)$, this prints the value of the
+//! representing cocycle $R_s \to \Sigma^{n + s} N$ on each generator of $R_s$, in the form
+//! ```text
+//! x_(n, s, i)
+//! f(x_(s, t', j)) = [...]
+//! ```
+//! where `x_(s, t', j)` is the $j$th generator of $R_s$ in degree $t'$ and the vector is an
+//! element of $N$ in degree $t' - n - s$. This is the format expected by the
+//! [`lift_hom`](../lift_hom/index.html) example.
+
+use std::sync::Arc;
+
+use algebra::module::{BoundedModule, FiniteModule, Module};
+use ext::chain_complex::{ChainComplex, FreeChainComplex};
+use ext::ext_groups::ExtGroups;
+use ext::utils::{parse_module_name, query_module};
+
+fn main() -> anyhow::Result<()> {
+    let resolution = Arc::new(query_module(None, false)?);
+    let coefficients: String = query::with_default("Coefficient module", "S_2", str::parse);
+    let json = parse_module_name(&coefficients)?;
+    let module = Arc::new(FiniteModule::from_json(resolution.algebra(), &json)?);
+
+    let ext = ExtGroups::new(Arc::clone(&resolution), Arc::clone(&module));
+
+    let min_t = ext.min_degree();
+    let max_n = resolution.module(0).max_computed_degree();
+    for n in min_t..=max_n {
+        for s in 0..ext.next_homological_degree() {
+            let t = n + s as i32;
+            if !ext.has_computed_bidegree(s, t) {
+                continue;
+            }
+            for (i, cocycle) in ext.cocycles(s, t).enumerate() {
+                println!("x_({n}, {s}, {i})");
+                for out_t in module.min_degree()..=module.max_degree() {
+                    if module.dimension(out_t) == 0 {
+                        continue;
+                    }
+                    let gen_t = out_t + t;
+                    for j in 0..resolution.number_of_gens_in_bidegree(s, gen_t) {
+                        let value = ext.evaluate(s, t, cocycle.as_slice(), gen_t, j);
+                        println!("f(x_({s}, {gen_t}, {j})) = {value}");
+                    }
+                }
+            }
+        }
+    }
+    Ok(())
+}
@@ -1,7 +1,8 @@
 //! Computes the triple Massey product up to a sign
 //!
 //! This is optimized to compute <a, b, -> for fixed a, b and all -, where a and b have small
-//! degree.
+//! degree. See [`ext::massey`] for Massey products with their signs and indeterminacy, as well as
+//! quadruple and matric Massey products.
 
 use algebra::module::{FDModule, Module};
 use ext::chain_complex::{AugmentedChainComplex, ChainComplex, ChainHomotopy, FiniteChainComplex};
@@ -85,6 +86,7 @@ fn main() -> anyhow::Result<()> {
     b_hom.extend_through_stem(shift_s, shift_n);
 
     let offset_a = unit.module(a_s).generator_offset(a_t, a_t, 0);
+    let names = ext::names::ClassNames::for_resolution(&resolution)?;
     for (s, n, t) in resolution.iter_stem() {
         if !resolution.has_computed_bidegree(s + shift_s, t + shift_t) {
             continue;
@@ -146,7 +148,7 @@ fn main() -> anyhow::Result<()> {
 
         for row in &**kernel {
             print!("<a, b, ");
-            ext::utils::print_element(row.as_slice(), n, s);
+            ext::utils::print_element(row.as_slice(), n, s, &names);
             print!("> = [");
 
             for i in 0..target_num_gens {
@@ -0,0 +1,36 @@
+//! Computes the May spectral sequence for $\Ext_A(\mathbb{F}_2, \mathbb{F}_2)$. See [`ext::may`]
+//! for the conventions.
+//!
+//! This prints each basis element of the $E_1$ page in the format `n,s: name`, followed by the
+//! differential it supports, or by `permanent` if it survives to $E_\infty$. Basis elements that
+//! are hit by a differential are printed with `hit`.
+
+use ext::may::MaySpectralSequence;
+
+use std::time::Instant;
+
+fn main() -> anyhow::Result<()> {
+    let max_t: i32 = query::with_default("Max t", "14", str::parse);
+
+    let start = Instant::now();
+    let may = MaySpectralSequence::new(max_t);
+    eprintln!("Time spent: {:?}", start.elapsed());
+
+    for t in 0..=max_t {
+        for s in 0..=t as u32 {
+            let n = t - s as i32;
+            let e_infinity = may.e_infinity(n, s);
+            for idx in 0..may.sseq().dimension(n, s as i32) {
+                let name = may.name(n, s, idx);
+                if let Some((r, target)) = may.differential(n, s, idx) {
+                    println!("{n},{s}: {name} d_{r} = {}", may.name(n - 1, s + 1, target));
+                } else if e_infinity.contains(&idx) {
+                    println!("{n},{s}: {name} permanent");
+                } else {
+                    println!("{n},{s}: {name} hit");
+                }
+            }
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,33 @@
+//! This prints the names of the basis elements of Ext, as computed by
+//! [`ClassNames::for_resolution`](ext::names::ClassNames::for_resolution).
+//!
+//! # Usage
+//! This asks for a module in the usual way. If a save directory is supplied, it then asks whether
+//! to write the names to `names.csv` in the save directory. This file can be edited to rename
+//! classes, and the new names will be used by all scripts using the same save directory.
+//!
+//! # Output
+//! For each basis element, this prints a line of the form `n,s,i,name`, where unnamed classes are
+//! written as `x_(n, s, i)`.
+
+use ext::chain_complex::{ChainComplex, FreeChainComplex};
+use ext::names::{ClassNames, NAMES_FILE};
+use ext::utils::query_module;
+
+fn main() -> anyhow::Result<()> {
+    let resolution = query_module(None, false)?;
+    let names = ClassNames::for_resolution(&resolution)?;
+
+    for (s, n, t) in resolution.iter_stem() {
+        for i in 0..resolution.number_of_gens_in_bidegree(s, t) {
+            println!("{n},{s},{i},{}", names.name(n, s, i));
+        }
+    }
+
+    if let Some(dir) = resolution.save_dir() {
+        if query::yes_no(&format!("Write names to {NAMES_FILE}")) {
+            names.save(&dir.join(NAMES_FILE))?;
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,19 @@
+//! Checks a module for known obstructions to being the cohomology of a space or spectrum. See
+//! [`ext::realizability`] for the list of checks.
+//!
+//! For each check, this prints either `pass`, `FAIL` or `inconclusive`, followed by a reason. A
+//! module that passes every check need not be realizable.
+//!
+//! Infinite modules are only checked up to the degree given by the user.
+
+use ext::utils::query_module_only;
+
+fn main() -> anyhow::Result<()> {
+    let resolution = query_module_only("Module", None)?;
+    let max_degree = query::with_default("Max degree", "40", str::parse);
+
+    for check in ext::realizability::check(&resolution, max_degree) {
+        println!("{check}");
+    }
+    Ok(())
+}
@@ -6,14 +6,17 @@
 //! necessary for the algorithm the work. It only works with the Milnor basis.
 //!
 //! # Output
-//! We omit differentials if the target bidegree is zero.
+//! We omit differentials if the target bidegree is zero. Classes are written using the
+//! [`ClassNames`](ext::names::ClassNames) of the resolution.
 
 use algebra::module::Module;
 use std::sync::Arc;
 
 use ext::chain_complex::{AugmentedChainComplex, ChainComplex};
+use ext::names::ClassNames;
 use ext::secondary::*;
 use ext::utils::query_module;
+use fp::vector::FpVector;
 
 fn main() -> anyhow::Result<()> {
     let resolution = Arc::new(query_module(
@@ -36,6 +39,9 @@ fn main() -> anyhow::Result<()> {
 
     eprintln!("Time spent: {:?}", start.elapsed());
 
+    let p = resolution.prime();
+    let names = ClassNames::for_resolution(&resolution)?;
+
     // Iterate through target of the d2
     for (s, n, t) in lift.underlying().iter_stem() {
         if s < 3 {
@@ -52,7 +58,11 @@ fn main() -> anyhow::Result<()> {
         let m = homotopy.homotopies.hom_k(t - 1);
 
         for (i, entry) in m.into_iter().enumerate() {
-            println!("d_2 x_({}, {}, {}) = {:?}", n + 1, s - 2, i, entry);
+            println!(
+                "d_2 {} = {}",
+                names.name(n + 1, s - 2, i),
+                names.format(FpVector::from_slice(p, &entry).as_slice(), n, s)
+            );
         }
     }
 
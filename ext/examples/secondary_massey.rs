@@ -253,6 +253,7 @@ fn main() -> anyhow::Result<()> {
     let b_name = b.name();
 
     // Iterate through the multiplicand
+    let names = ext::names::ClassNames::for_resolution(&unit)?;
     for (s, n, t) in unit.iter_stem() {
         if !resolution.has_computed_bidegree(s + shift_s - 2, t + shift_t)
             || !resolution.has_computed_bidegree(s + shift_s, t + shift_t + 1)
@@ -390,7 +391,7 @@ fn main() -> anyhow::Result<()> {
                     let ext_part = gen.slice(0, target_num_gens);
                     if ext_part.iter_nonzero().count() > 0 {
                         print!("[");
-                        ext::utils::print_element(ext_part, n, s);
+                        ext::utils::print_element(ext_part, n, s, &names);
                         print!("]");
                         true
                     } else {
@@ -410,6 +411,7 @@ fn main() -> anyhow::Result<()> {
                             gen.slice(target_num_gens, target_all_gens),
                             n,
                             s + 1,
+                            &names,
                         );
                     } else {
                         print!("(");
@@ -417,6 +419,7 @@ fn main() -> anyhow::Result<()> {
                             gen.slice(target_num_gens, target_all_gens),
                             n,
                             s + 1,
+                            &names,
                         );
                         print!(")");
                     }
@@ -148,6 +148,7 @@ fn main() -> anyhow::Result<()> {
     }
 
     let name = hom_lift.name();
+    let names = ext::names::ClassNames::for_resolution(&unit)?;
     // Iterate through the multiplicand
     for (s, n, t) in unit.iter_stem() {
         // The potential target has to be hit, and we need to have computed (the data need for) the
@@ -184,7 +185,7 @@ fn main() -> anyhow::Result<()> {
         );
         for (gen, output) in page_data.subspace_gens().zip_eq(outputs) {
             print!("{name} [");
-            ext::utils::print_element(gen.as_slice(), n, s);
+            ext::utils::print_element(gen.as_slice(), n, s, &names);
             println!(
                 "] = {} + τ {}",
                 output.slice(0, target_num_gens),
@@ -1,18 +1,13 @@
-use algebra::module::homomorphism::{
-    FiniteModuleHomomorphism, FreeModuleHomomorphism, IdentityHomomorphism, ModuleHomomorphism,
-};
-use algebra::module::{BoundedModule, Module};
-use ext::chain_complex::{
-    AugmentedChainComplex, ChainComplex, FreeChainComplex, TensorChainComplex,
-};
-use ext::resolution_homomorphism::ResolutionHomomorphism;
+//! Computes algebraic Steenrod operations in $\Ext_A(\mathbb{F}_p, \mathbb{F}_p)$.
+//!
+//! At the prime 2, this prints $\Sq^i x$ for all $i$, and at odd primes it prints $P^i x$ and
+//! $\beta P^i x$ for all $i$. See [`ext::steenrod`] for the indexing conventions and for
+//! operations on the $\Ext$ of other modules.
+
+use ext::chain_complex::ChainComplex;
+use ext::steenrod::{SteenrodOperation, SteenrodOperations};
 use ext::utils;
-use ext::yoneda::yoneda_representative_element;
-use fp::matrix::Matrix;
-use fp::prime::ValidPrime;
-use fp::vector::FpVector;
 
-use std::io::{stderr, stdout, Write};
 use std::sync::Arc;
 use std::time::Instant;
 
@@ -21,310 +16,31 @@ fn main() -> anyhow::Result<()> {
         "Module",
         Some(algebra::AlgebraType::Adem),
     )?);
+    let p = resolution.prime();
 
-    let complex = resolution.target();
-    let module = complex.module(0);
-
-    let p = ValidPrime::new(2);
-
-    let x: i32 = query::with_default("t - s", "8", str::parse);
+    let n: i32 = query::with_default("t - s", "8", str::parse);
     let s: u32 = query::with_default("s", "3", str::parse);
     let idx: usize = query::with_default("idx", "0", str::parse);
+    let t = s as i32 + n;
 
-    let t = s as i32 + x;
-    eprint!("Resolving ext: ");
-    let start = Instant::now();
-
-    resolution.compute_through_bidegree(2 * s, 2 * t);
-
-    eprintln!("{:?}", start.elapsed());
+    let steenrod = SteenrodOperations::new(resolution);
 
-    eprint!("Computing Yoneda representative: ");
     let start = Instant::now();
-    let yoneda = Arc::new(yoneda_representative_element(
-        Arc::clone(&resolution),
-        s,
-        t,
-        idx,
-    ));
-    eprintln!("{:?}", start.elapsed());
-
-    print!("Dimensions of Yoneda representative: 1");
-    let mut check = vec![0; t as usize + 1];
-    for s in 0..=s {
-        let module = yoneda.module(s);
-        print!(" {}", module.total_dimension());
-
-        for t in 0..=t {
-            check[t as usize] += (if s % 2 == 0 { 1 } else { -1 }) * module.dimension(t) as i32;
-        }
-    }
-    println!();
-
-    // We check that lifting the identity returns the original class. Even if the
-    // algorithm in yoneda.rs is incorrect, this ensures that a posteriori we happened
-    // to have a valid Yoneda representative. (Not really --- we don't check it is exact, just
-    // that its Euler characteristic is 0 in each degree)
-    eprint!("Checking Yoneda representative: ");
-    let start = Instant::now();
-    {
-        assert_eq!(check[0], 1, "Incorrect Euler characteristic at t = 0");
-        for entry in check.into_iter().skip(1) {
-            assert_eq!(entry, 0, "Incorrect Euler characteristic at t = {}", t);
-        }
-        let f = ResolutionHomomorphism::from_module_homomorphism(
-            "".to_string(),
-            Arc::clone(&resolution),
-            Arc::clone(&yoneda),
-            &FiniteModuleHomomorphism::identity_homomorphism(Arc::clone(&module)),
-        );
-
-        f.extend(s, t);
-        let final_map = f.get_map(s);
-        let num_gens = resolution.number_of_gens_in_bidegree(s, t);
-        for i_ in 0..num_gens {
-            assert_eq!(final_map.output(t, i_).len(), 1);
-            if i_ == idx {
-                assert_eq!(final_map.output(t, i_).entry(0), 1);
-            } else {
-                assert_eq!(final_map.output(t, i_).entry(0), 0);
-            }
-        }
-    }
-    eprintln!("{:?}", start.elapsed());
-
-    let square = Arc::new(TensorChainComplex::new(
-        Arc::clone(&yoneda),
-        Arc::clone(&yoneda),
-    ));
-
-    eprint!("Computing quasi_inverses: ");
-    let start = Instant::now();
-    square.compute_through_bidegree(2 * s, 2 * t);
-    for s in 0..=2 * s {
-        square
-            .differential(s as u32)
-            .compute_auxiliary_data_through_degree(2 * t);
-    }
-    eprintln!("{:?}", start.elapsed());
-
-    eprintln!("Computing Steenrod operations: ");
-
-    let mut delta = Vec::with_capacity(s as usize);
-
-    for i in 0..=s {
-        let mut maps: Vec<Arc<FreeModuleHomomorphism<_>>> = Vec::with_capacity(2 * s as usize - 1);
-
-        for s in 0..=2 * s - i {
-            let source = resolution.module(s);
-            let target = square.module(s + i);
-
-            let map = FreeModuleHomomorphism::new(Arc::clone(&source), Arc::clone(&target), 0);
-            maps.push(Arc::new(map));
-        }
-        delta.push(maps);
-    }
-
-    /* #[cfg(feature = "concurrent")]
-    let mut prev_i_receivers: Vec<Option<Receiver<()>>> = Vec::new();
-    #[cfg(feature = "concurrent")]
-    for _ in 0..=2 * s {
-        prev_i_receivers.push(None);
-    }
-
-    #[cfg(feature = "concurrent")]
-    let mut handles: Vec<Vec<JoinHandle<()>>> = Vec::with_capacity(s as usize + 1);*/
-
-    let start = Instant::now();
-
-    // We use the formula d Δ_i + Δ_i d = Δ_{i-1} + τΔ_{i-1}
-    for i in 0..=s {
-        // Δ_i is a map C_s -> C_{s + i}. So to hit C_{2s}, we only need to compute up to 2
-        // * s - i
-        //        #[cfg(not(feature = "concurrent"))]
-        let start = Instant::now();
-
-        /* #[cfg(feature = "concurrent")]
-        let mut handles_inner: Vec<JoinHandle<()>> = Vec::with_capacity((2 * s - i + 1) as usize);
-
-        #[cfg(feature = "concurrent")]
-        let mut last_receiver: Option<Receiver<()>> = None;
-
-        #[cfg(feature = "concurrent")]
-        let top_s = 2 * s - i;*/
-
-        for s in 0..=2 * s - i {
-            if i == 0 && s == 0 {
-                let map = &delta[0][0];
-                map.add_generators_from_matrix_rows(
-                    0,
-                    Matrix::from_vec(p, &[vec![1]]).as_slice_mut(),
-                );
-                map.extend_by_zero(2 * t);
-                continue;
-            }
-
-            let square = Arc::clone(&square);
-
-            let source = resolution.module(s);
-            let target = square.module(s + i);
-
-            let dtarget_module = square.module(s + i - 1);
-
-            let d_res = resolution.differential(s);
-            let d_target = square.differential(s + i as u32);
-
-            let map = Arc::clone(&delta[i as usize][s as usize]);
-            let prev_map = match s {
-                0 => None,
-                _ => Some(Arc::clone(&delta[i as usize][s as usize - 1])),
-            };
-
-            let prev_delta = match i {
-                0 => None,
-                _ => Some(Arc::clone(&delta[i as usize - 1][s as usize])),
-            };
-
-            /* #[cfg(feature = "concurrent")]
-            let (sender, new_receiver) = unbounded();
-            #[cfg(feature = "concurrent")]
-            let (prev_i_sender, new_prev_i_receiver) = unbounded();
-
-
-            #[cfg(feature = "concurrent")]
-            let prev_i_receiver =
-                std::mem::replace(&mut prev_i_receivers[s as usize], Some(new_prev_i_receiver));*/
-
-            // Define this as a closure so that we can easily switch between threaded and
-            // un-threaded
-            let fun = move || {
-                /* #[cfg(feature = "concurrent")]
-                let mut token = bucket.take_token();*/
-
-                for t in 0..=2 * t {
-                    /* #[cfg(feature = "concurrent")]
-                    {
-                        token = bucket.recv2_or_release(token, &last_receiver, &prev_i_receiver);
-                    }*/
-
-                    let num_gens = source.number_of_gens_in_degree(t);
-
-                    let fx_dim = target.dimension(t);
-                    let fdx_dim = dtarget_module.dimension(t);
-
-                    if fx_dim == 0 || fdx_dim == 0 || num_gens == 0 {
-                        map.extend_by_zero(t);
-
-                        /* #[cfg(feature = "concurrent")]
-                        {
-                            if s < top_s {
-                                sender.send(()).unwrap();
-                                prev_i_sender.send(()).unwrap();
-                            }
-                        }*/
-
-                        continue;
-                    }
-
-                    let mut output_matrix = Matrix::new(p, num_gens, fx_dim);
-                    let mut result = FpVector::new(p, fdx_dim);
-                    for j in 0..num_gens {
-                        if let Some(m) = &prev_delta {
-                            // Δ_{i-1} x
-                            let prevd = m.output(t, j);
-
-                            // τ Δ_{i-1}x
-                            square.swap(&mut result, prevd, s + i as u32 - 1, t);
-                            result += prevd;
-                        }
-
-                        if let Some(m) = &prev_map {
-                            let dx = d_res.output(t, j);
-                            m.apply(result.as_slice_mut(), 1, t, dx.as_slice());
-                        }
-                        assert!(d_target.apply_quasi_inverse(
-                            output_matrix[j].as_slice_mut(),
-                            t,
-                            result.as_slice(),
-                        ));
-
-                        result.set_to_zero();
-                    }
-                    map.add_generators_from_matrix_rows(t, output_matrix.as_slice_mut());
-
-                    /* #[cfg(feature = "concurrent")]
-                    {
-                        if s < top_s {
-                            sender.send(()).unwrap();
-                            prev_i_sender.send(()).unwrap();
-                        }
-                    }*/
-                }
-            };
-
-            /* #[cfg(feature = "concurrent")]
-            {
-                let handle = thread::Builder::new()
-                    .name(format!("D_{}, s = {}", i, s))
-                    .spawn(fun);
-                last_receiver = Some(new_receiver);
-                handles_inner.push(handle.unwrap());
-            }*/
-            // #[cfg(not(feature = "concurrent"))]
-            fun();
-        }
-        /* #[cfg(feature = "concurrent")]
-        handles.push(handles_inner); */
+    let values = steenrod.operations(s, t, idx);
+    eprintln!("Computing Steenrod operations: {:?}", start.elapsed());
 
-        // #[cfg(not(feature = "concurrent"))]
-        {
-            let final_map = &delta[i as usize][(2 * s - i) as usize];
-            let num_gens = resolution.number_of_gens_in_bidegree(2 * s - i, 2 * t);
-            print!(
-                "Sq^{} x_({}, {}, {}) = [{}]",
-                s - i,
-                t - s as i32,
-                s,
-                idx,
-                (0..num_gens)
-                    .map(|k| format!("{}", final_map.output(2 * t, k).entry(0)))
-                    .collect::<Vec<_>>()
-                    .join(", "),
-            );
-            stdout().flush().unwrap();
-            eprint!(" ({:?})", start.elapsed());
-            stderr().flush().unwrap();
-            println!();
+    let ops: Vec<SteenrodOperation> = if *p == 2 {
+        (0..=s).map(SteenrodOperation::Sq).collect()
+    } else {
+        (0..=s / 2)
+            .flat_map(|i| [SteenrodOperation::P(i), SteenrodOperation::BetaP(i)])
+            .collect()
+    };
+
+    for op in ops {
+        if let Some(j) = op.index(p, s) {
+            println!("{op} x_({n}, {s}, {idx}) = {}", values[j as usize]);
         }
     }
-
-    /* #[cfg(feature = "concurrent")]
-    for (i, handle_inner) in handles.into_iter().enumerate() {
-        let i = i as u32;
-
-        for handle in handle_inner {
-            handle.join().unwrap();
-        }
-        let final_map = &delta[i as usize][(2 * s - i) as usize];
-        let num_gens = resolution.number_of_gens_in_bidegree(2 * s - i, 2 * t);
-        print!(
-            "Sq^{} x_({}, {}, {}) = [{}]",
-            s - i,
-            t - s as i32,
-            s,
-            idx,
-            (0..num_gens)
-                .map(|k| format!("{}", final_map.output(2 * t, k).entry(0)))
-                .collect::<Vec<_>>()
-                .join(", "),
-        );
-        stdout().flush().unwrap();
-        eprint!(" ({:?} total)", start.elapsed());
-        stderr().flush().unwrap();
-        println!();
-    }*/
-
-    eprintln!("Computing Steenrod operations: {:?}", start.elapsed());
-
     Ok(())
 }
@@ -0,0 +1,452 @@
+//! Run a computation pipeline described by a job file.
+//!
+//! A job file is a TOML (or JSON, if the extension is `.json`) file of the following form:
+//! ```toml
+//! # Output paths are relative to this directory, which is itself relative to the job file. This
+//! # defaults to the directory containing the job file.
+//! output_dir = "output"
+//!
+//! [[module]]
+//! name = "S_2"            # The name used to refer to the module in this file. Defaults to `spec`.
+//! spec = "S_2@milnor"
+//! save_dir = "save/S_2"   # Optional, relative to the job file.
+//! max_n = 40
+//! max_s = 20
+//! num_gens = "S_2.csv"    # Optional. Write the output of `num-gens` to this file.
+//! d2 = "S_2-d2.txt"       # Optional. Write all d_2 differentials to this file.
+//!
+//! [[product]]
+//! name = "h0"             # This is also the name of the save subdirectory of the product.
+//! module = "S_2"          # The module whose Ext class we multiply with.
+//! unit = "S_2"            # Optional. The resolution of the unit, which must be a module above.
+//! n = 0
+//! s = 1
+//! class = [1]
+//! output = "S_2-h0.txt"
+//!
+//! [[chart]]
+//! module = "S_2"
+//! output = "S_2.svg"      # A `.tex` extension produces a TikZ picture instead.
+//! d2 = true               # Optional. Draw d_2 differentials.
+//! labels = true           # Optional. Label the classes that have names.
+//! ```
+//!
+//! Each output is a stage of the pipeline. A stage is skipped if its output already exists, and a
+//! module is only resolved if some stage that needs it is run. Outputs are written to a temporary
+//! file first, so that an interrupted job does not leave behind incomplete outputs.
+
+use std::collections::HashMap;
+use std::io::{BufWriter, Write};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use algebra::module::{FDModule, Module};
+use anyhow::{anyhow, Context};
+use chart::{Backend, SvgBackend, TikzBackend};
+use ext::chain_complex::{
+    AugmentedChainComplex, ChainComplex, FiniteChainComplex, FreeChainComplex,
+};
+use ext::names::ClassNames;
+use ext::resolution::Resolution;
+use ext::resolution_homomorphism::ResolutionHomomorphism;
+use ext::secondary::{SecondaryLift, SecondaryResolution};
+use ext::CCC;
+use fp::matrix::Matrix;
+use serde::Deserialize;
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Job {
+    #[serde(default)]
+    output_dir: Option<PathBuf>,
+    #[serde(default, rename = "module")]
+    modules: Vec<ModuleJob>,
+    #[serde(default, rename = "product")]
+    products: Vec<ProductJob>,
+    #[serde(default, rename = "chart")]
+    charts: Vec<ChartJob>,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct ModuleJob {
+    name: Option<String>,
+    spec: String,
+    save_dir: Option<PathBuf>,
+    max_n: i32,
+    max_s: u32,
+    num_gens: Option<PathBuf>,
+    d2: Option<PathBuf>,
+}
+
+impl ModuleJob {
+    fn name(&self) -> &str {
+        self.name.as_deref().unwrap_or(&self.spec)
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct ProductJob {
+    name: String,
+    module: String,
+    unit: Option<String>,
+    n: i32,
+    s: u32,
+    class: Vec<u32>,
+    output: PathBuf,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct ChartJob {
+    module: String,
+    output: PathBuf,
+    #[serde(default)]
+    d2: bool,
+    #[serde(default)]
+    labels: bool,
+}
+
+impl Job {
+    pub fn from_file(path: &Path) -> anyhow::Result<Self> {
+        let contents = std::fs::read_to_string(path)
+            .with_context(|| format!("Failed to read job file {path:?}"))?;
+        let job: Self = if path.extension().is_some_and(|ext| ext == "json") {
+            serde_json::from_str(&contents)?
+        } else {
+            toml::from_str(&contents)?
+        };
+
+        let mut names = std::collections::HashSet::new();
+        for module in &job.modules {
+            anyhow::ensure!(
+                names.insert(module.name()),
+                "Duplicate module name {}",
+                module.name()
+            );
+        }
+        let references = job
+            .products
+            .iter()
+            .flat_map(|p| std::iter::once(&p.module).chain(&p.unit))
+            .chain(job.charts.iter().map(|c| &c.module));
+        for name in references {
+            anyhow::ensure!(names.contains(name.as_str()), "Unknown module {name}");
+        }
+        Ok(job)
+    }
+}
+
+type SecondaryInner = SecondaryResolution<algebra::SteenrodAlgebra, Resolution<CCC>>;
+
+pub struct Runner {
+    job: Job,
+    /// The directory containing the job file.
+    base: PathBuf,
+    output_dir: PathBuf,
+    force: bool,
+    resolutions: HashMap<String, Arc<Resolution<CCC>>>,
+    secondaries: HashMap<String, Arc<SecondaryInner>>,
+}
+
+impl Runner {
+    /// Create a runner for a job file. If `force` is set, stages are run even if their outputs
+    /// exist.
+    pub fn new(path: &Path, force: bool) -> anyhow::Result<Self> {
+        let job = Job::from_file(path)?;
+        let base = path.parent().unwrap_or(Path::new("")).to_owned();
+        let output_dir = match &job.output_dir {
+            Some(dir) => base.join(dir),
+            None => base.clone(),
+        };
+        std::fs::create_dir_all(&output_dir)
+            .with_context(|| format!("Failed to create output directory {output_dir:?}"))?;
+
+        Ok(Self {
+            job,
+            base,
+            output_dir,
+            force,
+            resolutions: HashMap::new(),
+            secondaries: HashMap::new(),
+        })
+    }
+
+    pub fn run(&mut self) -> anyhow::Result<()> {
+        for i in 0..self.job.modules.len() {
+            let module = &self.job.modules[i];
+            let name = module.name().to_owned();
+            if let Some(path) = self.stage(module.num_gens.as_deref())? {
+                let res = self.resolution(&name)?;
+                write_output(&path, |out| {
+                    for (s, n, t) in res.iter_stem() {
+                        writeln!(out, "{},{},{}", n, s, res.number_of_gens_in_bidegree(s, t))?;
+                    }
+                    Ok(())
+                })?;
+            }
+
+            let module = &self.job.modules[i];
+            if let Some(path) = self.stage(module.d2.as_deref())? {
+                let res = self.resolution(&name)?;
+                let lift = self.secondary(&name)?;
+                write_output(&path, |out| {
+                    for (s, n, t) in res.iter_stem() {
+                        for (i, row) in d2(&res, &lift, s, t).into_iter().enumerate() {
+                            writeln!(out, "d_2 x_({n}, {s}, {i}) = {row:?}")?;
+                        }
+                    }
+                    Ok(())
+                })?;
+            }
+        }
+
+        for i in 0..self.job.products.len() {
+            if let Some(path) = self.stage(Some(&self.job.products[i].output))? {
+                self.product(i, &path)?;
+            }
+        }
+
+        for i in 0..self.job.charts.len() {
+            let chart = &self.job.charts[i];
+            if let Some(path) = self.stage(Some(&chart.output))? {
+                let (name, draw_d2, labels) = (chart.module.clone(), chart.d2, chart.labels);
+                let res = self.resolution(&name)?;
+                let lift = if draw_d2 {
+                    Some(self.secondary(&name)?)
+                } else {
+                    None
+                };
+                let names = if labels {
+                    Some(ClassNames::for_resolution(&res)?)
+                } else {
+                    None
+                };
+                write_output(&path, |out| {
+                    if path
+                        .extension()
+                        .is_some_and(|ext| ext == TikzBackend::<&mut dyn Write>::EXT)
+                    {
+                        draw_chart(TikzBackend::new(out), &res, lift.as_deref(), names.as_ref())
+                    } else {
+                        draw_chart(SvgBackend::new(out), &res, lift.as_deref(), names.as_ref())
+                    }
+                })?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns the full path of the output if the stage has to be run.
+    fn stage(&self, output: Option<&Path>) -> anyhow::Result<Option<PathBuf>> {
+        let Some(output) = output else {
+            return Ok(None);
+        };
+        let path = self.output_dir.join(output);
+        if path.exists() && !self.force {
+            eprintln!("Skipping {path:?}: output exists");
+            Ok(None)
+        } else {
+            eprintln!("Computing {path:?}");
+            Ok(Some(path))
+        }
+    }
+
+    fn module_job(&self, name: &str) -> &ModuleJob {
+        self.job.modules.iter().find(|m| m.name() == name).unwrap()
+    }
+
+    fn resolution(&mut self, name: &str) -> anyhow::Result<Arc<Resolution<CCC>>> {
+        if let Some(res) = self.resolutions.get(name) {
+            return Ok(Arc::clone(res));
+        }
+        let module = self.module_job(name);
+        let save_dir = module.save_dir.as_ref().map(|dir| self.base.join(dir));
+        let mut res = ext::utils::construct(module.spec.as_str(), save_dir)
+            .with_context(|| format!("Failed to construct module {name}"))?;
+        res.load_quasi_inverse = res.save_dir().is_none();
+
+        eprintln!("Resolving {name}");
+        res.compute_through_stem(module.max_s, module.max_n);
+
+        let res = Arc::new(res);
+        self.resolutions.insert(name.to_owned(), Arc::clone(&res));
+        Ok(res)
+    }
+
+    fn secondary(&mut self, name: &str) -> anyhow::Result<Arc<SecondaryInner>> {
+        if let Some(lift) = self.secondaries.get(name) {
+            return Ok(Arc::clone(lift));
+        }
+        let res = self.resolution(name)?;
+        anyhow::ensure!(
+            ext::secondary::can_compute(&res),
+            "Cannot compute d2 for the module {name}"
+        );
+
+        eprintln!("Computing secondary resolution of {name}");
+        let lift = SecondaryResolution::new(res);
+        lift.extend_all();
+
+        let lift = Arc::new(lift);
+        self.secondaries.insert(name.to_owned(), Arc::clone(&lift));
+        Ok(lift)
+    }
+
+    /// Compute the products of the `i`th product job with all classes in Ext of the unit, in the
+    /// format of the `lift_hom` example.
+    fn product(&mut self, i: usize, path: &Path) -> anyhow::Result<()> {
+        let product = &self.job.products[i];
+        let (name, shift_s, shift_n) = (product.name.clone(), product.s, product.n);
+        let shift_t = shift_n + shift_s as i32;
+        let class = product.class.clone();
+        let unit_name = product.unit.clone();
+        let res = self.resolution(&product.module.clone())?;
+
+        let unit = match unit_name {
+            Some(unit) => self.resolution(&unit)?,
+            None if res.target().module(0).is_unit() => Arc::clone(&res),
+            None => {
+                let module = Arc::new(
+                    FDModule::new(
+                        res.algebra(),
+                        format!("S_{}", res.prime()),
+                        bivec::BiVec::from_vec(0, vec![1]),
+                    )
+                    .into(),
+                );
+                Arc::new(Resolution::new(Arc::new(FiniteChainComplex::ccdz(module))))
+            }
+        };
+        anyhow::ensure!(
+            unit.target().module(0).is_unit(),
+            "The unit of product {name} is not the sphere"
+        );
+        anyhow::ensure!(
+            res.has_computed_bidegree(shift_s, shift_t)
+                && res.number_of_gens_in_bidegree(shift_s, shift_t) == class.len(),
+            "Class of product {name} does not have the dimension of Ext in its bidegree"
+        );
+
+        unit.compute_through_stem(
+            res.next_homological_degree() - 1 - shift_s,
+            res.module(0).max_computed_degree() - shift_n,
+        );
+
+        let hom = ResolutionHomomorphism::new(
+            name.clone(),
+            Arc::clone(&res),
+            Arc::clone(&unit),
+            shift_s,
+            shift_t,
+        );
+        let mut matrix = Matrix::new(res.prime(), class.len(), 1);
+        for (row, &v) in matrix.iter_mut().zip(&class) {
+            row.set_entry(0, v);
+        }
+        hom.extend_step(shift_s, shift_t, Some(&matrix));
+        hom.extend_all();
+
+        write_output(path, |out| {
+            for (s, n, t) in unit.iter_stem() {
+                if s + shift_s >= res.next_homological_degree()
+                    || t + shift_t > res.module(s + shift_s).max_computed_degree()
+                {
+                    continue;
+                }
+                let matrix = hom.get_map(s + shift_s).hom_k(t);
+                for (i, r) in matrix.iter().enumerate() {
+                    writeln!(out, "{name} x_({n}, {s}, {i}) = {r:?}")?;
+                }
+            }
+            Ok(())
+        })
+    }
+}
+
+/// The $d_2$ differentials on the classes in bidegree `(s, t)`, or nothing if the target has not
+/// been computed.
+fn d2(res: &Resolution<CCC>, lift: &SecondaryInner, s: u32, t: i32) -> Vec<Vec<u32>> {
+    if t > 0 && res.number_of_gens_in_bidegree(s, t) > 0 && res.has_computed_bidegree(s + 2, t + 1)
+    {
+        lift.homotopy(s + 2).homotopies.hom_k(t)
+    } else {
+        Vec::new()
+    }
+}
+
+/// Draw the $E_2$ page with all filtration one products, and optionally the $d_2$ differentials
+/// and the names of the named classes.
+fn draw_chart<B: Backend<Error = std::io::Error>>(
+    mut g: B,
+    res: &Resolution<CCC>,
+    lift: Option<&SecondaryInner>,
+    names: Option<&ClassNames>,
+) -> std::io::Result<()> {
+    let p = *res.prime();
+    g.init(
+        res.module(0).max_computed_degree(),
+        res.next_homological_degree() as i32 - 1,
+    )?;
+
+    for (s, n, t) in res.iter_stem() {
+        let num_gens = res.number_of_gens_in_bidegree(s, t);
+        g.node(n, s as i32, num_gens)?;
+        if let Some(names) = names {
+            for i in 0..num_gens {
+                if let Some(name) = names.get(n, s, i) {
+                    g.label((n, s as i32, i), name)?;
+                }
+            }
+        }
+    }
+
+    // The degrees of the indecomposables of the algebra that give filtration one classes
+    let op_degs: Vec<i32> = if p == 2 {
+        vec![1, 2, 4]
+    } else {
+        vec![1, 2 * (p as i32 - 1)]
+    };
+
+    for (s, n, t) in res.iter_stem() {
+        for &op_deg in &op_degs {
+            if let Some(products) = res.filtration_one_product(op_deg, 0, s + 1, t + op_deg) {
+                g.structline_matrix(
+                    (n, s as i32),
+                    (n + op_deg - 1, s as i32 + 1),
+                    products,
+                    None,
+                )?;
+            }
+        }
+        if let Some(lift) = lift {
+            g.structline_matrix(
+                (n, s as i32),
+                (n - 1, s as i32 + 2),
+                d2(res, lift, s, t),
+                Some("d2"),
+            )?;
+        }
+    }
+    Ok(())
+}
+
+/// Write an output to a temporary file and move it into place once it is complete.
+fn write_output(
+    path: &Path,
+    f: impl FnOnce(&mut dyn Write) -> std::io::Result<()>,
+) -> anyhow::Result<()> {
+    let mut tmp = path.as_os_str().to_owned();
+    tmp.push(".tmp");
+    let tmp = PathBuf::from(tmp);
+
+    let mut out = BufWriter::new(
+        std::fs::File::create(&tmp).with_context(|| format!("Failed to create {tmp:?}"))?,
+    );
+    f(&mut out)
+        .and_then(|()| out.flush())
+        .with_context(|| format!("Failed to write {tmp:?}"))?;
+    drop(out);
+    std::fs::rename(&tmp, path).map_err(|e| anyhow!("Failed to move {tmp:?} to {path:?}: {e}"))
+}
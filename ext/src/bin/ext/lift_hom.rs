@@ -0,0 +1,147 @@
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use algebra::module::{BoundedModule, Module};
+use clap::Args;
+use ext::chain_complex::{AugmentedChainComplex, ChainComplex, FreeChainComplex};
+use ext::resolution_homomorphism::ResolutionHomomorphism;
+use ext::utils::LoadQuasiInverseOption;
+use fp::matrix::Matrix;
+
+use crate::settings::{Class, Settings};
+use crate::{ModuleArgs, RangeArgs};
+
+#[derive(Args)]
+pub struct LiftHomArgs {
+    /// The target of the map of modules, i.e. the module N of Ext(M, N).
+    #[command(flatten)]
+    target: ModuleArgs,
+    /// The maximum target bidegrees to compute.
+    #[command(flatten)]
+    range: RangeArgs,
+
+    /// The source of the map of modules, i.e. the module M of Ext(M, N). Defaults to the target.
+    #[arg(long, env = "EXT_SOURCE_MODULE")]
+    source_module: Option<String>,
+    /// The save directory of the source module.
+    #[arg(long, env = "EXT_SOURCE_SAVE_DIR")]
+    source_save_dir: Option<PathBuf>,
+
+    /// The name of the product. This is used for the save directory.
+    #[arg(long, env = "EXT_NAME")]
+    name: Option<String>,
+    /// The stem of the Ext class.
+    #[arg(long, env = "EXT_SHIFT_N", allow_negative_numbers = true)]
+    shift_n: Option<i32>,
+    /// The filtration of the Ext class.
+    #[arg(long, env = "EXT_SHIFT_S")]
+    shift_s: Option<u32>,
+    /// The values of the Ext class on the generators, in the order they are prompted for in the
+    /// `lift_hom` example. Repeat the flag for each generator.
+    #[arg(long = "map")]
+    map: Vec<Class>,
+}
+
+/// Compute the map on Ext induced by an element of $\Ext(M, N)$, as in the `lift_hom` example.
+pub fn run(settings: &Settings, args: LiftHomArgs) -> anyhow::Result<()> {
+    let target = Arc::new(settings.resolution(
+        args.target.module,
+        args.target.save_dir,
+        "",
+        "Target module",
+        None,
+        LoadQuasiInverseOption::IfNoSave,
+    )?);
+
+    let source_module =
+        settings.get_optional(args.source_module, "source_module", "Source module")?;
+    let source = match source_module {
+        None => Arc::clone(&target),
+        Some(module) => Arc::new(settings.resolution(
+            Some(module),
+            args.source_save_dir,
+            "source_",
+            "Source module",
+            None,
+            LoadQuasiInverseOption::No,
+        )?),
+    };
+    let source_equal_target = Arc::ptr_eq(&source, &target);
+
+    anyhow::ensure!(
+        source.prime() == target.prime(),
+        "Source and target must have the same prime"
+    );
+    let p = source.prime();
+
+    let name: String = settings.get(args.name, "name", "Name of product", None)?;
+
+    let shift_n: i32 = settings.get(args.shift_n, "shift_n", "n of Ext class", Some("0"))?;
+    let shift_s: u32 = settings.get(args.shift_s, "shift_s", "s of Ext class", Some("0"))?;
+    let shift_t = shift_n + shift_s as i32;
+
+    let (s, n) = args.range.get(settings)?;
+
+    if source_equal_target {
+        target.compute_through_stem(s + shift_s, n + std::cmp::max(0, shift_n));
+    } else {
+        source.compute_through_stem(s + shift_s, n + shift_n);
+        target.compute_through_stem(s, n);
+    }
+
+    let target_module = target.target().module(0);
+    let hom = ResolutionHomomorphism::new(name.clone(), source, target, shift_s, shift_t);
+
+    let mut values = settings.get_list(args.map, "map")?.into_iter();
+    for output_t in 0..=target_module.max_degree() {
+        let input_t = output_t + shift_t;
+        let mut matrix = Matrix::new(
+            p,
+            hom.source.number_of_gens_in_bidegree(shift_s, input_t),
+            target_module.dimension(output_t),
+        );
+
+        if matrix.rows() == 0 || matrix.columns() == 0 {
+            hom.extend_step(shift_s, input_t, None);
+        } else {
+            for (idx, row) in matrix.iter_mut().enumerate() {
+                let prompt = format!("f(x_({shift_s}, {input_t}, {idx}))");
+                let v = match values.next() {
+                    Some(Class(v)) => {
+                        anyhow::ensure!(
+                            v.len() == row.len(),
+                            "{prompt} has dimension {} but {} coordinates supplied",
+                            row.len(),
+                            v.len()
+                        );
+                        v
+                    }
+                    None => settings.get_vector(&prompt, row.len())?,
+                };
+                for (i, &x) in v.iter().enumerate() {
+                    row.set_entry(i, x);
+                }
+            }
+            hom.extend_step(shift_s, input_t, Some(&matrix));
+        }
+    }
+    anyhow::ensure!(
+        values.next().is_none(),
+        "More values of the map supplied than there are generators"
+    );
+
+    hom.extend_all();
+
+    for (s, n, t) in hom.target.iter_stem() {
+        if s + shift_s >= hom.source.next_homological_degree()
+            || t + shift_t > hom.source.module(s + shift_s).max_computed_degree()
+        {
+            continue;
+        }
+        let matrix = hom.get_map(s + shift_s).hom_k(t);
+        for (i, r) in matrix.iter().enumerate() {
+            println!("{name} x_({n}, {s}, {i}) = {r:?}");
+        }
+    }
+    Ok(())
+}
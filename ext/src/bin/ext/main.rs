@@ -0,0 +1,250 @@
+//! A non-interactive command line interface to the most common computations. Unlike the examples,
+//! which read the answers to their prompts positionally, every parameter is named. For example,
+//! ```sh
+//!  $ cargo run --release --features concurrent --bin ext -- num-gens --module C2 --max-n 40 --max-s 20
+//! ```
+//!
+//! Each parameter is looked up in the following order:
+//!
+//!  1. The command line flag, e.g. `--max-n 40`;
+//!  2. The corresponding environment variable, e.g. `EXT_MAX_N=40`, as listed by `--help`;
+//!  3. The config file supplied by `--config` (or `EXT_CONFIG`). This is a TOML file whose keys are
+//!     the flag names in snake case, e.g. `max_n = 40`. Keys in a table named after the subcommand in
+//!     snake case, e.g. `[num_gens]`, take precedence over top-level keys;
+//!  4. An interactive prompt as in the examples. If `--batch` (or `EXT_BATCH`) is set, the default
+//!     value is used instead, and it is an error for a parameter without a default to be missing.
+//!
+//! If no subcommand is given, the subcommand and all its parameters are prompted for.
+//!
+//! The `job` subcommand instead runs a whole pipeline of computations described in a file. See the
+//! [`job`] module for the format.
+
+mod job;
+mod lift_hom;
+mod massey;
+mod settings;
+
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use algebra::module::Module;
+use clap::{Args, Parser, Subcommand};
+use ext::chain_complex::{ChainComplex, FreeChainComplex};
+use ext::secondary::{SecondaryLift, SecondaryResolution};
+use ext::utils::LoadQuasiInverseOption;
+
+use settings::Settings;
+
+#[derive(Parser)]
+#[command(name = "ext", about = "Compute Ext over the Steenrod algebra")]
+struct Cli {
+    /// A TOML file supplying values for parameters not given on the command line.
+    #[arg(long, global = true, env = "EXT_CONFIG")]
+    config: Option<PathBuf>,
+
+    /// Never prompt for missing parameters. Use the default values instead, and fail if there is
+    /// none.
+    #[arg(
+        long,
+        global = true,
+        env = "EXT_BATCH",
+        value_parser = clap::builder::FalseyValueParser::new()
+    )]
+    batch: bool,
+
+    #[command(subcommand)]
+    command: Option<Command>,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Resolve a module and print the dimensions of Ext as an ASCII chart.
+    Resolve {
+        #[command(flatten)]
+        module: ModuleArgs,
+        #[command(flatten)]
+        range: RangeArgs,
+    },
+    /// Print the number of generators in each bidegree in the format `n,s,num_gens`.
+    NumGens {
+        #[command(flatten)]
+        module: ModuleArgs,
+        #[command(flatten)]
+        range: RangeArgs,
+    },
+    /// Compute d_2 differentials using the secondary Steenrod algebra.
+    Secondary {
+        #[command(flatten)]
+        module: ModuleArgs,
+        #[command(flatten)]
+        range: RangeArgs,
+    },
+    /// Compute the Massey products <a, b, -> for fixed a and b.
+    Massey(massey::MasseyArgs),
+    /// Compute the map on Ext induced by an element of Ext(M, N).
+    LiftHom(lift_hom::LiftHomArgs),
+    /// Run the computations described by a job file, skipping those whose outputs exist.
+    Job {
+        /// The job file, in TOML or JSON format.
+        path: PathBuf,
+        /// Recompute outputs even if they exist.
+        #[arg(long)]
+        force: bool,
+    },
+}
+
+impl Command {
+    const NAMES: [&'static str; 6] = [
+        "resolve",
+        "num-gens",
+        "secondary",
+        "massey",
+        "lift-hom",
+        "job",
+    ];
+
+    fn name(&self) -> &'static str {
+        match self {
+            Self::Resolve { .. } => "resolve",
+            Self::NumGens { .. } => "num_gens",
+            Self::Secondary { .. } => "secondary",
+            Self::Massey(_) => "massey",
+            Self::LiftHom(_) => "lift_hom",
+            Self::Job { .. } => "job",
+        }
+    }
+}
+
+#[derive(Args)]
+struct ModuleArgs {
+    /// The module to resolve, e.g. `C2` or `Ceta[1]@adem`.
+    #[arg(long, short, env = "EXT_MODULE")]
+    module: Option<String>,
+
+    /// The directory to save the resolution to and load it from.
+    #[arg(long, env = "EXT_SAVE_DIR")]
+    save_dir: Option<PathBuf>,
+}
+
+#[derive(Args)]
+struct RangeArgs {
+    /// The maximum stem to compute.
+    #[arg(long, env = "EXT_MAX_N", allow_negative_numbers = true)]
+    max_n: Option<i32>,
+
+    /// The maximum filtration to compute.
+    #[arg(long, env = "EXT_MAX_S")]
+    max_s: Option<u32>,
+}
+
+impl RangeArgs {
+    fn get(self, settings: &Settings) -> anyhow::Result<(u32, i32)> {
+        let max_n = settings.get(self.max_n, "max_n", "Max n", Some("30"))?;
+        let max_s = settings.get(self.max_s, "max_s", "Max s", Some("7"))?;
+        Ok((max_s, max_n))
+    }
+}
+
+fn main() -> anyhow::Result<()> {
+    let mut cli = Cli::parse();
+    query::clear_args();
+
+    let command = match cli.command.take() {
+        Some(command) => command,
+        None => {
+            anyhow::ensure!(!cli.batch, "A subcommand is required in batch mode");
+            let name: String = query::raw("Command", |name: &str| {
+                if Command::NAMES.contains(&name) {
+                    Ok(name.to_owned())
+                } else {
+                    Err(format!("Command must be one of {:?}", Command::NAMES))
+                }
+            });
+            Cli::parse_from(["ext", &name]).command.unwrap()
+        }
+    };
+    let settings = Settings::new(command.name(), cli.config.as_deref(), cli.batch)?;
+
+    match command {
+        Command::Resolve { module, range } => {
+            let res = settings.resolution(
+                module.module,
+                module.save_dir,
+                "",
+                "Module",
+                None,
+                LoadQuasiInverseOption::No,
+            )?;
+            let (max_s, max_n) = range.get(&settings)?;
+            res.compute_through_stem(max_s, max_n);
+            println!("{}", res.graded_dimension_string());
+        }
+        Command::NumGens { module, range } => {
+            let res = settings.resolution(
+                module.module,
+                module.save_dir,
+                "",
+                "Module",
+                None,
+                LoadQuasiInverseOption::No,
+            )?;
+            let (max_s, max_n) = range.get(&settings)?;
+            res.compute_through_stem(max_s, max_n);
+            for (s, n, t) in res.iter_stem() {
+                println!("{},{},{}", n, s, res.module(s).number_of_gens_in_degree(t));
+            }
+        }
+        Command::Secondary { module, range } => {
+            let res = settings.resolution(
+                module.module,
+                module.save_dir,
+                "",
+                "Module",
+                Some(algebra::AlgebraType::Milnor),
+                LoadQuasiInverseOption::IfNoSave,
+            )?;
+            let (max_s, max_n) = range.get(&settings)?;
+            res.compute_through_stem(max_s, max_n);
+            secondary(Arc::new(res))?;
+        }
+        Command::Massey(args) => massey::run(&settings, args)?,
+        Command::LiftHom(args) => lift_hom::run(&settings, args)?,
+        Command::Job { path, force } => job::Runner::new(&path, force)?.run()?,
+    }
+    Ok(())
+}
+
+/// Print the $d_2$ differentials whose target bidegree is non-zero, including the zero ones, as in
+/// the `secondary` example.
+fn secondary(resolution: Arc<ext::resolution::Resolution<ext::CCC>>) -> anyhow::Result<()> {
+    use ext::chain_complex::AugmentedChainComplex;
+
+    anyhow::ensure!(
+        ext::secondary::can_compute(&resolution),
+        "Cannot compute d2 for the module {}",
+        resolution.target().module(0)
+    );
+
+    let lift = SecondaryResolution::new(Arc::clone(&resolution));
+    lift.extend_all();
+
+    let p = resolution.prime();
+    let names = ext::names::ClassNames::for_resolution(&resolution)?;
+    for (s, n, t) in resolution.iter_stem() {
+        if s < 3
+            || t - 1 > resolution.module(s - 2).max_computed_degree()
+            || resolution.module(s).number_of_gens_in_degree(t) == 0
+        {
+            continue;
+        }
+        let m = lift.homotopy(s).homotopies.hom_k(t - 1);
+        for (i, entry) in m.into_iter().enumerate() {
+            println!(
+                "d_2 {} = {}",
+                names.name(n + 1, s - 2, i),
+                names.format(fp::vector::FpVector::from_slice(p, &entry).as_slice(), n, s)
+            );
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,205 @@
+use std::sync::Arc;
+
+use algebra::module::{FDModule, Module};
+use clap::Args;
+use ext::chain_complex::{
+    AugmentedChainComplex, ChainComplex, FiniteChainComplex, FreeChainComplex,
+};
+use ext::massey::massey_product;
+use ext::resolution::Resolution;
+use ext::resolution_homomorphism::ResolutionHomomorphism;
+use ext::utils::LoadQuasiInverseOption;
+use fp::matrix::AugmentedMatrix;
+
+use crate::settings::{Class, Settings};
+use crate::{ModuleArgs, RangeArgs};
+
+#[derive(Args)]
+pub struct MasseyArgs {
+    #[command(flatten)]
+    module: ModuleArgs,
+    #[command(flatten)]
+    range: RangeArgs,
+
+    /// The stem of a.
+    #[arg(long, env = "EXT_A_N", allow_negative_numbers = true)]
+    a_n: Option<i32>,
+    /// The filtration of a.
+    #[arg(long, env = "EXT_A_S")]
+    a_s: Option<u32>,
+    /// The class a, as a vector such as `[1, 0]`.
+    #[arg(long, env = "EXT_A_CLASS")]
+    a_class: Option<Class>,
+
+    /// The stem of b.
+    #[arg(long, env = "EXT_B_N", allow_negative_numbers = true)]
+    b_n: Option<i32>,
+    /// The filtration of b.
+    #[arg(long, env = "EXT_B_S")]
+    b_s: Option<u32>,
+    /// The class b, as a vector such as `[1, 0]`.
+    #[arg(long, env = "EXT_B_CLASS")]
+    b_class: Option<Class>,
+}
+
+/// Compute the Massey products $\langle a, b, -\rangle$ with their indeterminacy, as in the `massey` example.
+pub fn run(settings: &Settings, args: MasseyArgs) -> anyhow::Result<()> {
+    let resolution = settings.resolution(
+        args.module.module,
+        args.module.save_dir,
+        "",
+        "Module",
+        None,
+        LoadQuasiInverseOption::Yes,
+    )?;
+    let (max_s, max_n) = args.range.get(settings)?;
+    resolution.compute_through_stem(max_s, max_n);
+    let resolution = Arc::new(resolution);
+    let p = resolution.prime();
+
+    let (is_unit, unit) = if resolution.target().module(0).is_unit() {
+        (true, Arc::clone(&resolution))
+    } else {
+        let module = Arc::new(
+            FDModule::new(
+                resolution.algebra(),
+                format!("S_{}", p),
+                bivec::BiVec::from_vec(0, vec![1]),
+            )
+            .into(),
+        );
+        let ccdz = Arc::new(FiniteChainComplex::ccdz(module));
+        (false, Arc::new(Resolution::new(ccdz)))
+    };
+
+    let a_n: i32 = settings.get(args.a_n, "a_n", "n of a", Some("0"))?;
+    let a_s: u32 = settings.get(args.a_s, "a_s", "s of a", Some("1"))?;
+    let Class(a_class) = settings.get(args.a_class, "a_class", "a", Some("[1]"))?;
+    let b_n: i32 = settings.get(args.b_n, "b_n", "n of b", Some("1"))?;
+    let b_s: u32 = settings.get(args.b_s, "b_s", "s of b", Some("1"))?;
+    let Class(b_class) = settings.get(args.b_class, "b_class", "b", Some("[1]"))?;
+    anyhow::ensure!(
+        a_s > 0 && b_s > 0,
+        "a and b must be positive filtration classes"
+    );
+
+    let a_t = a_n + a_s as i32;
+    let b_t = b_n + b_s as i32;
+
+    // The Massey product shifts the bidegree by this amount
+    let shift_s = a_s + b_s - 1;
+    let shift_t = a_t + b_t;
+    let shift_n = shift_t - shift_s as i32;
+
+    if !is_unit {
+        unit.compute_through_stem(shift_s, shift_n);
+    }
+
+    if !resolution.has_computed_bidegree(shift_s, shift_t + resolution.min_degree()) {
+        eprintln!("No computable bidegrees");
+        return Ok(());
+    }
+
+    for (name, s, t, class) in [("a", a_s, a_t, &a_class), ("b", b_s, b_t, &b_class)] {
+        anyhow::ensure!(
+            unit.has_computed_bidegree(s, t)
+                && unit.number_of_gens_in_bidegree(s, t) == class.len(),
+            "{name} does not have the dimension of Ext in its bidegree"
+        );
+    }
+
+    let b_hom = Arc::new(ResolutionHomomorphism::from_class(
+        "b".into(),
+        Arc::clone(&unit),
+        Arc::clone(&unit),
+        b_s,
+        b_t,
+        &b_class,
+    ));
+
+    b_hom.extend_through_stem(shift_s, shift_n);
+
+    let a_hom = Arc::new(ResolutionHomomorphism::from_class(
+        "a".into(),
+        Arc::clone(&unit),
+        Arc::clone(&unit),
+        a_s,
+        a_t,
+        &a_class,
+    ));
+    a_hom.extend_through_stem(shift_s, shift_n);
+
+    let names = ext::names::ClassNames::for_resolution(&resolution)?;
+    for (s, n, t) in resolution.iter_stem() {
+        if !resolution.has_computed_bidegree(s + shift_s, t + shift_t) {
+            continue;
+        }
+
+        let num_gens = resolution.module(s).number_of_gens_in_degree(t);
+        let product_num_gens = resolution.module(s + b_s).number_of_gens_in_degree(t + b_t);
+        let target_num_gens = resolution
+            .module(s + shift_s)
+            .number_of_gens_in_degree(t + shift_t);
+        if num_gens == 0 || target_num_gens == 0 {
+            continue;
+        }
+
+        let mut product = AugmentedMatrix::<2>::new(p, num_gens, [product_num_gens, num_gens]);
+        product.segment(1, 1).add_identity();
+
+        let mut class = vec![0; num_gens];
+        for idx in 0..num_gens {
+            class[idx] = 1;
+            let hom = ResolutionHomomorphism::from_class(
+                "c".into(),
+                Arc::clone(&resolution),
+                Arc::clone(&unit),
+                s,
+                t,
+                &class,
+            );
+            class[idx] = 0;
+            hom.extend_through_stem(s + b_s, n + b_n);
+
+            for (k, &v) in b_class.iter().enumerate() {
+                if v != 0 {
+                    hom.act(product[idx].slice_mut(0, product_num_gens), v, b_s, b_t, k);
+                }
+            }
+        }
+        product.row_reduce();
+        let kernel = product.compute_kernel();
+
+        for row in &**kernel {
+            let class: Vec<u32> = row.iter().collect();
+            let c_hom = Arc::new(ResolutionHomomorphism::from_class(
+                "c".into(),
+                Arc::clone(&resolution),
+                Arc::clone(&unit),
+                s,
+                t,
+                &class,
+            ));
+            let Some(massey) = massey_product(&[Arc::clone(&a_hom), Arc::clone(&b_hom)], &c_hom)
+            else {
+                continue;
+            };
+
+            print!("<a, b, ");
+            ext::utils::print_element(row.as_slice(), n, s, &names);
+            print!("> = {}", massey.value);
+            if massey.indeterminacy.dimension() > 0 {
+                let basis: Vec<String> = massey
+                    .indeterminacy
+                    .basis()
+                    .iter()
+                    .map(ToString::to_string)
+                    .collect();
+                print!(" + <{}>", basis.join(", "));
+            }
+            println!();
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,196 @@
+use std::fmt::Display;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+use algebra::AlgebraType;
+use anyhow::{anyhow, Context};
+use ext::chain_complex::ChainComplex;
+use ext::resolution::Resolution;
+use ext::utils::{Config, LoadQuasiInverseOption};
+use ext::CCC;
+
+/// An element of an Ext group, written as a vector such as `[1, 0, 1]`. The brackets are optional.
+#[derive(Clone, Debug)]
+pub struct Class(pub Vec<u32>);
+
+impl FromStr for Class {
+    type Err = std::num::ParseIntError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        let s = s.strip_prefix('[').unwrap_or(s);
+        let s = s.strip_suffix(']').unwrap_or(s);
+        if s.trim().is_empty() {
+            return Ok(Self(Vec::new()));
+        }
+        s.split(',')
+            .map(|x| x.trim().parse())
+            .collect::<Result<_, _>>()
+            .map(Self)
+    }
+}
+
+/// Resolves the values of the parameters of a subcommand. A parameter is taken from the first of
+/// the following that supplies it:
+///
+///  1. The command line flag or its environment variable (this is handled by `clap`);
+///  2. The table of the config file named after the subcommand;
+///  3. The top level of the config file;
+///  4. An interactive prompt, or the default value in batch mode.
+pub struct Settings {
+    command: &'static str,
+    config: toml::Table,
+    batch: bool,
+}
+
+impl Settings {
+    pub fn new(command: &'static str, config: Option<&Path>, batch: bool) -> anyhow::Result<Self> {
+        let config = match config {
+            Some(path) => std::fs::read_to_string(path)
+                .with_context(|| format!("Failed to read config file {path:?}"))?
+                .parse::<toml::Table>()
+                .with_context(|| format!("Failed to parse config file {path:?}"))?,
+            None => toml::Table::new(),
+        };
+        Ok(Self {
+            command,
+            config,
+            batch,
+        })
+    }
+
+    fn config_value(&self, key: &str) -> Option<&toml::Value> {
+        self.config
+            .get(self.command)
+            .and_then(|table| table.get(key))
+            .or_else(|| self.config.get(key).filter(|v| !v.is_table()))
+    }
+
+    fn parse_config<T>(&self, key: &str, value: &toml::Value) -> anyhow::Result<T>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        let value = match value {
+            toml::Value::String(s) => s.clone(),
+            v => v.to_string(),
+        };
+        value
+            .parse()
+            .map_err(|e| anyhow!("Invalid value {value:?} for {key} in config file: {e}"))
+    }
+
+    /// Get a required parameter. If `default` is `None` and the value is not supplied, this fails
+    /// in batch mode.
+    pub fn get<T>(
+        &self,
+        flag: Option<T>,
+        key: &str,
+        prompt: &str,
+        default: Option<&str>,
+    ) -> anyhow::Result<T>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        if let Some(v) = flag {
+            return Ok(v);
+        }
+        if let Some(v) = self.config_value(key) {
+            return self.parse_config(key, v);
+        }
+        match (default, self.batch) {
+            (Some(default), true) => default
+                .parse()
+                .map_err(|e| anyhow!("Invalid default value for {key}: {e}")),
+            (None, true) => Err(anyhow!("Missing value for {key}")),
+            (Some(default), false) => Ok(query::with_default(prompt, default, str::parse)),
+            (None, false) => Ok(query::raw(prompt, str::parse)),
+        }
+    }
+
+    /// Get an optional parameter. In batch mode, a value that is not supplied is `None`.
+    pub fn get_optional<T>(
+        &self,
+        flag: Option<T>,
+        key: &str,
+        prompt: &str,
+    ) -> anyhow::Result<Option<T>>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        if flag.is_some() {
+            return Ok(flag);
+        }
+        if let Some(v) = self.config_value(key) {
+            return self.parse_config(key, v).map(Some);
+        }
+        if self.batch {
+            Ok(None)
+        } else {
+            Ok(query::optional(prompt, str::parse))
+        }
+    }
+
+    /// Get a list of parameters, which is given by repeating the flag or as an array in the config
+    /// file. If neither is supplied, this returns an empty list.
+    pub fn get_list<T>(&self, flag: Vec<T>, key: &str) -> anyhow::Result<Vec<T>>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        if !flag.is_empty() {
+            return Ok(flag);
+        }
+        match self.config_value(key) {
+            Some(toml::Value::Array(values)) => {
+                values.iter().map(|v| self.parse_config(key, v)).collect()
+            }
+            Some(v) => Ok(vec![self.parse_config(key, v)?]),
+            None => Ok(Vec::new()),
+        }
+    }
+
+    /// Get a vector of length `len`, which is used when a list supplied by [`Settings::get_list`]
+    /// runs out.
+    pub fn get_vector(&self, prompt: &str, len: usize) -> anyhow::Result<Vec<u32>> {
+        if self.batch {
+            Err(anyhow!("Missing value for {prompt}"))
+        } else {
+            Ok(query::vector(prompt, len))
+        }
+    }
+
+    /// Construct the resolution of a module. The config keys are `{prefix}module` and
+    /// `{prefix}save_dir`.
+    pub fn resolution(
+        &self,
+        module: Option<String>,
+        save_dir: Option<PathBuf>,
+        prefix: &str,
+        prompt: &str,
+        algebra: Option<AlgebraType>,
+        load_quasi_inverse: LoadQuasiInverseOption,
+    ) -> anyhow::Result<Resolution<CCC>> {
+        let module: String = self.get(module, &format!("{prefix}module"), prompt, Some("S_2"))?;
+        let save_dir = self.get_optional(
+            save_dir,
+            &format!("{prefix}save_dir"),
+            &format!("{prompt} save directory"),
+        )?;
+
+        let config: Config = match algebra {
+            Some(algebra) => (module.as_str(), algebra).try_into()?,
+            None => module.as_str().try_into()?,
+        };
+        let mut resolution = ext::utils::construct(config, save_dir)
+            .context("Failed to load module from save file")?;
+        resolution.load_quasi_inverse = match load_quasi_inverse {
+            LoadQuasiInverseOption::Yes => true,
+            LoadQuasiInverseOption::No => false,
+            LoadQuasiInverseOption::IfNoSave => resolution.save_dir().is_none(),
+        };
+        Ok(resolution)
+    }
+}
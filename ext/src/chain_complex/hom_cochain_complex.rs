@@ -0,0 +1,200 @@
+use super::{CochainComplex, FreeChainComplex};
+use algebra::module::homomorphism::HomPullback;
+use algebra::module::{BoundedModule, HomModule, Module};
+use algebra::Field;
+use once::{OnceBiVec, OnceVec};
+
+use std::sync::Arc;
+
+/// The cochain complex $\Hom_A(C_\bullet, M)$, where $C_\bullet$ is a free chain complex and $M$
+/// is a bounded module. The elements of degree $t$ in the `s`th module are the homomorphisms
+/// $C_s \to \Sigma^t M$. If $C_\bullet$ is a free resolution of $N$, then the cohomology of this
+/// complex is $\Ext_A(N, M)$.
+///
+/// The `s`th differential is the map $\Hom_A(C_{s - 1}, M) \to \Hom_A(C_s, M)$ given by
+/// precomposition with the differential of $C_\bullet$. This only uses the part of $C_\bullet$ that
+/// has already been computed, so $C_\bullet$ must be computed through degree $t + \max(M)$ in order
+/// to compute the cohomology in degree $t$.
+pub struct HomCochainComplex<CC, M>
+where
+    CC: FreeChainComplex,
+    M: BoundedModule<Algebra = CC::Algebra>,
+{
+    source: Arc<CC>,
+    target: Arc<M>,
+    zero_module: Arc<HomModule<M>>,
+    modules: OnceVec<Arc<HomModule<M>>>,
+    differentials: OnceVec<Arc<HomPullback<M>>>,
+    cohomology_basis: OnceVec<OnceBiVec<Vec<usize>>>,
+}
+
+impl<CC, M> HomCochainComplex<CC, M>
+where
+    CC: FreeChainComplex,
+    M: BoundedModule<Algebra = CC::Algebra>,
+{
+    pub fn new(source: Arc<CC>, target: Arc<M>) -> Self {
+        target.compute_basis(target.max_degree());
+        Self {
+            zero_module: Arc::new(HomModule::new(source.zero_module(), Arc::clone(&target))),
+            source,
+            target,
+            modules: OnceVec::new(),
+            differentials: OnceVec::new(),
+            cohomology_basis: OnceVec::new(),
+        }
+    }
+
+    pub fn source(&self) -> Arc<CC> {
+        Arc::clone(&self.source)
+    }
+
+    pub fn target(&self) -> Arc<M> {
+        Arc::clone(&self.target)
+    }
+
+    /// The largest degree in which the `s`th module can be computed.
+    fn max_computable_degree(&self, s: u32) -> i32 {
+        self.source.module(s).max_computed_degree() - self.target.max_degree()
+    }
+
+    /// Whether the cohomology at bidegree `(s, t)` can be computed, i.e. whether the modules
+    /// $\Hom_A(C_{s'}, M)$ can be computed in degree `t` for $s' = s - 1, s, s + 1$.
+    pub fn has_computed_bidegree(&self, s: u32, t: i32) -> bool {
+        s + 1 < self.source.next_homological_degree()
+            && (s.saturating_sub(1)..=s + 1).all(|s| t <= self.max_computable_degree(s))
+    }
+}
+
+impl<CC, M> CochainComplex for HomCochainComplex<CC, M>
+where
+    CC: FreeChainComplex,
+    M: BoundedModule<Algebra = CC::Algebra>,
+{
+    type Algebra = Field;
+    type Module = HomModule<M>;
+    type Homomorphism = HomPullback<M>;
+
+    fn algebra(&self) -> Arc<Field> {
+        self.zero_module.algebra()
+    }
+
+    fn min_degree(&self) -> i32 {
+        self.source.min_degree() - self.target.max_degree()
+    }
+
+    fn zero_module(&self) -> Arc<Self::Module> {
+        Arc::clone(&self.zero_module)
+    }
+
+    fn module(&self, homological_degree: u32) -> Arc<Self::Module> {
+        self.modules.extend(homological_degree as usize, |s| {
+            Arc::new(HomModule::new(
+                self.source.module(s as u32),
+                Arc::clone(&self.target),
+            ))
+        });
+        Arc::clone(&self.modules[homological_degree as usize])
+    }
+
+    fn differential(&self, homological_degree: u32) -> Arc<Self::Homomorphism> {
+        self.differentials.extend(homological_degree as usize, |s| {
+            let source = if s == 0 {
+                self.zero_module()
+            } else {
+                self.module(s as u32 - 1)
+            };
+            Arc::new(HomPullback::new(
+                source,
+                self.module(s as u32),
+                self.source.differential(s as u32),
+            ))
+        });
+        Arc::clone(&self.differentials[homological_degree as usize])
+    }
+
+    /// Compute the modules through the given bidegree, as far as the source complex has been
+    /// computed.
+    fn compute_through_bidegree(&self, homological_degree: u32, degree: i32) {
+        let max_zero_degree =
+            self.source.zero_module().max_computed_degree() - self.target.max_degree();
+        self.zero_module
+            .compute_basis(std::cmp::min(degree, max_zero_degree));
+        for s in 0..=homological_degree {
+            self.module(s)
+                .compute_basis(std::cmp::min(degree, self.max_computable_degree(s)));
+        }
+    }
+
+    fn set_cohomology_basis(
+        &self,
+        homological_degree: u32,
+        internal_degree: i32,
+        cohomology_basis: Vec<usize>,
+    ) {
+        let min_degree = self.min_degree();
+        self.cohomology_basis
+            .extend(homological_degree as usize, |_| OnceBiVec::new(min_degree));
+        self.cohomology_basis[homological_degree as usize]
+            .push_checked(cohomology_basis, internal_degree);
+    }
+
+    fn cohomology_basis(&self, homological_degree: u32, internal_degree: i32) -> &Vec<usize> {
+        &self.cohomology_basis[homological_degree as usize][internal_degree]
+    }
+
+    fn max_cohomology_degree(&self, homological_degree: u32) -> i32 {
+        self.cohomology_basis
+            .get(homological_degree as usize)
+            .map_or(self.min_degree() - 1, OnceBiVec::max_degree)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::chain_complex::{AugmentedChainComplex, ChainComplex};
+    use crate::utils::construct;
+
+    /// Check that $\Ext^{s, t}(N, M)$ agrees with $\Ext^{s, t}(D, k)$, where `dual` is the module
+    /// $D = N \otimes DM$.
+    fn check(source: &str, coefficients: &str, dual: &str) {
+        let resolution = Arc::new(construct(source, None).unwrap());
+        let dual = construct(dual, None).unwrap();
+        let module = construct(coefficients, None).unwrap().target().module(0);
+        resolution.compute_through_stem(5, 16);
+        dual.compute_through_stem(4, 10);
+
+        let cc = HomCochainComplex::new(Arc::clone(&resolution), module);
+        for s in 0..dual.next_homological_degree() {
+            let mut t = cc.min_degree();
+            while cc.has_computed_bidegree(s, t) {
+                cc.compute_cohomology(s, t);
+                if dual.has_computed_bidegree(s, t) {
+                    assert_eq!(
+                        cc.cohomology_dimension(s, t),
+                        dual.number_of_gens_in_bidegree(s, t),
+                        "Ext^({s}, {t})({source}, {coefficients})"
+                    );
+                }
+                t += 1;
+            }
+        }
+    }
+
+    #[test]
+    fn trivial_coefficients() {
+        check("C2", "S_2", "C2");
+    }
+
+    #[test]
+    fn c2_coefficients() {
+        check("S_2", "C2", "C2[-1]");
+    }
+
+    #[test]
+    fn joker_coefficients() {
+        check("S_2", "Joker", "Joker[-4]");
+    }
+}
@@ -1,6 +1,9 @@
 mod chain_homotopy;
 mod finite_chain_complex;
+mod hom_cochain_complex;
 mod tensor_product_chain_complex;
+mod tor_chain_complex;
+mod zpk_chain_complex;
 
 use crate::utils::ascii_num;
 use algebra::module::homomorphism::{FreeModuleHomomorphism, ModuleHomomorphism};
@@ -13,10 +16,12 @@ use std::sync::Arc;
 
 use itertools::Itertools;
 
-// pub use hom_complex::HomComplex;
 pub use chain_homotopy::ChainHomotopy;
 pub use finite_chain_complex::{FiniteAugmentedChainComplex, FiniteChainComplex};
+pub use hom_cochain_complex::HomCochainComplex;
 pub use tensor_product_chain_complex::TensorChainComplex;
+pub use tor_chain_complex::TorChainComplex;
+pub use zpk_chain_complex::ZpkChainComplex;
 
 pub enum ChainComplexGrading {
     Homological,
@@ -345,7 +350,7 @@ pub trait CochainComplex: Send + Sync {
         let row_index = self.cohomology_basis(homological_degree, internal_degree)[index];
         result.add(
             &self
-                .differential(homological_degree)
+                .differential(homological_degree + 1)
                 .kernel(internal_degree)
                 .unwrap()[row_index],
             coeff,
@@ -353,7 +358,6 @@ pub trait CochainComplex: Send + Sync {
     }
 
     fn compute_cohomology(&self, homological_degree: u32, internal_degree: i32) {
-        println!("==== {}, {}", homological_degree, internal_degree);
         self.compute_through_bidegree(homological_degree + 1, internal_degree);
         let d_cur = self.differential(homological_degree);
         let d_prev = self.differential(homological_degree + 1);
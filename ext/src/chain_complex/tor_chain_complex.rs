@@ -0,0 +1,219 @@
+use super::{ChainComplex, FreeChainComplex};
+use algebra::module::homomorphism::FreeTensorHomomorphism;
+use algebra::module::{FreeTensorModule, Module, RightModule};
+use algebra::Field;
+use once::{OnceBiVec, OnceVec};
+
+use std::sync::Arc;
+
+/// The chain complex $N \otimes_A C_\bullet$, where $N$ is a right module and $C_\bullet$ is a free
+/// chain complex. If $C_\bullet$ is a free resolution of $M$, then the homology of this complex is
+/// $\Tor^A(N, M)$.
+///
+/// The `s`th module in degree `t` only involves the generators of $C_s$ in degrees at most $t -
+/// \min(N)$, so $C_\bullet$ must be computed that far in order to compute the homology in degree
+/// `t`. The right module $N$ has to be computed through the range needed by the caller, e.g. via
+/// [`FreeModule::extend_by_zero`](algebra::module::FreeModule::extend_by_zero).
+pub struct TorChainComplex<N, CC>
+where
+    N: RightModule,
+    CC: FreeChainComplex<Algebra = N::Algebra>,
+{
+    left: Arc<N>,
+    right: Arc<CC>,
+    zero_module: Arc<FreeTensorModule<N>>,
+    modules: OnceVec<Arc<FreeTensorModule<N>>>,
+    differentials: OnceVec<Arc<FreeTensorHomomorphism<N>>>,
+    homology_basis: OnceVec<OnceBiVec<Vec<usize>>>,
+}
+
+impl<N, CC> TorChainComplex<N, CC>
+where
+    N: RightModule,
+    CC: FreeChainComplex<Algebra = N::Algebra>,
+{
+    pub fn new(left: Arc<N>, right: Arc<CC>) -> Self {
+        Self {
+            zero_module: Arc::new(FreeTensorModule::new(
+                Arc::clone(&left),
+                right.zero_module(),
+            )),
+            left,
+            right,
+            modules: OnceVec::new(),
+            differentials: OnceVec::new(),
+            homology_basis: OnceVec::new(),
+        }
+    }
+
+    pub fn left(&self) -> Arc<N> {
+        Arc::clone(&self.left)
+    }
+
+    pub fn right(&self) -> Arc<CC> {
+        Arc::clone(&self.right)
+    }
+
+    /// The largest degree in which the `s`th module can be computed.
+    fn max_computable_degree(&self, s: u32) -> i32 {
+        self.right.module(s).max_computed_degree() + self.left.min_degree()
+    }
+}
+
+impl<N, CC> ChainComplex for TorChainComplex<N, CC>
+where
+    N: RightModule,
+    CC: FreeChainComplex<Algebra = N::Algebra>,
+{
+    type Algebra = Field;
+    type Module = FreeTensorModule<N>;
+    type Homomorphism = FreeTensorHomomorphism<N>;
+
+    fn algebra(&self) -> Arc<Field> {
+        self.zero_module.algebra()
+    }
+
+    fn min_degree(&self) -> i32 {
+        self.right.min_degree() + self.left.min_degree()
+    }
+
+    fn zero_module(&self) -> Arc<Self::Module> {
+        Arc::clone(&self.zero_module)
+    }
+
+    fn module(&self, homological_degree: u32) -> Arc<Self::Module> {
+        self.modules.extend(homological_degree as usize, |s| {
+            Arc::new(FreeTensorModule::new(
+                Arc::clone(&self.left),
+                self.right.module(s as u32),
+            ))
+        });
+        Arc::clone(&self.modules[homological_degree as usize])
+    }
+
+    fn differential(&self, homological_degree: u32) -> Arc<Self::Homomorphism> {
+        self.differentials.extend(homological_degree as usize, |s| {
+            let target = if s == 0 {
+                self.zero_module()
+            } else {
+                self.module(s as u32 - 1)
+            };
+            Arc::new(FreeTensorHomomorphism::new(
+                self.module(s as u32),
+                target,
+                self.right.differential(s as u32),
+            ))
+        });
+        Arc::clone(&self.differentials[homological_degree as usize])
+    }
+
+    /// Whether the homology at bidegree `(s, t)` can be computed, i.e. whether the modules $N
+    /// \otimes C_{s'}$ can be computed in degree `t` for $s' = s - 1, s, s + 1$.
+    fn has_computed_bidegree(&self, s: u32, t: i32) -> bool {
+        s + 1 < self.right.next_homological_degree()
+            && (s.saturating_sub(1)..=s + 1).all(|s| t <= self.max_computable_degree(s))
+    }
+
+    /// Compute the modules through the given bidegree, as far as the underlying complex has been
+    /// computed.
+    fn compute_through_bidegree(&self, homological_degree: u32, degree: i32) {
+        let max_zero_degree =
+            self.right.zero_module().max_computed_degree() + self.left.min_degree();
+        self.zero_module
+            .compute_basis(std::cmp::min(degree, max_zero_degree));
+        for s in 0..=homological_degree {
+            self.module(s)
+                .compute_basis(std::cmp::min(degree, self.max_computable_degree(s)));
+        }
+    }
+
+    fn next_homological_degree(&self) -> u32 {
+        self.right.next_homological_degree()
+    }
+
+    fn set_homology_basis(
+        &self,
+        homological_degree: u32,
+        internal_degree: i32,
+        homology_basis: Vec<usize>,
+    ) {
+        let min_degree = self.min_degree();
+        self.homology_basis
+            .extend(homological_degree as usize, |_| OnceBiVec::new(min_degree));
+        self.homology_basis[homological_degree as usize]
+            .push_checked(homology_basis, internal_degree);
+    }
+
+    fn homology_basis(&self, homological_degree: u32, internal_degree: i32) -> &Vec<usize> {
+        &self.homology_basis[homological_degree as usize][internal_degree]
+    }
+
+    fn max_homology_degree(&self, homological_degree: u32) -> i32 {
+        self.homology_basis
+            .get(homological_degree as usize)
+            .map_or(self.min_degree() - 1, OnceBiVec::max_degree)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::chain_complex::AugmentedChainComplex;
+    use crate::utils::construct;
+    use algebra::module::{FreeModule, TruncatedModule};
+
+    /// $\Tor^A(k, M)$ is the dual of $\Ext_A(M, k)$, so its dimensions are the numbers of
+    /// generators of a minimal resolution.
+    #[test]
+    fn trivial_coefficients() {
+        let resolution = Arc::new(construct("Joker", None).unwrap());
+        resolution.compute_through_stem(5, 12);
+
+        let free = FreeModule::new(resolution.algebra(), String::from("A"), 0);
+        free.add_generators(0, 1, None);
+        free.extend_by_zero(30);
+        let k = Arc::new(TruncatedModule::new(Arc::new(free), 0));
+
+        let cc = TorChainComplex::new(k, Arc::clone(&resolution));
+        for s in 0..cc.next_homological_degree() {
+            let mut t = cc.min_degree();
+            while cc.has_computed_bidegree(s, t) {
+                cc.compute_homology(s, t);
+                assert_eq!(
+                    cc.homology_dimension(s, t),
+                    resolution.number_of_gens_in_bidegree(s, t),
+                    "Tor_({s}, {t})(k, Joker)"
+                );
+                t += 1;
+            }
+        }
+    }
+
+    /// $\Tor^A(A, M)$ is $M$ concentrated in homological degree 0.
+    #[test]
+    fn free_coefficients() {
+        let resolution = Arc::new(construct("Joker", None).unwrap());
+        resolution.compute_through_stem(4, 8);
+        let module = resolution.target().module(0);
+
+        let free = FreeModule::new(resolution.algebra(), String::from("A"), 0);
+        free.add_generators(0, 1, None);
+        free.extend_by_zero(20);
+
+        let cc = TorChainComplex::new(Arc::new(free), Arc::clone(&resolution));
+        for s in 0..cc.next_homological_degree() {
+            let mut t = cc.min_degree();
+            while cc.has_computed_bidegree(s, t) {
+                cc.compute_homology(s, t);
+                let expected = if s == 0 { module.dimension(t) } else { 0 };
+                assert_eq!(
+                    cc.homology_dimension(s, t),
+                    expected,
+                    "Tor_({s}, {t})(A, Joker)"
+                );
+                t += 1;
+            }
+        }
+    }
+}
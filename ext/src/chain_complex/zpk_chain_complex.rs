@@ -0,0 +1,217 @@
+use fp::prime::ValidPrime;
+use fp::zpk::{Zpk, ZpkMatrix};
+
+/// A finite chain complex of finitely generated free $\mathbb{Z}/p^k$-modules. Unlike the other
+/// chain complexes in this module, this is not a complex of modules over an $\F_p$-algebra, and is
+/// only singly graded. The intended use is to compute the homology of small complexes (e.g.
+/// cellular chain complexes) with torsion information.
+///
+/// The `s`th module is $(\mathbb{Z}/p^k)^{d_s}$, and the differential starting from the `s`th
+/// module is a $d_s \times d_{s - 1}$ matrix acting on the right of row vectors, as usual.
+pub struct ZpkChainComplex {
+    ring: Zpk,
+    dimensions: Vec<usize>,
+    /// `differentials[s]` is the differential from the `s + 1`th module to the `s`th module.
+    differentials: Vec<ZpkMatrix>,
+}
+
+impl ZpkChainComplex {
+    /// Construct a chain complex from its differentials, where `differentials[s]` is the
+    /// differential from the `s + 1`th module to the `s`th module. The dimension of the zeroth
+    /// module is `dim_zero`, which is only relevant if `differentials` is empty.
+    ///
+    /// This panics if the dimensions don't match up or the composite of two differentials is
+    /// non-zero.
+    pub fn new(ring: Zpk, dim_zero: usize, differentials: Vec<ZpkMatrix>) -> Self {
+        let mut dimensions = vec![dim_zero];
+        for (s, d) in differentials.iter().enumerate() {
+            assert_eq!(d.ring(), ring);
+            assert_eq!(
+                d.columns(),
+                dimensions[s],
+                "Target of differential {} has the wrong dimension",
+                s + 1
+            );
+            dimensions.push(d.rows());
+        }
+        for (s, w) in differentials.windows(2).enumerate() {
+            assert!(
+                (&w[1] * &w[0]).is_zero(),
+                "d^2 != 0 at homological degree {}",
+                s + 2
+            );
+        }
+
+        Self {
+            ring,
+            dimensions,
+            differentials,
+        }
+    }
+
+    /// Construct a chain complex over $\mathbb{Z}/p^k$ from integer matrices, reducing all
+    /// entries mod $p^k$. The conventions are as in [`ZpkChainComplex::new`].
+    pub fn from_integer_matrices(
+        p: ValidPrime,
+        k: u32,
+        dim_zero: usize,
+        differentials: &[Vec<Vec<i64>>],
+    ) -> Self {
+        let ring = Zpk::new(p, k);
+        let mut dim = dim_zero;
+        let differentials = differentials
+            .iter()
+            .map(|d| {
+                let m = ZpkMatrix::from_vec(ring, d, dim);
+                dim = m.rows();
+                m
+            })
+            .collect();
+        Self::new(ring, dim_zero, differentials)
+    }
+
+    pub fn ring(&self) -> Zpk {
+        self.ring
+    }
+
+    /// The first s such that the `s`th module is zero.
+    pub fn max_s(&self) -> u32 {
+        self.dimensions.len() as u32
+    }
+
+    pub fn dimension(&self, s: u32) -> usize {
+        self.dimensions.get(s as usize).copied().unwrap_or(0)
+    }
+
+    /// The differential from the `s`th module to the `s - 1`th module, or `None` if it is zero
+    /// for trivial reasons.
+    pub fn differential(&self, s: u32) -> Option<&ZpkMatrix> {
+        if s == 0 {
+            None
+        } else {
+            self.differentials.get(s as usize - 1)
+        }
+    }
+
+    /// Compute the `s`th homology group. The result is a list of exponents $e_i$ such that the
+    /// homology is $\bigoplus \mathbb{Z}/p^{e_i}$, sorted in increasing order. In particular, a
+    /// free summand shows up as an exponent of $k$.
+    ///
+    /// # Algorithm
+    /// We first put the outgoing differential in Smith normal form. In the new coordinates, the
+    /// cycles are generated by $p^{k - e_i} \epsilon_i$, where $p^{e_i}$ are the diagonal entries,
+    /// and the $i$th generator has annihilator $p^{e_i}$. We then express the boundaries in terms
+    /// of these generators, and the homology is the cokernel of the resulting relation matrix.
+    pub fn homology(&self, s: u32) -> Vec<u32> {
+        let ring = self.ring;
+        let k = ring.k();
+        let dim = self.dimension(s);
+
+        // Exponents of the generators of the cycles and the change of coordinates.
+        let (exponents, u_inverse) = match self.differential(s) {
+            Some(d) => {
+                let snf = d.smith_normal_form();
+                let mut exponents = snf.exponents;
+                exponents.resize(dim, k);
+                (exponents, snf.u_inverse)
+            }
+            None => (vec![k; dim], ZpkMatrix::identity(ring, dim)),
+        };
+
+        let mut relations: Vec<Vec<i64>> = exponents
+            .iter()
+            .enumerate()
+            .filter(|&(_, &e)| e < k)
+            .map(|(i, &e)| {
+                let mut row = vec![0; dim];
+                row[i] = ring.p_power(e) as i64;
+                row
+            })
+            .collect();
+
+        if let Some(d) = self.differential(s + 1) {
+            for i in 0..d.rows() {
+                let y = u_inverse.apply(d.row(i));
+                let row = y
+                    .iter()
+                    .zip(&exponents)
+                    .map(|(&c, &e)| {
+                        let divisor = (*ring.prime()).pow(k - e);
+                        assert_eq!(c % divisor, 0, "Boundary is not a cycle");
+                        (c / divisor) as i64
+                    })
+                    .collect();
+                relations.push(row);
+            }
+        }
+
+        ZpkMatrix::from_vec(ring, &relations, dim).cokernel()
+    }
+
+    /// Format the `s`th homology group as a direct sum of cyclic groups.
+    pub fn homology_string(&self, s: u32) -> String {
+        let p = *self.ring.prime();
+        let homology = self.homology(s);
+        if homology.is_empty() {
+            return String::from("0");
+        }
+        homology
+            .into_iter()
+            .map(|e| format!("Z/{}", p.pow(e)))
+            .collect::<Vec<_>>()
+            .join(" + ")
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// The cellular chain complex of RP^n, whose differentials alternate between 0 and 2.
+    fn rp(n: usize, p: u32, k: u32) -> ZpkChainComplex {
+        let differentials: Vec<_> = (1..=n)
+            .map(|i| vec![vec![if i % 2 == 0 { 2 } else { 0 }]])
+            .collect();
+        ZpkChainComplex::from_integer_matrices(ValidPrime::new(p), k, 1, &differentials)
+    }
+
+    #[test]
+    fn rp_homology() {
+        let cc = rp(4, 2, 2);
+        assert_eq!(cc.homology(0), vec![2]);
+        assert_eq!(cc.homology(1), vec![1]);
+        assert_eq!(cc.homology(2), vec![1]);
+        assert_eq!(cc.homology(3), vec![1]);
+        assert_eq!(cc.homology(4), vec![1]);
+        assert_eq!(cc.homology(5), Vec::<u32>::new());
+
+        let cc = rp(3, 2, 3);
+        assert_eq!(cc.homology_string(0), "Z/8");
+        assert_eq!(cc.homology_string(1), "Z/2");
+        assert_eq!(cc.homology_string(2), "Z/2");
+        assert_eq!(cc.homology_string(3), "Z/8");
+
+        // 2 is invertible mod 3
+        let cc = rp(4, 3, 2);
+        assert_eq!(cc.homology_string(0), "Z/9");
+        assert_eq!(cc.homology_string(1), "0");
+        assert_eq!(cc.homology_string(4), "0");
+    }
+
+    #[test]
+    fn mixed_torsion() {
+        // The differential d_1: (Z/8)^2 -> Z/8 is given by (2, 4) and d_2: Z/8 -> (Z/8)^2 by (4, 6)
+        let cc = ZpkChainComplex::from_integer_matrices(
+            ValidPrime::new(2),
+            3,
+            1,
+            &[vec![vec![2], vec![4]], vec![vec![4, 6]]],
+        );
+        assert_eq!(cc.homology_string(0), "Z/2");
+        // The cycles are {(a, b) | 2a + 4b = 0} = <(-2, 1), (4, 0)> = Z/8 + Z/2, and the boundary
+        // (4, 6) is 6 times the first generator.
+        assert_eq!(cc.homology_string(1), "Z/2 + Z/2");
+        // The kernel of d_2 is {0, 4}.
+        assert_eq!(cc.homology_string(2), "Z/2");
+    }
+}
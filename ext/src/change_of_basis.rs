@@ -0,0 +1,484 @@
+//! Change of basis of $\Ext$ between two resolutions of the same module.
+//!
+//! Each resolution comes with its own basis of $\Ext$, so the class `x_(n, s, i)` of a resolution
+//! over the Milnor basis need not agree with the class of the same name in a resolution over the
+//! Adem basis, or in a resolution computed with [Nassau's algorithm](crate::nassau). A
+//! [`ChangeOfBasis`] lifts the identity of the module to a chain map from one resolution to the
+//! other, and reads off the change of basis matrix in each bidegree.
+//!
+//! The source resolution can be any [`FreeChainComplex`] over an algebra whose basis can be
+//! converted to that of the target resolution, as specified by [`ConvertBasis`]. We first rewrite
+//! the source resolution over the algebra of the target resolution, and then use a
+//! [`ResolutionHomomorphism`] to lift the identity. This only uses the quasi-inverses of the
+//! target resolution, so the source resolution need not have any.
+//!
+//! # Conventions
+//! In each bidegree, the change of basis matrix $P$ has a row for each class of the target and a
+//! column for each class of the source, and the $i$th row expresses the $i$th class of the target
+//! in terms of the classes of the source. In other words, if $v$ is an element written in the
+//! basis of the target, then $vP$ is the same element written in the basis of the source. This is
+//! the same convention as [`FreeModuleHomomorphism::hom_k`].
+//!
+//! Products and differentials of $\Ext$ are recorded as matrices whose rows are the images of the
+//! basis elements. These can be translated with [`ChangeOfBasis::map_to_source`] and
+//! [`ChangeOfBasis::map_to_target`].
+
+use std::sync::Arc;
+
+use algebra::change_of_basis::{adem_to_milnor_on_basis, milnor_to_adem_on_basis};
+use algebra::module::homomorphism::{FreeModuleHomomorphism, ModuleHomomorphism};
+use algebra::module::{FreeModule, Module};
+use algebra::{Algebra, MilnorAlgebra, SteenrodAlgebra};
+use fp::matrix::{AugmentedMatrix, Matrix};
+use fp::prime::ValidPrime;
+use fp::vector::{FpVector, Slice};
+
+use crate::chain_complex::{
+    AugmentedChainComplex, ChainComplex, FiniteChainComplex, FreeChainComplex,
+};
+use crate::resolution_homomorphism::ResolutionHomomorphism;
+
+/// An algebra whose basis elements can be written in terms of the basis of `A`, where `A` is the
+/// same algebra with a possibly different basis.
+pub trait ConvertBasis<A: Algebra>: Algebra {
+    /// Add `coeff` times the `idx`th basis element in degree `degree` to `result`, which is an
+    /// element of `target` in degree `degree`.
+    fn convert_basis(&self, target: &A, result: &mut FpVector, coeff: u32, degree: i32, idx: usize);
+}
+
+impl ConvertBasis<SteenrodAlgebra> for SteenrodAlgebra {
+    fn convert_basis(
+        &self,
+        target: &SteenrodAlgebra,
+        result: &mut FpVector,
+        coeff: u32,
+        degree: i32,
+        idx: usize,
+    ) {
+        match (self, target) {
+            (SteenrodAlgebra::AdemAlgebra(_), SteenrodAlgebra::AdemAlgebra(_))
+            | (SteenrodAlgebra::MilnorAlgebra(_), SteenrodAlgebra::MilnorAlgebra(_)) => {
+                result.add_basis_element(idx, coeff)
+            }
+            (SteenrodAlgebra::AdemAlgebra(a), SteenrodAlgebra::MilnorAlgebra(m)) => {
+                adem_to_milnor_on_basis(a, m, result, coeff, degree, idx)
+            }
+            (SteenrodAlgebra::MilnorAlgebra(m), SteenrodAlgebra::AdemAlgebra(a)) => {
+                milnor_to_adem_on_basis(a, m, result, coeff, degree, idx)
+            }
+        }
+    }
+}
+
+impl ConvertBasis<SteenrodAlgebra> for MilnorAlgebra {
+    fn convert_basis(
+        &self,
+        target: &SteenrodAlgebra,
+        result: &mut FpVector,
+        coeff: u32,
+        degree: i32,
+        idx: usize,
+    ) {
+        match target {
+            SteenrodAlgebra::MilnorAlgebra(_) => result.add_basis_element(idx, coeff),
+            SteenrodAlgebra::AdemAlgebra(a) => {
+                milnor_to_adem_on_basis(a, self, result, coeff, degree, idx)
+            }
+        }
+    }
+}
+
+type Translated<A> = FiniteChainComplex<FreeModule<A>, FreeModuleHomomorphism<FreeModule<A>>>;
+
+/// The change of basis of $\Ext$ from a source resolution to a target resolution of the same
+/// module. See the [module level documentation](self) for details.
+pub struct ChangeOfBasis<CC: FreeChainComplex> {
+    hom: ResolutionHomomorphism<Translated<CC::Algebra>, CC>,
+    /// The exclusive upper bound of the internal degrees in which both resolutions have been
+    /// computed, indexed by `s`.
+    max_t: Vec<i32>,
+}
+
+impl<CC> ChangeOfBasis<CC>
+where
+    CC: FreeChainComplex + AugmentedChainComplex,
+{
+    /// Compare two resolutions of the same module $M$. The modules must have the same basis, e.g.
+    /// if they are constructed from the same module specification with different bases of the
+    /// Steenrod algebra.
+    ///
+    /// The comparison is computed in all bidegrees in which both resolutions have been computed.
+    pub fn new<CC1>(source: &CC1, target: Arc<CC>) -> Self
+    where
+        CC1: FreeChainComplex + AugmentedChainComplex,
+        CC1::Algebra: ConvertBasis<CC::Algebra>,
+    {
+        let source_module = source.target().module(0);
+        let target_module = target.target().module(0);
+        let chain_map = source.chain_map(0);
+        let p = source.prime();
+
+        Self::with_augmentation(source, target, |t, idx| {
+            let dim = source_module.dimension(t);
+            assert_eq!(
+                dim,
+                target_module.dimension(t),
+                "The modules have different dimensions in degree {t}"
+            );
+            let mut result = FpVector::new(p, dim);
+            let gen = source.module(0).operation_generator_to_index(0, 0, t, idx);
+            chain_map.apply_to_basis_element(result.as_slice_mut(), 1, t, gen);
+            result
+        })
+    }
+
+    /// Compare a resolution of the unit that is not augmented, e.g. one computed by
+    /// [Nassau's algorithm](crate::nassau), to a resolution of the unit. The generator of the
+    /// source in filtration $0$ is taken to be the class of the identity.
+    pub fn new_unit<CC1>(source: &CC1, target: Arc<CC>) -> Self
+    where
+        CC1: FreeChainComplex,
+        CC1::Algebra: ConvertBasis<CC::Algebra>,
+    {
+        assert!(target.target().module(0).is_unit());
+        let p = source.prime();
+        let min_degree = source.min_degree();
+
+        Self::with_augmentation(source, target, |t, idx| {
+            assert_eq!(
+                (t, idx),
+                (min_degree, 0),
+                "The source is not a resolution of the unit"
+            );
+            FpVector::from_slice(p, &[1])
+        })
+    }
+
+    /// The general constructor. The function `augmentation` returns the image in the module of the
+    /// `idx`th generator of the source in filtration $0$ and degree `t`.
+    fn with_augmentation<CC1>(
+        source: &CC1,
+        target: Arc<CC>,
+        augmentation: impl Fn(i32, usize) -> FpVector,
+    ) -> Self
+    where
+        CC1: FreeChainComplex,
+        CC1::Algebra: ConvertBasis<CC::Algebra>,
+    {
+        let p = source.prime();
+        let min_degree = source.min_degree();
+        assert_eq!(min_degree, target.min_degree());
+
+        let num_s = std::cmp::min(
+            source.next_homological_degree(),
+            target.next_homological_degree(),
+        );
+        let max_t: Vec<i32> = (0..num_s)
+            .map(|s| {
+                let mut t = min_degree;
+                while source.has_computed_bidegree(s, t) && target.has_computed_bidegree(s, t) {
+                    t += 1;
+                }
+                t
+            })
+            .collect();
+
+        let translated = Arc::new(translate(source, target.algebra(), &max_t));
+
+        let hom = ResolutionHomomorphism::new(String::new(), translated, target, 0, 0);
+        let module = hom.target.target().module(0);
+        for t in min_degree..max_t.first().copied().unwrap_or(min_degree) {
+            let num_gens = hom.source.number_of_gens_in_bidegree(0, t);
+            let mut images = Matrix::new(p, num_gens, module.dimension(t));
+            for (idx, row) in images.iter_mut().enumerate() {
+                row.assign(&augmentation(t, idx));
+            }
+            hom.extend_step(0, t, Some(&images));
+        }
+        if num_s > 1 {
+            hom.extend_profile(num_s, |s| max_t[s as usize]);
+        }
+
+        Self { hom, max_t }
+    }
+
+    pub fn prime(&self) -> ValidPrime {
+        self.hom.source.prime()
+    }
+
+    /// Whether the change of basis has been computed in bidegree `(s, t)`.
+    pub fn has_computed_bidegree(&self, s: u32, t: i32) -> bool {
+        self.max_t.get(s as usize).is_some_and(|&max_t| t < max_t)
+    }
+
+    /// The change of basis matrix in bidegree `(s, t)`. The `i`th row expresses the `i`th class of
+    /// the target in terms of the classes of the source.
+    pub fn matrix(&self, s: u32, t: i32) -> Matrix {
+        assert!(self.has_computed_bidegree(s, t));
+        let p = self.prime();
+        let dim = self.hom.target.number_of_gens_in_bidegree(s, t);
+        let mut matrix = Matrix::new(p, dim, dim);
+        for (row, entries) in matrix.iter_mut().zip(self.hom.get_map(s).hom_k(t)) {
+            for (j, v) in entries.into_iter().enumerate() {
+                row.set_entry(j, v);
+            }
+        }
+        matrix
+    }
+
+    /// The inverse of [`ChangeOfBasis::matrix`]. The `i`th row expresses the `i`th class of the
+    /// source in terms of the classes of the target.
+    pub fn inverse_matrix(&self, s: u32, t: i32) -> Matrix {
+        let matrix = self.matrix(s, t);
+        let dim = matrix.rows();
+
+        let mut augmented = AugmentedMatrix::<2>::new(self.prime(), dim, [dim, dim]);
+        augmented
+            .segment(0, 0)
+            .add_masked(&matrix, &(0..dim).collect::<Vec<_>>());
+        augmented.segment(1, 1).add_identity();
+        augmented.row_reduce();
+
+        let mut inverse = Matrix::new(self.prime(), dim, dim);
+        for (i, row) in inverse.iter_mut().enumerate() {
+            assert_eq!(augmented.pivots()[i], i as isize, "Not a change of basis");
+            row.as_slice_mut()
+                .assign(augmented.row_segment(i, 1, 1).as_slice());
+        }
+        inverse
+    }
+
+    /// Rewrite an element of bidegree `(s, t)` in the basis of the target in terms of the basis of
+    /// the source.
+    pub fn to_source(&self, s: u32, t: i32, v: Slice) -> FpVector {
+        apply(&self.matrix(s, t), v)
+    }
+
+    /// Rewrite an element of bidegree `(s, t)` in the basis of the source in terms of the basis of
+    /// the target.
+    pub fn to_target(&self, s: u32, t: i32, v: Slice) -> FpVector {
+        apply(&self.inverse_matrix(s, t), v)
+    }
+
+    /// Rewrite a linear map from bidegree `from` to bidegree `to`, such as a $d_2$ differential or
+    /// the multiplication by a fixed class, in terms of the bases of the source. The `i`th row of
+    /// `map` is the image of the `i`th class of the target in bidegree `from`, written in the
+    /// basis of the target.
+    pub fn map_to_source(&self, from: (u32, i32), to: (u32, i32), map: &Matrix) -> Matrix {
+        // The map in the basis of the source is P^{-1} M Q
+        let left = self.inverse_matrix(from.0, from.1);
+        let right = self.matrix(to.0, to.1);
+        compose(&compose(&left, map), &right)
+    }
+
+    /// The inverse of [`ChangeOfBasis::map_to_source`].
+    pub fn map_to_target(&self, from: (u32, i32), to: (u32, i32), map: &Matrix) -> Matrix {
+        let left = self.matrix(from.0, from.1);
+        let right = self.inverse_matrix(to.0, to.1);
+        compose(&compose(&left, map), &right)
+    }
+}
+
+/// Rewrite `source` as a chain complex over `algebra`, in the degrees below `max_t`. The modules
+/// are extended by zero by one degree so that every bidegree below `max_t` counts as computed.
+fn translate<CC, A>(source: &CC, algebra: Arc<A>, max_t: &[i32]) -> Translated<A>
+where
+    CC: FreeChainComplex,
+    CC::Algebra: ConvertBasis<A>,
+    A: Algebra,
+{
+    let p = source.prime();
+    let min_degree = source.min_degree();
+    let source_algebra = source.algebra();
+    let top = max_t.iter().copied().max().unwrap_or(min_degree);
+    algebra.compute_basis(top - min_degree);
+
+    let zero_module = Arc::new(FreeModule::new(
+        Arc::clone(&algebra),
+        String::from("0"),
+        min_degree,
+    ));
+    let modules: Vec<_> = (0..max_t.len())
+        .map(|s| {
+            Arc::new(FreeModule::new(
+                Arc::clone(&algebra),
+                format!("F{s}"),
+                min_degree,
+            ))
+        })
+        .collect();
+
+    for (s, module) in modules.iter().enumerate() {
+        for t in min_degree..max_t[s] {
+            module.add_generators(t, source.number_of_gens_in_bidegree(s as u32, t), None);
+        }
+        module.extend_by_zero(top);
+    }
+
+    let mut differentials = Vec::with_capacity(modules.len());
+    differentials.push(Arc::new(FreeModuleHomomorphism::new(
+        Arc::clone(&modules[0]),
+        Arc::clone(&zero_module),
+        0,
+    )));
+    for s in 1..modules.len() {
+        let d =
+            FreeModuleHomomorphism::new(Arc::clone(&modules[s]), Arc::clone(&modules[s - 1]), 0);
+        let source_d = source.differential(s as u32);
+        let source_target = source.module(s as u32 - 1);
+        let target = &modules[s - 1];
+        let mut op = FpVector::new(p, 0);
+
+        for t in min_degree..max_t[s] {
+            let rows = (0..modules[s].number_of_gens_in_degree(t))
+                .map(|idx| {
+                    let mut row = FpVector::new(p, target.dimension(t));
+                    for (i, c) in source_d.output(t, idx).iter_nonzero() {
+                        let op_gen = source_target.index_to_op_gen(t, i);
+                        op.set_scratch_vector_size(algebra.dimension(op_gen.operation_degree));
+                        source_algebra.convert_basis(
+                            &algebra,
+                            &mut op,
+                            c,
+                            op_gen.operation_degree,
+                            op_gen.operation_index,
+                        );
+                        for (k, v) in op.iter_nonzero() {
+                            row.add_basis_element(
+                                target.operation_generator_to_index(
+                                    op_gen.operation_degree,
+                                    k,
+                                    op_gen.generator_degree,
+                                    op_gen.generator_index,
+                                ),
+                                v,
+                            );
+                        }
+                    }
+                    row
+                })
+                .collect();
+            d.add_generators_from_rows(t, rows);
+        }
+        d.extend_by_zero(top);
+        differentials.push(Arc::new(d));
+    }
+    zero_module.extend_by_zero(top + 1);
+    for m in &modules {
+        m.extend_by_zero(top + 1);
+    }
+    for d in &differentials {
+        d.extend_by_zero(top + 1);
+    }
+
+    FiniteChainComplex {
+        modules,
+        zero_module,
+        differentials,
+    }
+}
+
+/// The product of a row vector with a matrix.
+fn apply(matrix: &Matrix, v: Slice) -> FpVector {
+    let mut result = FpVector::new(matrix.prime(), matrix.columns());
+    for (i, c) in v.iter_nonzero() {
+        result.add(&matrix[i], c);
+    }
+    result
+}
+
+fn compose(a: &Matrix, b: &Matrix) -> Matrix {
+    let mut result = Matrix::new(a.prime(), a.rows(), b.columns());
+    for (row, a_row) in result.iter_mut().zip(a.iter()) {
+        *row = apply(b, a_row.as_slice());
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::utils::construct;
+
+    #[test]
+    fn adem_milnor() {
+        // The operations of degree 1 and 2 at p = 2, and of degree 1 and 4 at p = 3, are unique up
+        // to a scalar in both bases, so the products by h_0, h_1 and a_0, h_0 are compatible.
+        for (module, max_n, ops) in [("S_2", 20, [1, 2]), ("C2", 15, [1, 2]), ("S_3", 30, [1, 4])] {
+            let milnor = construct((module, "milnor"), None).unwrap();
+            let adem = Arc::new(construct((module, "adem"), None).unwrap());
+            milnor.compute_through_stem(6, max_n);
+            adem.compute_through_stem(6, max_n);
+
+            let change = ChangeOfBasis::new(&milnor, Arc::clone(&adem));
+            let p = change.prime();
+            let mut checked = 0;
+            for (s, _, t) in adem.iter_stem() {
+                for op_deg in ops {
+                    if !change.has_computed_bidegree(s + 1, t + op_deg) {
+                        continue;
+                    }
+                    let Some(product) = adem.filtration_one_product(op_deg, 0, s + 1, t + op_deg)
+                    else {
+                        continue;
+                    };
+                    let milnor_product = milnor
+                        .filtration_one_product(op_deg, 0, s + 1, t + op_deg)
+                        .unwrap();
+                    let product = Matrix::from_vec(p, &product);
+                    let milnor_product = Matrix::from_vec(p, &milnor_product);
+                    if product.rows() == 0 || product.columns() == 0 {
+                        continue;
+                    }
+                    assert_eq!(
+                        change
+                            .map_to_source((s, t), (s + 1, t + op_deg), &product)
+                            .to_vec(),
+                        milnor_product.to_vec(),
+                        "{module} at (s, t) = ({s}, {t}) with op_deg = {op_deg}"
+                    );
+                    checked += 1;
+                }
+            }
+            assert!(checked > 0);
+        }
+    }
+
+    #[test]
+    fn nassau() {
+        let nassau = crate::nassau::Resolution::new();
+        nassau.compute_through_stem(6, 20);
+        let milnor = Arc::new(construct("S_2@milnor", None).unwrap());
+        milnor.compute_through_stem(6, 20);
+
+        let change = ChangeOfBasis::new_unit(&nassau, Arc::clone(&milnor));
+        assert!(change.has_computed_bidegree(6, 26));
+
+        // The products by h_0 in the two bases are compatible.
+        let p = change.prime();
+        for (s, _, t) in milnor.iter_stem() {
+            if !change.has_computed_bidegree(s + 1, t + 1) {
+                continue;
+            }
+            let Some(product) = milnor.filtration_one_product(1, 0, s + 1, t + 1) else {
+                continue;
+            };
+            let Some(nassau_product) = nassau.filtration_one_product(1, 0, s + 1, t + 1) else {
+                continue;
+            };
+            let product = Matrix::from_vec(p, &product);
+            let nassau_product = Matrix::from_vec(p, &nassau_product);
+            if product.rows() == 0 || product.columns() == 0 {
+                continue;
+            }
+            assert_eq!(
+                change
+                    .map_to_source((s, t), (s + 1, t + 1), &product)
+                    .to_vec(),
+                nassau_product.to_vec(),
+                "(s, t) = ({s}, {t})"
+            );
+        }
+    }
+}
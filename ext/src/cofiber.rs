@@ -0,0 +1,553 @@
+//! Cofibers of maps of spectra, computed in cohomology.
+//!
+//! A map of spectra $g\colon Y \to X$ induces a map $f = g^*\colon H^* X \to H^* Y$, and the
+//! cohomology of the cofiber $Cg$ is an extension
+//! $$ 0 \to \Sigma \coker f \to H^* Cg \to \ker f \to 0. $$
+//! This is determined by $f$ when $f$ is surjective, in which case $H^* Cg = \ker f$, or when $f$
+//! is injective, in which case $H^* Cg = \Sigma \coker f$. [`cofiber`] computes $H^* Cg$ in these
+//! two cases.
+//!
+//! A class in $\Ext^{0, t}(H^* X, \F_p)$ is a homomorphism $H^* X \to \Sigma^t \F_p$, which is the
+//! map induced in cohomology by a map $S^t \to X$ if it is realized by one. [`cofiber_of_class`]
+//! computes the cofiber of such a map. For example, the cofiber of the bottom cell of the Joker is the Joker
+//! with the bottom cell removed.
+//!
+//! In either case, we get a short exact sequence of modules $0 \to A \to B \to Q \to 0$, and hence
+//! a long exact sequence
+//! $$ \cdots \to \Ext^{s, t}(Q) \to \Ext^{s, t}(B) \to \Ext^{s, t}(A) \to \Ext^{s + 1, t}(Q) \to
+//! \cdots. $$
+//! If $f$ is surjective, this is the sequence $0 \to H^* Cg \to H^* X \to H^* Y \to 0$, so $Q$ is
+//! the shifted source $\Sigma^t \F_p$ in the case of a class. If $f$ is injective, this is the
+//! sequence $0 \to H^* X \to H^* Y \to \Sigma^{-1} H^* Cg \to 0$. The [`CofiberSequence`] contains
+//! the resolutions of the three modules and chain maps inducing the maps in this sequence.
+
+use std::sync::Arc;
+
+use algebra::module::homomorphism::{
+    BoundedModuleHomomorphism, FiniteModuleHomomorphism, FreeModuleHomomorphism, ModuleHomomorphism,
+};
+use algebra::module::{BoundedModule, FDModule, FiniteModule, Module, QuotientModule};
+use algebra::{Algebra, SteenrodAlgebra};
+use anyhow::anyhow;
+use bivec::BiVec;
+use fp::matrix::{Matrix, Subspace};
+use fp::vector::FpVector;
+
+use crate::chain_complex::{
+    AugmentedChainComplex, ChainComplex, FiniteChainComplex, FreeChainComplex,
+};
+use crate::resolution::Resolution;
+use crate::resolution_homomorphism::ResolutionHomomorphism;
+use crate::CCC;
+
+type ChainMap = ResolutionHomomorphism<Resolution<CCC>, Resolution<CCC>>;
+
+/// The cofiber of a map together with the long exact sequence in $\Ext$. See the [module level
+/// documentation](self) for the notation.
+pub struct CofiberSequence {
+    /// The cohomology of the cofiber.
+    pub cofiber: FDModule<SteenrodAlgebra>,
+    /// The resolution of the submodule $A$.
+    pub sub: Arc<Resolution<CCC>>,
+    /// The resolution of the module $B$.
+    pub middle: Arc<Resolution<CCC>>,
+    /// The resolution of the quotient $Q = B/A$.
+    pub quotient: Arc<Resolution<CCC>>,
+    /// The lift of the inclusion $A \to B$, which induces $\Ext(B) \to \Ext(A)$.
+    pub inclusion: ChainMap,
+    /// The lift of the projection $B \to Q$, which induces $\Ext(Q) \to \Ext(B)$.
+    pub projection: ChainMap,
+    /// The chain map $R_{s + 1}(Q) \to R_s(A)$ representing the extension, which induces the
+    /// connecting homomorphism $\Ext^{s, t}(A) \to \Ext^{s + 1, t}(Q)$.
+    pub connecting: ChainMap,
+}
+
+/// Compute the cofiber of the map of spectra that induces `f` in cohomology. This fails if `f` is
+/// neither injective nor surjective, since the cofiber is then not determined by `f`.
+///
+/// The modules must be bounded and `f` must have degree zero.
+pub fn cofiber(f: &FiniteModuleHomomorphism<FiniteModule>) -> anyhow::Result<CofiberSequence> {
+    if f.degree_shift() != 0 {
+        return Err(anyhow!("The homomorphism must have degree zero"));
+    }
+    let source = to_fd_module(&f.source())?;
+    let target = to_fd_module(&f.target())?;
+    let min_degree = std::cmp::min(source.min_degree(), target.min_degree());
+    let max_degree = std::cmp::max(source.max_degree(), target.max_degree());
+
+    let mut images = BiVec::with_capacity(min_degree, max_degree + 1);
+    let mut kernels = BiVec::with_capacity(min_degree, max_degree + 1);
+    let mut matrices = BiVec::with_capacity(source.min_degree(), source.max_degree() + 1);
+    for t in min_degree..=max_degree {
+        let (image, kernel, _) = f.auxiliary_data(t);
+        images.push(image);
+        kernels.push(kernel);
+        if t >= source.min_degree() && t <= source.max_degree() {
+            let mut matrix = Matrix::new(f.prime(), source.dimension(t), target.dimension(t));
+            f.get_matrix(matrix.as_slice_mut(), t);
+            matrices.push(matrix);
+        }
+    }
+
+    if images
+        .iter_enum()
+        .all(|(t, image)| image.dimension() == target.dimension(t))
+    {
+        let (sub, inclusion) = submodule(&source, &kernels);
+        Ok(CofiberSequence::new(
+            sub.clone(),
+            [sub, source, target],
+            inclusion,
+            matrices,
+        ))
+    } else if kernels.iter().all(|kernel| kernel.dimension() == 0) {
+        let (quotient, projection) = quotient(&target, &images);
+        Ok(CofiberSequence::new(
+            shift(&quotient, 1),
+            [source, target, quotient],
+            matrices,
+            projection,
+        ))
+    } else {
+        Err(anyhow!(
+            "The homomorphism is neither injective nor surjective, so the cofiber is not \
+             determined by the map on cohomology"
+        ))
+    }
+}
+
+/// Compute the cofiber of the map $S^t \to X$ detected by the class in $\Ext^{0, t}(H^* X, \F_p)$
+/// whose coordinates are `class`, where `resolution` is a resolution of $H^* X$.
+pub fn cofiber_of_class(
+    resolution: &Resolution<CCC>,
+    t: i32,
+    class: &[u32],
+) -> anyhow::Result<CofiberSequence> {
+    let p = resolution.prime();
+    let module = resolution.target().module(0);
+    resolution.compute_through_bidegree(0, t);
+
+    let num_gens = resolution.number_of_gens_in_bidegree(0, t);
+    assert_eq!(num_gens, class.len());
+    if class.iter().all(|&c| c % *p == 0) {
+        return Err(anyhow!("The class must be non-zero"));
+    }
+
+    // The homomorphism $H^* X \to \Sigma^t \F_p$ sends an element to the coefficient of the class
+    // in its preimage under the augmentation. Decomposable elements are sent to zero.
+    let chain_map = resolution.chain_map(0);
+    chain_map.compute_auxiliary_data_through_degree(t);
+    let free = resolution.module(0);
+    let mut functional = Matrix::new(p, module.dimension(t), 1);
+    let mut input = FpVector::new(p, module.dimension(t));
+    let mut preimage = FpVector::new(p, free.dimension(t));
+    for (idx, row) in functional.iter_mut().enumerate() {
+        input.set_entry(idx, 1);
+        assert!(chain_map.apply_quasi_inverse(preimage.as_slice_mut(), t, input.as_slice()));
+        let value = class.iter().enumerate().fold(0, |acc, (i, &c)| {
+            acc + c * preimage.entry(free.operation_generator_to_index(0, 0, t, i))
+        });
+        row.set_entry(0, value % *p);
+        input.set_to_zero();
+        preimage.set_to_zero();
+    }
+
+    let mut sphere = FDModule::new(
+        module.algebra(),
+        format!("S_{p}[{t}]"),
+        BiVec::from_vec(t, vec![1]),
+    );
+    sphere.set_basis_element_name(t, 0, format!("x{t}"));
+
+    let mut matrices = BiVec::with_capacity(module.min_degree(), module.max_degree() + 1);
+    for t_ in module.min_degree()..=module.max_degree() {
+        if t_ == t {
+            matrices.push(functional.clone());
+        } else {
+            matrices.push(Matrix::new(p, module.dimension(t_), sphere.dimension(t_)));
+        }
+    }
+    let f = BoundedModuleHomomorphism::from_matrices(
+        module,
+        Arc::new(FiniteModule::from(sphere)),
+        0,
+        matrices,
+    );
+    cofiber(&f.into())
+}
+
+impl CofiberSequence {
+    /// Set up the long exact sequence of the short exact sequence $0 \to A \to B \to Q \to 0$,
+    /// where `modules` is $[A, B, Q]$ and the maps are given by their matrices in each degree.
+    fn new(
+        cofiber: FDModule<SteenrodAlgebra>,
+        modules: [FDModule<SteenrodAlgebra>; 3],
+        inclusion: BiVec<Matrix>,
+        projection: BiVec<Matrix>,
+    ) -> Self {
+        let p = cofiber.prime();
+        let [a, b, q] = modules.map(|m| Arc::new(FiniteModule::from(m)));
+        let [sub, middle, quotient] = [&a, &b, &q].map(|m| {
+            Arc::new(Resolution::new(Arc::new(FiniteChainComplex::ccdz(
+                Arc::clone(m),
+            ))))
+        });
+
+        let i = FiniteModuleHomomorphism::from(BoundedModuleHomomorphism::from_matrices(
+            Arc::clone(&a),
+            Arc::clone(&b),
+            0,
+            inclusion,
+        ));
+        let pi = FiniteModuleHomomorphism::from(BoundedModuleHomomorphism::from_matrices(
+            Arc::clone(&b),
+            Arc::clone(&q),
+            0,
+            projection,
+        ));
+
+        let inclusion = ResolutionHomomorphism::from_module_homomorphism(
+            String::new(),
+            Arc::clone(&sub),
+            Arc::clone(&middle),
+            &i,
+        );
+        let projection = ResolutionHomomorphism::from_module_homomorphism(
+            String::new(),
+            Arc::clone(&middle),
+            Arc::clone(&quotient),
+            &pi,
+        );
+
+        // The extension class is the composite $R_1(Q) \to R_0(Q) \to B$, where the second map
+        // lifts the augmentation of $R_0(Q)$ along the projection. This lands in $A$.
+        let max_degree = a.max_degree();
+        sub.compute_through_bidegree(0, max_degree);
+        quotient.compute_through_bidegree(1, max_degree);
+
+        let augmentation = quotient.chain_map(0);
+        let lift = FreeModuleHomomorphism::new(quotient.module(0), Arc::clone(&b), 0);
+        for t in quotient.module(0).min_degree()..=max_degree {
+            let num_gens = quotient.number_of_gens_in_bidegree(0, t);
+            let mut matrix = Matrix::new(p, num_gens, b.dimension(t));
+            if num_gens > 0 && b.dimension(t) > 0 {
+                let (_, _, qi) = pi.auxiliary_data(t);
+                for (j, row) in matrix.iter_mut().enumerate() {
+                    qi.apply(row.as_slice_mut(), 1, augmentation.output(t, j).as_slice());
+                }
+            }
+            lift.add_generators_from_matrix_rows(t, matrix.as_slice_mut());
+        }
+
+        let connecting = ResolutionHomomorphism::new(
+            String::new(),
+            Arc::clone(&quotient),
+            Arc::clone(&sub),
+            1,
+            0,
+        );
+        let d = quotient.differential(1);
+        for t in a.min_degree()..=max_degree {
+            let num_gens = quotient.number_of_gens_in_bidegree(1, t);
+            if num_gens == 0 || a.dimension(t) == 0 {
+                connecting.extend_step(1, t, None);
+                continue;
+            }
+            let (_, _, qi) = i.auxiliary_data(t);
+            let mut matrix = Matrix::new(p, num_gens, a.dimension(t));
+            let mut image = FpVector::new(p, b.dimension(t));
+            for (j, row) in matrix.iter_mut().enumerate() {
+                lift.apply(image.as_slice_mut(), 1, t, d.output(t, j).as_slice());
+                qi.apply(row.as_slice_mut(), 1, image.as_slice());
+                image.set_to_zero();
+            }
+            connecting.extend_step(1, t, Some(&matrix));
+        }
+
+        Self {
+            cofiber,
+            sub,
+            middle,
+            quotient,
+            inclusion,
+            projection,
+            connecting,
+        }
+    }
+
+    /// Compute the resolutions and the chain maps through the bidegree `(max_s, max_t)`. The maps
+    /// in the long exact sequence can then be computed for $s < \mathrm{max\_s}$ and $t \leq
+    /// \mathrm{max\_t}$.
+    pub fn compute_through_bidegree(&self, max_s: u32, max_t: i32) {
+        for resolution in [&self.sub, &self.middle, &self.quotient] {
+            resolution.compute_through_bidegree(max_s, max_t);
+        }
+        self.inclusion.extend(max_s, max_t);
+        self.projection.extend(max_s, max_t);
+        self.connecting.extend(max_s, max_t);
+    }
+
+    /// The maps $\Ext^{s, t}(Q) \to \Ext^{s, t}(B) \to \Ext^{s, t}(A) \to \Ext^{s + 1, t}(Q)$ in the
+    /// long exact sequence, in the basis given by the generators of the resolutions. The rows of
+    /// each matrix are the images of the basis elements.
+    pub fn maps(&self, s: u32, t: i32) -> [Matrix; 3] {
+        let induced = |map: &ChainMap, source: &Resolution<CCC>, target: &Resolution<CCC>| {
+            let mut matrix = Matrix::new(
+                self.cofiber.prime(),
+                target.number_of_gens_in_bidegree(s, t),
+                source.number_of_gens_in_bidegree(s + map.shift_s, t),
+            );
+            for (idx, row) in matrix.iter_mut().enumerate() {
+                map.act(row.as_slice_mut(), 1, s, t, idx);
+            }
+            matrix
+        };
+        [
+            induced(&self.projection, &self.middle, &self.quotient),
+            induced(&self.inclusion, &self.sub, &self.middle),
+            induced(&self.connecting, &self.quotient, &self.sub),
+        ]
+    }
+}
+
+fn to_fd_module(module: &FiniteModule) -> anyhow::Result<FDModule<SteenrodAlgebra>> {
+    if module.max_degree() == i32::MAX {
+        return Err(anyhow!("{} is not bounded", module));
+    }
+    Ok(module.to_fd_module())
+}
+
+/// The submodule of `module` spanned by `subspaces`, together with the matrices of the inclusion.
+/// The basis elements are named after the basis elements of `module` at the pivots.
+fn submodule(
+    module: &FDModule<SteenrodAlgebra>,
+    subspaces: &BiVec<Subspace>,
+) -> (FDModule<SteenrodAlgebra>, BiVec<Matrix>) {
+    let p = module.prime();
+    let algebra = module.algebra();
+    let min_degree = subspaces.min_degree();
+    let max_degree = subspaces.max_degree();
+
+    let mut graded_dimension = BiVec::with_capacity(min_degree, max_degree + 1);
+    let mut inclusion = BiVec::with_capacity(min_degree, max_degree + 1);
+    for (t, subspace) in subspaces.iter_enum() {
+        graded_dimension.push(subspace.dimension());
+        inclusion.push(Matrix::from_rows(
+            p,
+            subspace.basis().to_vec(),
+            module.dimension(t),
+        ));
+    }
+    let mut result = FDModule::new(
+        Arc::clone(&algebra),
+        format!("Submodule of {}", module),
+        graded_dimension,
+    );
+    for (t, subspace) in subspaces.iter_enum() {
+        for (col, &row) in subspace.pivots().iter().enumerate() {
+            if row >= 0 {
+                result.set_basis_element_name(
+                    t,
+                    row as usize,
+                    module.basis_element_to_string(t, col),
+                );
+            }
+        }
+    }
+
+    for input_degree in min_degree..=max_degree {
+        for output_degree in input_degree + 1..=max_degree {
+            let subspace = &subspaces[output_degree];
+            if subspace.dimension() == 0 {
+                continue;
+            }
+            let op_degree = output_degree - input_degree;
+            let mut output = FpVector::new(p, module.dimension(output_degree));
+            for op_idx in 0..algebra.dimension(op_degree) {
+                for (idx, v) in subspaces[input_degree].basis().iter().enumerate() {
+                    module.act(
+                        output.as_slice_mut(),
+                        1,
+                        op_degree,
+                        op_idx,
+                        input_degree,
+                        v.as_slice(),
+                    );
+                    let mut coordinates = FpVector::new(p, subspace.dimension());
+                    for (col, &row) in subspace.pivots().iter().enumerate() {
+                        if row >= 0 {
+                            coordinates.set_entry(row as usize, output.entry(col));
+                        }
+                    }
+                    result.set_action_vector(op_degree, op_idx, input_degree, idx, &coordinates);
+                    output.set_to_zero();
+                }
+            }
+        }
+    }
+    (result, inclusion)
+}
+
+/// The quotient of `module` by `subspaces`, together with the matrices of the projection.
+fn quotient(
+    module: &FDModule<SteenrodAlgebra>,
+    subspaces: &BiVec<Subspace>,
+) -> (FDModule<SteenrodAlgebra>, BiVec<Matrix>) {
+    let p = module.prime();
+    let mut quotient = QuotientModule::new(Arc::new(module.clone()));
+    quotient.compute_basis(module.max_degree());
+    for t in module.min_degree()..=module.max_degree() {
+        quotient.quotient_vectors(t, subspaces[t].basis().to_vec());
+    }
+
+    let mut projection = BiVec::with_capacity(module.min_degree(), module.max_degree() + 1);
+    for t in module.min_degree()..=module.max_degree() {
+        let mut matrix = Matrix::new(p, module.dimension(t), quotient.dimension(t));
+        let mut v = FpVector::new(p, module.dimension(t));
+        for (idx, row) in matrix.iter_mut().enumerate() {
+            v.set_entry(idx, 1);
+            quotient.reduce(t, v.as_slice_mut());
+            quotient.old_basis_to_new(t, row.as_slice_mut(), v.as_slice());
+            v.set_to_zero();
+        }
+        projection.push(matrix);
+    }
+    (quotient.to_fd_module(), projection)
+}
+
+/// The module `module` shifted up by `shift`.
+fn shift(module: &FDModule<SteenrodAlgebra>, shift: i32) -> FDModule<SteenrodAlgebra> {
+    let algebra = module.algebra();
+    let min_degree = module.min_degree();
+    let max_degree = module.max_degree();
+
+    let mut graded_dimension = BiVec::with_capacity(min_degree + shift, max_degree + shift + 1);
+    for t in min_degree..=max_degree {
+        graded_dimension.push(module.dimension(t));
+    }
+    let mut result = FDModule::new(Arc::clone(&algebra), module.to_string(), graded_dimension);
+    for t in min_degree..=max_degree {
+        for idx in 0..module.dimension(t) {
+            result.set_basis_element_name(t + shift, idx, module.basis_element_to_string(t, idx));
+        }
+    }
+    for input_degree in min_degree..=max_degree {
+        for output_degree in input_degree + 1..=max_degree {
+            if module.dimension(output_degree) == 0 {
+                continue;
+            }
+            let op_degree = output_degree - input_degree;
+            for op_idx in 0..algebra.dimension(op_degree) {
+                for idx in 0..module.dimension(input_degree) {
+                    let output = module.action(op_degree, op_idx, input_degree, idx);
+                    result.set_action_vector(op_degree, op_idx, input_degree + shift, idx, output);
+                }
+            }
+        }
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::utils::construct;
+
+    /// Check that the long exact sequence is exact at the terms $\Ext^{s, t}(B)$, $\Ext^{s,
+    /// t}(A)$ and $\Ext^{s + 1, t}(Q)$.
+    fn check_exact(sequence: &CofiberSequence, max_s: u32, max_t: i32) {
+        let rank = |m: &Matrix| {
+            let mut m = m.clone();
+            m.row_reduce()
+        };
+        let check = |f: &Matrix, g: &Matrix, name: &str| {
+            for row in f.iter() {
+                let mut result = FpVector::new(g.prime(), g.columns());
+                g.apply(result.as_slice_mut(), 1, row.as_slice());
+                assert!(result.is_zero(), "{name}: composite is non-zero");
+            }
+            assert_eq!(rank(f) + rank(g), g.rows(), "{name}: not exact");
+        };
+
+        sequence.compute_through_bidegree(max_s + 1, max_t);
+        for s in 0..max_s {
+            for t in s as i32..=max_t {
+                let [p, i, d] = sequence.maps(s, t);
+                let [p_next, _, _] = sequence.maps(s + 1, t);
+                check(&p, &i, &format!("Ext^({s}, {t})(B)"));
+                check(&i, &d, &format!("Ext^({s}, {t})(A)"));
+                check(&d, &p_next, &format!("Ext^({}, {t})(Q)", s + 1));
+            }
+        }
+    }
+
+    #[test]
+    fn ceta_bottom_cell() {
+        let resolution = construct("Ceta", None).unwrap();
+        let sequence = cofiber_of_class(&resolution, 0, &[1]).unwrap();
+        assert_eq!(sequence.cofiber.min_degree(), 0);
+        assert_eq!(sequence.cofiber.max_degree(), 2);
+        assert_eq!(sequence.cofiber.dimension(0), 0);
+        assert_eq!(sequence.cofiber.dimension(2), 1);
+        check_exact(&sequence, 4, 12);
+    }
+
+    #[test]
+    fn joker_bottom_cell() {
+        let resolution = construct("Joker", None).unwrap();
+        let sequence = cofiber_of_class(&resolution, 0, &[1]).unwrap();
+        let dimensions: Vec<usize> = (0..=4).map(|t| sequence.cofiber.dimension(t)).collect();
+        assert_eq!(dimensions, [0, 1, 1, 1, 1]);
+        check_exact(&sequence, 3, 12);
+    }
+
+    #[test]
+    fn ceta_pinch() {
+        // The pinch map Cη → S^2 is the inclusion of the top cell in cohomology, and its cofiber
+        // is S^1.
+        let resolution = construct("Ceta", None).unwrap();
+        let module = resolution.target().module(0);
+        let p = module.prime();
+
+        let mut sphere = FDModule::new(
+            module.algebra(),
+            "S_2[2]".into(),
+            BiVec::from_vec(2, vec![1]),
+        );
+        sphere.set_basis_element_name(2, 0, "x2".into());
+        let mut matrices = BiVec::new(2);
+        matrices.push(Matrix::from_vec(p, &[vec![1]]));
+        let f = BoundedModuleHomomorphism::from_matrices(
+            Arc::new(FiniteModule::from(sphere)),
+            module,
+            0,
+            matrices,
+        );
+
+        let sequence = cofiber(&f.into()).unwrap();
+        let dimensions: Vec<usize> = (0..=3).map(|t| sequence.cofiber.dimension(t)).collect();
+        assert_eq!(dimensions, [0, 1, 0, 0]);
+        check_exact(&sequence, 4, 12);
+    }
+
+    #[test]
+    fn not_determined() {
+        // The zero map S^1 → S^0 has cofiber S^0 ∨ S^2 or Cη, depending on the map.
+        let resolution = construct("S_2", None).unwrap();
+        let module = resolution.target().module(0);
+        let mut sphere = FDModule::new(
+            module.algebra(),
+            "S_2[1]".into(),
+            BiVec::from_vec(1, vec![1]),
+        );
+        sphere.set_basis_element_name(1, 0, "x1".into());
+        let f = BoundedModuleHomomorphism::from_matrices(
+            module,
+            Arc::new(FiniteModule::from(sphere)),
+            0,
+            BiVec::from_vec(0, vec![Matrix::new(fp::prime::ValidPrime::new(2), 1, 0)]),
+        );
+        assert!(cofiber(&f.into()).is_err());
+    }
+}
@@ -0,0 +1,221 @@
+//! The Curtis algorithm for computing the unstable Adams $E_2$ page of spheres.
+//!
+//! The homology of the subcomplex $\Lambda(n)$ of the [`LambdaAlgebra`] is the $E_2$ page of the
+//! unstable Adams spectral sequence of $S^n$, and $\Lambda(n) \subseteq \Lambda(n + 1)$. We order
+//! the admissible monomials of each bidegree lexicographically, which refines the filtration by
+//! first index, and row reduce the differential as in the computation of persistent homology. The
+//! leading term of a chain is its lexicographically largest term. Every admissible monomial then
+//! receives a [`Tag`]:
+//!
+//!  - it is the leading term of a chain whose boundary has leading term some other monomial, or
+//!  - it is the leading term of a cycle, which is either the boundary of such a chain, or is never
+//!    a boundary.
+//!
+//! A cycle with leading term $\lambda_{i_1} \cdots \lambda_{i_s}$ is born on $S^{i_1 + 1}$. If it
+//! is killed by a chain with leading term $\lambda_{j_1} \cdots \lambda_{j_{s - 1}}$, it dies on
+//! $S^{j_1 + 1}$. Otherwise it survives to the stable $\Ext$. The leading terms of the cycles
+//! alive on $S^n$ form a basis of $\Ext^{s, t}$ of $S^n$, and the cycles themselves are
+//! representatives in $\Lambda$ that can be compared with the literature.
+//!
+//! This is the classical Curtis table. It is only available at the prime 2.
+
+use std::sync::Arc;
+
+use algebra::{Algebra, LambdaAlgebra};
+use fp::prime::ValidPrime;
+use fp::vector::FpVector;
+use rustc_hash::FxHashMap as HashMap;
+
+/// The role of an admissible monomial in the Curtis table.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Tag {
+    /// The leading term of a cycle that is never a boundary. It represents a class in the stable
+    /// $\Ext$.
+    Stable,
+    /// The leading term of a cycle that is the boundary of the chain whose leading term is the
+    /// given basis element.
+    KilledBy(usize),
+    /// The leading term of a chain whose boundary has leading term the given basis element.
+    Kills(usize),
+}
+
+/// The Curtis table of the Lambda algebra through a fixed internal degree.
+pub struct CurtisTable {
+    algebra: Arc<LambdaAlgebra>,
+    /// t -> idx -> tag
+    tags: Vec<Vec<Tag>>,
+    /// t -> idx -> cycle whose leading term is idx
+    cycles: Vec<HashMap<usize, FpVector>>,
+}
+
+impl CurtisTable {
+    /// Compute the Curtis table for all bidegrees with $t \leq$ `max_t`.
+    pub fn new(max_t: i32) -> Self {
+        let p = ValidPrime::new(2);
+        let algebra = Arc::new(LambdaAlgebra::new(p));
+        algebra.compute_basis(max_t);
+
+        let mut tags = Vec::with_capacity(max_t as usize + 1);
+        let mut cycles = Vec::with_capacity(max_t as usize + 1);
+        for t in 0..=max_t {
+            let (t_tags, t_cycles) = Self::reduce(&algebra, t);
+            tags.push(t_tags);
+            cycles.push(t_cycles);
+        }
+
+        Self {
+            algebra,
+            tags,
+            cycles,
+        }
+    }
+
+    /// Row reduce the differentials in degree `t`. The basis is sorted by length, and the
+    /// differential increases length, so every pivot is processed as a column after the columns
+    /// that hit it.
+    fn reduce(algebra: &LambdaAlgebra, t: i32) -> (Vec<Tag>, HashMap<usize, FpVector>) {
+        let p = algebra.prime();
+        let dim = algebra.dimension(t);
+
+        let mut tags = Vec::with_capacity(dim);
+        let mut cycles = HashMap::default();
+        let mut killed_by: HashMap<usize, usize> = HashMap::default();
+        // leading term of boundary -> (boundary, chain)
+        let mut pivots: HashMap<usize, (FpVector, FpVector)> = HashMap::default();
+
+        for idx in 0..dim {
+            let mut boundary = FpVector::new(p, dim);
+            algebra.differential(boundary.as_slice_mut(), 1, t, idx);
+            let mut chain = FpVector::new(p, dim);
+            chain.set_entry(idx, 1);
+
+            loop {
+                let leading = match boundary.iter_nonzero().last() {
+                    Some((leading, _)) => leading,
+                    None => {
+                        tags.push(match killed_by.get(&idx) {
+                            Some(&killer) => Tag::KilledBy(killer),
+                            None => Tag::Stable,
+                        });
+                        cycles.insert(idx, chain);
+                        break;
+                    }
+                };
+                match pivots.get(&leading) {
+                    Some((b, c)) => {
+                        boundary.add(b, 1);
+                        chain.add(c, 1);
+                    }
+                    None => {
+                        tags.push(Tag::Kills(leading));
+                        killed_by.insert(leading, idx);
+                        pivots.insert(leading, (boundary, chain));
+                        break;
+                    }
+                }
+            }
+        }
+        (tags, cycles)
+    }
+
+    pub fn algebra(&self) -> Arc<LambdaAlgebra> {
+        Arc::clone(&self.algebra)
+    }
+
+    pub fn max_t(&self) -> i32 {
+        self.tags.len() as i32 - 1
+    }
+
+    /// The tag of the `idx`th basis element of $\Lambda$ in degree `t`.
+    pub fn tag(&self, t: i32, idx: usize) -> &Tag {
+        &self.tags[t as usize][idx]
+    }
+
+    /// The cycle whose leading term is the `idx`th basis element in degree `t`, if there is one.
+    pub fn cycle(&self, t: i32, idx: usize) -> Option<&FpVector> {
+        self.cycles[t as usize].get(&idx)
+    }
+
+    /// The range of spheres $S^n$ on which the cycle with leading term `idx` represents a nonzero
+    /// class. The end is `None` if the class survives to the stable $\Ext$. This returns `None` if
+    /// `idx` is not the leading term of a cycle, or if the cycle is a boundary on every sphere on
+    /// which it is defined.
+    pub fn lifetime(&self, t: i32, idx: usize) -> Option<(u32, Option<u32>)> {
+        let birth = self.algebra.basis_element_from_index(t, idx).sphere();
+        match *self.tag(t, idx) {
+            Tag::Stable => Some((birth, None)),
+            Tag::KilledBy(killer) => {
+                let death = self.algebra.basis_element_from_index(t, killer).sphere();
+                (birth < death).then_some((birth, Some(death)))
+            }
+            Tag::Kills(_) => None,
+        }
+    }
+
+    /// The leading terms of a basis of $\Ext^{s, t}$ of $S^n$.
+    pub fn unstable_ext(&self, n: u32, s: u32, t: i32) -> Vec<usize> {
+        self.algebra
+            .length_range(t, s)
+            .filter(|&idx| match self.lifetime(t, idx) {
+                Some((birth, death)) => birth <= n && death.is_none_or(|death| n < death),
+                None => false,
+            })
+            .collect()
+    }
+
+    /// The leading terms of a basis of the stable $\Ext^{s, t}$.
+    pub fn stable_ext(&self, s: u32, t: i32) -> Vec<usize> {
+        self.algebra
+            .length_range(t, s)
+            .filter(|&idx| *self.tag(t, idx) == Tag::Stable)
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::chain_complex::{ChainComplex, FreeChainComplex};
+    use crate::utils::construct;
+
+    #[test]
+    fn stable() {
+        let table = CurtisTable::new(14);
+        let resolution = construct("S_2", None).unwrap();
+        resolution.compute_through_bidegree(8, 14);
+
+        for t in 0..=14 {
+            for s in 0..=8 {
+                assert_eq!(
+                    table.stable_ext(s, t).len(),
+                    resolution.number_of_gens_in_bidegree(s, t),
+                    "Ext^({s}, {t})"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn unstable() {
+        let table = CurtisTable::new(6);
+        let algebra = table.algebra();
+
+        let h1 = algebra.basis_element_to_string(2, table.stable_ext(1, 2)[0]);
+        assert_eq!(h1, "λ1");
+
+        // h0 h1 is nonzero on S^2, since π_3(S^2) = Z, but is zero on S^3.
+        let h0h1 = table.unstable_ext(2, 2, 3);
+        assert_eq!(h0h1.len(), 1);
+        assert_eq!(algebra.basis_element_to_string(3, h0h1[0]), "λ1 λ0");
+        assert_eq!(table.lifetime(3, h0h1[0]), Some((2, Some(3))));
+        assert!(table.unstable_ext(3, 2, 3).is_empty());
+
+        // Ext of S^1 is concentrated in stem 0.
+        for t in 1..=6 {
+            for s in 0..t as u32 {
+                assert!(table.unstable_ext(1, s, t).is_empty());
+            }
+        }
+    }
+}
@@ -0,0 +1,366 @@
+//! Ext groups $\Ext_A^{s, t}(M, N)$ with non-trivial coefficients.
+//!
+//! Given a free resolution $R_\bullet \to M$ and a bounded module $N$, we compute $\Ext(M, N)$ as
+//! the cohomology of the [`HomCochainComplex`] $\Hom_A(R_\bullet, N)$. A class
+//! in $\Ext^{s, t}(M, N)$ is represented by a cocycle in $\Hom_A(R_s, \Sigma^t N)$, i.e. a choice of
+//! an element of $N$ in degree $t' - t$ for each generator of $R_s$ in degree $t'$. This is the
+//! same data the [`lift_hom`](../../lift_hom/index.html) example asks for.
+//!
+//! Classes can be lifted to [`ResolutionHomomorphism`]s with [`ExtGroups::lift`], and the
+//! composition product $\Ext(N', N) \otimes \Ext(M, N') \to \Ext(M, N)$ is computed by
+//! [`ExtGroups::compose`].
+
+use std::sync::Arc;
+
+use algebra::module::homomorphism::{FreeModuleHomomorphism, ModuleHomomorphism};
+use algebra::module::{BoundedModule, HomModule, Module};
+use fp::matrix::{Matrix, Subquotient};
+use fp::prime::ValidPrime;
+use fp::vector::{FpVector, Slice};
+use once::{OnceBiVec, OnceVec};
+
+use crate::chain_complex::{
+    AugmentedChainComplex, ChainComplex, CochainComplex, FreeChainComplex, HomCochainComplex,
+};
+use crate::resolution_homomorphism::ResolutionHomomorphism;
+
+/// The groups $\Ext^{s, t}(M, N)$, where $M$ is resolved by `CC` and the coefficients $N$ are a
+/// bounded module.
+pub struct ExtGroups<CC, N>
+where
+    CC: FreeChainComplex,
+    N: BoundedModule<Algebra = CC::Algebra>,
+{
+    resolution: Arc<CC>,
+    module: Arc<N>,
+    cochains: HomCochainComplex<CC, N>,
+    groups: OnceVec<OnceBiVec<Subquotient>>,
+}
+
+impl<CC, N> ExtGroups<CC, N>
+where
+    CC: FreeChainComplex,
+    N: BoundedModule<Algebra = CC::Algebra>,
+{
+    /// Set up the cochain complex $\Hom_A(R_\bullet, N)$. Only the part of the resolution that has
+    /// been computed can be used, so the resolution should be computed first.
+    pub fn new(resolution: Arc<CC>, module: Arc<N>) -> Self {
+        Self {
+            cochains: HomCochainComplex::new(Arc::clone(&resolution), Arc::clone(&module)),
+            resolution,
+            module,
+            groups: OnceVec::new(),
+        }
+    }
+
+    pub fn prime(&self) -> ValidPrime {
+        self.resolution.prime()
+    }
+
+    pub fn resolution(&self) -> Arc<CC> {
+        Arc::clone(&self.resolution)
+    }
+
+    pub fn module(&self) -> Arc<N> {
+        Arc::clone(&self.module)
+    }
+
+    /// The lowest degree $t$ in which $\Ext^{s, t}(M, N)$ can be non-zero.
+    pub fn min_degree(&self) -> i32 {
+        self.cochains.min_degree()
+    }
+
+    /// The exclusive upper bound of the homological degrees in which we can compute $\Ext$. Since
+    /// we need to know the cocycles, this is one less than the number of computed terms of the
+    /// resolution.
+    pub fn next_homological_degree(&self) -> u32 {
+        self.resolution.next_homological_degree().saturating_sub(1)
+    }
+
+    /// The cochain complex $\Hom_A(R_\bullet, N)$.
+    pub fn cochain_complex(&self) -> &HomCochainComplex<CC, N> {
+        &self.cochains
+    }
+
+    /// The term $\Hom_A(R_s, N)$ of the cochain complex. The elements of degree $t$ are the maps
+    /// $R_s \to \Sigma^t N$.
+    pub fn hom_module(&self, s: u32) -> Arc<HomModule<N>> {
+        self.cochains.module(s)
+    }
+
+    pub fn has_computed_bidegree(&self, s: u32, t: i32) -> bool {
+        self.cochains.has_computed_bidegree(s, t)
+    }
+
+    /// The group $\Ext^{s, t}(M, N)$ as a subquotient of $\Hom_A(R_s, \Sigma^t N)$.
+    pub fn group(&self, s: u32, t: i32) -> &Subquotient {
+        assert!(
+            self.has_computed_bidegree(s, t),
+            "Ext^({s}, {t}) has not been computed"
+        );
+        let p = self.prime();
+        let min_degree = self.min_degree();
+        self.groups
+            .extend(s as usize, |_| OnceBiVec::new(min_degree));
+        self.groups[s as usize].extend(t, |t| {
+            self.cochains.compute_through_bidegree(s + 1, t);
+
+            let mut group = Subquotient::new(p, self.hom_module(s).dimension(t));
+            if s > 0 {
+                let d = self.cochains.differential(s);
+                d.compute_auxiliary_data_through_degree(t);
+                for v in d.image(t).unwrap().basis() {
+                    group.quotient(v.as_slice());
+                }
+            }
+            let d = self.cochains.differential(s + 1);
+            d.compute_auxiliary_data_through_degree(t);
+            for v in d.kernel(t).unwrap().basis() {
+                group.add_gen(v.as_slice());
+            }
+            group
+        });
+        &self.groups[s as usize][t]
+    }
+
+    pub fn dimension(&self, s: u32, t: i32) -> usize {
+        self.group(s, t).dimension()
+    }
+
+    /// Cocycle representatives of a basis of $\Ext^{s, t}(M, N)$, as elements of
+    /// [`ExtGroups::hom_module`].
+    pub fn cocycles(&self, s: u32, t: i32) -> impl Iterator<Item = &FpVector> {
+        self.group(s, t).gens()
+    }
+
+    /// The cocycle representing the class whose coordinates in the basis of $\Ext^{s, t}(M, N)$
+    /// are `class`.
+    pub fn cocycle(&self, s: u32, t: i32, class: &[u32]) -> FpVector {
+        let group = self.group(s, t);
+        assert_eq!(class.len(), group.dimension());
+
+        let mut result = FpVector::new(self.prime(), group.ambient_dimension());
+        for (v, &c) in group.gens().zip(class) {
+            result.add(v, c);
+        }
+        result
+    }
+
+    /// Express the class of a cocycle in $\Hom_A(R_s, \Sigma^t N)$ in terms of the basis of
+    /// $\Ext^{s, t}(M, N)$.
+    pub fn reduce(&self, s: u32, t: i32, cocycle: Slice) -> Vec<u32> {
+        let mut v = FpVector::new(self.prime(), cocycle.len());
+        v.as_slice_mut().assign(cocycle);
+        let result = self.group(s, t).reduce(v.as_slice_mut());
+        assert!(v.is_zero(), "Not a cocycle");
+        result
+    }
+
+    /// The value of a cocycle in $\Hom_A(R_s, \Sigma^t N)$ on the `idx`th generator of $R_s$ in
+    /// degree `gen_t`. This is an element of $N$ in degree `gen_t - t`.
+    pub fn evaluate(&self, s: u32, t: i32, cocycle: Slice, gen_t: i32, idx: usize) -> FpVector {
+        let mut result = FpVector::new(self.prime(), self.module.dimension(gen_t - t));
+        if result.is_empty() {
+            return result;
+        }
+        let hom_module = self.hom_module(s);
+        let block = hom_module.block_structures[t].generator_to_block(gen_t, idx);
+        result.as_slice_mut().assign(cocycle.slice(
+            block.block_start_index,
+            block.block_start_index + block.block_size,
+        ));
+        result
+    }
+
+    /// The cocycle as a homomorphism $R_s \to N$ of degree `t`.
+    pub fn homomorphism(&self, s: u32, t: i32, class: &[u32]) -> FreeModuleHomomorphism<N> {
+        let cocycle = self.cocycle(s, t, class);
+        self.hom_module(s)
+            .element_to_homomorphism(t, cocycle.as_slice())
+    }
+
+    /// Lift a class in $\Ext^{s, t}(M, N)$ to a chain map from the resolution of $M$ to `target`,
+    /// which is a resolution of $N$. Only the initial step is computed, and the result has to be
+    /// extended before use, e.g. with [`ResolutionHomomorphism::extend_all`].
+    ///
+    /// The module `target.target().module(0)` must have the same basis as the coefficients.
+    pub fn lift<CC2>(
+        &self,
+        name: String,
+        s: u32,
+        t: i32,
+        class: &[u32],
+        target: Arc<CC2>,
+    ) -> ResolutionHomomorphism<CC, CC2>
+    where
+        CC2: AugmentedChainComplex<Algebra = CC::Algebra>,
+    {
+        let p = self.prime();
+        let cocycle = self.cocycle(s, t, class);
+        let target_module = target.target().module(0);
+
+        let hom = ResolutionHomomorphism::new(name, self.resolution(), target, s, t);
+        for output_t in self.module.min_degree()..=self.module.max_degree() {
+            let input_t = output_t + t;
+            let dim = self.module.dimension(output_t);
+            assert_eq!(dim, target_module.dimension(output_t));
+
+            let num_gens = self.resolution.number_of_gens_in_bidegree(s, input_t);
+            let mut matrix = Matrix::new(p, num_gens, dim);
+            if num_gens == 0 || dim == 0 {
+                hom.extend_step(s, input_t, None);
+                continue;
+            }
+            for (idx, row) in matrix.iter_mut().enumerate() {
+                row.assign(&self.evaluate(s, t, cocycle.as_slice(), input_t, idx));
+            }
+            hom.extend_step(s, input_t, Some(&matrix));
+        }
+        hom
+    }
+
+    /// Compute the composite $y \circ x$, where $x \in \Ext^{s_0, t_0}(M, N')$ is lifted to the
+    /// chain map `hom` and `class` is the class $y \in \Ext^{s, t}(N', N)$ in `other`. The result
+    /// is an element of $\Ext^{s + s_0, t + t_0}(M, N)$.
+    ///
+    /// The chain map `hom` must be defined through the internal degree $t + t_0$ plus the top
+    /// degree of $N$ in homological degree $s + s_0$.
+    pub fn compose<CC2>(
+        &self,
+        hom: &ResolutionHomomorphism<CC, CC2>,
+        other: &ExtGroups<CC2, N>,
+        s: u32,
+        t: i32,
+        class: &[u32],
+    ) -> Vec<u32>
+    where
+        CC2: FreeChainComplex<Algebra = CC::Algebra>,
+    {
+        let p = self.prime();
+        let y = other.homomorphism(s, t, class);
+        let source_s = s + hom.shift_s;
+        let source_t = t + hom.shift_t;
+        let f = hom.get_map(source_s);
+
+        let hom_module = self.hom_module(source_s);
+        hom_module.compute_basis(source_t);
+        let mut result = FpVector::new(p, hom_module.dimension(source_t));
+
+        for out_t in self.module.min_degree()..=self.module.max_degree() {
+            let gen_t = source_t + out_t;
+            // The chain map vanishes on generators below its minimum degree
+            if self.module.dimension(out_t) == 0 || gen_t < f.min_degree() {
+                continue;
+            }
+            let middle_t = gen_t - hom.shift_t;
+            for idx in 0..self.resolution.number_of_gens_in_bidegree(source_s, gen_t) {
+                let block = hom_module.block_structures[source_t].generator_to_block(gen_t, idx);
+                y.apply(
+                    result.slice_mut(
+                        block.block_start_index,
+                        block.block_start_index + block.block_size,
+                    ),
+                    1,
+                    middle_t,
+                    f.output(gen_t, idx).as_slice(),
+                );
+            }
+        }
+        self.reduce(source_s, source_t, result.as_slice())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::utils::construct;
+
+    #[test]
+    fn reduce_cocycle() {
+        let sphere = Arc::new(construct("S_2", None).unwrap());
+        sphere.compute_through_stem(4, 8);
+        let ext = ExtGroups::new(
+            Arc::clone(&sphere),
+            construct("C2", None).unwrap().target().module(0),
+        );
+
+        for (s, _, t) in sphere.iter_stem() {
+            if s == 0 || !ext.has_computed_bidegree(s, t) {
+                continue;
+            }
+            let dim = ext.dimension(s, t);
+            let d = ext.cochain_complex().differential(s);
+            for i in 0..dim {
+                let mut class = vec![0; dim];
+                class[i] = 1;
+                let mut cocycle = ext.cocycle(s, t, &class);
+                assert_eq!(ext.reduce(s, t, cocycle.as_slice()), class);
+
+                // Adding coboundaries does not change the class
+                for j in 0..ext.hom_module(s - 1).dimension(t) {
+                    d.apply_to_basis_element(cocycle.as_slice_mut(), 1, t, j);
+                }
+                assert_eq!(ext.reduce(s, t, cocycle.as_slice()), class);
+            }
+        }
+    }
+
+    #[test]
+    fn lift() {
+        let sphere = Arc::new(construct("S_2", None).unwrap());
+        let c2 = Arc::new(construct("C2", None).unwrap());
+        sphere.compute_through_stem(4, 8);
+        c2.compute_through_stem(4, 8);
+        let module = c2.target().module(0);
+        let ext = ExtGroups::new(Arc::clone(&sphere), Arc::clone(&module));
+
+        // h_1 on the bottom cell, which is also h_0 on the top cell
+        let (s, t) = (1, 1);
+        assert_eq!(ext.dimension(s, t), 1);
+        let cocycle = ext.cocycle(s, t, &[1]);
+        let hom = ext.lift(String::new(), s, t, &[1], Arc::clone(&c2));
+        hom.extend_all();
+
+        // The lift composed with the augmentation of the resolution of C2 is the cocycle
+        let f = hom.get_map(s);
+        let augmentation = c2.chain_map(0);
+        for gen_t in t..=t + module.max_degree() {
+            for idx in 0..sphere.number_of_gens_in_bidegree(s, gen_t) {
+                let mut value = FpVector::new(ext.prime(), module.dimension(gen_t - t));
+                augmentation.apply(
+                    value.as_slice_mut(),
+                    1,
+                    gen_t - t,
+                    f.output(gen_t, idx).as_slice(),
+                );
+                assert_eq!(value, ext.evaluate(s, t, cocycle.as_slice(), gen_t, idx));
+            }
+        }
+
+        // Composing with the identity of C2 recovers the class
+        let endomorphisms = ExtGroups::new(Arc::clone(&c2), module);
+        assert_eq!(endomorphisms.dimension(0, 0), 1);
+        assert_eq!(ext.compose(&hom, &endomorphisms, 0, 0, &[1]), [1]);
+    }
+
+    #[test]
+    fn compose() {
+        let sphere = Arc::new(construct("S_2", None).unwrap());
+        sphere.compute_through_stem(4, 10);
+        let unit = ExtGroups::new(Arc::clone(&sphere), sphere.target().module(0));
+        let ext = ExtGroups::new(
+            Arc::clone(&sphere),
+            construct("C2", None).unwrap().target().module(0),
+        );
+
+        // Products of h_1 and h_2 with the top cell of C2 and with h_1 on the bottom cell. Since
+        // π_2(S/2) = Z/4, h_1^2 on the bottom cell is non-zero.
+        for (t0, s, t, expected) in [(2, 0, -1, (1, 1)), (4, 0, -1, (1, 3)), (2, 1, 1, (2, 3))] {
+            let hom = unit.lift(String::new(), 1, t0, &[1], Arc::clone(&sphere));
+            hom.extend_all();
+            assert_eq!(ext.dimension(expected.0, expected.1), 1);
+            assert_eq!(ext.compose(&hom, &ext, s, t, &[1]), [1], "({t0}, {s}, {t})");
+        }
+    }
+}
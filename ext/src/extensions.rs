@@ -0,0 +1,553 @@
+//! Hidden extensions and Toda brackets from products in $\Mod_{C\tau^2}$.
+//!
+//! The [`secondary`](crate::secondary) module computes the products of standard lifts of $\Ext$
+//! classes in $\Mod_{C\tau^2}$. Such a product is of the form $a + \tau b$, where $a$ is the
+//! product in $\Ext$ and $b$ lies one filtration higher. This module packages these computations
+//! into the following higher level operations on the $E_3$ page:
+//!
+//!  - [`SecondaryProducts::hidden_extensions`] finds hidden $2$, $\eta$ and $\nu$ extensions. If
+//!    $h \in \{h_0, h_1, h_2\}$ and $x$ is a permanent class with $hx = 0$ in $\Ext$, then the $\tau$
+//!    part $b$ of the product of the lifts detects the product of the homotopy classes. If $b$ is
+//!    non-zero modulo the indeterminacy, this is a hidden extension from $x$ to $b$.
+//!  - [`SecondaryProducts::toda_bracket`] evaluates a Toda bracket $\langle \alpha, \beta,
+//!    \gamma\rangle$ via the Moss convergence theorem, by computing the Massey product $\langle a,
+//!    b, c\rangle$ of the classes detecting $\alpha$, $\beta$ and $\gamma$.
+//!
+//! Results are reported in terms of the [`ClassNames`] of the resolutions.
+//!
+//! # Limitations
+//! We only know the $d_2$ differentials, so we treat the $E_3$ page as the $E_\infty$ page. In
+//! particular, the results are only valid in the range where there are no higher differentials.
+//! Further, products in $\Mod_{C\tau^2}$ only see extensions that jump by exactly one filtration.
+//!
+//! Since the standard lift of an $\Ext$ class need not be the image of a homotopy class, the
+//! $\tau$ part of a product is only well-defined modulo the products of the factors with the
+//! elements one filtration above the other factor, and modulo the image of $d_2$. This is the
+//! indeterminacy we report. Classes whose product in $\Ext$ is non-zero but hit by a $d_2$ are not
+//! considered by [`SecondaryProducts::hidden_extensions`]. Neither are classes in filtration 0,
+//! whose lifts are not computed by [`secondary`](crate::secondary).
+//!
+//! This only supports the prime 2.
+
+use std::rc::Rc;
+use std::sync::Arc;
+
+use algebra::module::Module;
+use algebra::SteenrodAlgebra;
+use anyhow::anyhow;
+use fp::matrix::{Matrix, Subquotient, Subspace};
+use fp::prime::ValidPrime;
+use fp::vector::FpVector;
+
+use crate::chain_complex::{AugmentedChainComplex, ChainComplex, FreeChainComplex};
+use crate::massey::massey_product;
+use crate::names::ClassNames;
+use crate::resolution::Resolution;
+use crate::resolution_homomorphism::ResolutionHomomorphism;
+use crate::secondary::{
+    can_compute, SecondaryLift, SecondaryResolution, SecondaryResolutionHomomorphism,
+};
+use crate::CCC;
+
+type Lift = SecondaryResolution<SteenrodAlgebra, Resolution<CCC>>;
+type LiftHom = SecondaryResolutionHomomorphism<SteenrodAlgebra, Resolution<CCC>, Resolution<CCC>>;
+
+/// The operations whose hidden extensions are detected by
+/// [`SecondaryProducts::hidden_extensions`], as the name and the stem of the Hopf invariant one
+/// class detecting it.
+pub const EXTENSIONS: [(&str, i32); 3] = [("2", 0), ("η", 1), ("ν", 3)];
+
+/// A hidden extension found by [`SecondaryProducts::hidden_extensions`].
+#[derive(Clone, Debug)]
+pub struct HiddenExtension {
+    /// The name of the operation, which is one of the entries of [`EXTENSIONS`].
+    pub operation: &'static str,
+    pub n: i32,
+    pub s: u32,
+    /// The source of the extension, which is an element of $\Ext^{s, n + s}$.
+    pub source: FpVector,
+    pub target_n: i32,
+    pub target_s: u32,
+    /// The target of the extension, which is well-defined modulo `indeterminacy`.
+    pub target: FpVector,
+    pub indeterminacy: Subspace,
+}
+
+impl HiddenExtension {
+    pub fn describe(&self, names: &ClassNames) -> String {
+        let mut result = format!(
+            "{} · {} is detected by {}",
+            self.operation,
+            names.format(self.source.as_slice(), self.n, self.s),
+            names.format(self.target.as_slice(), self.target_n, self.target_s),
+        );
+        if self.indeterminacy.dimension() > 0 {
+            result += &format!(
+                " modulo {}",
+                names.format_subspace(&self.indeterminacy, self.target_n, self.target_s)
+            );
+        }
+        result
+    }
+}
+
+/// A Toda bracket computed by [`SecondaryProducts::toda_bracket`]. The Toda bracket contains an
+/// element detected by an element of `value + indeterminacy`.
+#[derive(Clone, Debug)]
+pub struct TodaBracket {
+    pub n: i32,
+    pub s: u32,
+    pub value: FpVector,
+    /// The indeterminacy of the Massey product together with the image of $d_2$.
+    pub indeterminacy: Subspace,
+}
+
+impl TodaBracket {
+    /// Whether the Toda bracket contains an element of Adams filtration greater than `s`.
+    pub fn contains_zero(&self) -> bool {
+        self.indeterminacy.contains(self.value.as_slice())
+    }
+
+    pub fn describe(&self, names: &ClassNames) -> String {
+        let value = names.format(self.value.as_slice(), self.n, self.s);
+        if self.indeterminacy.dimension() == 0 {
+            value
+        } else {
+            format!(
+                "{value} + {}",
+                names.format_subspace(&self.indeterminacy, self.n, self.s)
+            )
+        }
+    }
+}
+
+/// Computes hidden extensions and Toda brackets in the Adams spectral sequence of a module $M$.
+pub struct SecondaryProducts {
+    resolution: Arc<Resolution<CCC>>,
+    unit: Arc<Resolution<CCC>>,
+    res_lift: Arc<Lift>,
+    unit_lift: Arc<Lift>,
+    res_sseq: Rc<sseq::Sseq>,
+    unit_sseq: Rc<sseq::Sseq>,
+    /// Names of classes in $\Ext(M, k)$.
+    pub names: ClassNames,
+    /// Names of classes in $\Ext(k, k)$. If $M = k$, only `names` is used.
+    pub unit_names: ClassNames,
+}
+
+impl SecondaryProducts {
+    /// Compute the $d_2$ differentials of `resolution` and `unit`, which must be resolutions of
+    /// $M$ and $k$ respectively over the Milnor basis. They can be the same resolution if $M = k$.
+    ///
+    /// Both resolutions should already be computed through the desired range. The unit has to be
+    /// resolved as far as the products with classes of $\Ext(M, k)$ require.
+    pub fn new(
+        resolution: Arc<Resolution<CCC>>,
+        unit: Arc<Resolution<CCC>>,
+    ) -> anyhow::Result<Self> {
+        if *resolution.prime() != 2 {
+            return Err(anyhow!(
+                "Hidden extensions are only supported at the prime 2"
+            ));
+        }
+        if !can_compute(&resolution) {
+            return Err(anyhow!(
+                "Cannot compute d2 for the module {}",
+                resolution.target().module(0)
+            ));
+        }
+        assert!(unit.target().module(0).is_unit());
+
+        let is_unit = Arc::ptr_eq(&resolution, &unit);
+        let names = ClassNames::for_resolution(&resolution)?;
+        let unit_names = if is_unit {
+            ClassNames::new()
+        } else {
+            ClassNames::for_resolution(&unit)?
+        };
+
+        let res_lift = Arc::new(SecondaryResolution::new(Arc::clone(&resolution)));
+        res_lift.extend_all();
+        let res_sseq = Rc::new(res_lift.e3_page());
+
+        let (unit_lift, unit_sseq) = if is_unit {
+            (Arc::clone(&res_lift), Rc::clone(&res_sseq))
+        } else {
+            let unit_lift = Arc::new(SecondaryResolution::new(Arc::clone(&unit)));
+            unit_lift.extend_all();
+            let unit_sseq = Rc::new(unit_lift.e3_page());
+            (unit_lift, unit_sseq)
+        };
+
+        Ok(Self {
+            resolution,
+            unit,
+            res_lift,
+            unit_lift,
+            res_sseq,
+            unit_sseq,
+            names,
+            unit_names,
+        })
+    }
+
+    pub fn prime(&self) -> ValidPrime {
+        self.resolution.prime()
+    }
+
+    fn is_unit(&self) -> bool {
+        Arc::ptr_eq(&self.resolution, &self.unit)
+    }
+
+    /// The $E_3$ page of $M$ in bidegree `(n, s)`.
+    pub fn e3_page(&self, n: i32, s: u32) -> &Subquotient {
+        page(&self.res_sseq, n, s)
+    }
+
+    /// Whether the resolution is computed far enough to find extensions by an element of degree
+    /// `h_t` on classes in bidegree `(s, t)`. The extensions lie in bidegree
+    /// `(s + 2, t + h_t + 1)`, and the homotopies of the lift of a class in bidegree `(s, t)`
+    /// require the resolution in bidegree `(s - 1, t + h_t - 1)`.
+    fn has_computed_extension(&self, s: u32, t: i32, h_t: i32) -> bool {
+        self.resolution.has_computed_bidegree(s + 2, t + h_t + 1)
+            && self.resolution.has_computed_bidegree(s - 1, t + h_t - 1)
+    }
+
+    /// Find all hidden $2$, $\eta$ and $\nu$ extensions on classes in stem at most `max_n` and
+    /// filtration at most `max_s`. Bidegrees where the necessary data has not been computed are
+    /// skipped.
+    pub fn hidden_extensions(&self, max_n: i32, max_s: u32) -> Vec<HiddenExtension> {
+        let p = self.prime();
+        let mut result = Vec::new();
+        for (operation, h_n) in EXTENSIONS {
+            let h_t = h_n + 1;
+            if !self.unit.has_computed_bidegree(2, h_t + 1) {
+                continue;
+            }
+            let h = [1];
+            for (s, n, t) in self.resolution.iter_stem() {
+                if s == 0 || s > max_s || n > max_n || !self.has_computed_extension(s, t, h_t) {
+                    continue;
+                }
+                let page = self.e3_page(n, s);
+                if page.is_empty() {
+                    continue;
+                }
+
+                // Find the classes whose product with h vanishes in Ext
+                let gens: Vec<FpVector> = page.gens().cloned().collect();
+                let product_dim = self.resolution.number_of_gens_in_bidegree(s + 1, t + h_t);
+                let mut matrix = Matrix::new(p, gens.len(), product_dim + gens.len());
+                for (row, gen) in matrix.iter_mut().zip(&gens) {
+                    let gen: Vec<u32> = gen.iter().collect();
+                    let product = self.product(false, (n, s, &gen), (h_n, 1, &h));
+                    row.slice_mut(0, product_dim).assign(product.ext.as_slice());
+                }
+                for (i, row) in matrix.iter_mut().enumerate() {
+                    row.add_basis_element(product_dim + i, 1);
+                }
+                matrix.row_reduce();
+                let kernel = matrix.compute_kernel(product_dim);
+
+                for c in kernel.basis() {
+                    let mut source = FpVector::new(p, gens[0].len());
+                    for (i, v) in c.iter_nonzero() {
+                        source.add(&gens[i], v);
+                    }
+                    let class: Vec<u32> = source.iter().collect();
+                    let product = self.product(false, (n, s, &class), (h_n, 1, &h));
+                    debug_assert!(product.ext.is_zero());
+                    let mut target = product.tau;
+                    product.indeterminacy.reduce(target.as_slice_mut());
+                    if !target.is_zero() {
+                        result.push(HiddenExtension {
+                            operation,
+                            n,
+                            s,
+                            source,
+                            target_n: n + h_n,
+                            target_s: s + 2,
+                            target,
+                            indeterminacy: product.indeterminacy,
+                        });
+                    }
+                }
+            }
+        }
+        result
+    }
+
+    /// Evaluate the Toda bracket $\langle \alpha, \beta, \gamma\rangle$ via the Moss convergence
+    /// theorem, where $\alpha, \beta$ are detected by the classes $a, b \in \Ext(k, k)$ and
+    /// $\gamma$ is detected by $c \in \Ext(M, k)$. Each class is given by its stem, filtration and
+    /// coordinates.
+    ///
+    /// This returns an error if the classes are not permanent, or if the products $ab$ and $bc$
+    /// are non-zero in $\Ext$ or in $\Mod_{C\tau^2}$, since the Massey product is then not defined
+    /// or the corresponding Toda bracket need not be defined. The resolutions must be computed
+    /// through the bidegree of the bracket, and the unit must have its quasi-inverses available.
+    pub fn toda_bracket(
+        &self,
+        a: (i32, u32, &[u32]),
+        b: (i32, u32, &[u32]),
+        c: (i32, u32, &[u32]),
+    ) -> anyhow::Result<TodaBracket> {
+        let p = self.prime();
+        let unit_names = if self.is_unit() {
+            &self.names
+        } else {
+            &self.unit_names
+        };
+        let format = |names: &ClassNames, (n, s, v): (i32, u32, &[u32])| {
+            names.format(FpVector::from_slice(p, v).as_slice(), n, s)
+        };
+
+        if a.1 == 0 || b.1 == 0 || c.1 == 0 {
+            return Err(anyhow!("Classes in filtration 0 are not supported"));
+        }
+        for (class, sseq, names) in [
+            (a, &self.unit_sseq, unit_names),
+            (b, &self.unit_sseq, unit_names),
+            (c, &self.res_sseq, &self.names),
+        ] {
+            let (n, s, v) = class;
+            let mut v = FpVector::from_slice(p, v);
+            page(sseq, n, s).reduce(v.as_slice_mut());
+            if !v.is_zero() {
+                return Err(anyhow!("{} supports a non-zero d2", format(names, class)));
+            }
+        }
+
+        for (x, y, on_unit, names) in [(b, a, true, unit_names), (c, b, false, &self.names)] {
+            let product = self.product(on_unit, x, y);
+            let (n, s) = (x.0 + y.0, x.1 + y.1);
+            if !product.ext.is_zero() {
+                return Err(anyhow!(
+                    "The product of {} and {} is {} in Ext",
+                    format(unit_names, y),
+                    format(names, x),
+                    names.format(product.ext.as_slice(), n, s)
+                ));
+            }
+            let mut tau = product.tau;
+            product.indeterminacy.reduce(tau.as_slice_mut());
+            if !tau.is_zero() {
+                return Err(anyhow!(
+                    "The product of {} and {} is detected by the hidden extension {}",
+                    format(unit_names, y),
+                    format(names, x),
+                    names.format(tau.as_slice(), n, s + 1)
+                ));
+            }
+        }
+
+        let hom = |source: &Arc<Resolution<CCC>>, (n, s, v): (i32, u32, &[u32])| {
+            Arc::new(ResolutionHomomorphism::from_class(
+                String::new(),
+                Arc::clone(source),
+                Arc::clone(&self.unit),
+                s,
+                n + s as i32,
+                v,
+            ))
+        };
+        let massey = massey_product(
+            &[hom(&self.unit, a), hom(&self.unit, b)],
+            &hom(&self.resolution, c),
+        )
+        .ok_or_else(|| anyhow!("The Massey product is not defined"))?;
+
+        let n = massey.n();
+        let s = massey.s;
+        let mut indeterminacy = massey.indeterminacy;
+        for v in page(&self.res_sseq, n, s).zeros().basis() {
+            indeterminacy.add_vector(v.as_slice());
+        }
+        let mut value = massey.value;
+        indeterminacy.reduce(value.as_slice_mut());
+        Ok(TodaBracket {
+            n,
+            s,
+            value,
+            indeterminacy,
+        })
+    }
+
+    /// The product in $\Mod_{C\tau^2}$ of the standard lifts of $x \in \Ext(M, k)$ and $y \in
+    /// \Ext(k, k)$, or of $x, y \in \Ext(k, k)$ if `on_unit` is true.
+    fn product(&self, on_unit: bool, x: (i32, u32, &[u32]), y: (i32, u32, &[u32])) -> Product {
+        let p = self.prime();
+        let (source, source_lift, sseq) = if on_unit {
+            (&self.unit, &self.unit_lift, &self.unit_sseq)
+        } else {
+            (&self.resolution, &self.res_lift, &self.res_sseq)
+        };
+        let (x_n, x_s, x_class) = x;
+        let (y_n, y_s, y_class) = y;
+        let (n, s) = (x_n + y_n, x_s + y_s);
+        let t = n + s as i32;
+
+        let hom = |class: &[u32], s: u32| {
+            let hom = ResolutionHomomorphism::from_class(
+                String::new(),
+                Arc::clone(source),
+                Arc::clone(&self.unit),
+                s,
+                x_n + s as i32,
+                class,
+            );
+            hom.extend_all();
+            Arc::new(hom)
+        };
+
+        let underlying = hom(x_class, x_s);
+        let lift = LiftHom::new(
+            Arc::clone(source_lift),
+            Arc::clone(&self.unit_lift),
+            Arc::clone(&underlying),
+        );
+        lift.extend_all();
+
+        let num_gens = source.number_of_gens_in_bidegree(s, t);
+        let tau_num_gens = source.number_of_gens_in_bidegree(s + 1, t + 1);
+        let mut output = FpVector::new(p, num_gens + tau_num_gens);
+        lift.hom_k(
+            Some(sseq),
+            y_s,
+            y_n + y_s as i32,
+            [FpVector::from_slice(p, y_class).as_slice()].into_iter(),
+            [output.as_slice_mut()].into_iter(),
+        );
+
+        // The products of x with the elements above y, and of the elements above x with y.
+        let mut indeterminacy = Subspace::new(p, tau_num_gens + 1, tau_num_gens);
+        let mut v = FpVector::new(p, tau_num_gens);
+        let y_t = y_n + y_s as i32;
+        for idx in 0..self.unit.number_of_gens_in_bidegree(y_s + 1, y_t + 1) {
+            v.set_to_zero();
+            underlying.act(v.as_slice_mut(), 1, y_s + 1, y_t + 1, idx);
+            indeterminacy.add_vector(v.as_slice());
+        }
+        let x_t = x_n + x_s as i32;
+        let above_num_gens = source.number_of_gens_in_bidegree(x_s + 1, x_t + 1);
+        for i in 0..above_num_gens {
+            let mut class = vec![0; above_num_gens];
+            class[i] = 1;
+            let above = hom(&class, x_s + 1);
+            for (idx, c) in y_class.iter().enumerate() {
+                if c % *p != 0 {
+                    above.act(v.as_slice_mut(), *c, y_s, y_t, idx);
+                }
+            }
+            indeterminacy.add_vector(v.as_slice());
+            v.set_to_zero();
+        }
+        for v in page(sseq, n, s + 1).zeros().basis() {
+            indeterminacy.add_vector(v.as_slice());
+        }
+
+        Product {
+            ext: output.slice(0, num_gens).to_owned(),
+            tau: output.slice(num_gens, num_gens + tau_num_gens).to_owned(),
+            indeterminacy,
+        }
+    }
+}
+
+/// A product in $\Mod_{C\tau^2}$, whose $\tau$ part is well-defined modulo `indeterminacy`.
+struct Product {
+    ext: FpVector,
+    tau: FpVector,
+    indeterminacy: Subspace,
+}
+
+fn page(sseq: &sseq::Sseq, n: i32, s: u32) -> &Subquotient {
+    let d = sseq.page_data(n, s as i32);
+    &d[std::cmp::min(3, d.len() - 1)]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::utils::construct;
+
+    fn sphere(max_s: u32, max_n: i32) -> SecondaryProducts {
+        let resolution = construct("S_2@milnor", None).unwrap();
+        resolution.compute_through_stem(max_s, max_n);
+        let resolution = Arc::new(resolution);
+        SecondaryProducts::new(Arc::clone(&resolution), resolution).unwrap()
+    }
+
+    #[test]
+    fn toda_brackets() {
+        let mut products = sphere(6, 10);
+        products.names.insert(2, 2, 0, "h1^2");
+
+        let h0 = (0, 1, &[1][..]);
+        let h1 = (1, 1, &[1][..]);
+        let h2 = (3, 1, &[1][..]);
+
+        let bracket = products.toda_bracket(h0, h1, h0).unwrap();
+        assert_eq!((bracket.n, bracket.s), (2, 2));
+        assert!(!bracket.contains_zero());
+        assert_eq!(bracket.describe(&products.names), "h1^2");
+
+        let bracket = products.toda_bracket(h1, h0, h1).unwrap();
+        assert_eq!((bracket.n, bracket.s), (3, 2));
+        assert_eq!(bracket.describe(&products.names), "h_0 h_2");
+
+        let bracket = products.toda_bracket(h2, h1, h2).unwrap();
+        assert_eq!((bracket.n, bracket.s), (8, 2));
+        assert!(!bracket.contains_zero());
+
+        assert!(products.toda_bracket(h0, h0, h0).is_err());
+    }
+
+    #[test]
+    fn extension_boundary() {
+        let products = sphere(6, 12);
+        // η extensions on classes in stem 10 land in stem 11, and the lifts of the classes need
+        // stem 12 in filtration one lower
+        for s in 1..=4 {
+            assert!(products.has_computed_extension(s, 10 + s as i32, 2));
+            assert!(!products.has_computed_extension(s, 11 + s as i32, 2));
+        }
+        assert!(!products.has_computed_extension(5, 15, 2));
+    }
+
+    #[test]
+    fn no_hidden_extensions() {
+        let products = sphere(6, 12);
+        assert!(products.hidden_extensions(12, 6).is_empty());
+    }
+
+    #[test]
+    fn hidden_extensions_c2() {
+        let unit = construct("S_2@milnor", None).unwrap();
+        unit.compute_through_stem(6, 17);
+        let resolution = construct("C2@milnor", None).unwrap();
+        resolution.compute_through_stem(6, 17);
+        let products = SecondaryProducts::new(Arc::new(resolution), Arc::new(unit)).unwrap();
+        let p = products.prime();
+
+        // The class x in (15, 3) detects a lift of κ to the top cell. Then 2x = i(ηκ) is detected
+        // by h_1 d_0 on the bottom cell, and ηx is detected by h_1 d_0 on the top cell.
+        let extensions = products.hidden_extensions(17, 6);
+        assert_eq!(extensions.len(), 2);
+        for (extension, operation, target_n) in
+            [(&extensions[0], "2", 15), (&extensions[1], "η", 16)]
+        {
+            assert_eq!(extension.operation, operation);
+            assert_eq!((extension.n, extension.s), (15, 3));
+            assert_eq!((extension.target_n, extension.target_s), (target_n, 5));
+            assert_eq!(extension.source, FpVector::from_slice(p, &[1]));
+            assert_eq!(extension.target, FpVector::from_slice(p, &[1]));
+            assert_eq!(extension.indeterminacy.dimension(), 0);
+            assert_eq!(
+                extension.describe(&products.names),
+                format!("{operation} · x_(15, 3, 0) is detected by x_({target_n}, 5, 0)")
+            );
+        }
+    }
+}
@@ -60,6 +60,22 @@
 //!  $ cargo run --features concurrent --example filtration_one -- C2 "" 2 40 20
 //! ```
 //!
+//! ## The `ext` binary
+//! The most common computations are also available as subcommands of the `ext` binary, which
+//! takes named flags instead of positional answers. The parameters can also be supplied via
+//! environment variables or a TOML config file, and any parameter that is not supplied is
+//! prompted for as in the examples. For example,
+//! ```sh
+//!  $ cargo run --release --features concurrent --bin ext -- num-gens --module C2 --max-n 40 --max-s 20
+//! ```
+//! The available subcommands are `resolve`, `num-gens`, `secondary`, `massey`, `lift-hom` and
+//! `job`. Run `ext --help` for a full list of options. With `--batch`, missing parameters take
+//! their default values instead of being prompted for, which is useful for scripts.
+//!
+//! The `job` subcommand runs a whole pipeline described by a TOML or JSON job file: resolving a
+//! list of modules, computing products and $d_2$ differentials, and drawing charts. Stages whose
+//! outputs already exist are skipped, so rerunning a job only computes what is missing.
+//!
 //! ## Conventions
 //!
 //! ### Module specification
@@ -79,6 +95,10 @@
 //! module without having to define a new one. For example, to shift `Ceta` by one,
 //! we supply `Ceta[1]`.
 //!
+//! More generally, modules can be combined using [module expressions](module_expression), e.g.
+//! `C2 ^ Ceta` is the cohomology of $C2 \wedge C\eta$ and `D(Joker)` is the dual of the Joker.
+//! When a save directory is given, the evaluated module is written to `module.json` in it.
+//!
 //! When resolving a module, we have to pick a basis of the Steenrod algebra, which
 //! is either the Adem basis or the Milnor basis. The default choice is the Milnor
 //! basis. We can specify the basis by appending `@basis_name`. For example, if we
@@ -90,6 +110,15 @@
 //! group, we either write it as a linear combination of the `x_(n, s, i)`, or
 //! written as a vector of the form e.g. `[0, 1, 0]`. In the latter case, the bidegree is implicit.
 //!
+//! When printing Ext elements, basis elements that have names are written using their names
+//! instead, e.g. `h_0^2 h_3`. These names are generated automatically from filtration one products
+//! and the standard names of indecomposables, and can be overridden by listing names in the file
+//! `names.csv` in the save directory. See the [`names`] module for details.
+//!
+//! The basis depends on the resolution, so resolutions of the same module with different bases of
+//! the Steenrod algebra may label classes differently. The [`change_of_basis`] module converts
+//! between the two.
+//!
 //! ### Save directory
 //! For most scripts, one can specify a save directory for each module. All save data relating to
 //! the module will be saved in this directory, including resolution data, products, secondary
@@ -121,12 +150,18 @@
 //! | --- | --- |
 //! | [algebra_dim](../algebra_dim/index.html) | Print the dimension of the Steenrod algebra in each degree. |
 //! | [bruner](../bruner/index.html) | Compare our basis with Bruner's. |
+//! | [change_of_basis](../change_of_basis/index.html) | Compare the bases of Ext coming from two resolutions of the same module. |
+//! | [curtis](../curtis/index.html) | Print the Curtis table of the Lambda algebra. |
 //! | [define_module](../define_module/index.html) | Interactively define a Steenrod module. |
 //! | [differentials](../differentials/index.html) | Print all differentials in the minimal resolution. |
+//! | [ext_groups](../ext_groups/index.html) | Compute $\Ext(M, N)$ with cocycle representatives. |
 //! | [filtration_one](../filtration_one/index.html) | Print all filtration one products. |
 //! | [lift_hom](../lift_hom/index.html) | Compute the map $\Ext(N, k) \to \Ext(M, k)$ induced by an element in $\Ext(M, N)$. |
 //! | [massey](../massey/index.html) | Compute Massey products. |
+//! | [may](../may/index.html) | Compute the May spectral sequence. |
+//! | [names](../names/index.html) | Print the names of the classes in Ext. |
 //! | [num_gens](../num_gens/index.html) | Compute the dimension of Ext in each bidegree. |
+//! | [realizability](../realizability/index.html) | Check a Steenrod module for obstructions to realizability. |
 //! | [resolution_size](../resolution_size/index.html) | Compute the size of the minimal resolution in each bidegree |
 //! | [resolve](../resolve/index.html) | Resolve a module to a fixed $(s, t)$ and potentially save the resolution. |
 //! | [resolve_through_stem](../resolve_through_stem/index.html) | Resolve a module to a fixed $(s, n)$ and potentially save the resolution. |
@@ -158,6 +193,7 @@
 //! - `concurrent`: Use multiple threads for computations. The number of threads used can be
 //!   configured via the `RAYON_NUM_THREADS` environment variable.
 //! - `use-zstd`: Support reading zstd-compressed save files.
+//! - `cli`: Build the `ext` binary. This is enabled by default.
 //! - `cache-multiplication`: Precompute and cache the multiplication table under the Milnor basis.
 //!    This is only feasible when using a small, finite subalgebra, e.g. when working with
 //!    $\mathrm{tmf}$ modules.
@@ -165,6 +201,7 @@
 #![allow(clippy::upper_case_acronyms)]
 
 pub mod chain_complex;
+pub mod cofiber;
 pub mod resolution;
 pub mod resolution_homomorphism;
 pub mod save;
@@ -176,8 +213,18 @@ use algebra::module::homomorphism::FiniteModuleHomomorphism;
 use algebra::module::FiniteModule;
 pub type CCC = FiniteChainComplex<FiniteModule, FiniteModuleHomomorphism<FiniteModule>>;
 
+pub mod change_of_basis;
+pub mod curtis;
+pub mod ext_groups;
+pub mod extensions;
+pub mod massey;
+pub mod may;
+pub mod module_expression;
+pub mod names;
 pub mod nassau;
+pub mod realizability;
 pub mod secondary;
+pub mod steenrod;
 pub mod utils;
 
 // Ensure dependencies don't accidentally activate odd primes
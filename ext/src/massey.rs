@@ -0,0 +1,965 @@
+//! Massey products in $\Ext$.
+//!
+//! This module computes triple and quadruple matric Massey products $\langle A_1, \ldots,
+//! A_n\rangle$, where $A_1, \ldots, A_{n - 1}$ are matrices of elements of $\Ext_A(k, k)$ and $A_n$
+//! is a matrix of elements of $\Ext_A(M, k)$. The first matrix must have a single row and the last
+//! matrix a single column, so that the Massey product is a subset of $\Ext_A(M, k)$. Ordinary
+//! Massey products are the case of $1 \times 1$ matrices.
+//!
+//! Each class is specified by a [`ResolutionHomomorphism`] lifting it, as constructed by
+//! [`ResolutionHomomorphism::from_class`]. The product of two classes is the class of the
+//! composite of these chain maps, as computed by [`ResolutionHomomorphism::act`]. The resolutions
+//! must be computed through the bidegree of the Massey product, and the resolution of the unit
+//! must have its quasi-inverses available.
+//!
+//! # Sign conventions
+//! We work in the differential graded category of resolutions. Chain maps lifting $\Ext$ classes
+//! commute with the differentials and are considered to be even, and the differential of a map
+//! $f$ of parity $|f|$ is $Df = df - (-1)^{|f|} fd$. Following May's "Matric Massey products", a
+//! defining system consists of maps $a_{ij}$ of parity $j - i$ such that $a_{ii}$ lifts the $i$th
+//! factor and
+//! $$ D a_{ij} = \sum_{k = i}^{j - 1} \bar{a}_{ik} a_{k + 1, j},\quad \bar{a} = (-1)^{1 + |a|} a.
+//! $$
+//! The Massey product is the set of classes of $\sum_{k = 1}^{n - 1} \bar{a}_{1k} a_{k + 1, n}$,
+//! where the class of a cycle is read off from its component that lands in the bottom of the
+//! resolution of the unit. With these conventions, $\langle a, b, c\rangle$ is represented by
+//! $ah$, where $h$ is the null-homotopy of $bc$ satisfying $dh + hd = bc$. This is what the
+//! `massey` example computes.
+//!
+//! # Indeterminacy
+//! The [`MasseyProduct`] returned records the value of the product for one defining system,
+//! together with the subspace spanned by the differences between the values of all defining
+//! systems. For triple products, this is the usual indeterminacy and the Massey product is exactly
+//! the coset `value + indeterminacy`. For quadruple products, the set of values need not be a
+//! coset, and every value lies in `value + indeterminacy`.
+use std::sync::Arc;
+
+use algebra::module::homomorphism::{FreeModuleHomomorphism, ModuleHomomorphism};
+use algebra::module::Module;
+use fp::matrix::{AugmentedMatrix, Subspace};
+use fp::prime::ValidPrime;
+use fp::vector::{FpVector, Slice, SliceMut};
+use once::OnceBiVec;
+
+use crate::chain_complex::{AugmentedChainComplex, FreeChainComplex};
+use crate::resolution_homomorphism::ResolutionHomomorphism;
+
+/// A matrix of chain maps lifting $\Ext$ classes, given as a list of rows.
+pub type HomMatrix<S, U> = Vec<Vec<Arc<ResolutionHomomorphism<S, U>>>>;
+
+/// A Massey product, which is a subset of $\Ext^{s, t}$.
+#[derive(Clone, Debug)]
+pub struct MasseyProduct {
+    pub s: u32,
+    pub t: i32,
+    /// The value of the product for a particular defining system.
+    pub value: FpVector,
+    /// The subspace spanned by the differences between the values of different defining systems.
+    pub indeterminacy: Subspace,
+}
+
+impl MasseyProduct {
+    fn new(s: u32, t: i32, value: FpVector, indeterminacy: Vec<FpVector>) -> Self {
+        let dim = value.len();
+        let mut subspace = Subspace::new(value.prime(), dim + 1, dim);
+        subspace.add_vectors(indeterminacy.into_iter());
+        Self {
+            s,
+            t,
+            value,
+            indeterminacy: subspace,
+        }
+    }
+
+    pub fn n(&self) -> i32 {
+        self.t - self.s as i32
+    }
+
+    /// Whether `v` lies in the coset `value + indeterminacy`.
+    pub fn contains(&self, v: Slice) -> bool {
+        let mut v = v.to_owned();
+        v.add(&self.value, *self.value.prime() - 1);
+        self.indeterminacy.contains(v.as_slice())
+    }
+
+    pub fn contains_zero(&self) -> bool {
+        self.indeterminacy.contains(self.value.as_slice())
+    }
+
+    /// The representative of `value + indeterminacy` that is zero in the pivot columns of the
+    /// indeterminacy. This does not depend on the defining system used for a triple product.
+    pub fn reduced_value(&self) -> FpVector {
+        let mut value = self.value.clone();
+        self.indeterminacy.reduce(value.as_slice_mut());
+        value
+    }
+}
+
+/// Compute the Massey product $\langle a_1, \ldots, a_{n - 1}, x\rangle$, where $n$ is 3 or 4.
+/// This returns `None` if the Massey product is not defined.
+///
+/// See the [module level documentation](self) for the conventions used.
+pub fn massey_product<S, U>(
+    factors: &[Arc<ResolutionHomomorphism<U, U>>],
+    x: &Arc<ResolutionHomomorphism<S, U>>,
+) -> Option<MasseyProduct>
+where
+    S: FreeChainComplex,
+    U: FreeChainComplex<Algebra = S::Algebra> + AugmentedChainComplex,
+{
+    let factors: Vec<HomMatrix<U, U>> = factors.iter().map(|f| vec![vec![Arc::clone(f)]]).collect();
+    matric_massey_product(&factors, &vec![vec![Arc::clone(x)]])
+}
+
+/// Compute the matric Massey product $\langle A_1, \ldots, A_{n - 1}, X\rangle$, where $n$ is 3 or
+/// 4. The matrix $A_1$ must have a single row and $X$ a single column. This returns `None` if the
+/// Massey product is not defined.
+///
+/// The entries of the matrices may lie in different bidegrees, but the entries of each product of
+/// consecutive matrices must be homogeneous.
+///
+/// See the [module level documentation](self) for the conventions used.
+pub fn matric_massey_product<S, U>(
+    factors: &[HomMatrix<U, U>],
+    x: &HomMatrix<S, U>,
+) -> Option<MasseyProduct>
+where
+    S: FreeChainComplex,
+    U: FreeChainComplex<Algebra = S::Algebra> + AugmentedChainComplex,
+{
+    assert!(
+        factors.len() == 2 || factors.len() == 3,
+        "Only triple and quadruple Massey products are supported"
+    );
+    assert_eq!(factors[0].len(), 1, "The first matrix must have one row");
+    assert!(
+        x.iter().all(|row| row.len() == 1),
+        "The last matrix must have one column"
+    );
+    for (i, f) in factors.iter().enumerate() {
+        let next_rows = factors.get(i + 1).map_or(x.len(), Vec::len);
+        assert!(
+            f.iter().all(|row| row.len() == next_rows),
+            "Matrices have incompatible dimensions"
+        );
+        assert!(
+            f.iter().flatten().all(|hom| hom.shift_s > 0),
+            "Only the last factor may have filtration zero"
+        );
+    }
+
+    let factors: Vec<Elements<U, U>> = factors.iter().map(|f| from_homs(f)).collect();
+    let x = from_homs(x);
+
+    match &*factors {
+        [a, b] => triple_product(a, b, &x),
+        [a, b, c] => quadruple_product(a, b, c, &x),
+        _ => unreachable!(),
+    }
+}
+
+fn triple_product<S, U>(
+    a: &Elements<U, U>,
+    b: &Elements<U, U>,
+    c: &Elements<S, U>,
+) -> Option<MasseyProduct>
+where
+    S: FreeChainComplex,
+    U: FreeChainComplex<Algebra = S::Algebra> + AugmentedChainComplex,
+{
+    let minus = *c[0][0].prime() - 1;
+
+    let d_x12 = composites(product(minus, a, b));
+    let d_x23 = composites(product(minus, b, c));
+    if !is_zero(&d_x12) || !is_zero(&d_x23) {
+        return None;
+    }
+    let x12 = lifts(&d_x12);
+    let x23 = lifts(&d_x23);
+
+    let cycle = &composites(sum(product(minus, a, &x23), product(1, &x12, c)))[0][0];
+
+    // Changing x12 and x23 by cycles changes the value by the products of these cycles with c
+    // and a respectively.
+    let mut indeterminacy = Vec::new();
+    for (a, x) in a[0].iter().zip(&x23) {
+        for z in HomElement::basis_like(&x[0], true) {
+            indeterminacy.push(HomElement::composite(vec![(minus, Arc::clone(a), z)]).class());
+        }
+    }
+    for (x, c) in x12[0].iter().zip(c) {
+        for z in HomElement::basis_like(x, true) {
+            indeterminacy.push(HomElement::composite(vec![(1, z, Arc::clone(&c[0]))]).class());
+        }
+    }
+
+    Some(MasseyProduct::new(
+        cycle.shift_s,
+        cycle.shift_t,
+        cycle.class(),
+        indeterminacy,
+    ))
+}
+
+fn quadruple_product<S, U>(
+    a: &Elements<U, U>,
+    b: &Elements<U, U>,
+    c: &Elements<U, U>,
+    d: &Elements<S, U>,
+) -> Option<MasseyProduct>
+where
+    S: FreeChainComplex,
+    U: FreeChainComplex<Algebra = S::Algebra> + AugmentedChainComplex,
+{
+    let p = d[0][0].prime();
+    let minus = *p - 1;
+
+    let d_x12 = composites(product(minus, a, b));
+    let d_x23 = composites(product(minus, b, c));
+    let d_x34 = composites(product(minus, c, d));
+    if !is_zero(&d_x12) || !is_zero(&d_x23) || !is_zero(&d_x34) {
+        return None;
+    }
+    let x12 = lifts(&d_x12);
+    let x23 = lifts(&d_x23);
+    let x34 = lifts(&d_x34);
+
+    // The defining system is only determined up to adding cycles to x12, x23 and x34. We
+    // parametrize these choices by the coordinates of the classes of these cycles.
+    let mut num_params = 0;
+    let z12 = parameters(&x12, &mut num_params);
+    let z23 = parameters(&x23, &mut num_params);
+    let z34 = parameters(&x34, &mut num_params);
+
+    // The obstructions to finding x13 and x24 are the classes of their would-be boundaries. These
+    // are affine functions of the parameters, and we solve for the parameters that kill them.
+    let d_x13 = composites(sum(product(minus, a, &x23), product(1, &x12, c)));
+    let d_x24 = composites(sum(product(minus, b, &x34), product(1, &x23, d)));
+
+    let obstructions: Vec<FpVector> = d_x13[0]
+        .iter()
+        .map(|x| x.class())
+        .chain(d_x24.iter().map(|row| row[0].class()))
+        .collect();
+    let mut offsets = Vec::with_capacity(obstructions.len() + 1);
+    offsets.push(0);
+    for o in &obstructions {
+        offsets.push(offsets.last().unwrap() + o.len());
+    }
+    let o13 = |q: usize| q;
+    let o24 = |p: usize| d_x13[0].len() + p;
+
+    let num_obstructions = *offsets.last().unwrap();
+    let mut matrix =
+        AugmentedMatrix::<2>::new(p, num_params + 1, [num_obstructions, num_params + 1]);
+    let mut add_obstruction = |param: usize, entry: usize, class: FpVector| {
+        matrix
+            .row_segment(param, 0, 0)
+            .slice_mut(offsets[entry], offsets[entry + 1])
+            .add(class.as_slice(), 1);
+    };
+
+    for (p, row) in z23.iter().enumerate() {
+        for (q, entry) in row.iter().enumerate() {
+            for (i, z) in entry {
+                let class =
+                    HomElement::composite(vec![(minus, Arc::clone(&a[0][p]), Arc::clone(z))])
+                        .class();
+                add_obstruction(*i, o13(q), class);
+                let class =
+                    HomElement::composite(vec![(1, Arc::clone(z), Arc::clone(&d[q][0]))]).class();
+                add_obstruction(*i, o24(p), class);
+            }
+        }
+    }
+    for (r, entry) in z12[0].iter().enumerate() {
+        for (i, z) in entry {
+            for (q, c) in c[r].iter().enumerate() {
+                let class = HomElement::composite(vec![(1, Arc::clone(z), Arc::clone(c))]).class();
+                add_obstruction(*i, o13(q), class);
+            }
+        }
+    }
+    for (r, entry) in z34.iter().enumerate() {
+        for (i, z) in &entry[0] {
+            for (p, b) in b.iter().enumerate() {
+                let class =
+                    HomElement::composite(vec![(minus, Arc::clone(&b[r]), Arc::clone(z))]).class();
+                add_obstruction(*i, o24(p), class);
+            }
+        }
+    }
+    for (entry, class) in obstructions.into_iter().enumerate() {
+        add_obstruction(num_params, entry, class);
+    }
+    for i in 0..num_params {
+        matrix.row_segment(i, 1, 1).set_entry(i + 1, 1);
+    }
+    matrix.row_segment(num_params, 1, 1).set_entry(0, 1);
+
+    matrix.row_reduce();
+    let solutions = matrix.compute_kernel();
+    if solutions.pivots()[0] < 0 {
+        return None;
+    }
+    let particular = solutions[solutions.pivots()[0] as usize].slice(1, num_params + 1);
+    let kernel: Vec<Slice> = solutions
+        .basis()
+        .iter()
+        .filter(|v| v.entry(0) == 0)
+        .map(|v| v.slice(1, num_params + 1))
+        .collect();
+
+    let x12 = adjust(&x12, &z12, particular, true);
+    let x23 = adjust(&x23, &z23, particular, true);
+    let x34 = adjust(&x34, &z34, particular, true);
+    let x24 = lifts(&composites(sum(
+        product(minus, b, &x34),
+        product(1, &x23, d),
+    )));
+
+    // The value is a quadratic function on the space of solutions. Write it as
+    // value + sum_i λ_i linear_i + sum_{i, j} λ_i λ_j quadratic_ij.
+    let cycle = &composites(sum(product(minus, a, &x24), product(1, &x12, &x34)))[0][0];
+    let value = cycle.class();
+
+    let dz12: Vec<_> = kernel
+        .iter()
+        .map(|k| adjust(&x12, &z12, *k, false))
+        .collect();
+    let dz34: Vec<_> = kernel
+        .iter()
+        .map(|k| adjust(&x34, &z34, *k, false))
+        .collect();
+    let mut linear = Vec::with_capacity(kernel.len());
+    for (i, k) in kernel.iter().enumerate() {
+        let dz23 = adjust(&x23, &z23, *k, false);
+        let dx24 = lifts(&composites(sum(
+            product(minus, b, &dz34[i]),
+            product(1, &dz23, d),
+        )));
+        let terms = sum(
+            sum(product(minus, a, &dx24), product(1, &x12, &dz34[i])),
+            product(1, &dz12[i], &x34),
+        );
+        linear.push(composites(terms)[0][0].class());
+    }
+    let quadratic = |i: usize, j: usize| composites(product(1, &dz12[i], &dz34[j]))[0][0].class();
+
+    let mut indeterminacy = Vec::new();
+    for (i, l) in linear.iter().enumerate() {
+        let mut q = quadratic(i, i);
+        if *p == 2 {
+            q.add(l, 1);
+        } else {
+            indeterminacy.push(l.clone());
+        }
+        indeterminacy.push(q);
+        for j in 0..i {
+            let mut q = quadratic(i, j);
+            q.add(&quadratic(j, i), 1);
+            indeterminacy.push(q);
+        }
+    }
+
+    // Changing x13 and x24 by cycles changes the value by the products of these cycles with d
+    // and a respectively.
+    for (a, x) in a[0].iter().zip(&x24) {
+        for w in HomElement::basis_like(&x[0], false) {
+            indeterminacy.push(HomElement::composite(vec![(minus, Arc::clone(a), w)]).class());
+        }
+    }
+    for (x, d) in d_x13[0].iter().zip(d) {
+        for w in HomElement::basis(&x.source, &x.target, x.shift_s - 1, x.shift_t, false) {
+            indeterminacy.push(HomElement::composite(vec![(minus, w, Arc::clone(&d[0]))]).class());
+        }
+    }
+
+    Some(MasseyProduct::new(
+        cycle.shift_s,
+        cycle.shift_t,
+        value,
+        indeterminacy,
+    ))
+}
+
+/// A matrix of elements of the Hom complex.
+type Elements<S, U> = Vec<Vec<Arc<HomElement<S, U>>>>;
+
+/// A matrix whose entries are linear combinations of composites.
+type Terms<S, U> = Vec<Vec<Vec<Composite<S, U>>>>;
+
+type Composite<S, U> = (u32, Arc<HomElement<U, U>>, Arc<HomElement<S, U>>);
+
+fn from_homs<S, U>(homs: &HomMatrix<S, U>) -> Elements<S, U>
+where
+    S: FreeChainComplex,
+    U: FreeChainComplex<Algebra = S::Algebra> + AugmentedChainComplex,
+{
+    homs.iter()
+        .map(|row| {
+            row.iter()
+                .map(|hom| HomElement::from_hom(Arc::clone(hom), false))
+                .collect()
+        })
+        .collect()
+}
+
+/// The terms of the matrix product `coef * f * g`.
+fn product<S, U>(coef: u32, f: &Elements<U, U>, g: &Elements<S, U>) -> Terms<S, U>
+where
+    S: FreeChainComplex,
+    U: FreeChainComplex<Algebra = S::Algebra> + AugmentedChainComplex,
+{
+    f.iter()
+        .map(|row| {
+            (0..g[0].len())
+                .map(|j| {
+                    row.iter()
+                        .zip(g)
+                        .map(|(x, g)| (coef, Arc::clone(x), Arc::clone(&g[j])))
+                        .collect()
+                })
+                .collect()
+        })
+        .collect()
+}
+
+fn sum<S, U>(mut x: Terms<S, U>, y: Terms<S, U>) -> Terms<S, U>
+where
+    S: FreeChainComplex,
+    U: FreeChainComplex<Algebra = S::Algebra> + AugmentedChainComplex,
+{
+    for (x, y) in x.iter_mut().flatten().zip(y.into_iter().flatten()) {
+        x.extend(y);
+    }
+    x
+}
+
+fn composites<S, U>(terms: Terms<S, U>) -> Elements<S, U>
+where
+    S: FreeChainComplex,
+    U: FreeChainComplex<Algebra = S::Algebra> + AugmentedChainComplex,
+{
+    terms
+        .into_iter()
+        .map(|row| row.into_iter().map(HomElement::composite).collect())
+        .collect()
+}
+
+fn lifts<S, U>(cycles: &Elements<S, U>) -> Elements<S, U>
+where
+    S: FreeChainComplex,
+    U: FreeChainComplex<Algebra = S::Algebra> + AugmentedChainComplex,
+{
+    cycles
+        .iter()
+        .map(|row| {
+            row.iter()
+                .map(|x| HomElement::lift(Arc::clone(x)))
+                .collect()
+        })
+        .collect()
+}
+
+fn is_zero<S, U>(cycles: &Elements<S, U>) -> bool
+where
+    S: FreeChainComplex,
+    U: FreeChainComplex<Algebra = S::Algebra> + AugmentedChainComplex,
+{
+    cycles.iter().flatten().all(|x| x.class().is_zero())
+}
+
+/// For each entry of `x`, the cycles lifting a basis of $\Ext$ in its bidegree, labelled by
+/// consecutive indices starting from `start`, which is incremented accordingly.
+#[allow(clippy::type_complexity)]
+fn parameters<S, U>(
+    x: &Elements<S, U>,
+    start: &mut usize,
+) -> Vec<Vec<Vec<(usize, Arc<HomElement<S, U>>)>>>
+where
+    S: FreeChainComplex,
+    U: FreeChainComplex<Algebra = S::Algebra> + AugmentedChainComplex,
+{
+    x.iter()
+        .map(|row| {
+            row.iter()
+                .map(|x| {
+                    HomElement::basis_like(x, x.odd)
+                        .into_iter()
+                        .map(|z| {
+                            *start += 1;
+                            (*start - 1, z)
+                        })
+                        .collect()
+                })
+                .collect()
+        })
+        .collect()
+}
+
+/// Add to `x` the linear combination of `parameters` with coefficients `coefs`. If `base` is
+/// false, this returns the linear combination itself.
+fn adjust<S, U>(
+    x: &Elements<S, U>,
+    parameters: &[Vec<Vec<(usize, Arc<HomElement<S, U>>)>>],
+    coefs: Slice,
+    base: bool,
+) -> Elements<S, U>
+where
+    S: FreeChainComplex,
+    U: FreeChainComplex<Algebra = S::Algebra> + AugmentedChainComplex,
+{
+    x.iter()
+        .zip(parameters)
+        .map(|(row, params)| {
+            row.iter()
+                .zip(params)
+                .map(|(x, params)| {
+                    let mut terms: Vec<_> = params
+                        .iter()
+                        .map(|(i, z)| (coefs.entry(*i), Arc::clone(z)))
+                        .filter(|(c, _)| *c != 0)
+                        .collect();
+                    if base {
+                        terms.push((1, Arc::clone(x)));
+                    }
+                    HomElement::sum(x, terms)
+                })
+                .collect()
+        })
+        .collect()
+}
+
+/// An element of the Hom complex $\Hom(C, U)$, where $C$ is a free chain complex and $U$ is the
+/// resolution of the unit. This is a family of maps $C_\sigma \to U_{\sigma - s}$ of internal
+/// degree $t$ for $\sigma \geq s$, where $(s, t)$ is the shift of the element.
+struct HomElement<S, U>
+where
+    S: FreeChainComplex,
+    U: FreeChainComplex<Algebra = S::Algebra> + AugmentedChainComplex,
+{
+    source: Arc<S>,
+    target: Arc<U>,
+    shift_s: u32,
+    shift_t: i32,
+    odd: bool,
+    kind: Kind<S, U>,
+}
+
+enum Kind<S, U>
+where
+    S: FreeChainComplex,
+    U: FreeChainComplex<Algebra = S::Algebra> + AugmentedChainComplex,
+{
+    /// A chain map. If the element is odd, the map on $C_\sigma$ is multiplied by $(-1)^{\sigma -
+    /// s}$ so that it anticommutes with the differentials.
+    Map(Arc<ResolutionHomomorphism<S, U>>),
+    /// A linear combination of elements.
+    Sum(Vec<(u32, Arc<HomElement<S, U>>)>),
+    /// A linear combination of composites.
+    Composite(Vec<Composite<S, U>>),
+    /// A null-homotopy $h$ of `cycle`, i.e. $Dh$ is `cycle`. The component landing in $U_0$ is
+    /// zero.
+    Lift {
+        cycle: Arc<HomElement<S, U>>,
+        maps: OnceBiVec<FreeModuleHomomorphism<U::Module>>,
+    },
+}
+
+impl<S, U> HomElement<S, U>
+where
+    S: FreeChainComplex,
+    U: FreeChainComplex<Algebra = S::Algebra> + AugmentedChainComplex,
+{
+    fn from_hom(hom: Arc<ResolutionHomomorphism<S, U>>, odd: bool) -> Arc<Self> {
+        Arc::new(Self {
+            source: Arc::clone(&hom.source),
+            target: Arc::clone(&hom.target),
+            shift_s: hom.shift_s,
+            shift_t: hom.shift_t,
+            odd,
+            kind: Kind::Map(hom),
+        })
+    }
+
+    /// The chain maps lifting the basis of $\Ext^{s, t}$.
+    fn basis(source: &Arc<S>, target: &Arc<U>, s: u32, t: i32, odd: bool) -> Vec<Arc<Self>> {
+        let num_gens = source.number_of_gens_in_bidegree(s, t);
+        (0..num_gens)
+            .map(|i| {
+                let mut class = vec![0; num_gens];
+                class[i] = 1;
+                let hom = ResolutionHomomorphism::from_class(
+                    String::new(),
+                    Arc::clone(source),
+                    Arc::clone(target),
+                    s,
+                    t,
+                    &class,
+                );
+                Self::from_hom(Arc::new(hom), odd)
+            })
+            .collect()
+    }
+
+    /// The chain maps lifting the basis of $\Ext$ in the bidegree of `x`.
+    fn basis_like(x: &Self, odd: bool) -> Vec<Arc<Self>> {
+        Self::basis(&x.source, &x.target, x.shift_s, x.shift_t, odd)
+    }
+
+    /// A linear combination of elements with the same shift and parity as `like`.
+    fn sum(like: &Self, terms: Vec<(u32, Arc<Self>)>) -> Arc<Self> {
+        for (_, x) in &terms {
+            assert_eq!(
+                (x.shift_s, x.shift_t, x.odd),
+                (like.shift_s, like.shift_t, like.odd)
+            );
+        }
+        Arc::new(Self {
+            source: Arc::clone(&like.source),
+            target: Arc::clone(&like.target),
+            shift_s: like.shift_s,
+            shift_t: like.shift_t,
+            odd: like.odd,
+            kind: Kind::Sum(terms),
+        })
+    }
+
+    fn composite(terms: Vec<Composite<S, U>>) -> Arc<Self> {
+        let (_, f, g) = &terms[0];
+        let shift_s = f.shift_s + g.shift_s;
+        let shift_t = f.shift_t + g.shift_t;
+        let odd = f.odd != g.odd;
+        for (_, f, g) in &terms {
+            assert!(
+                f.shift_s + g.shift_s == shift_s && f.shift_t + g.shift_t == shift_t,
+                "Entries of the product of matrices are not homogeneous"
+            );
+        }
+        Arc::new(Self {
+            source: Arc::clone(&g.source),
+            target: Arc::clone(&f.target),
+            shift_s,
+            shift_t,
+            odd,
+            kind: Kind::Composite(terms),
+        })
+    }
+
+    fn lift(cycle: Arc<Self>) -> Arc<Self> {
+        let shift_s = cycle.shift_s - 1;
+        Arc::new(Self {
+            source: Arc::clone(&cycle.source),
+            target: Arc::clone(&cycle.target),
+            shift_s,
+            shift_t: cycle.shift_t,
+            odd: !cycle.odd,
+            kind: Kind::Lift {
+                cycle,
+                maps: OnceBiVec::new(shift_s as i32),
+            },
+        })
+    }
+
+    fn prime(&self) -> ValidPrime {
+        self.source.prime()
+    }
+
+    fn stem(&self) -> i32 {
+        self.shift_t - self.shift_s as i32
+    }
+
+    /// Compute the element on $C_\sigma$ for $\sigma \leq$ `max_s`, through stem `max_n`.
+    fn extend_through_stem(&self, max_s: u32, max_n: i32) {
+        if max_s < self.shift_s {
+            return;
+        }
+        match &self.kind {
+            Kind::Map(hom) => hom.extend_through_stem(max_s, max_n),
+            Kind::Sum(terms) => {
+                for (_, x) in terms {
+                    x.extend_through_stem(max_s, max_n);
+                }
+            }
+            Kind::Composite(terms) => {
+                for (_, f, g) in terms {
+                    g.extend_through_stem(max_s, max_n);
+                    f.extend_through_stem(max_s - g.shift_s, max_n - g.stem());
+                }
+            }
+            Kind::Lift { cycle, maps } => {
+                cycle.extend_through_stem(max_s, max_n);
+                maps.extend(max_s as i32, |s| {
+                    FreeModuleHomomorphism::new(
+                        self.source.module(s as u32),
+                        self.target.module(s as u32 - self.shift_s),
+                        self.shift_t,
+                    )
+                });
+                for s in self.shift_s..=max_s {
+                    for t in maps[s as i32].next_degree()..=max_n + s as i32 {
+                        self.lift_step(cycle, maps, s, t);
+                    }
+                }
+            }
+        }
+    }
+
+    fn lift_step(
+        &self,
+        cycle: &Self,
+        maps: &OnceBiVec<FreeModuleHomomorphism<U::Module>>,
+        s: u32,
+        t: i32,
+    ) {
+        let p = self.prime();
+        let target_s = s - self.shift_s;
+        let target_t = t - self.shift_t;
+        assert!(self.source.has_computed_bidegree(s, t));
+        assert!(self.target.has_computed_bidegree(target_s, target_t));
+
+        let num_gens = self.source.number_of_gens_in_bidegree(s, t);
+        let target_dim = self.target.module(target_s).dimension(target_t);
+        let mut outputs = vec![FpVector::new(p, target_dim); num_gens];
+
+        if target_s > 0 && num_gens > 0 && target_dim > 0 {
+            // We need d h = cycle + (-1)^{|h|} h d.
+            let sign = if self.odd { *p - 1 } else { 1 };
+            let d = self.source.differential(s);
+            let scratch_dim = self.target.module(target_s - 1).dimension(target_t);
+            let scratches: Vec<FpVector> = (0..num_gens)
+                .map(|i| {
+                    let mut scratch = FpVector::new(p, scratch_dim);
+                    let generator = self.generator(s, t, i);
+                    cycle.apply(scratch.as_slice_mut(), 1, s, t, generator.as_slice());
+                    maps[s as i32 - 1].apply(
+                        scratch.as_slice_mut(),
+                        sign,
+                        t,
+                        d.output(t, i).as_slice(),
+                    );
+                    scratch
+                })
+                .collect();
+
+            assert!(self
+                .target
+                .apply_quasi_inverse(&mut outputs, target_s, target_t, &scratches));
+
+            #[cfg(debug_assertions)]
+            for (output, scratch) in outputs.iter().zip(&scratches) {
+                let mut image = FpVector::new(p, scratch_dim);
+                self.target.differential(target_s).apply(
+                    image.as_slice_mut(),
+                    1,
+                    target_t,
+                    output.as_slice(),
+                );
+                assert!(
+                    image == *scratch,
+                    "Failed to lift at (s, t) = ({target_s}, {target_t})"
+                );
+            }
+        }
+        maps[s as i32].add_generators_from_rows(t, outputs);
+    }
+
+    /// The `i`th generator of $C_s$ in degree `t`.
+    fn generator(&self, s: u32, t: i32, i: usize) -> FpVector {
+        let module = self.source.module(s);
+        let mut result = FpVector::new(self.prime(), module.dimension(t));
+        result.set_entry(module.operation_generator_to_index(0, 0, t, i), 1);
+        result
+    }
+
+    /// Add `coef` times the image of `input`, an element of $C_s$ in degree `t`, to `result`.
+    fn apply(&self, mut result: SliceMut, coef: u32, s: u32, t: i32, input: Slice) {
+        let p = *self.prime();
+        match &self.kind {
+            Kind::Map(hom) => {
+                let coef = if self.odd && (s - self.shift_s) % 2 == 1 {
+                    coef * (p - 1) % p
+                } else {
+                    coef
+                };
+                hom.get_map(s).apply(result, coef, t, input);
+            }
+            Kind::Sum(terms) => {
+                for (c, x) in terms {
+                    x.apply(result.copy(), c * coef % p, s, t, input);
+                }
+            }
+            Kind::Composite(terms) => {
+                for (c, f, g) in terms {
+                    let mid_s = s - g.shift_s;
+                    let mid_t = t - g.shift_t;
+                    let mut scratch =
+                        FpVector::new(self.prime(), self.target.module(mid_s).dimension(mid_t));
+                    g.apply(scratch.as_slice_mut(), 1, s, t, input);
+                    f.apply(
+                        result.copy(),
+                        c * coef % p,
+                        mid_s,
+                        mid_t,
+                        scratch.as_slice(),
+                    );
+                }
+            }
+            Kind::Lift { maps, .. } => maps[s as i32].apply(result, coef, t, input),
+        }
+    }
+
+    /// The class of a cycle in $\Ext^{s, t}$, where $(s, t)$ is the shift of the cycle.
+    fn class(&self) -> FpVector {
+        let (s, t) = (self.shift_s, self.shift_t);
+        self.extend_through_stem(s, self.stem());
+
+        let unit = self.target.module(0);
+        let idx = unit.operation_generator_to_index(0, 0, 0, 0);
+        let mut output = FpVector::new(self.prime(), unit.dimension(0));
+
+        let num_gens = self.source.number_of_gens_in_bidegree(s, t);
+        let mut result = FpVector::new(self.prime(), num_gens);
+        for i in 0..num_gens {
+            output.set_to_zero();
+            let generator = self.generator(s, t, i);
+            self.apply(output.as_slice_mut(), 1, s, t, generator.as_slice());
+            result.set_entry(i, output.entry(idx));
+        }
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::resolution::Resolution;
+    use crate::utils::construct;
+    use crate::CCC;
+
+    type Hom = ResolutionHomomorphism<Resolution<CCC>, Resolution<CCC>>;
+
+    fn class(resolution: &Arc<Resolution<CCC>>, n: i32, s: u32, class: &[u32]) -> Arc<Hom> {
+        Arc::new(ResolutionHomomorphism::from_class(
+            String::new(),
+            Arc::clone(resolution),
+            Arc::clone(resolution),
+            s,
+            n + s as i32,
+            class,
+        ))
+    }
+
+    fn sphere(spec: &str, max_s: u32, max_n: i32) -> Arc<Resolution<CCC>> {
+        let resolution = construct(spec, None).unwrap();
+        resolution.compute_through_stem(max_s, max_n);
+        Arc::new(resolution)
+    }
+
+    #[test]
+    fn triple() {
+        let resolution = sphere("S_2", 3, 4);
+        let h0 = class(&resolution, 0, 1, &[1]);
+        let h1 = class(&resolution, 1, 1, &[1]);
+
+        let product = massey_product(&[Arc::clone(&h0), Arc::clone(&h1)], &h0).unwrap();
+        assert_eq!((product.n(), product.s), (2, 2));
+        assert_eq!(
+            product.value,
+            FpVector::from_slice(product.value.prime(), &[1])
+        );
+        assert_eq!(product.indeterminacy.dimension(), 0);
+
+        let product = massey_product(&[Arc::clone(&h1), Arc::clone(&h0)], &h1).unwrap();
+        assert_eq!((product.n(), product.s), (3, 2));
+        assert!(product.contains(FpVector::from_slice(product.value.prime(), &[1]).as_slice()));
+
+        // h0 h0 is non-zero
+        assert!(massey_product(&[Arc::clone(&h1), Arc::clone(&h0)], &h0).is_none());
+    }
+
+    #[test]
+    fn triple_odd_prime() {
+        let p = ValidPrime::new(3);
+        let resolution = sphere("S_3", 3, 20);
+        let h0 = class(&resolution, 3, 1, &[1]);
+        let h1 = class(&resolution, 11, 1, &[1]);
+
+        // <h0, h0, h0> = -b0 at p = 3, with no indeterminacy since Ext^{1, 8} = 0.
+        let product = massey_product(&[Arc::clone(&h0), Arc::clone(&h0)], &h0).unwrap();
+        assert_eq!((product.n(), product.s), (10, 2));
+        assert_eq!(product.indeterminacy.dimension(), 0);
+        assert_eq!(product.value, FpVector::from_slice(p, &[2]));
+
+        // Brackets of h0, h0 and h1 lie in the one-dimensional group in stem 18. The three
+        // cyclic permutations sum to zero, and the indeterminacy vanishes since Ext^1 is zero in
+        // stems 7 and 15.
+        assert_eq!(resolution.number_of_gens_in_bidegree(2, 20), 1);
+        for (a, b, c) in [(&h0, &h1, &h0), (&h1, &h0, &h0), (&h0, &h0, &h1)] {
+            let product = massey_product(&[Arc::clone(a), Arc::clone(b)], c).unwrap();
+            assert_eq!((product.n(), product.s), (18, 2));
+            assert_eq!(product.indeterminacy.dimension(), 0);
+            assert_eq!(product.value, FpVector::from_slice(p, &[2]));
+        }
+    }
+
+    #[test]
+    fn module() {
+        // <h1, h0, 1> is the element of Ext(C2) in stem 2
+        let unit = sphere("S_2", 2, 3);
+        let c2 = sphere("C2", 2, 3);
+        let h0 = class(&unit, 0, 1, &[1]);
+        let h1 = class(&unit, 1, 1, &[1]);
+        let bottom = Arc::new(ResolutionHomomorphism::from_class(
+            String::new(),
+            Arc::clone(&c2),
+            Arc::clone(&unit),
+            0,
+            0,
+            &[1],
+        ));
+
+        let product = massey_product(&[h1, h0], &bottom).unwrap();
+        assert_eq!((product.n(), product.s), (2, 1));
+        assert_eq!(product.indeterminacy.dimension(), 0);
+        assert!(!product.value.is_zero());
+    }
+
+    #[test]
+    fn matric() {
+        // <h0, (h1 0), (h0, h1)^T> contains <h0, h1, h0> = h1^2
+        let resolution = sphere("S_2", 3, 4);
+        let zero = class(&resolution, 0, 1, &[0]);
+        let h0 = class(&resolution, 0, 1, &[1]);
+        let h1 = class(&resolution, 1, 1, &[1]);
+
+        let product = matric_massey_product(
+            &[
+                vec![vec![Arc::clone(&h0)]],
+                vec![vec![Arc::clone(&h1), zero]],
+            ],
+            &vec![vec![Arc::clone(&h0)], vec![h1]],
+        )
+        .unwrap();
+        assert_eq!((product.n(), product.s), (2, 2));
+        assert!(product.contains(FpVector::from_slice(product.value.prime(), &[1]).as_slice()));
+    }
+
+    #[test]
+    fn quadruple() {
+        // d0 = <h0, h2^2, h0, h2^2>
+        let resolution = sphere("S_2", 4, 14);
+        let h0 = class(&resolution, 0, 1, &[1]);
+        let h2_2 = class(&resolution, 6, 2, &[1]);
+
+        let product = massey_product(
+            &[Arc::clone(&h0), Arc::clone(&h2_2), Arc::clone(&h0)],
+            &h2_2,
+        )
+        .unwrap();
+        assert_eq!((product.n(), product.s), (14, 4));
+        assert!(!product.contains_zero());
+    }
+}
@@ -0,0 +1,544 @@
+//! The May spectral sequence at the prime 2.
+//!
+//! We give the dual Steenrod algebra $A_*$ the May filtration, in which $\xi_i^{2^j}$ has weight
+//! $2i - 1$ and the weight of a monomial is the sum of the weights of its factors. The associated
+//! graded $E^0 A_*$ is an exterior algebra on the primitive classes $\xi_{i, j} = [\xi_i^{2^j}]$, so
+//! the cobar complex of $A_*$, filtered by the total weight of each tensor, gives a spectral
+//! sequence
+//! $$ E_1 = \mathbb{F}_2[h_{i, j} \mid i \geq 1, j \geq 0] \Rightarrow \Ext_A(\mathbb{F}_2,
+//! \mathbb{F}_2). $$
+//! Here $h_{i, j}$ is the class of $[\xi_i^{2^j}]$ and $h_j = h_{1, j}$.
+//!
+//! In terms of the classical May filtration, where $h_{i, j}$ has filtration $i$, a May $d_r$
+//! lowers the filtration by $r - 1$ and the weight by $2r - 1$. We index the pages in the
+//! classical way, so that $d_1(h_{i, j}) = \sum_{0 < k < i} h_{k, j} h_{i - k, k + j}$.
+//!
+//! We compute the cobar complex from the multiplication of the [`MilnorAlgebra`], since the
+//! Milnor basis is dual to the monomial basis of $A_*$. The spectral sequence of the filtered
+//! complex is then computed by row reducing the differential with the basis sorted by weight, as in
+//! the computation of persistent homology; see [`curtis`](crate::curtis) for the same algorithm
+//! applied to the Lambda algebra. Each basis element of $E_1$ is named after the leading term of
+//! its representative, which is a monomial in the $h_{i, j}$ whenever possible.
+//!
+//! The cobar complex grows quickly, so this is only practical in a small range.
+
+use std::fmt::Write as _;
+
+use algebra::{Algebra, MilnorAlgebra};
+use fp::prime::ValidPrime;
+use fp::vector::{FpVector, Slice};
+use itertools::Itertools;
+use rustc_hash::FxHashMap as HashMap;
+use sseq::{May, Sseq};
+
+/// A basis element of the cobar complex, given by its factors as indices into
+/// `MaySpectralSequence::factors`.
+type Tensor = Vec<u32>;
+
+/// The cobar complex in a fixed internal degree and homological degree. The basis is sorted by
+/// weight.
+struct CobarDegree {
+    basis: Vec<Tensor>,
+    weights: Vec<i32>,
+    index: HashMap<Tensor, usize>,
+    /// The basis elements that survive to $E_1$, i.e. those that are not paired with a basis
+    /// element of the same weight.
+    e1: Vec<usize>,
+    /// The positions in `e1` of the permanent cycles that are not boundaries.
+    e_infinity: Vec<usize>,
+    /// position in `e1` -> (r, position in `e1` of the target of the $d_r$)
+    differentials: HashMap<usize, (i32, usize)>,
+}
+
+/// The May spectral sequence for $\Ext_A(\mathbb{F}_2, \mathbb{F}_2)$ through a fixed internal
+/// degree.
+pub struct MaySpectralSequence {
+    algebra: MilnorAlgebra,
+    /// The basis elements of $\bar{A}$ as (degree, index), in increasing degree
+    factors: Vec<(i32, usize)>,
+    /// degree -> index of the first factor of that degree
+    factor_offsets: Vec<usize>,
+    /// factor -> weight
+    factor_weights: Vec<i32>,
+    /// factor -> terms of the reduced coproduct of the dual basis element
+    coproducts: Vec<Vec<(u32, u32)>>,
+    /// t -> s -> cobar complex
+    cobar: Vec<Vec<CobarDegree>>,
+    sseq: Sseq<May>,
+}
+
+impl MaySpectralSequence {
+    /// Compute the May spectral sequence in all bidegrees with $t \leq$ `max_t`.
+    pub fn new(max_t: i32) -> Self {
+        let p = ValidPrime::new(2);
+        let algebra = MilnorAlgebra::new(p);
+        algebra.compute_basis(max_t);
+
+        let mut factors = Vec::new();
+        let mut factor_offsets = vec![0, 0];
+        let mut factor_weights = Vec::new();
+        for t in 1..=max_t {
+            for idx in 0..algebra.dimension(t) {
+                factors.push((t, idx));
+                let elt = algebra.basis_element_from_index(t, idx);
+                factor_weights.push(
+                    elt.p_part
+                        .iter()
+                        .enumerate()
+                        .map(|(i, &r)| (2 * i as i32 + 1) * r.count_ones() as i32)
+                        .sum(),
+                );
+            }
+            factor_offsets.push(factors.len());
+        }
+
+        let mut result = Self {
+            algebra,
+            factors,
+            factor_offsets,
+            factor_weights,
+            coproducts: Vec::new(),
+            cobar: Vec::new(),
+            sseq: Sseq::new(p, 0, 0),
+        };
+        result.compute_coproducts();
+        for t in 0..=max_t {
+            result.compute_cobar(t);
+        }
+        result.compute_sseq();
+        result
+    }
+
+    fn factor(&self, t: i32, idx: usize) -> u32 {
+        (self.factor_offsets[t as usize] + idx) as u32
+    }
+
+    /// The coefficient of $\xi^{R'} \otimes \xi^{R''}$ in the coproduct of $\xi^R$ is the
+    /// coefficient of $Sq(R)$ in $Sq(R') Sq(R'')$.
+    fn compute_coproducts(&mut self) {
+        let p = self.algebra.prime();
+        let mut coproducts = vec![Vec::new(); self.factors.len()];
+        let max_t = self.factor_offsets.len() as i32 - 2;
+        for t in 2..=max_t {
+            let mut product = FpVector::new(p, self.algebra.dimension(t));
+            for left_t in 1..t {
+                let right_t = t - left_t;
+                for left in 0..self.algebra.dimension(left_t) {
+                    for right in 0..self.algebra.dimension(right_t) {
+                        product.set_to_zero();
+                        self.algebra.multiply_basis_elements(
+                            product.as_slice_mut(),
+                            1,
+                            left_t,
+                            left,
+                            right_t,
+                            right,
+                        );
+                        for (idx, _) in product.iter_nonzero() {
+                            coproducts[self.factor(t, idx) as usize]
+                                .push((self.factor(left_t, left), self.factor(right_t, right)));
+                        }
+                    }
+                }
+            }
+        }
+        self.coproducts = coproducts;
+    }
+
+    /// Whether the factor is $\xi_i^{2^j}$ for some $i, j$, in which case return $(i, j)$.
+    fn primitive(&self, factor: u32) -> Option<(usize, u32)> {
+        let (t, idx) = self.factors[factor as usize];
+        let p_part = &self.algebra.basis_element_from_index(t, idx).p_part;
+        let mut nonzero = p_part.iter().enumerate().filter(|(_, &r)| r != 0);
+        match (nonzero.next(), nonzero.next()) {
+            (Some((i, &r)), None) if r.is_power_of_two() => Some((i + 1, r.trailing_zeros())),
+            _ => None,
+        }
+    }
+
+    fn compute_cobar(&mut self, t: i32) {
+        let mut degrees = Vec::with_capacity(t as usize + 1);
+        for s in 0..=t as usize {
+            let mut basis: Vec<Tensor> = Vec::new();
+            if s == 0 {
+                if t == 0 {
+                    basis.push(Vec::new());
+                }
+            } else {
+                for first_t in 1..=t {
+                    let tails = &self.cobar[(t - first_t) as usize];
+                    if s > tails.len() {
+                        continue;
+                    }
+                    for first in self.factor_offsets[first_t as usize]
+                        ..self.factor_offsets[first_t as usize + 1]
+                    {
+                        for tail in &tails[s - 1].basis {
+                            let mut tensor = Vec::with_capacity(s);
+                            tensor.push(first as u32);
+                            tensor.extend_from_slice(tail);
+                            basis.push(tensor);
+                        }
+                    }
+                }
+            }
+
+            // Within each weight, tensors of primitives come last so that they are preferred as
+            // leading terms.
+            basis.sort_by_cached_key(|tensor| {
+                (
+                    self.weight(tensor),
+                    tensor.iter().all(|&f| self.primitive(f).is_some()),
+                    tensor.clone(),
+                )
+            });
+            let weights = basis.iter().map(|tensor| self.weight(tensor)).collect();
+            let index = basis
+                .iter()
+                .enumerate()
+                .map(|(i, tensor)| (tensor.clone(), i))
+                .collect();
+            degrees.push(CobarDegree {
+                basis,
+                weights,
+                index,
+                e1: Vec::new(),
+                e_infinity: Vec::new(),
+                differentials: HashMap::default(),
+            });
+        }
+        self.cobar.push(degrees);
+    }
+
+    fn weight(&self, tensor: &[u32]) -> i32 {
+        tensor
+            .iter()
+            .map(|&f| self.factor_weights[f as usize])
+            .sum()
+    }
+
+    /// The cobar differential of the `idx`th basis element of $C^{s, t}$.
+    fn cobar_differential(&self, t: i32, s: usize, idx: usize) -> FpVector {
+        let p = self.algebra.prime();
+        let target = &self.cobar[t as usize][s + 1];
+        let mut result = FpVector::new(p, target.basis.len());
+        let tensor = &self.cobar[t as usize][s].basis[idx];
+        let mut term = Vec::with_capacity(s + 1);
+        for (k, &factor) in tensor.iter().enumerate() {
+            for &(left, right) in &self.coproducts[factor as usize] {
+                term.clear();
+                term.extend_from_slice(&tensor[..k]);
+                term.push(left);
+                term.push(right);
+                term.extend_from_slice(&tensor[k + 1..]);
+                result.add_basis_element(target.index[&term], 1);
+            }
+        }
+        result
+    }
+
+    /// Row reduce the cobar complex in degree `t` and record the pairs of basis elements that
+    /// cancel. Returns the pairs `(s, source, target, gap)`, where `gap` is the difference in
+    /// weight, and the unpaired cycles `(s, idx)`.
+    #[allow(clippy::type_complexity)]
+    fn reduce(&self, t: i32) -> (Vec<(usize, usize, usize, i32)>, Vec<(usize, usize)>) {
+        let degrees = &self.cobar[t as usize];
+        let mut pairs = Vec::new();
+        let mut permanent = Vec::new();
+        for s in 0..degrees.len() {
+            let mut killed = vec![false; degrees[s].basis.len()];
+            for &(source_s, _, target, _) in &pairs {
+                if source_s + 1 == s {
+                    killed[target] = true;
+                }
+            }
+            // leading term -> reduced boundary
+            let mut pivots: HashMap<usize, FpVector> = HashMap::default();
+            for (idx, &killed) in killed.iter().enumerate() {
+                if s + 1 >= degrees.len() {
+                    if !killed {
+                        permanent.push((s, idx));
+                    }
+                    continue;
+                }
+                let mut boundary = self.cobar_differential(t, s, idx);
+                loop {
+                    match boundary.iter_nonzero().last() {
+                        None => {
+                            if !killed {
+                                permanent.push((s, idx));
+                            }
+                            break;
+                        }
+                        Some((leading, _)) => match pivots.get(&leading) {
+                            Some(b) => boundary.add(b, 1),
+                            None => {
+                                let gap = degrees[s].weights[idx] - degrees[s + 1].weights[leading];
+                                debug_assert!(gap >= 0);
+                                pairs.push((s, idx, leading, gap));
+                                pivots.insert(leading, boundary);
+                                break;
+                            }
+                        },
+                    }
+                }
+            }
+        }
+        (pairs, permanent)
+    }
+
+    fn compute_sseq(&mut self) {
+        let p = self.algebra.prime();
+        let max_t = self.cobar.len() as i32 - 1;
+
+        let mut results = Vec::with_capacity(self.cobar.len());
+        for t in 0..=max_t {
+            let (pairs, permanent) = self.reduce(t);
+            let degrees = &mut self.cobar[t as usize];
+            for &(s, source, target, gap) in &pairs {
+                if gap > 0 {
+                    degrees[s].e1.push(source);
+                    degrees[s + 1].e1.push(target);
+                }
+            }
+            for &(s, idx) in &permanent {
+                degrees[s].e1.push(idx);
+            }
+            for degree in degrees.iter_mut() {
+                degree.e1.sort_unstable();
+            }
+            results.push((pairs, permanent));
+        }
+
+        for n in 0..=max_t {
+            for s in 0..=(max_t - n) as usize {
+                let t = n + s as i32;
+                self.sseq
+                    .set_dimension(n, s as i32, self.cobar[t as usize][s].e1.len());
+            }
+        }
+
+        let mut source = FpVector::new(p, 0);
+        let mut target = FpVector::new(p, 0);
+        for (t, (pairs, permanent)) in results.into_iter().enumerate() {
+            let degrees = &mut self.cobar[t];
+            for &(s, idx) in &permanent {
+                let position = degrees[s].e1.binary_search(&idx).unwrap();
+                degrees[s].e_infinity.push(position);
+            }
+            for &(s, source_idx, target_idx, gap) in &pairs {
+                if gap > 0 {
+                    let source = degrees[s].e1.binary_search(&source_idx).unwrap();
+                    let target = degrees[s + 1].e1.binary_search(&target_idx).unwrap();
+                    degrees[s]
+                        .differentials
+                        .insert(source, ((gap + 1) / 2, target));
+                }
+            }
+            let degrees = &self.cobar[t];
+            let position = |s: usize, idx: usize| degrees[s].e1.binary_search(&idx).unwrap();
+            for (s, idx) in permanent {
+                source.set_scratch_vector_size(degrees[s].e1.len());
+                source.set_entry(position(s, idx), 1);
+                self.sseq
+                    .add_permanent_class(t as i32 - s as i32, s as i32, source.as_slice());
+            }
+            for (s, source_idx, target_idx, gap) in pairs {
+                if gap == 0 {
+                    continue;
+                }
+                debug_assert_eq!(gap % 2, 1);
+                source.set_scratch_vector_size(degrees[s].e1.len());
+                source.set_entry(position(s, source_idx), 1);
+                target.set_scratch_vector_size(degrees[s + 1].e1.len());
+                target.set_entry(position(s + 1, target_idx), 1);
+                self.sseq.add_differential(
+                    (gap + 1) / 2,
+                    t as i32 - s as i32,
+                    s as i32,
+                    source.as_slice(),
+                    target.as_slice(),
+                );
+            }
+        }
+        self.sseq.update();
+    }
+
+    /// The spectral sequence, indexed by the stem and the homological degree. The first page has
+    /// basis the classes named by [`MaySpectralSequence::name`].
+    pub fn sseq(&self) -> &Sseq<May> {
+        &self.sseq
+    }
+
+    pub fn max_t(&self) -> i32 {
+        self.cobar.len() as i32 - 1
+    }
+
+    fn e1_element(&self, n: i32, s: u32, idx: usize) -> (&CobarDegree, usize) {
+        let degree = &self.cobar[(n + s as i32) as usize][s as usize];
+        (degree, degree.e1[idx])
+    }
+
+    /// The classical May filtration of the `idx`th basis element of $E_1$ in bidegree `(n, s)`,
+    /// where $h_{i, j}$ has filtration $i$.
+    pub fn filtration(&self, n: i32, s: u32, idx: usize) -> i32 {
+        let (degree, i) = self.e1_element(n, s, idx);
+        (degree.weights[i] + s as i32) / 2
+    }
+
+    /// The name of the `idx`th basis element of $E_1$ in bidegree `(n, s)`. This is a monomial in
+    /// the $h_{i, j}$ if the leading term of its representative is a tensor of primitives, where
+    /// we write $h_j$ for $h_{1, j}$ and $b_{i, j}$ for $h_{i, j}^2$. Otherwise, it is the leading
+    /// term in the cobar complex.
+    pub fn name(&self, n: i32, s: u32, idx: usize) -> String {
+        let (degree, i) = self.e1_element(n, s, idx);
+        let tensor = &degree.basis[i];
+        if tensor.is_empty() {
+            return "1".to_owned();
+        }
+
+        let primitives: Option<Vec<(usize, u32)>> =
+            tensor.iter().map(|&f| self.primitive(f)).collect();
+        let mut primitives = match primitives {
+            Some(primitives) => primitives,
+            None => return self.cobar_string(tensor),
+        };
+        primitives.sort_unstable();
+
+        let mut result = Vec::new();
+        for (count, &(i, j)) in primitives.iter().dedup_with_count() {
+            let h = if i == 1 {
+                format!("h_{j}")
+            } else {
+                format!("h_{i}{j}")
+            };
+            let (b, h_count) = if i == 1 {
+                (0, count)
+            } else {
+                (count / 2, count % 2)
+            };
+            if b > 0 {
+                result.push(power(format!("b_{i}{j}"), b));
+            }
+            if h_count > 0 {
+                result.push(power(h, h_count));
+            }
+        }
+        result.join(" ")
+    }
+
+    fn cobar_string(&self, tensor: &[u32]) -> String {
+        let mut result = String::from("[");
+        for (k, &f) in tensor.iter().enumerate() {
+            if k > 0 {
+                result.push('|');
+            }
+            let (t, idx) = self.factors[f as usize];
+            let elt = self.algebra.basis_element_from_index(t, idx);
+            let mut first = true;
+            for (i, &r) in elt.p_part.iter().enumerate() {
+                if r == 0 {
+                    continue;
+                }
+                if !first {
+                    result.push(' ');
+                }
+                first = false;
+                write!(result, "ξ_{}", i + 1).unwrap();
+                if r > 1 {
+                    write!(result, "^{r}").unwrap();
+                }
+            }
+        }
+        result.push(']');
+        result
+    }
+
+    /// Format an element of $E_1$ in bidegree `(n, s)` in terms of the names of the basis
+    /// elements.
+    pub fn format(&self, n: i32, s: u32, v: Slice) -> String {
+        let terms: Vec<String> = v.iter_nonzero().map(|(i, _)| self.name(n, s, i)).collect();
+        if terms.is_empty() {
+            "0".to_owned()
+        } else {
+            terms.join(" + ")
+        }
+    }
+
+    /// The differential supported by the `idx`th basis element of $E_1$ in bidegree `(n, s)`, as
+    /// the page and the index of its target in bidegree `(n - 1, s + 1)`. The basis of $E_1$ is
+    /// chosen so that every differential is of this form, and the target of a $d_r$ survives to
+    /// $E_r$.
+    pub fn differential(&self, n: i32, s: u32, idx: usize) -> Option<(i32, usize)> {
+        self.cobar[(n + s as i32) as usize][s as usize]
+            .differentials
+            .get(&idx)
+            .copied()
+    }
+
+    /// The basis elements of $E_1$ in bidegree `(n, s)` that represent a basis of $E_\infty$. Each
+    /// is a permanent cycle that is not hit by a differential.
+    pub fn e_infinity(&self, n: i32, s: u32) -> &[usize] {
+        &self.cobar[(n + s as i32) as usize][s as usize].e_infinity
+    }
+}
+
+fn power(name: String, exponent: usize) -> String {
+    if exponent == 1 {
+        name
+    } else {
+        format!("{name}^{exponent}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::chain_complex::{ChainComplex, FreeChainComplex};
+    use crate::utils::construct;
+
+    fn find(may: &MaySpectralSequence, n: i32, s: u32, name: &str) -> usize {
+        (0..may.sseq().dimension(n, s as i32))
+            .find(|&i| may.name(n, s, i) == name)
+            .unwrap_or_else(|| panic!("{name} not found"))
+    }
+
+    #[test]
+    fn differentials() {
+        let may = MaySpectralSequence::new(8);
+
+        let h20 = find(&may, 2, 1, "h_20");
+        assert_eq!(may.filtration(2, 1, h20), 2);
+        let (r, target) = may.differential(2, 1, h20).unwrap();
+        assert_eq!((r, may.name(1, 2, target).as_str()), (1, "h_0 h_1"));
+
+        let b20 = find(&may, 4, 2, "b_20");
+        let (r, target) = may.differential(4, 2, b20).unwrap();
+        assert_eq!(r, 2);
+        assert_eq!(may.name(3, 3, target), "h_1^3");
+
+        let h3 = find(&may, 7, 1, "h_3");
+        assert_eq!(may.differential(7, 1, h3), None);
+        assert_eq!(may.e_infinity(7, 1), [h3]);
+    }
+
+    #[test]
+    fn e_infinity() {
+        let max_t = 10;
+        let may = MaySpectralSequence::new(max_t);
+        let resolution = construct("S_2", None).unwrap();
+        resolution.compute_through_bidegree(max_t as u32, max_t);
+
+        for t in 0..=max_t {
+            for s in 0..=t as u32 {
+                let n = t - s as i32;
+                assert_eq!(
+                    may.e_infinity(n, s).len(),
+                    resolution.number_of_gens_in_bidegree(s, t),
+                    "Ext^({s}, {t})"
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,636 @@
+//! This module evaluates module expressions. A module expression builds a Steenrod module out of
+//! the modules in `steenrod_modules/` using the following operations, listed in increasing order
+//! of precedence:
+//!
+//!  - `M v N`: The direct sum $M \oplus N$, i.e. the cohomology of the wedge.
+//!  - `M ^ N`: The tensor product $M \otimes N$, i.e. the cohomology of the smash product.
+//!  - `M / N`: The cohomology of the cofiber of the inclusion of the bottom cells, i.e. the
+//!    submodule of $M$ in degrees above the top degree of $N$. We require $N$ to agree with $M$ in
+//!    these bottom degrees.
+//!  - `D(M)`: The Spanier–Whitehead dual of $M$.
+//!  - `M[n]`: The shift of $M$ by $n$.
+//!
+//! Parentheses can be used for grouping, and `v` and `/` must be surrounded by whitespace, so that
+//! module names can be paths. For example,
+//! `C2 ^ Ceta`, `D(Joker)`, `Csigma[3] v C2` and `RP_inf / RP4` are valid expressions. An
+//! expression consisting of a single module name evaluates to the json file of that module.
+//!
+//! The result of an operation is always a finite dimensional module, except for quotients of
+//! (real, complex or quaternionic) projective spaces and lens spaces, which are again of the same
+//! type.
+//!
+//! Modules that are specified as cofibers of maps from the sphere, such as `C4`, cannot be used
+//! as operands.
+
+use std::sync::Arc;
+
+use algebra::module::{BoundedModule, FDModule, FiniteModule, Module, SumModule, TensorModule};
+use algebra::{Algebra, AlgebraType, JsonAlgebra, SteenrodAlgebra};
+use anyhow::{anyhow, Context};
+use bivec::BiVec;
+use serde_json::{json, Value};
+
+use crate::utils::load_module_json;
+
+/// A parsed module expression. See the [module level documentation](self) for the syntax.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ModuleExpression {
+    Name(String),
+    Shift(Box<ModuleExpression>, i64),
+    Dual(Box<ModuleExpression>),
+    Smash(Box<ModuleExpression>, Box<ModuleExpression>),
+    Wedge(Box<ModuleExpression>, Box<ModuleExpression>),
+    Quotient(Box<ModuleExpression>, Box<ModuleExpression>),
+}
+
+impl std::fmt::Display for ModuleExpression {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        // Wrap binary operations in parentheses when they appear as operands
+        let operand = |e: &Self| match e {
+            Self::Smash(..) | Self::Wedge(..) | Self::Quotient(..) => format!("({e})"),
+            _ => e.to_string(),
+        };
+        match self {
+            Self::Name(name) => write!(f, "{name}"),
+            Self::Shift(e, shift) => write!(f, "{}[{shift}]", operand(e)),
+            Self::Dual(e) => write!(f, "D({e})"),
+            Self::Smash(a, b) => write!(f, "{} ^ {}", operand(a), operand(b)),
+            Self::Wedge(a, b) => write!(f, "{} v {}", operand(a), operand(b)),
+            Self::Quotient(a, b) => write!(f, "{} / {}", operand(a), operand(b)),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+enum Token<'a> {
+    LParen,
+    RParen,
+    Caret,
+    Shift(i64),
+    Name(&'a str),
+}
+
+fn tokenize(input: &str) -> anyhow::Result<Vec<Token<'_>>> {
+    let is_special = |c: char| c.is_whitespace() || "()[]^".contains(c);
+
+    let mut tokens = Vec::new();
+    let mut rest = input.trim_start();
+    while let Some(c) = rest.chars().next() {
+        let len = match c {
+            '(' => {
+                tokens.push(Token::LParen);
+                1
+            }
+            ')' => {
+                tokens.push(Token::RParen);
+                1
+            }
+            '^' => {
+                tokens.push(Token::Caret);
+                1
+            }
+            '[' => {
+                let end = rest
+                    .find(']')
+                    .ok_or_else(|| anyhow!("Unterminated shift ["))?;
+                let shift = rest[1..end].trim();
+                tokens.push(Token::Shift(shift.parse().with_context(|| {
+                    format!("Cannot parse shift value ({}) as an integer", shift)
+                })?));
+                end + 1
+            }
+            ']' => return Err(anyhow!("Unexpected ]")),
+            _ => {
+                let end = rest.find(is_special).unwrap_or(rest.len());
+                tokens.push(Token::Name(&rest[..end]));
+                end
+            }
+        };
+        rest = rest[len..].trim_start();
+    }
+    Ok(tokens)
+}
+
+struct Parser<'a> {
+    tokens: Vec<Token<'a>>,
+    pos: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn peek(&self) -> Option<&Token<'a>> {
+        self.tokens.get(self.pos)
+    }
+
+    fn next(&mut self) -> Option<Token<'a>> {
+        let token = self.tokens.get(self.pos).cloned();
+        self.pos += 1;
+        token
+    }
+
+    fn expect_rparen(&mut self) -> anyhow::Result<()> {
+        match self.next() {
+            Some(Token::RParen) => Ok(()),
+            _ => Err(anyhow!("Expected )")),
+        }
+    }
+
+    fn wedge(&mut self) -> anyhow::Result<ModuleExpression> {
+        let mut result = self.smash()?;
+        while self.peek() == Some(&Token::Name("v")) {
+            self.next();
+            result = ModuleExpression::Wedge(Box::new(result), Box::new(self.smash()?));
+        }
+        Ok(result)
+    }
+
+    fn smash(&mut self) -> anyhow::Result<ModuleExpression> {
+        let mut result = self.quotient()?;
+        while self.peek() == Some(&Token::Caret) {
+            self.next();
+            result = ModuleExpression::Smash(Box::new(result), Box::new(self.quotient()?));
+        }
+        Ok(result)
+    }
+
+    fn quotient(&mut self) -> anyhow::Result<ModuleExpression> {
+        let mut result = self.shift()?;
+        while self.peek() == Some(&Token::Name("/")) {
+            self.next();
+            result = ModuleExpression::Quotient(Box::new(result), Box::new(self.shift()?));
+        }
+        Ok(result)
+    }
+
+    fn shift(&mut self) -> anyhow::Result<ModuleExpression> {
+        let mut result = self.primary()?;
+        while let Some(&Token::Shift(shift)) = self.peek() {
+            self.next();
+            result = ModuleExpression::Shift(Box::new(result), shift);
+        }
+        Ok(result)
+    }
+
+    fn primary(&mut self) -> anyhow::Result<ModuleExpression> {
+        match self.next() {
+            Some(Token::Name("D")) if self.peek() == Some(&Token::LParen) => {
+                self.next();
+                let result = self.wedge()?;
+                self.expect_rparen()?;
+                Ok(ModuleExpression::Dual(Box::new(result)))
+            }
+            Some(Token::LParen) => {
+                let result = self.wedge()?;
+                self.expect_rparen()?;
+                Ok(result)
+            }
+            Some(Token::Name(name)) => Ok(ModuleExpression::Name(name.to_string())),
+            Some(token) => Err(anyhow!("Unexpected token {:?}", token)),
+            None => Err(anyhow!("Unexpected end of module expression")),
+        }
+    }
+}
+
+impl std::str::FromStr for ModuleExpression {
+    type Err = anyhow::Error;
+
+    fn from_str(input: &str) -> anyhow::Result<Self> {
+        let mut parser = Parser {
+            tokens: tokenize(input)?,
+            pos: 0,
+        };
+        let result = parser.wedge()?;
+        if let Some(token) = parser.peek() {
+            return Err(anyhow!("Unexpected token {:?}", token));
+        }
+        Ok(result)
+    }
+}
+
+impl ModuleExpression {
+    /// Evaluate the expression to the json specification of a module.
+    pub fn evaluate(&self) -> anyhow::Result<Value> {
+        match self {
+            Self::Name(name) => load_module_json(name)
+                .with_context(|| format!("Failed to load module file {}", name)),
+            Self::Shift(e, shift) => {
+                let mut module = e.evaluate()?;
+                if let Some(gens) = module["gens"].as_object_mut() {
+                    for entry in gens.into_iter() {
+                        *entry.1 = (entry.1.as_i64().unwrap() + shift).into()
+                    }
+                }
+                Ok(module)
+            }
+            Self::Dual(e) => {
+                let json = e.evaluate()?;
+                let (_, module) = load_fd_module(&json, &e.to_string())?;
+                to_json(&json, self, &module.dual())
+            }
+            Self::Smash(a, b) => {
+                let (left, right) = (a.evaluate()?, b.evaluate()?);
+                let (algebra, left_module) = load_fd_module(&left, &a.to_string())?;
+                let right_module = load_fd_module_over(&algebra, &right, &b.to_string())?;
+                let tensor = TensorModule::new(Arc::new(left_module), Arc::new(right_module));
+                to_json(&left, self, &tensor.to_fd_module())
+            }
+            Self::Wedge(a, b) => {
+                let (left, right) = (a.evaluate()?, b.evaluate()?);
+                let (algebra, left_module) = load_fd_module(&left, &a.to_string())?;
+                let right_module = load_fd_module_over(&algebra, &right, &b.to_string())?;
+                let min_degree = std::cmp::min(left_module.min_degree(), right_module.min_degree());
+                let sum = SumModule::new(
+                    algebra,
+                    vec![Arc::new(left_module), Arc::new(right_module)],
+                    min_degree,
+                );
+                let mut module = sum.to_fd_module();
+                dedup_names(&mut module);
+                to_json(&left, self, &module)
+            }
+            Self::Quotient(a, b) => {
+                let (mut top, bottom) = (a.evaluate()?, b.evaluate()?);
+                check_prime(&top, &bottom)?;
+                let algebra = Arc::new(SteenrodAlgebra::from_json(&top, AlgebraType::Milnor)?);
+                let top_module = FiniteModule::from_json(Arc::clone(&algebra), &top)?;
+                let bottom_module = FiniteModule::from_json(algebra, &bottom)?;
+
+                let cut = bottom_module.max_degree();
+                if bottom_module.min_degree() != top_module.min_degree()
+                    || cut == i32::MAX
+                    || (top_module.min_degree()..=cut)
+                        .any(|t| top_module.dimension(t) != bottom_module.dimension(t))
+                {
+                    return Err(anyhow!("{} is not the bottom of {}", b, a));
+                }
+
+                if top_module.is_real_projective_space() || top_module.is_projective_space() {
+                    top["min"] = Value::from(cut + 1);
+                    top["name"] = Value::from(self.to_string());
+                    return Ok(top);
+                }
+                let module = top_module
+                    .into_fd_module()
+                    .ok_or_else(|| anyhow!("{} is not a finite dimensional module", a))?;
+                to_json(&top, self, &truncate_below(&module, cut + 1))
+            }
+        }
+    }
+}
+
+fn check_prime(left: &Value, right: &Value) -> anyhow::Result<()> {
+    if left["p"] != right["p"] {
+        return Err(anyhow!("Two modules must be over the same prime"));
+    }
+    Ok(())
+}
+
+/// Load a json module as a finite dimensional module over the Milnor algebra. (Real) projective
+/// spaces are allowed as long as they are bounded.
+fn load_fd_module(
+    json: &Value,
+    name: &str,
+) -> anyhow::Result<(Arc<SteenrodAlgebra>, FDModule<SteenrodAlgebra>)> {
+    let algebra = Arc::new(SteenrodAlgebra::from_json(json, AlgebraType::Milnor)?);
+    let module = load_fd_module_over(&algebra, json, name)?;
+    Ok((algebra, module))
+}
+
+fn load_fd_module_over(
+    algebra: &Arc<SteenrodAlgebra>,
+    json: &Value,
+    name: &str,
+) -> anyhow::Result<FDModule<SteenrodAlgebra>> {
+    if *algebra.prime() as u64 != json["p"].as_u64().unwrap_or(0) {
+        return Err(anyhow!("Two modules must be over the same prime"));
+    }
+    // The module underlying such a specification is not the module being resolved
+    if !json["cofiber"].is_null() {
+        return Err(anyhow!("{} is specified as a cofiber", name));
+    }
+    let module = FiniteModule::from_json(Arc::clone(algebra), json)?;
+    if module.is_fd_module() {
+        Ok(module.into_fd_module().unwrap())
+    } else if module.max_degree() != i32::MAX {
+        Ok(module.to_fd_module())
+    } else {
+        Err(anyhow!("{} is not a finite dimensional module", name))
+    }
+}
+
+/// Produce the json of `module`, keeping the prime and algebra options of `base`.
+fn to_json(
+    base: &Value,
+    expression: &ModuleExpression,
+    module: &FDModule<SteenrodAlgebra>,
+) -> anyhow::Result<Value> {
+    let mut result = json!({
+        "p": base["p"],
+        "generic": module.algebra().generic(),
+    });
+    for key in ["algebra", "profile"] {
+        if !base[key].is_null() {
+            result[key] = base[key].clone();
+        }
+    }
+    module.to_json(&mut result);
+    result["name"] = Value::from(expression.to_string());
+    if !base["profile"].is_null() {
+        // Actions of the generators cannot be extended to the whole algebra when there is a
+        // profile, so we record the action of every Milnor basis element instead.
+        result.as_object_mut().unwrap().remove("actions");
+        result["algebra"] = json!(["milnor"]);
+        result["milnor_actions"] = milnor_actions_to_json(module);
+    }
+    Ok(result)
+}
+
+fn milnor_actions_to_json(module: &FDModule<SteenrodAlgebra>) -> Value {
+    let algebra = module.algebra();
+    let mut actions = Vec::new();
+    for input_degree in module.min_degree()..=module.max_degree() {
+        for output_degree in input_degree + 1..=module.max_degree() {
+            let op_degree = output_degree - input_degree;
+            for op_idx in 0..algebra.dimension(op_degree) {
+                for input_idx in 0..module.dimension(input_degree) {
+                    let output = module.action(op_degree, op_idx, input_degree, input_idx);
+                    if output.is_zero() {
+                        continue;
+                    }
+                    let output: Vec<Value> = output
+                        .iter_nonzero()
+                        .map(|(i, c)| {
+                            json!({
+                                "gen": module.basis_element_to_string(output_degree, i),
+                                "coeff": c,
+                            })
+                        })
+                        .collect();
+                    actions.push(json!({
+                        "op": algebra.json_from_basis(op_degree, op_idx),
+                        "input": module.basis_element_to_string(input_degree, input_idx),
+                        "output": output,
+                    }));
+                }
+            }
+        }
+    }
+    Value::from(actions)
+}
+
+/// Rename basis elements so that the names are distinct, which is needed for the json format.
+fn dedup_names(module: &mut FDModule<SteenrodAlgebra>) {
+    let mut seen = std::collections::HashSet::new();
+    for t in module.min_degree()..=module.max_degree() {
+        for idx in 0..module.dimension(t) {
+            let mut name = module.basis_element_to_string(t, idx);
+            while !seen.insert(name.clone()) {
+                name.push('\'');
+            }
+            module.set_basis_element_name(t, idx, name);
+        }
+    }
+}
+
+/// The submodule of `module` consisting of elements of degree at least `min_degree`.
+fn truncate_below(
+    module: &FDModule<SteenrodAlgebra>,
+    min_degree: i32,
+) -> FDModule<SteenrodAlgebra> {
+    let algebra = module.algebra();
+    let max_degree = module.max_degree();
+
+    let mut graded_dimension = BiVec::new(min_degree);
+    for t in min_degree..=max_degree {
+        graded_dimension.push(module.dimension(t));
+    }
+    let mut result = FDModule::new(Arc::clone(&algebra), module.name.clone(), graded_dimension);
+    for t in min_degree..=max_degree {
+        for idx in 0..module.dimension(t) {
+            result.set_basis_element_name(t, idx, module.basis_element_to_string(t, idx));
+        }
+    }
+    for input_degree in min_degree..=max_degree {
+        for output_degree in input_degree + 1..=max_degree {
+            if module.dimension(output_degree) == 0 {
+                continue;
+            }
+            let op_degree = output_degree - input_degree;
+            for op_idx in 0..algebra.dimension(op_degree) {
+                for idx in 0..module.dimension(input_degree) {
+                    let output = module.action(op_degree, op_idx, input_degree, idx);
+                    result.set_action_vector(op_degree, op_idx, input_degree, idx, output);
+                }
+            }
+        }
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::chain_complex::{ChainComplex, FreeChainComplex};
+    use crate::utils::construct;
+
+    #[test]
+    fn parse() {
+        let e: ModuleExpression = "D(C2 ^ Ceta)[2] v RP_inf / RP4 ^ C2".parse().unwrap();
+        assert_eq!(e.to_string(), "D(C2 ^ Ceta)[2] v ((RP_inf / RP4) ^ C2)");
+        // `/` binds tighter than `^` on either side
+        let e: ModuleExpression = "C2 ^ RP_inf / RP4".parse().unwrap();
+        let name = |n: &str| Box::new(ModuleExpression::Name(n.into()));
+        assert_eq!(
+            e,
+            ModuleExpression::Smash(
+                name("C2"),
+                Box::new(ModuleExpression::Quotient(name("RP_inf"), name("RP4")))
+            )
+        );
+        assert_eq!(e.to_string(), "C2 ^ (RP_inf / RP4)");
+        let e: ModuleExpression = "C2v14 v A-mod-Sq1-Sq2".parse().unwrap();
+        assert_eq!(e.to_string(), "C2v14 v A-mod-Sq1-Sq2");
+        let e: ModuleExpression = "S_2[-4]".parse().unwrap();
+        assert_eq!(
+            e,
+            ModuleExpression::Shift(Box::new(ModuleExpression::Name("S_2".into())), -4)
+        );
+
+        let e: ModuleExpression = "save/C2/module ^ Ceta".parse().unwrap();
+        assert_eq!(e.to_string(), "save/C2/module ^ Ceta");
+
+        assert!("C2 ^".parse::<ModuleExpression>().is_err());
+        assert!("(C2".parse::<ModuleExpression>().is_err());
+        assert!("C2[1".parse::<ModuleExpression>().is_err());
+        assert!("C2 C2".parse::<ModuleExpression>().is_err());
+    }
+
+    /// Check that the two module specifications have the same Ext in a range
+    fn check_same_ext(expr: &str, expected: &str) {
+        let a = construct(expr, None).unwrap();
+        let b = construct(expected, None).unwrap();
+        a.compute_through_stem(4, 10);
+        b.compute_through_stem(4, 10);
+        assert_eq!(
+            a.graded_dimension_string(),
+            b.graded_dimension_string(),
+            "{expr} vs {expected}"
+        );
+    }
+
+    #[test]
+    fn smash() {
+        check_same_ext("C2 ^ Ceta", "C2_sm_Ceta");
+    }
+
+    #[test]
+    fn self_dual() {
+        check_same_ext("D(Csigma)", "Csigma[-8]");
+        check_same_ext("D(C2)", "C2[-1]");
+        check_same_ext("D(Ceta ^ C2)[3]", "C2_sm_Ceta");
+        check_same_ext("D(C3v1)[6]", "C3v1");
+
+        // Atiyah duality: D(RP^4_1) = Σ RP^{-2}_{-5}
+        let dual = construct("D(RP4)", None).unwrap();
+        let stunted = construct(
+            (
+                json!({"type": "real projective space", "p": 2, "min": -5, "max": -2}),
+                "milnor",
+            ),
+            None,
+        )
+        .unwrap();
+        dual.compute_through_stem(4, 10);
+        stunted.compute_through_stem(4, 11);
+        for (s, _, t) in dual.iter_stem() {
+            assert_eq!(
+                dual.number_of_gens_in_bidegree(s, t),
+                stunted.number_of_gens_in_bidegree(s, t - 1)
+            );
+        }
+
+        // Sq^4 acts trivially on the Joker but χ(Sq^4) = Sq^4 + Sq^3 Sq^1 does not, so the Joker is
+        // not self-dual.
+        let dual: ModuleExpression = "D(Joker)".parse().unwrap();
+        let dual = dual.evaluate().unwrap();
+        assert!(dual["actions"]
+            .as_array()
+            .unwrap()
+            .contains(&json!("Sq4 x4* = x0*")));
+
+        assert!("D(C4)"
+            .parse::<ModuleExpression>()
+            .unwrap()
+            .evaluate()
+            .is_err());
+    }
+
+    /// Check which of the finite modules in `steenrod_modules/` are self-dual up to shift, by
+    /// comparing Ext of $DM$ shifted by the sum of the top and bottom degrees of $M$ with that of
+    /// $M$. The duals of the other modules are resolved in the process. The range is chosen so that
+    /// it detects the difference between the Joker and its dual.
+    #[test]
+    fn steenrod_modules_self_dual() {
+        const SELF_DUAL: &[&str] = &[
+            "C2",
+            "C2_sm_Ceta",
+            "C3",
+            "C3v1",
+            "C5v1",
+            "Calpha",
+            "Ceta",
+            "Cnu",
+            "Csigma",
+            "S_2",
+            "S_23",
+            "S_3",
+            "S_31",
+            "S_5",
+            "S_7",
+            "X3",
+            "Z",
+            "ko",
+            "l",
+            "t1",
+            "tmf-mod-eta",
+            "tmf-mod-eta-nu",
+            "tmf-mod-nu",
+            "tmf2",
+            "tmf2_sm_DA1",
+            "y1_2",
+            "y1_3",
+            "y2_2",
+            "y2_3",
+        ];
+
+        let dir = concat!(env!("CARGO_MANIFEST_DIR"), "/steenrod_modules");
+        let mut names: Vec<String> = std::fs::read_dir(dir)
+            .unwrap()
+            .filter_map(|entry| {
+                let path = entry.unwrap().path();
+                let json = load_module_json(path.to_str()?).ok()?;
+                (json["type"] == "finite dimensional module" && json["cofiber"].is_null())
+                    .then(|| path.file_stem().unwrap().to_str().unwrap().to_owned())
+            })
+            .collect();
+        names.sort();
+
+        for name in &names {
+            let gens = load_module_json(name).unwrap()["gens"].clone();
+            let degrees = gens
+                .as_object()
+                .unwrap()
+                .values()
+                .map(|t| t.as_i64().unwrap());
+            let shift = degrees.clone().min().unwrap() + degrees.max().unwrap();
+
+            let module = construct(name.as_str(), None).unwrap();
+            let dual = construct(format!("D({name})[{shift}]").as_str(), None).unwrap();
+            module.compute_through_stem(3, 23);
+            dual.compute_through_stem(3, 23);
+            assert_eq!(
+                module.graded_dimension_string() == dual.graded_dimension_string(),
+                SELF_DUAL.contains(&name.as_str()),
+                "{name}"
+            );
+        }
+    }
+
+    #[test]
+    fn wedge() {
+        let json: ModuleExpression = "Csigma[3] v C2".parse().unwrap();
+        let json = json.evaluate().unwrap();
+        assert_eq!(json["gens"].as_object().unwrap().len(), 4);
+
+        let wedge = construct("C2 v C2", None).unwrap();
+        let c2 = construct("C2", None).unwrap();
+        wedge.compute_through_stem(4, 10);
+        c2.compute_through_stem(4, 10);
+        for (s, _, t) in c2.iter_stem() {
+            assert_eq!(
+                wedge.number_of_gens_in_bidegree(s, t),
+                2 * c2.number_of_gens_in_bidegree(s, t)
+            );
+        }
+    }
+
+    #[test]
+    fn quotient() {
+        let json: ModuleExpression = "RP_inf / RP4".parse().unwrap();
+        let json = json.evaluate().unwrap();
+        assert_eq!(json["type"], "real projective space");
+        assert_eq!(json["min"], 5);
+
+        let json: ModuleExpression = "Joker / C2".parse().unwrap();
+        let json = json.evaluate().unwrap();
+        let gens = json["gens"].as_object().unwrap();
+        assert_eq!(gens.len(), 3);
+        assert!(gens.values().all(|t| t.as_i64().unwrap() >= 2));
+
+        assert!("C2 / Ceta"
+            .parse::<ModuleExpression>()
+            .unwrap()
+            .evaluate()
+            .is_err());
+    }
+}
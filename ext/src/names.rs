@@ -0,0 +1,441 @@
+//! Names of classes in $\Ext$.
+//!
+//! By default, the `i`th basis element of $\Ext^{s, n + s}$ is written as `x_(n, s, i)`. A
+//! [`ClassNames`] assigns more meaningful names to some of the basis elements, and is used
+//! wherever we print classes, e.g. by [`print_element`](crate::utils::print_element).
+//!
+//! [`ClassNames::for_resolution`] produces the names of a resolution as follows:
+//!
+//!  1. If the resolution has a save directory, the names listed in the file `names.csv` in the
+//!     save directory are read. Each line of the file is of the form `n,s,i,name`, and empty lines
+//!     and lines starting with `#` are ignored. This file is meant to be edited by hand, and names
+//!     set this way always take precedence over automatically generated names.
+//!  2. The remaining classes are named automatically, in increasing order of $s$:
+//!      - The bottom class of the unit is named `1`. Other classes in filtration $0$ are named
+//!        after the element of the module they are dual to, if this is a basis element.
+//!      - If $h_i x$ is a basis element and $x$ has a name, then it is named after $h_i$ and $x$.
+//!        For example, $h_0 \cdot h_0 h_1$ is named `h_0^2 h_1`.
+//!      - An indecomposable of $\Ext$ of the sphere is given its standard name, e.g. `h_4` or
+//!        `c_0`, if it is the only indecomposable in its bidegree. Beyond $s = 1$, this is only
+//!        done at the prime $2$ and through the $23$ stem.
+//!
+//! Since the indecomposables are only defined modulo decomposables, the class that receives a
+//! standard name is some basis element that is not decomposable, which need not agree with the
+//! literature. Such names can be corrected by listing the desired names in `names.csv`, and these
+//! are then propagated to their products.
+//!
+//! The file `names.csv` can be populated with the automatically generated names using
+//! [`ClassNames::save`], e.g. via the [`names`](../names/index.html) example.
+
+use std::collections::HashMap;
+use std::io::{BufRead, BufReader, Write};
+use std::path::Path;
+
+use algebra::module::Module;
+use algebra::{Algebra, SteenrodAlgebra};
+use anyhow::{anyhow, Context};
+use fp::matrix::Subspace;
+use fp::prime::ValidPrime;
+use fp::vector::{FpVector, Slice};
+
+use crate::chain_complex::{AugmentedChainComplex, ChainComplex, FreeChainComplex};
+use crate::resolution::Resolution;
+use crate::CCC;
+
+/// The name of the file in the save directory that stores the names of classes.
+pub const NAMES_FILE: &str = "names.csv";
+
+/// Standard names of the indecomposables of $\Ext$ of the sphere at the prime $2$ in filtration
+/// at least $2$, as `(n, s, name)`.
+const SPHERE_2: [(i32, u32, &str); 13] = [
+    (8, 3, "c_0"),
+    (9, 5, "Ph_1"),
+    (11, 5, "Ph_2"),
+    (14, 4, "d_0"),
+    (16, 7, "Pc_0"),
+    (17, 4, "e_0"),
+    (17, 9, "P^2h_1"),
+    (18, 4, "f_0"),
+    (19, 3, "c_1"),
+    (19, 9, "P^2h_2"),
+    (20, 4, "g"),
+    (22, 8, "Pd_0"),
+    (23, 7, "i"),
+];
+
+/// Names of basis elements of $\Ext$. Basis elements without a name are written as
+/// `x_(n, s, i)`.
+#[derive(Clone, Debug, Default)]
+pub struct ClassNames {
+    names: HashMap<(i32, u32, usize), String>,
+}
+
+impl ClassNames {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The names of the classes of `resolution`. These are the names listed in the save
+    /// directory, if any, together with the automatically generated names for the classes that
+    /// have been computed. See the [module level documentation](self) for details.
+    pub fn for_resolution(resolution: &Resolution<CCC>) -> anyhow::Result<Self> {
+        let mut names = match resolution.save_dir() {
+            Some(dir) if dir.join(NAMES_FILE).exists() => Self::load(&dir.join(NAMES_FILE))?,
+            _ => Self::new(),
+        };
+        names.name_classes(resolution);
+        Ok(names)
+    }
+
+    /// Read names from a file in the format of `names.csv`.
+    pub fn load(path: &Path) -> anyhow::Result<Self> {
+        let file = std::fs::File::open(path)
+            .with_context(|| format!("Failed to open names file {path:?}"))?;
+
+        let mut names = Self::new();
+        for (num, line) in BufReader::new(file).lines().enumerate() {
+            let line = line?;
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let parse = || -> Option<(i32, u32, usize, &str)> {
+                let mut fields = line.splitn(4, ',');
+                let n = fields.next()?.trim().parse().ok()?;
+                let s = fields.next()?.trim().parse().ok()?;
+                let idx = fields.next()?.trim().parse().ok()?;
+                let name = fields.next()?.trim();
+                (!name.is_empty()).then_some((n, s, idx, name))
+            };
+            let (n, s, idx, name) = parse().ok_or_else(|| {
+                anyhow!(
+                    "{path:?}, line {}: expected n,s,i,name but got {line:?}",
+                    num + 1
+                )
+            })?;
+            names.insert(n, s, idx, name);
+        }
+        Ok(names)
+    }
+
+    /// Write all names to a file in the format of `names.csv`, sorted by bidegree.
+    pub fn save(&self, path: &Path) -> anyhow::Result<()> {
+        let mut entries: Vec<_> = self.names.iter().collect();
+        entries.sort_by_key(|&(&(n, s, idx), _)| (s, n, idx));
+
+        let mut f = std::io::BufWriter::new(
+            std::fs::File::create(path)
+                .with_context(|| format!("Failed to create names file {path:?}"))?,
+        );
+        writeln!(f, "# n,s,i,name")?;
+        for ((n, s, idx), name) in entries {
+            writeln!(f, "{n},{s},{idx},{name}")?;
+        }
+        Ok(())
+    }
+
+    /// Name the `idx`th basis element of the bidegree `(n, s)`, replacing the existing name if
+    /// there is one.
+    pub fn insert(&mut self, n: i32, s: u32, idx: usize, name: impl Into<String>) {
+        self.names.insert((n, s, idx), name.into());
+    }
+
+    pub fn get(&self, n: i32, s: u32, idx: usize) -> Option<&str> {
+        self.names.get(&(n, s, idx)).map(String::as_str)
+    }
+
+    /// The name of the `idx`th basis element of the bidegree `(n, s)`, which is `x_(n, s, idx)`
+    /// if it is not named.
+    pub fn name(&self, n: i32, s: u32, idx: usize) -> String {
+        match self.get(n, s, idx) {
+            Some(name) => name.to_owned(),
+            None => format!("x_({n}, {s}, {idx})"),
+        }
+    }
+
+    /// Format an element of the bidegree `(n, s)`. For example, `[0, 2, 1]` is formatted as
+    /// `2 x_(n, s, 1) + h_0 c_0` if the last basis element is named `h_0 c_0`.
+    pub fn format(&self, v: Slice, n: i32, s: u32) -> String {
+        let terms: Vec<String> = v
+            .iter_nonzero()
+            .map(|(i, c)| {
+                if c == 1 {
+                    self.name(n, s, i)
+                } else {
+                    format!("{c} {}", self.name(n, s, i))
+                }
+            })
+            .collect();
+        if terms.is_empty() {
+            "0".to_owned()
+        } else {
+            terms.join(" + ")
+        }
+    }
+
+    pub(crate) fn format_subspace(&self, subspace: &Subspace, n: i32, s: u32) -> String {
+        let basis: Vec<String> = subspace
+            .basis()
+            .iter()
+            .map(|v| self.format(v.as_slice(), n, s))
+            .collect();
+        format!("{{{}}}", basis.join(", "))
+    }
+
+    /// Name the computed classes of `resolution` that do not already have a name.
+    fn name_classes(&mut self, resolution: &Resolution<CCC>) {
+        let p = resolution.prime();
+        let algebra = resolution.algebra();
+        let is_unit = resolution.target().module(0).is_unit();
+        let sphere_names = is_unit && *p == 2 && is_full(&algebra);
+
+        let mut ops = algebra.default_filtration_one_products();
+
+        for s in 0..resolution.next_homological_degree() {
+            let module = resolution.module(s);
+            for t in resolution.min_degree() + s as i32..=module.max_computed_degree() {
+                let n = t - s as i32;
+                let dim = module.number_of_gens_in_degree(t);
+                if dim == 0 {
+                    continue;
+                }
+                if s == 0 {
+                    self.name_filtration_zero(resolution, t);
+                    continue;
+                }
+
+                // The span of the products of the classes in filtration s - 1
+                let mut decomposables = Subspace::new(p, dim + 1, dim);
+                for (op_name, op_deg, op_idx) in &ops {
+                    let Some(products) = resolution.filtration_one_product(*op_deg, *op_idx, s, t)
+                    else {
+                        continue;
+                    };
+                    let source_n = n - op_deg + 1;
+                    for (j, row) in products.iter().enumerate() {
+                        if decomposables.dimension() < dim {
+                            decomposables.add_vector(FpVector::from_slice(p, row).as_slice());
+                        }
+                        let mut nonzero = row.iter().enumerate().filter(|(_, &c)| c != 0);
+                        let (Some((i, &1)), None) = (nonzero.next(), nonzero.next()) else {
+                            continue;
+                        };
+                        if self.get(n, s, i).is_some() {
+                            continue;
+                        }
+                        if let Some(name) = self.get(source_n, s - 1, j) {
+                            let name = multiply(op_name, name, &ops);
+                            self.insert(n, s, i, name);
+                        }
+                    }
+                }
+
+                if !is_unit || dim - decomposables.dimension() != 1 {
+                    continue;
+                }
+                let standard_name = if s == 1 {
+                    hopf_name(p, n)
+                } else if sphere_names {
+                    SPHERE_2
+                        .iter()
+                        .find(|&&(n_, s_, _)| (n_, s_) == (n, s))
+                        .map(|&(_, _, name)| name.to_owned())
+                } else {
+                    None
+                };
+                let Some(standard_name) = standard_name else {
+                    continue;
+                };
+                let mut v = FpVector::new(p, dim);
+                let indecomposable = (0..dim).find(|&i| {
+                    v.set_to_zero();
+                    v.set_entry(i, 1);
+                    !decomposables.contains(v.as_slice())
+                });
+                if let Some(i) = indecomposable {
+                    if self.get(n, s, i).is_none() {
+                        self.insert(n, s, i, standard_name);
+                    }
+                }
+            }
+
+            // The Hopf invariant one classes give us filtration one products with elements of
+            // the algebra beyond the default ones.
+            if s == 1 && is_unit {
+                self.extend_ops(resolution, &mut ops);
+            }
+        }
+    }
+
+    /// Name the classes in filtration 0 and degree `t` after the elements of the module they are
+    /// dual to.
+    fn name_filtration_zero(&mut self, resolution: &Resolution<CCC>, t: i32) {
+        let module = resolution.target().module(0);
+        let chain_map = resolution.chain_map(0);
+        for i in 0..resolution.number_of_gens_in_bidegree(0, t) {
+            if self.get(t, 0, i).is_some() {
+                continue;
+            }
+            if module.is_unit() {
+                self.insert(t, 0, i, "1");
+                continue;
+            }
+            let image = chain_map.output(t, i);
+            let mut nonzero = image.iter_nonzero();
+            if let (Some((idx, 1)), None) = (nonzero.next(), nonzero.next()) {
+                self.insert(t, 0, i, module.basis_element_to_string(t, idx));
+            }
+        }
+    }
+
+    /// Add the named classes in filtration one of the unit to `ops` if their differential is a
+    /// single basis element of the algebra that is not already in `ops`.
+    fn extend_ops(&self, resolution: &Resolution<CCC>, ops: &mut Vec<(String, i32, usize)>) {
+        let d = resolution.differential(1);
+        let target = resolution.module(0);
+        for t in resolution.min_degree() + 1..=resolution.module(1).max_computed_degree() {
+            if ops.iter().any(|&(_, op_deg, _)| op_deg == t) {
+                continue;
+            }
+            for i in 0..resolution.number_of_gens_in_bidegree(1, t) {
+                let Some(name) = self.get(t - 1, 1, i) else {
+                    continue;
+                };
+                let dx = d.output(t, i);
+                let mut nonzero = dx.iter_nonzero();
+                if let (Some((idx, 1)), None) = (nonzero.next(), nonzero.next()) {
+                    let op_gen = target.index_to_op_gen(t, idx);
+                    ops.push((
+                        name.to_owned(),
+                        op_gen.operation_degree,
+                        op_gen.operation_index,
+                    ));
+                }
+            }
+        }
+    }
+}
+
+/// Whether `algebra` is the full Steenrod algebra, as opposed to a sub-Hopf algebra.
+fn is_full(algebra: &SteenrodAlgebra) -> bool {
+    match algebra {
+        SteenrodAlgebra::AdemAlgebra(_) => true,
+        SteenrodAlgebra::MilnorAlgebra(a) => a.profile.is_trivial(),
+    }
+}
+
+/// The name of the class in $\Ext^{1, n + 1}$ of the sphere, if it is a Hopf invariant one class.
+fn hopf_name(p: ValidPrime, n: i32) -> Option<String> {
+    let t = n + 1;
+    if *p == 2 {
+        return (t.count_ones() == 1).then(|| format!("h_{}", t.trailing_zeros()));
+    }
+    if t == 1 {
+        return Some("a_0".to_owned());
+    }
+    let q = 2 * (*p as i32 - 1);
+    if t % q != 0 {
+        return None;
+    }
+    let mut k = t / q;
+    let mut i = 0;
+    while k % *p as i32 == 0 {
+        k /= *p as i32;
+        i += 1;
+    }
+    (k == 1).then(|| format!("h_{i}"))
+}
+
+/// The name of the product of `op` with the class named `name`. Names are treated as monomials
+/// whose factors are separated by spaces, and the factors that are names of `ops` come first, in
+/// the order of `ops`.
+fn multiply(op: &str, name: &str, ops: &[(String, i32, usize)]) -> String {
+    if name == "1" {
+        return op.to_owned();
+    }
+    let mut factors: Vec<(&str, u32)> = name
+        .split(' ')
+        .map(|factor| match factor.rsplit_once('^') {
+            Some((base, exp)) if exp.parse::<u32>().is_ok() => (base, exp.parse().unwrap()),
+            _ => (factor, 1),
+        })
+        .collect();
+    match factors.iter_mut().find(|(base, _)| *base == op) {
+        Some((_, exp)) => *exp += 1,
+        None => factors.push((op, 1)),
+    }
+    factors.sort_by_key(|(base, _)| {
+        ops.iter()
+            .position(|(op_name, _, _)| op_name == base)
+            .unwrap_or(usize::MAX)
+    });
+    factors
+        .into_iter()
+        .map(|(base, exp)| {
+            if exp == 1 {
+                base.to_owned()
+            } else {
+                format!("{base}^{exp}")
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::utils::construct;
+
+    #[test]
+    fn sphere() {
+        let resolution = construct("S_2", None).unwrap();
+        resolution.compute_through_stem(8, 20);
+        let names = ClassNames::for_resolution(&resolution).unwrap();
+
+        assert_eq!(names.get(0, 0, 0), Some("1"));
+        assert_eq!(names.get(0, 3, 0), Some("h_0^3"));
+        assert_eq!(names.get(3, 2, 0), Some("h_0 h_2"));
+        assert_eq!(names.get(6, 2, 0), Some("h_2^2"));
+        assert_eq!(names.get(8, 3, 0), Some("c_0"));
+        assert_eq!(names.get(9, 4, 0), Some("h_1 c_0"));
+        assert_eq!(names.get(9, 5, 0), Some("Ph_1"));
+        assert_eq!(names.get(15, 1, 0), Some("h_4"));
+        assert_eq!(names.get(15, 8, 0), Some("h_0^7 h_4"));
+        assert_eq!(names.get(16, 2, 0), Some("h_1 h_4"));
+        assert_eq!(names.get(14, 4, 0), Some("d_0"));
+        assert_eq!(names.get(20, 4, 0), Some("g"));
+    }
+
+    #[test]
+    fn module() {
+        let resolution = construct("C2", None).unwrap();
+        resolution.compute_through_stem(4, 6);
+        let names = ClassNames::for_resolution(&resolution).unwrap();
+
+        assert_eq!(names.get(0, 0, 0), Some("x0"));
+        assert_eq!(names.get(1, 1, 0), Some("h_1 x0"));
+        assert_eq!(names.get(3, 1, 0), Some("h_2 x0"));
+    }
+
+    #[test]
+    fn save_load() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let path = dir.path().join(NAMES_FILE);
+        std::fs::write(&path, "# comment\n\n8,3,0,c0\n").unwrap();
+
+        let resolution = construct("S_2", Some(dir.path().to_owned())).unwrap();
+        resolution.compute_through_stem(5, 10);
+        let names = ClassNames::for_resolution(&resolution).unwrap();
+        assert_eq!(names.get(8, 3, 0), Some("c0"));
+        assert_eq!(names.get(9, 4, 0), Some("h_1 c0"));
+
+        names.save(&path).unwrap();
+        let loaded = ClassNames::load(&path).unwrap();
+        assert_eq!(loaded.names, names.names);
+
+        std::fs::write(&path, "8,3,c0\n").unwrap();
+        assert!(ClassNames::load(&path).is_err());
+    }
+}
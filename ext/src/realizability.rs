@@ -0,0 +1,395 @@
+//! Checks for obstructions to realizing a Steenrod module as the cohomology of a space or a
+//! spectrum.
+//!
+//! A module that satisfies the Adem relations need not be the cohomology of anything. This module
+//! checks a few known constraints:
+//!
+//!  - [`unstable`]: The cohomology of a space is an unstable module, i.e. $\mathrm{Sq}^i x = 0$ if
+//!    $i > |x|$, or $\beta^e P^i x = 0$ if $2i + e > |x|$ at odd primes. Every module that is the
+//!    cohomology of a finite spectrum becomes unstable after a sufficiently large shift, and we
+//!    report the smallest such shift.
+//!  - [`hopf_invariant_one`]: By Adams' solution of the Hopf invariant one problem,
+//!    $\mathrm{Sq}^{2^n}$ is decomposable via secondary operations for $n \geq 4$, and the same
+//!    holds for $P^{p^n}$ with $n \geq 1$ at odd primes. Hence if $x$ is annihilated by all
+//!    generators of the Steenrod algebra of lower degree, then $\mathrm{Sq}^{2^n} x$ lies in the
+//!    span of the $a z$ with $a$ of positive degree and $|x| < |z| < |x| + 2^n$. In particular, a
+//!    two cell complex cannot be attached by $\mathrm{Sq}^{16}$.
+//!  - [`secondary`]: The cohomology of a spectrum is a module over the secondary Steenrod algebra
+//!    (the [`PairAlgebra`](algebra::pair_algebra::PairAlgebra)), and the obstruction to the
+//!    existence of such a structure lies in a subquotient of $\Hom_A(R_3, \Sigma^{-1} M)$, where
+//!    $R_\bullet$ is the minimal resolution of $M$. We check whether the trivial structure can be
+//!    used, which is the criterion of [`secondary::can_compute`](crate::secondary::can_compute).
+//!    This is only a sufficient condition and we do not compute the obstruction, so this check
+//!    never fails. If the criterion does not hold, the check is inconclusive.
+//!
+//! Passing all checks does not imply that the module is realizable.
+
+use algebra::module::{BoundedModule, Module};
+use algebra::{
+    milnor_algebra::MilnorBasisElement, Algebra, GeneratedAlgebra, SteenrodAlgebra,
+    SteenrodAlgebraBorrow, SteenrodAlgebraT,
+};
+use fp::matrix::{Matrix, Subspace};
+use fp::vector::FpVector;
+
+use crate::chain_complex::{AugmentedChainComplex, ChainComplex};
+use crate::resolution::Resolution;
+use crate::CCC;
+
+/// The outcome of a check.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Status {
+    /// The module satisfies the constraint.
+    Pass,
+    /// The module violates the constraint, for the given reason.
+    Fail(String),
+    /// The check does not apply to the module or cannot decide, for the given reason.
+    Inconclusive(String),
+}
+
+impl std::fmt::Display for Status {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::Pass => write!(f, "pass"),
+            Self::Fail(reason) => write!(f, "FAIL ({reason})"),
+            Self::Inconclusive(reason) => write!(f, "inconclusive ({reason})"),
+        }
+    }
+}
+
+/// The result of a single check, as returned by [`check`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Check {
+    pub name: &'static str,
+    pub status: Status,
+}
+
+impl std::fmt::Display for Check {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}: {}", self.name, self.status)
+    }
+}
+
+/// Run all checks on the module resolved by `resolution`. Infinite modules are only checked
+/// through `max_degree`.
+pub fn check(resolution: &Resolution<CCC>, max_degree: i32) -> Vec<Check> {
+    let module = resolution.target().module(0);
+    let max_degree = std::cmp::min(module.max_degree(), max_degree);
+    vec![
+        Check {
+            name: "unstable",
+            status: unstable(&*module, max_degree),
+        },
+        Check {
+            name: "hopf invariant one",
+            status: hopf_invariant_one(&*module, max_degree),
+        },
+        Check {
+            name: "secondary",
+            status: secondary(resolution),
+        },
+    ]
+}
+
+/// The index of $\beta^e P^i$ (or $\mathrm{Sq}^i$ at the prime 2) in the basis of `algebra`, if
+/// it is in the algebra.
+fn beps_pn(algebra: &SteenrodAlgebra, e: u32, i: u32) -> Option<usize> {
+    match algebra.steenrod_algebra() {
+        SteenrodAlgebraBorrow::BorrowAdem(a) => Some(a.beps_pn(e, i).1),
+        SteenrodAlgebraBorrow::BorrowMilnor(a) => {
+            a.try_basis_element_to_index(&MilnorBasisElement {
+                q_part: e,
+                p_part: if i == 0 { vec![] } else { vec![i as _] },
+                degree: e as i32 + i as i32 * a.q(),
+            })
+        }
+    }
+}
+
+fn element_to_string<M: Module>(module: &M, degree: i32, v: &FpVector) -> String {
+    v.iter_nonzero()
+        .map(|(i, c)| {
+            let name = module.basis_element_to_string(degree, i);
+            if c == 1 {
+                name
+            } else {
+                format!("{c} {name}")
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(" + ")
+}
+
+/// Check whether the module is unstable through `max_degree`. If it is not, the failure reports
+/// the smallest shift that makes the module unstable.
+pub fn unstable<M: Module<Algebra = SteenrodAlgebra>>(module: &M, max_degree: i32) -> Status {
+    let algebra = module.algebra();
+    let p = module.prime();
+    let generic = *p != 2;
+    let q = if generic { 2 * *p as i32 - 2 } else { 1 };
+    let min_degree = module.min_degree();
+    module.compute_basis(max_degree);
+    algebra.compute_basis(max_degree - min_degree);
+
+    // The required shift and a witness
+    let mut worst: Option<(i32, String)> = None;
+    for degree in min_degree..=max_degree {
+        for e in 0..=generic as u32 {
+            for i in 0.. {
+                let op_degree = e as i32 + q * i as i32;
+                if op_degree == 0 {
+                    continue;
+                }
+                if degree + op_degree > max_degree {
+                    break;
+                }
+                let excess = if generic {
+                    2 * i as i32 + e as i32
+                } else {
+                    i as i32
+                };
+                if excess <= degree || worst.as_ref().is_some_and(|w| excess - degree <= w.0) {
+                    continue;
+                }
+                let Some(op_idx) = beps_pn(&algebra, e, i) else {
+                    continue;
+                };
+                let mut output = FpVector::new(p, module.dimension(degree + op_degree));
+                for idx in 0..module.dimension(degree) {
+                    module.act_on_basis(output.as_slice_mut(), 1, op_degree, op_idx, degree, idx);
+                    if !output.is_zero() {
+                        worst = Some((
+                            excess - degree,
+                            format!(
+                                "{} {} = {}",
+                                algebra.basis_element_to_string(op_degree, op_idx),
+                                module.basis_element_to_string(degree, idx),
+                                element_to_string(module, degree + op_degree, &output)
+                            ),
+                        ));
+                        break;
+                    }
+                }
+            }
+        }
+    }
+    match worst {
+        None => Status::Pass,
+        Some((shift, witness)) => Status::Fail(format!(
+            "{witness}; the module is unstable after shifting by {shift}"
+        )),
+    }
+}
+
+/// Check that the generators $\mathrm{Sq}^{2^n}$ with $n \geq 4$ (or $P^{p^n}$ with $n \geq 1$ at
+/// odd primes) act decomposably on the elements annihilated by all generators of lower degree.
+pub fn hopf_invariant_one<M: Module<Algebra = SteenrodAlgebra>>(
+    module: &M,
+    max_degree: i32,
+) -> Status {
+    let algebra = module.algebra();
+    let p = module.prime();
+    let generic = *p != 2;
+    let q = if generic { 2 * *p as i32 - 2 } else { 1 };
+    let min_degree = module.min_degree();
+    module.compute_basis(max_degree);
+    algebra.compute_basis(max_degree - min_degree);
+
+    let mut power = if generic { *p } else { 16 };
+    while q * power as i32 <= max_degree - min_degree {
+        let op_degree = q * power as i32;
+        power *= *p;
+        let Some(op_idx) = beps_pn(&algebra, 0, op_degree as u32 / q as u32) else {
+            continue;
+        };
+        let lower_generators: Vec<(i32, usize)> = (1..op_degree)
+            .flat_map(|d| algebra.generators(d).into_iter().map(move |i| (d, i)))
+            .collect();
+
+        for degree in min_degree..=max_degree - op_degree {
+            let target_degree = degree + op_degree;
+            let dim = module.dimension(degree);
+            if dim == 0 || module.dimension(target_degree) == 0 {
+                continue;
+            }
+
+            // The elements annihilated by the lower generators
+            let total: usize = lower_generators
+                .iter()
+                .map(|&(d, _)| module.dimension(degree + d))
+                .sum();
+            let mut matrix = Matrix::new(p, dim, total + dim);
+            for (idx, row) in matrix.iter_mut().enumerate() {
+                let mut start = 0;
+                for &(d, i) in &lower_generators {
+                    let len = module.dimension(degree + d);
+                    module.act_on_basis(row.slice_mut(start, start + len), 1, d, i, degree, idx);
+                    start += len;
+                }
+                row.set_entry(total + idx, 1);
+            }
+            matrix.row_reduce();
+            let kernel = matrix.compute_kernel(total);
+
+            // The decomposable elements in the target degree
+            let target_dim = module.dimension(target_degree);
+            let mut decomposables = Subspace::new(p, target_dim + 1, target_dim);
+            let mut output = FpVector::new(p, target_dim);
+            for z_degree in degree + 1..target_degree {
+                let a_degree = target_degree - z_degree;
+                for a_idx in 0..algebra.dimension(a_degree) {
+                    for z_idx in 0..module.dimension(z_degree) {
+                        module.act_on_basis(
+                            output.as_slice_mut(),
+                            1,
+                            a_degree,
+                            a_idx,
+                            z_degree,
+                            z_idx,
+                        );
+                        decomposables.add_vector(output.as_slice());
+                        output.set_to_zero();
+                    }
+                }
+            }
+
+            for x in kernel.basis() {
+                module.act(
+                    output.as_slice_mut(),
+                    1,
+                    op_degree,
+                    op_idx,
+                    degree,
+                    x.as_slice(),
+                );
+                if !decomposables.contains(output.as_slice()) {
+                    return Status::Fail(format!(
+                        "{} ({}) = {} is indecomposable",
+                        algebra.basis_element_to_string(op_degree, op_idx),
+                        element_to_string(module, degree, x),
+                        element_to_string(module, target_degree, &output),
+                    ));
+                }
+                output.set_to_zero();
+            }
+        }
+    }
+    Status::Pass
+}
+
+/// Check whether the module admits the trivial secondary structure, i.e. whether
+/// [`secondary::can_compute`](crate::secondary::can_compute) holds. This computes `resolution`
+/// through homological degree 3.
+///
+/// This is only a sufficient condition for realizability by a spectrum. If it holds, $\delta_2 = 0$
+/// makes the module a module over the secondary Steenrod algebra and the check passes. Otherwise,
+/// the check is inconclusive, since we do not compute the obstruction class itself. In
+/// particular, this check never fails.
+pub fn secondary(resolution: &Resolution<CCC>) -> Status {
+    let max_degree = resolution.target().module(0).max_degree();
+    if max_degree == i32::MAX {
+        return Status::Inconclusive("the module is not finite dimensional".into());
+    }
+    resolution.compute_through_bidegree(3, max_degree + 1);
+
+    if crate::secondary::can_compute(resolution) {
+        Status::Pass
+    } else {
+        Status::Inconclusive("δ₂ = 0 does not give a secondary structure".into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use serde_json::json;
+
+    use crate::utils::{construct, load_module_json};
+
+    fn status(name: &str, json: serde_json::Value) -> Vec<Status> {
+        let resolution = construct((json, "milnor"), None).unwrap();
+        let checks = check(&resolution, 40);
+        assert_eq!(checks.len(), 3, "{name}");
+        checks.into_iter().map(|c| c.status).collect()
+    }
+
+    #[test]
+    fn hopf_invariant_one_cells() {
+        for (k, pass) in [(8, true), (16, false)] {
+            let json = json!({
+                "type": "finite dimensional module",
+                "p": 2,
+                "gens": {"x0": 0, format!("x{k}"): k},
+                "actions": [format!("Sq{k} x0 = x{k}")],
+            });
+            let statuses = status(&format!("Sq{k}"), json);
+            assert_eq!(statuses[1] == Status::Pass, pass, "Sq{k}: {}", statuses[1]);
+        }
+
+        // Sq^16 on a class that supports a non-zero Sq^8 is not constrained.
+        let json = json!({
+            "type": "finite dimensional module",
+            "p": 2,
+            "gens": {"x0": 0, "x8": 8, "x16": 16},
+            "actions": ["Sq8 x0 = x8", "Sq16 x0 = x16"],
+        });
+        assert_eq!(status("Sq8 + Sq16", json)[1], Status::Pass);
+    }
+
+    #[test]
+    fn unstable_shift() {
+        let resolution = construct("Joker", None).unwrap();
+        let module = resolution.target().module(0);
+        // Sq^2 x0 = x2 forces a shift of 2.
+        match unstable(&*module, 4) {
+            Status::Fail(reason) => assert!(reason.ends_with("shifting by 2"), "{reason}"),
+            s => panic!("Unexpected status {s}"),
+        }
+
+        let resolution = construct("RP_inf", None).unwrap();
+        assert_eq!(unstable(&*resolution.target().module(0), 30), Status::Pass);
+
+        let resolution = construct("S_3", None).unwrap();
+        assert_eq!(unstable(&*resolution.target().module(0), 30), Status::Pass);
+    }
+
+    #[test]
+    fn secondary_criterion() {
+        let resolution = construct("C2@milnor", None).unwrap();
+        assert_eq!(secondary(&resolution), Status::Pass);
+
+        // The criterion is only sufficient, so modules that do not satisfy it are not failures.
+        let resolution = construct("S_3@milnor", None).unwrap();
+        assert!(matches!(secondary(&resolution), Status::Inconclusive(_)));
+        let resolution = construct("RP_inf@milnor", None).unwrap();
+        assert!(matches!(secondary(&resolution), Status::Inconclusive(_)));
+    }
+
+    /// All modules in `steenrod_modules/` are known to be realizable, so none should fail the
+    /// Hopf invariant one or secondary checks.
+    #[test]
+    fn steenrod_modules() {
+        let dir = concat!(env!("CARGO_MANIFEST_DIR"), "/steenrod_modules");
+        let mut names: Vec<String> = std::fs::read_dir(dir)
+            .unwrap()
+            .filter_map(|entry| {
+                let path = entry.unwrap().path();
+                let json = load_module_json(path.to_str()?).ok()?;
+                (json["type"] == "finite dimensional module" && json["cofiber"].is_null())
+                    .then(|| path.file_stem().unwrap().to_str().unwrap().to_owned())
+            })
+            .collect();
+        names.sort();
+
+        for name in &names {
+            let resolution = construct(name.as_str(), None).unwrap();
+            for c in check(&resolution, 40) {
+                assert!(
+                    !matches!(c.status, Status::Fail(_)) || c.name == "unstable",
+                    "{name}: {c}"
+                );
+            }
+        }
+    }
+}
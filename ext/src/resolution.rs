@@ -5,10 +5,11 @@ use crate::save::SaveKind;
 use algebra::module::homomorphism::{FreeModuleHomomorphism, ModuleHomomorphism};
 use algebra::module::{FreeModule, Module};
 use algebra::Algebra;
-use fp::matrix::{AugmentedMatrix, QuasiInverse, Subspace};
+use fp::matrix::{AugmentedMatrix, DiskMatrix, Matrix, QuasiInverse, Subspace};
 use fp::vector::{FpVector, Slice, SliceMut};
 use once::OnceVec;
 
+use std::io::Read;
 use std::path::{Path, PathBuf};
 
 use anyhow::Context;
@@ -86,6 +87,16 @@ pub struct Resolution<CC: ChainComplex> {
     /// augmentation map are useful when the target chain complex is not concentrated in one
     /// degree, and they tend to be quite small anyway.
     pub load_quasi_inverse: bool,
+
+    /// If set, bidegrees whose matrix has more than this many entries are row reduced out of core
+    /// with a [`DiskMatrix`], using the save directory as scratch space. This has no effect if
+    /// there is no save directory.
+    ///
+    /// The row reduction then holds about twice this many entries in memory at a time. If
+    /// `should_save` is set, the kernel and the quasi-inverse of the differential are written
+    /// directly to the save directory instead of being held in memory, regardless of
+    /// `load_quasi_inverse`.
+    pub out_of_core_threshold: Option<usize>,
 }
 
 impl<CC: ChainComplex> Resolution<CC> {
@@ -117,6 +128,7 @@ impl<CC: ChainComplex> Resolution<CC> {
             differentials: OnceVec::new(),
             kernels: DashMap::new(),
             load_quasi_inverse: true,
+            out_of_core_threshold: None,
         })
     }
 
@@ -431,6 +443,15 @@ impl<CC: ChainComplex> Resolution<CC> {
             }
         }
 
+        if let (Some(threshold), Some(dir)) = (self.out_of_core_threshold, self.save_dir.as_ref()) {
+            let entries =
+                source_dimension * (target_cc_dimension + target_res_dimension + source_dimension);
+            if entries > threshold {
+                self.step_resolution_out_of_core(s, t, dir, threshold);
+                return;
+            }
+        }
+
         let mut matrix = AugmentedMatrix::<3>::new_with_capacity(
             p,
             source_dimension,
@@ -603,24 +624,7 @@ impl<CC: ChainComplex> Resolution<CC> {
 
         if self.should_save {
             if let Some(dir) = self.save_dir.as_ref() {
-                // Write differentials
-                let mut f = self
-                    .save_file(SaveKind::Differential, s, t)
-                    .create_file(dir.clone());
-
-                f.write_u64::<LittleEndian>(num_new_gens as u64).unwrap();
-                f.write_u64::<LittleEndian>(target_res_dimension as u64)
-                    .unwrap();
-                f.write_u64::<LittleEndian>(target_cc_dimension as u64)
-                    .unwrap();
-
-                for n in 0..num_new_gens {
-                    current_differential.output(t, n).to_bytes(&mut f).unwrap();
-                }
-                for n in 0..num_new_gens {
-                    current_chain_map.output(t, n).to_bytes(&mut f).unwrap();
-                }
-                drop(f);
+                self.write_differential(dir, s, t, num_new_gens);
 
                 // Write resolution qi
                 res_qi
@@ -665,6 +669,267 @@ impl<CC: ChainComplex> Resolution<CC> {
         current_differential.set_image(t, None);
     }
 
+    /// Write the values of the differential and chain map on the new generators in bidegree $(s,
+    /// t)$ to the save directory.
+    fn write_differential(&self, dir: &Path, s: u32, t: i32, num_new_gens: usize) {
+        let current_differential = self.differential(s);
+        let current_chain_map = self.chain_map(s);
+        let target_res_dimension = current_differential.target().dimension(t);
+        let target_cc_dimension = current_chain_map.target().dimension(t);
+
+        let mut f = self
+            .save_file(SaveKind::Differential, s, t)
+            .create_file(dir.to_owned());
+
+        f.write_u64::<LittleEndian>(num_new_gens as u64).unwrap();
+        f.write_u64::<LittleEndian>(target_res_dimension as u64)
+            .unwrap();
+        f.write_u64::<LittleEndian>(target_cc_dimension as u64)
+            .unwrap();
+
+        for n in 0..num_new_gens {
+            current_differential.output(t, n).to_bytes(&mut f).unwrap();
+        }
+        for n in 0..num_new_gens {
+            current_chain_map.output(t, n).to_bytes(&mut f).unwrap();
+        }
+    }
+
+    /// Calls `f` on the rows of the kernel of the differential starting at $(s, t)$, in order of
+    /// their pivots. If the kernel is not cached in memory, this reads it from the save file one
+    /// row at a time, and otherwise falls back to [`Resolution::get_kernel`].
+    fn for_each_kernel_row(&self, s: u32, t: i32, mut f: impl FnMut(Slice)) {
+        if !self.kernels.contains_key(&(s, t)) {
+            if let Some(mut file) = self.save_dir.as_ref().and_then(|dir| {
+                self.save_file(SaveKind::Kernel, s, t)
+                    .open_file(dir.clone())
+            }) {
+                let rows = file.read_u64::<LittleEndian>().unwrap() as usize;
+                let dimension = file.read_u64::<LittleEndian>().unwrap() as usize;
+                for _ in 0..rows {
+                    let row = FpVector::from_bytes(self.prime(), dimension, &mut file)
+                        .with_context(|| format!("Failed to read kernel at ({s}, {t})"))
+                        .unwrap();
+                    f(row.as_slice());
+                }
+                // Skip the pivots, which are the positions of the first non-zero entries
+                std::io::copy(
+                    &mut (&mut file).take(dimension as u64 * 8),
+                    &mut std::io::sink(),
+                )
+                .unwrap();
+                return;
+            }
+        }
+        for row in self.get_kernel(s, t).iter() {
+            f(row.as_slice());
+        }
+    }
+
+    /// The analogue of [`Resolution::step_resolution`] for bidegrees whose matrix is too large to
+    /// fit in memory. The matrices are stored in [`DiskMatrix`]es in `dir`, with `threshold / columns`
+    /// rows per block.
+    ///
+    /// We first row reduce the map from the existing part of $X_{s, t}$ as usual. The new
+    /// generators are determined by the pivots of the result as in
+    /// [`Matrix::extend_to_surjection`] and [`Matrix::extend_image`], and we then row reduce the
+    /// map from all of $X_{s, t}$ again to obtain the quasi-inverses. The new rows are unlikely to
+    /// be in the right place for the incremental update in [`Resolution::step_resolution`], and
+    /// the second row reduction is cheap compared to the cost of reading the matrix from disk.
+    ///
+    /// The kernel of the previous bidegree and the quasi-inverse of the differential are streamed
+    /// from and to the save directory if `should_save` is set.
+    fn step_resolution_out_of_core(&self, s: u32, t: i32, dir: &Path, threshold: usize) {
+        let p = self.prime();
+
+        let complex = self.target();
+        let current_differential = self.differential(s);
+        let current_chain_map = self.chain_map(s);
+        let complex_cur_differential = complex.differential(s);
+
+        let source_dimension = self.module(s).dimension(t);
+        let target_cc_dimension = complex.module(s).dimension(t);
+        let target_res_dimension = current_differential.target().dimension(t);
+
+        // The layout of the augmented matrix [f | d | I], as in AugmentedMatrix
+        let res_start = FpVector::padded_len(p, target_cc_dimension);
+        let res_end = res_start + target_res_dimension;
+        let source_start = res_start + FpVector::padded_len(p, target_res_dimension);
+
+        // Write the map (f, d) : X_{s, t} -> C_{s, t} (+) X_{s-1, t} on the first `rows`
+        // generators to a DiskMatrix, augmented with the identity.
+        let new_matrix = |rows: usize| {
+            let columns = source_start + rows;
+            let block_size = std::cmp::max(1, threshold / columns);
+            let mut matrix = DiskMatrix::new(p, columns, dir, block_size)
+                .with_context(|| format!("Failed to create scratch file in {dir:?}"))
+                .unwrap();
+            let mut row = FpVector::new(p, columns);
+            for i in 0..rows {
+                row.set_to_zero();
+                current_chain_map.apply_to_basis_element(
+                    row.slice_mut(0, target_cc_dimension),
+                    1,
+                    t,
+                    i,
+                );
+                current_differential.apply_to_basis_element(
+                    row.slice_mut(res_start, res_end),
+                    1,
+                    t,
+                    i,
+                );
+                row.set_entry(source_start + i, 1);
+                matrix.push_row(&row).unwrap();
+            }
+            matrix.row_reduce().unwrap();
+            matrix
+        };
+
+        let matrix = new_matrix(source_dimension);
+
+        if !self.has_computed_bidegree(s + 1, t) {
+            if self.should_save {
+                let mut f = self
+                    .save_file(SaveKind::Kernel, s, t)
+                    .create_file(dir.to_owned());
+                matrix
+                    .write_kernel(source_start, &mut f)
+                    .with_context(|| format!("Failed to write kernel at ({s}, {t})"))
+                    .unwrap();
+            } else {
+                self.kernels
+                    .insert((s, t), matrix.compute_kernel(source_start).unwrap());
+            }
+        }
+
+        // Add generators to surject onto C_{s, t}, and to hit the cycles in the kernel of the
+        // previous differential that are not yet in the image.
+        let mut a_targets = Vec::new();
+        let mut d_targets = Vec::new();
+        for column in 0..target_cc_dimension {
+            if matrix.pivots()[column] >= 0 {
+                continue;
+            }
+            let mut a_target = FpVector::new(p, target_cc_dimension);
+            a_target.set_entry(column, 1);
+            let mut d_target = FpVector::new(p, target_res_dimension);
+            if s > 0 {
+                // We set dX(x) = f^{-1}(dC(f(x)))
+                let mut dfx = FpVector::new(p, complex_cur_differential.target().dimension(t));
+                complex_cur_differential.apply_to_basis_element(dfx.as_slice_mut(), 1, t, column);
+                self.chain_map(s - 1).quasi_inverse(t).unwrap().apply(
+                    d_target.as_slice_mut(),
+                    1,
+                    dfx.as_slice(),
+                );
+            }
+            a_targets.push(a_target);
+            d_targets.push(d_target);
+        }
+        if s > 0 {
+            self.for_each_kernel_row(s - 1, t, |row| {
+                let (pivot, _) = row.iter_nonzero().next().unwrap();
+                if matrix.pivots()[res_start + pivot] >= 0 {
+                    return;
+                }
+                // The kernel was computed before the new generators of X_{s-1, t} were added
+                let mut d_target = FpVector::new(p, target_res_dimension);
+                d_target.slice_mut(0, row.len()).assign(row);
+                a_targets.push(FpVector::new(p, target_cc_dimension));
+                d_targets.push(d_target);
+            });
+        }
+        drop(matrix);
+
+        let num_new_gens = a_targets.len();
+        self.module(s).add_generators(t, num_new_gens, None);
+        current_chain_map.add_generators_from_rows(t, a_targets);
+        current_differential.add_generators_from_rows(t, d_targets);
+
+        let new_rows = source_dimension + num_new_gens;
+        let matrix = new_matrix(new_rows);
+
+        // The chain map is surjective, so every column of C_{s, t} has a pivot.
+        let mut cc_preimage = Matrix::new(p, target_cc_dimension, new_rows);
+        for (column, preimage) in cc_preimage.iter_mut().enumerate() {
+            let row = matrix.row(matrix.pivots()[column] as usize).unwrap();
+            preimage
+                .as_slice_mut()
+                .assign(row.slice(source_start, source_start + new_rows));
+        }
+        let cm_qi = QuasiInverse::new(None, cc_preimage);
+
+        // As in AugmentedMatrix::compute_quasi_inverses, the quasi-inverse of d comes from row
+        // reducing the rows of [d | I] that are not in the kernel of (f, d).
+        let res_columns = source_start - res_start;
+        let block_size = std::cmp::max(1, threshold / (res_columns + new_rows));
+        let mut res_matrix = DiskMatrix::new(p, res_columns + new_rows, dir, block_size)
+            .with_context(|| format!("Failed to create scratch file in {dir:?}"))
+            .unwrap();
+        let mut res_row = FpVector::new(p, res_columns + new_rows);
+        for &row in &matrix.pivots()[..source_start] {
+            if row < 0 {
+                continue;
+            }
+            let row = matrix.row(row as usize).unwrap();
+            res_row
+                .slice_mut(0, target_res_dimension)
+                .assign(row.slice(res_start, res_end));
+            res_row
+                .slice_mut(res_columns, res_columns + new_rows)
+                .assign(row.slice(source_start, source_start + new_rows));
+            res_matrix.push_row(&res_row).unwrap();
+        }
+        drop(matrix);
+        res_matrix.row_reduce().unwrap();
+
+        if self.should_save {
+            self.write_differential(dir, s, t, num_new_gens);
+
+            let mut f = self
+                .save_file(SaveKind::ResQi, s, t)
+                .create_file(dir.to_owned());
+            res_matrix
+                .write_quasi_inverse(target_res_dimension, res_columns, &mut f)
+                .unwrap();
+            drop(f);
+            current_differential.set_quasi_inverse(t, None);
+
+            cm_qi
+                .to_bytes(
+                    &mut self
+                        .save_file(SaveKind::AugmentationQi, s, t)
+                        .create_file(dir.to_owned()),
+                )
+                .unwrap();
+
+            if s > 0 {
+                self.save_file(SaveKind::Kernel, s - 1, t)
+                    .delete_file(dir.to_owned())
+                    .unwrap();
+            }
+        } else if self.load_quasi_inverse {
+            current_differential.set_quasi_inverse(
+                t,
+                Some(
+                    res_matrix
+                        .compute_quasi_inverse(target_res_dimension, res_columns)
+                        .unwrap(),
+                ),
+            );
+        } else {
+            current_differential.set_quasi_inverse(t, None);
+        }
+
+        current_chain_map.set_quasi_inverse(t, Some(cm_qi));
+        current_chain_map.set_kernel(t, None);
+        current_chain_map.set_image(t, None);
+
+        current_differential.set_kernel(t, None);
+        current_differential.set_image(t, None);
+    }
+
     pub fn compute_through_bidegree_with_callback(
         &self,
         max_s: u32,
@@ -920,6 +1185,7 @@ impl<CC: ChainComplex> AugmentedChainComplex for Resolution<CC> {
 mod test {
     use super::*;
     use crate::{chain_complex::FreeChainComplex, utils::construct};
+    use algebra::AlgebraType;
     use expect_test::expect;
 
     #[test]
@@ -959,4 +1225,106 @@ mod test {
         assert!(res.apply_quasi_inverse(&mut [w.as_slice_mut()], 8, 8, &[v.as_slice()]));
         assert!(w.is_zero());
     }
+
+    #[test]
+    fn test_out_of_core() {
+        let expected = construct("Ceta", None).unwrap();
+        expected.compute_through_stem(6, 12);
+
+        for should_save in [true, false] {
+            let tempdir = tempfile::TempDir::new().unwrap();
+            let mut res = construct("Ceta", Some(tempdir.path().into())).unwrap();
+            res.should_save = should_save;
+            res.out_of_core_threshold = Some(20);
+            res.compute_through_stem(6, 12);
+
+            assert_eq!(
+                res.graded_dimension_string(),
+                expected.graded_dimension_string()
+            );
+            for (s, _, t) in expected.iter_stem() {
+                let d = res.differential(s);
+                let expected_d = expected.differential(s);
+                for i in 0..expected.number_of_gens_in_bidegree(s, t) {
+                    assert_eq!(d.output(t, i), expected_d.output(t, i));
+                }
+
+                let p = res.prime();
+                let target_dim = d.target().dimension(t);
+                for i in 0..target_dim {
+                    let mut v = FpVector::new(p, target_dim);
+                    v.set_entry(i, 1);
+                    let mut w = FpVector::new(p, res.module(s).dimension(t));
+                    let mut expected_w = w.clone();
+                    assert!(res.apply_quasi_inverse(
+                        &mut [w.as_slice_mut()],
+                        s,
+                        t,
+                        &[v.as_slice()]
+                    ));
+                    assert!(expected.apply_quasi_inverse(
+                        &mut [expected_w.as_slice_mut()],
+                        s,
+                        t,
+                        &[v.as_slice()]
+                    ));
+                    assert_eq!(w, expected_w, "Quasi-inverse at ({s}, {t})");
+                }
+            }
+
+            if should_save {
+                // The save files can be read back
+                drop(res);
+                let res = construct("Ceta", Some(tempdir.path().into())).unwrap();
+                res.compute_through_stem(6, 12);
+                for (s, _, t) in expected.iter_stem() {
+                    for i in 0..expected.number_of_gens_in_bidegree(s, t) {
+                        assert_eq!(
+                            res.differential(s).output(t, i),
+                            expected.differential(s).output(t, i)
+                        );
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "odd-primes")]
+    fn test_generic_at_2() {
+        let json = serde_json::json!({
+            "type": "finite dimensional module",
+            "p": 2,
+            "generic": true,
+            "gens": {"x0": 0},
+            "actions": [],
+        });
+        let res = construct((json, AlgebraType::Milnor), None).unwrap();
+        assert!(res.algebra().generic());
+        res.compute_through_stem(6, 12);
+
+        expect![[r#"
+            ·           ·   ·   · :   
+            ·         ·   ·   · · · · 
+            ·       ·   · ·   ·   ·   
+            ·     ·   ·   · · ·       
+            ·   · ·     · · ·         
+            · ·   ·       ·           
+            ·                         
+        "#]]
+        .assert_eq(&res.graded_dimension_string());
+    }
+
+    #[test]
+    #[cfg(not(feature = "odd-primes"))]
+    fn test_generic_at_2_without_odd_primes() {
+        let json = serde_json::json!({
+            "type": "finite dimensional module",
+            "p": 2,
+            "generic": true,
+            "gens": {"x0": 0},
+            "actions": [],
+        });
+        assert!(construct((json, AlgebraType::Milnor), None).is_err());
+    }
 }
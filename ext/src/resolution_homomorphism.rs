@@ -393,6 +393,8 @@ where
             FiniteModule::FDModule(m) => m.max_degree(),
             FiniteModule::FPModule(m) => m.generators().get_max_generator_degree(),
             FiniteModule::RealProjectiveSpace(_) => panic!("Real Projective Space not supported"),
+            FiniteModule::ProjectiveSpace(_) => panic!("Projective Space not supported"),
+            FiniteModule::LazyModule(_) => panic!("Lazy Module not supported"),
         };
 
         let hom = Self::new(name, source, target, 0, degree_shift);
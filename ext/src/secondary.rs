@@ -1325,10 +1325,11 @@ pub fn can_compute(res: &Resolution<CCC>) -> bool {
         return false;
     }
     let module = module.unwrap();
+    let min_degree = module.min_degree();
     let max_degree = module.max_degree();
 
-    (0..max_degree)
+    (min_degree..max_degree)
         .all(|t| module.dimension(t) == 0 || res.number_of_gens_in_bidegree(2, t + 1) == 0)
-        || (0..max_degree)
+        || (min_degree..max_degree)
             .all(|t| module.dimension(t) == 0 || res.number_of_gens_in_bidegree(3, t + 1) == 0)
 }
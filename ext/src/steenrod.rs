@@ -0,0 +1,949 @@
+//! Algebraic Steenrod operations in $\Ext$.
+//!
+//! Let $M$ be a module with an $A$-linear, coassociative and cocommutative diagonal $\psi\colon M
+//! \to M^{\otimes p}$, for example $M = \mathbb{F}_p$. Then $\Ext_A(M, \mathbb{F}_p)$ admits
+//! Steenrod operations, which we compute via Steenrod's construction. Given a class $x \in
+//! \Ext^{s, t}$, let $Y$ be a [Yoneda representative](crate::yoneda) of $x$, so that $Y_s$ is a
+//! copy of $\mathbb{F}_p$ in degree $t$ and $Y$ resolves $M$. The cyclic group $C_p$ acts on
+//! $Y^{\otimes p}$ by cyclically permuting the factors, and we construct maps $\Delta_j\colon R
+//! \to Y^{\otimes p}$ of degree $j$ out of the resolution $R$ of $M$ such that $\Delta_0$ lifts
+//! $\psi$ and
+//! $$ d\Delta_j - (-1)^j \Delta_j d = \begin{cases} (\tau - 1) \Delta_{j - 1} & j \text{ odd}\\
+//! N \Delta_{j - 1} & j > 0 \text{ even}, \end{cases} $$
+//! where $\tau$ is the generator of $C_p$ and $N = 1 + \tau + \cdots + \tau^{p - 1}$. The
+//! operation $D_j(x) \in \Ext^{ps - j, pt}$ is the class of $x^{\otimes p} \Delta_j$.
+//!
+//! We index the operations so that $P^0$ is the "algebraic Frobenius" and the top operation is
+//! the $p$th power, following the convention of the `steenrod` example. Thus
+//! $$ P^i x = D_{(p - 1)(s - 2i)}(x) \in \Ext^{s + 2i(p - 1), pt},\quad \beta P^i x = D_{(p -
+//! 1)(s - 2i) - 1}(x) \in \Ext^{s + 2i(p - 1) + 1, pt}, $$
+//! and at the prime 2, $\Sq^i x = D_{s - i}(x) \in \Ext^{s + i, 2t}$, so that $P^i = \Sq^{2i}$ and
+//! $\beta P^i = \Sq^{2i + 1}$. At odd primes, these agree with the operations of May's "A general
+//! algebraic approach to Steenrod operations" up to multiplication by a unit, which we do not
+//! normalize.
+//!
+//! Odd primes require the Adem basis, since we need the coproduct of the Steenrod algebra, and
+//! are only supported for $M = \mathbb{F}_p$. At the prime 2, any module with a diagonal
+//! $\psi\colon M \to M \otimes M$ is supported, see [`SteenrodOperations::with_diagonal`].
+use std::sync::Arc;
+
+use algebra::module::homomorphism::{
+    BoundedModuleHomomorphism, FreeModuleHomomorphism, ModuleHomomorphism,
+};
+use algebra::module::{
+    BoundedModule, FDModule, FiniteModule, FreeModule, Module, QuotientModule, SumModule,
+    TensorModule,
+};
+use algebra::{GeneratedAlgebra, SteenrodAlgebra};
+use bivec::BiVec;
+use dashmap::DashMap;
+use fp::matrix::{AugmentedMatrix, Matrix, Subspace};
+use fp::prime::{minus_one_to_the_n, ValidPrime};
+use fp::vector::{FpVector, Slice, SliceMut};
+use rustc_hash::FxHashMap as HashMap;
+
+use crate::chain_complex::{
+    AugmentedChainComplex, BoundedChainComplex, ChainComplex, FreeChainComplex,
+};
+use crate::yoneda::{yoneda_representative_element, Yoneda};
+
+/// A diagonal $M \to M \otimes M$ of a module $M$.
+pub type Diagonal<M> = BoundedModuleHomomorphism<M, TensorModule<M, M>>;
+
+/// An algebraic Steenrod operation. See the [module level documentation](self) for the indexing
+/// convention.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum SteenrodOperation {
+    /// $\Sq^i$, which is only defined at the prime 2.
+    Sq(u32),
+    P(u32),
+    BetaP(u32),
+}
+
+impl SteenrodOperation {
+    /// The $j$ such that this operation is $D_j$ on $\Ext^{s, *}$, or `None` if the operation
+    /// vanishes there for degree reasons.
+    pub fn index(self, p: ValidPrime, s: u32) -> Option<u32> {
+        let (shift, bockstein) = match self {
+            Self::Sq(i) => {
+                assert_eq!(*p, 2, "Sq^i is only defined at the prime 2");
+                (i, 0)
+            }
+            Self::P(i) => (2 * i * (*p - 1), 0),
+            Self::BetaP(i) => (2 * i * (*p - 1), 1),
+        };
+        ((*p - 1) * s).checked_sub(shift + bockstein)
+    }
+
+    /// The bidegree $(s, t)$ of the image of $\Ext^{s, t}$, or `None` if the operation vanishes
+    /// there.
+    pub fn target(self, p: ValidPrime, s: u32, t: i32) -> Option<(u32, i32)> {
+        let j = self.index(p, s)?;
+        Some((*p * s - j, *p as i32 * t))
+    }
+}
+
+impl std::fmt::Display for SteenrodOperation {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::Sq(i) => write!(f, "Sq^{i}"),
+            Self::P(i) => write!(f, "P^{i}"),
+            Self::BetaP(i) => write!(f, "βP^{i}"),
+        }
+    }
+}
+
+/// Computes Steenrod operations in the $\Ext$ of a resolution.
+pub struct SteenrodOperations<CC>
+where
+    CC: AugmentedChainComplex<Algebra = SteenrodAlgebra>,
+    <CC::TargetComplex as ChainComplex>::Module: BoundedModule,
+{
+    resolution: Arc<CC>,
+    diagonal: Option<Arc<Diagonal<<CC::TargetComplex as ChainComplex>::Module>>>,
+    /// The values of [`SteenrodOperations::operations`], indexed by $(s, t, idx)$.
+    cache: DashMap<(u32, i32, usize), Arc<[FpVector]>>,
+}
+
+impl<TCM, TC, CC> SteenrodOperations<CC>
+where
+    TCM: BoundedModule<Algebra = SteenrodAlgebra>,
+    TC: BoundedChainComplex<Algebra = SteenrodAlgebra, Module = TCM>,
+    CC: AugmentedChainComplex<
+        Algebra = SteenrodAlgebra,
+        TargetComplex = TC,
+        Module = FreeModule<SteenrodAlgebra>,
+        Homomorphism = FreeModuleHomomorphism<FreeModule<SteenrodAlgebra>>,
+        ChainMap = FreeModuleHomomorphism<TCM>,
+    >,
+{
+    /// Steenrod operations in $\Ext_A(\mathbb{F}_p, \mathbb{F}_p)$. The resolution must be a
+    /// resolution of $\mathbb{F}_p$.
+    pub fn new(resolution: Arc<CC>) -> Self {
+        assert!(
+            resolution.target().module(0).is_unit(),
+            "Steenrod operations on a module other than k require a diagonal"
+        );
+        Self::check_algebra(&resolution);
+        Self {
+            resolution,
+            diagonal: None,
+            cache: DashMap::new(),
+        }
+    }
+
+    /// Steenrod operations in $\Ext_A(M, \mathbb{F}_2)$, where $M$ is the module resolved by
+    /// `resolution` and `diagonal` is an $A$-linear, coassociative and cocommutative diagonal $M
+    /// \to M \otimes M$. This is only supported at the prime 2.
+    pub fn with_diagonal(resolution: Arc<CC>, diagonal: Arc<Diagonal<TCM>>) -> Self {
+        assert_eq!(
+            *resolution.prime(),
+            2,
+            "Steenrod operations on modules with a diagonal are only supported at the prime 2"
+        );
+        assert!(Arc::ptr_eq(
+            &resolution.target().module(0),
+            &diagonal.source()
+        ));
+        assert_eq!(diagonal.degree_shift(), 0);
+        Self::check_algebra(&resolution);
+        Self {
+            resolution,
+            diagonal: Some(diagonal),
+            cache: DashMap::new(),
+        }
+    }
+
+    fn check_algebra(resolution: &CC) {
+        assert!(
+            *resolution.prime() == 2
+                || matches!(*resolution.algebra(), SteenrodAlgebra::AdemAlgebra(_)),
+            "Steenrod operations at odd primes require the Adem basis"
+        );
+    }
+
+    pub fn prime(&self) -> ValidPrime {
+        self.resolution.prime()
+    }
+
+    /// Compute the operations $D_j(x)$ for $0 \leq j \leq (p - 1)s$, where $x$ is the `idx`th
+    /// basis element of $\Ext^{s, t}$. The $j$th entry of the result lies in $\Ext^{ps - j,
+    /// pt}$.
+    ///
+    /// This resolves through the bidegree $(ps, pt)$ if necessary. The result is cached, so that
+    /// computing several operations on the same class only constructs the maps $\Delta_j$ once.
+    pub fn operations(&self, s: u32, t: i32, idx: usize) -> Arc<[FpVector]> {
+        if let Some(values) = self.cache.get(&(s, t, idx)) {
+            return Arc::clone(&values);
+        }
+        let values: Arc<[FpVector]> = self.compute_operations(s, t, idx).into();
+        self.cache.insert((s, t, idx), Arc::clone(&values));
+        values
+    }
+
+    fn compute_operations(&self, s: u32, t: i32, idx: usize) -> Vec<FpVector> {
+        let p = self.prime();
+        let resolution = &*self.resolution;
+        // The Yoneda representative needs the resolution through t plus the top degree of M
+        let max_degree = resolution.target().module(0).max_degree();
+        resolution.compute_through_bidegree(*p * s, std::cmp::max(*p as i32 * t, t + max_degree));
+
+        let yoneda = yoneda_representative_element(Arc::clone(&self.resolution), s, t, idx);
+        let power = TensorPower::new(p, &yoneda, s, resolution.min_degree());
+        // Evaluate the pth power of a cocycle representing the class on the top module
+        let top_t = *p as i32 * t;
+        let cocycle = self.cocycle(&yoneda, s, t, idx);
+        let cocycle: Vec<u32> = power.top_basis[t]
+            .iter()
+            .map(|&i| cocycle.entry(i))
+            .collect();
+        let mut evaluation = FpVector::new(p, power.modules[(*p * s) as usize].dimension(top_t));
+        for (i, tuple) in power.basis[(*p * s) as usize][top_t].iter().enumerate() {
+            if tuple.iter().all(|&(_, factor_t, _)| factor_t == t) {
+                let value = tuple
+                    .iter()
+                    .fold(1, |acc, &(_, _, k)| acc * cocycle[k] % *p);
+                evaluation.set_entry(i, value);
+            }
+        }
+
+        let top_t = *p as i32 * t;
+        // delta[j][m] is Δ_j on R_m.
+        let mut delta: Vec<Vec<FreeModuleHomomorphism<FDModule<SteenrodAlgebra>>>> = Vec::new();
+        let mut results = Vec::new();
+        for j in 0..=(*p - 1) * s {
+            let mut maps: Vec<FreeModuleHomomorphism<_>> = Vec::new();
+            for m in 0..=*p * s - j {
+                let source = resolution.module(m);
+                let map = FreeModuleHomomorphism::new(
+                    Arc::clone(&source),
+                    Arc::clone(&power.modules[(m + j) as usize]),
+                    0,
+                );
+                for deg in map.min_degree()..=top_t {
+                    let num_gens = source.number_of_gens_in_degree(deg);
+                    let dim = map.target().dimension(deg);
+                    if num_gens == 0 || dim == 0 {
+                        map.extend_by_zero(deg);
+                        continue;
+                    }
+                    let mut outputs = Matrix::new(p, num_gens, dim);
+                    for g in 0..num_gens {
+                        if m + j == 0 {
+                            self.lift_diagonal(&power, &yoneda, outputs[g].as_slice_mut(), deg, g);
+                            continue;
+                        }
+                        let d = &power.differentials[(m + j) as usize];
+                        let mut rhs = FpVector::new(p, d.target().dimension(deg));
+                        if j > 0 {
+                            let prev = delta[j as usize - 1][m as usize].output(deg, g);
+                            if j % 2 == 1 {
+                                power.rotate(
+                                    rhs.as_slice_mut(),
+                                    1,
+                                    m + j - 1,
+                                    deg,
+                                    prev.as_slice(),
+                                );
+                                rhs.add(prev, *p - 1);
+                            } else {
+                                power.norm(rhs.as_slice_mut(), m + j - 1, deg, prev.as_slice());
+                            }
+                        }
+                        if m > 0 {
+                            let dg = resolution.differential(m);
+                            maps[m as usize - 1].apply(
+                                rhs.as_slice_mut(),
+                                minus_one_to_the_n(*p, j as i32),
+                                deg,
+                                dg.output(deg, g).as_slice(),
+                            );
+                        }
+                        assert!(d.apply_quasi_inverse(
+                            outputs[g].as_slice_mut(),
+                            deg,
+                            rhs.as_slice()
+                        ));
+                        #[cfg(debug_assertions)]
+                        {
+                            let mut check = FpVector::new(p, rhs.len());
+                            d.apply(check.as_slice_mut(), 1, deg, outputs[g].as_slice());
+                            assert_eq!(check, rhs, "Failed to lift Δ_{j} on R_{m}");
+                        }
+                    }
+                    map.add_generators_from_matrix_rows(deg, outputs.as_slice_mut());
+                }
+                maps.push(map);
+            }
+
+            let map = maps.last().unwrap();
+            let m = *p * s - j;
+            let num_gens = resolution.number_of_gens_in_bidegree(m, top_t);
+            let mut result = FpVector::new(p, num_gens);
+            for g in 0..num_gens {
+                let value = map
+                    .output(top_t, g)
+                    .iter_nonzero()
+                    .map(|(i, v)| v * evaluation.entry(i))
+                    .sum::<u32>();
+                result.set_entry(g, value % *p);
+            }
+            results.push(result);
+            delta.push(maps);
+        }
+        results
+    }
+
+    /// Apply an operation to the element of $\Ext^{s, t}$ with coordinates `class`. This returns
+    /// `None` if the operation vanishes for degree reasons, and the bidegree of the result is
+    /// given by [`SteenrodOperation::target`].
+    pub fn apply(&self, op: SteenrodOperation, s: u32, t: i32, class: &[u32]) -> Option<FpVector> {
+        let p = self.prime();
+        let j = op.index(p, s)? as usize;
+        let (target_s, target_t) = op.target(p, s, t)?;
+        self.resolution
+            .compute_through_bidegree(*p * s, *p as i32 * t);
+
+        let mut result = FpVector::new(
+            p,
+            self.resolution
+                .number_of_gens_in_bidegree(target_s, target_t),
+        );
+        for (idx, &c) in class.iter().enumerate() {
+            if c % *p != 0 {
+                result.add(&self.operations(s, t, idx)[j], c);
+            }
+        }
+        Some(result)
+    }
+
+    /// The matrix of `op` from $\Ext^{s, t}$ to the bidegree given by
+    /// [`SteenrodOperation::target`], whose rows are the images of the basis elements. This
+    /// returns `None` if the operation vanishes for degree reasons.
+    pub fn matrix(&self, op: SteenrodOperation, s: u32, t: i32) -> Option<Matrix> {
+        let p = self.prime();
+        let j = op.index(p, s)? as usize;
+        let (target_s, target_t) = op.target(p, s, t)?;
+        self.resolution
+            .compute_through_bidegree(*p * s, *p as i32 * t);
+
+        let num_gens = self.resolution.number_of_gens_in_bidegree(s, t);
+        let mut matrix = Matrix::new(
+            p,
+            num_gens,
+            self.resolution
+                .number_of_gens_in_bidegree(target_s, target_t),
+        );
+        for idx in 0..num_gens {
+            matrix[idx].assign(&self.operations(s, t, idx)[j]);
+        }
+        Some(matrix)
+    }
+
+    /// The operation `op` on all classes with $s \leq$ `max_s` and $n \leq$ `max_n`, as a
+    /// [`sseq::Product`] whose coordinates are the stem and filtration. This resolves through the
+    /// bidegrees of the results, which can be expensive.
+    pub fn product(&self, op: SteenrodOperation, max_s: u32, max_n: i32) -> sseq::Product {
+        let p = self.prime();
+        let mut product = sseq::Product::new(op.to_string());
+        for s in 0..=max_s {
+            for n in self.resolution.min_degree()..=max_n {
+                let t = n + s as i32;
+                let (Some((target_s, target_t)), Some(matrix)) =
+                    (op.target(p, s, t), self.matrix(op, s, t))
+                else {
+                    continue;
+                };
+                product.set_matrix(
+                    n,
+                    s as i32,
+                    target_t - target_s as i32,
+                    target_s as i32,
+                    matrix,
+                );
+            }
+        }
+        product
+    }
+
+    /// Register `op` as a product on `sseq`, whose classes are indexed by the stem and
+    /// filtration, on all classes with $s \leq$ `max_s` and $n \leq$ `max_n`.
+    pub fn add_to_sseq<P: sseq::SseqProfile>(
+        &self,
+        sseq: &mut sseq::Sseq<P>,
+        op: SteenrodOperation,
+        max_s: u32,
+        max_n: i32,
+    ) {
+        sseq.add_product(self.product(op, max_s, max_n));
+    }
+
+    /// A cocycle on the top module $Y_s$ of the Yoneda representative in degree `t` that
+    /// represents the `idx`th basis element of $\Ext^{s, t}$. This is the functional that vanishes
+    /// on decomposables and on the kernel of the differential, and that restricts to the class
+    /// along a chain map $R \to Y$ lifting the identity of $M$.
+    fn cocycle(&self, yoneda: &Yoneda<CC>, s: u32, t: i32, idx: usize) -> FpVector {
+        let p = self.prime();
+        let resolution = &*self.resolution;
+
+        let mut maps: Vec<FreeModuleHomomorphism<FiniteModule>> = Vec::new();
+        for m in 0..=s {
+            let source = resolution.module(m);
+            let map = FreeModuleHomomorphism::new(Arc::clone(&source), yoneda.module(m), 0);
+            let d = yoneda.differential(m);
+            let augmentation = yoneda.chain_map(0);
+            if m == 0 {
+                augmentation.compute_auxiliary_data_through_degree(t);
+            } else {
+                d.compute_auxiliary_data_through_degree(t);
+            }
+            for deg in map.min_degree()..=t {
+                let num_gens = source.number_of_gens_in_degree(deg);
+                let dim = map.target().dimension(deg);
+                if num_gens == 0 || dim == 0 {
+                    map.extend_by_zero(deg);
+                    continue;
+                }
+                let mut outputs = Matrix::new(p, num_gens, dim);
+                for g in 0..num_gens {
+                    if m == 0 {
+                        assert!(augmentation.apply_quasi_inverse(
+                            outputs[g].as_slice_mut(),
+                            deg,
+                            resolution.chain_map(0).output(deg, g).as_slice()
+                        ));
+                    } else {
+                        let mut rhs = FpVector::new(p, yoneda.module(m - 1).dimension(deg));
+                        maps[m as usize - 1].apply(
+                            rhs.as_slice_mut(),
+                            1,
+                            deg,
+                            resolution.differential(m).output(deg, g).as_slice(),
+                        );
+                        assert!(d.apply_quasi_inverse(
+                            outputs[g].as_slice_mut(),
+                            deg,
+                            rhs.as_slice()
+                        ));
+                    }
+                }
+                map.add_generators_from_matrix_rows(deg, outputs.as_slice_mut());
+            }
+            maps.push(map);
+        }
+
+        // The constraints on the cocycle, as pairs (v, c) requiring that it sends v to c.
+        let top = yoneda.module(s);
+        let dim = top.dimension(t);
+        let mut constraints: Vec<(FpVector, u32)> = Vec::new();
+        for g in 0..resolution.number_of_gens_in_bidegree(s, t) {
+            constraints.push((maps[s as usize].output(t, g).clone(), (g == idx) as u32));
+        }
+        if s > 0 {
+            for v in kernel(&*yoneda.differential(s), t).basis() {
+                constraints.push((v.clone(), 0));
+            }
+        }
+        let algebra = top.algebra();
+        for op_deg in 1..=t - top.min_degree() {
+            for op_idx in algebra.generators(op_deg) {
+                for i in 0..top.dimension(t - op_deg) {
+                    let mut v = FpVector::new(p, dim);
+                    top.act_on_basis(v.as_slice_mut(), 1, op_deg, op_idx, t - op_deg, i);
+                    constraints.push((v, 0));
+                }
+            }
+        }
+
+        // Solve for the cocycle. The zeroth coordinate of the kernel tracks the right hand side.
+        let mut matrix = AugmentedMatrix::<2>::new(p, dim + 1, [constraints.len(), dim + 1]);
+        for (r, (v, c)) in constraints.iter().enumerate() {
+            for (i, x) in v.iter_nonzero() {
+                matrix.row_segment(i + 1, 0, 0).set_entry(r, x);
+            }
+            matrix.row_segment(0, 0, 0).set_entry(r, (*p - c) % *p);
+        }
+        matrix.segment(1, 1).add_identity();
+        matrix.row_reduce();
+        let solutions = matrix.compute_kernel();
+        assert!(
+            solutions.pivots()[0] >= 0,
+            "Failed to find a cocycle on the Yoneda representative"
+        );
+        solutions[solutions.pivots()[0] as usize]
+            .slice(1, dim + 1)
+            .to_owned()
+    }
+
+    /// Set `result` to a lift of $\psi(\epsilon(g))$ to $Y_0^{\otimes p}$, where $g$ is the `idx`th
+    /// generator of $R_0$ in degree `t`.
+    fn lift_diagonal(
+        &self,
+        power: &TensorPower,
+        yoneda: &Yoneda<CC>,
+        mut result: SliceMut,
+        t: i32,
+        idx: usize,
+    ) {
+        let p = self.prime();
+        let augmentation = yoneda.chain_map(0);
+        augmentation.compute_auxiliary_data_through_degree(t);
+        let module = self.resolution.target().module(0);
+        let chain_map = self.resolution.chain_map(0);
+        let value = chain_map.output(t, idx);
+
+        // The terms of ψ(ε(g)), as a coefficient and the (degree, index) of each factor.
+        let mut terms: Vec<(u32, Vec<(i32, usize)>)> = Vec::new();
+        match &self.diagonal {
+            None => {
+                if t == 0 && value.entry(0) != 0 {
+                    terms.push((value.entry(0), vec![(0, 0); *p as usize]));
+                }
+            }
+            Some(diagonal) => {
+                let target = diagonal.target();
+                let mut image = FpVector::new(p, target.dimension(t));
+                diagonal.apply(image.as_slice_mut(), 1, t, value.as_slice());
+                for left_t in module.min_degree()..=t - module.min_degree() {
+                    let right_t = t - left_t;
+                    let right_dim = module.dimension(right_t);
+                    if module.dimension(left_t) == 0 || right_dim == 0 {
+                        continue;
+                    }
+                    let offset = target.offset(t, left_t);
+                    for i in 0..module.dimension(left_t) {
+                        for j in 0..right_dim {
+                            let c = image.entry(offset + i * right_dim + j);
+                            if c != 0 {
+                                terms.push((c, vec![(left_t, i), (right_t, j)]));
+                            }
+                        }
+                    }
+                }
+            }
+        }
+
+        for (c, factors) in terms {
+            // Lift each factor along the augmentation Y_0 -> M
+            let lifts: Vec<FpVector> = factors
+                .iter()
+                .map(|&(t, i)| {
+                    let mut input = FpVector::new(p, module.dimension(t));
+                    input.set_entry(i, 1);
+                    let mut lift = FpVector::new(p, power.factors[0].dimension(t));
+                    assert!(augmentation.apply_quasi_inverse(
+                        lift.as_slice_mut(),
+                        t,
+                        input.as_slice()
+                    ));
+                    lift
+                })
+                .collect();
+
+            let mut partial: Vec<(u32, Vec<(u32, i32, usize)>)> = vec![(c, Vec::new())];
+            for (&(t, _), lift) in factors.iter().zip(&lifts) {
+                partial = partial
+                    .into_iter()
+                    .flat_map(|(c, tuple)| {
+                        lift.iter_nonzero().map(move |(i, v)| {
+                            let mut tuple = tuple.clone();
+                            tuple.push((0, t, i));
+                            (c * v % *p, tuple)
+                        })
+                    })
+                    .collect();
+            }
+            for (c, tuple) in partial {
+                result.add_basis_element(power.index[0][t][&tuple], c);
+            }
+        }
+    }
+}
+
+/// A basis element of a tensor power, given by the (homological degree, internal degree, index)
+/// of each factor.
+type Tuple = Vec<(u32, i32, usize)>;
+
+/// The tensor power $Y^{\otimes p}$ of a Yoneda representative.
+struct TensorPower {
+    p: ValidPrime,
+    factors: Vec<Arc<FDModule<SteenrodAlgebra>>>,
+    modules: Vec<Arc<FDModule<SteenrodAlgebra>>>,
+    /// n -> t -> basis of $(Y^{\otimes p})_n$ in degree t
+    basis: Vec<BiVec<Vec<Tuple>>>,
+    index: Vec<BiVec<HashMap<Tuple, usize>>>,
+    /// The differential out of $(Y^{\otimes p})_n$. The zeroth entry is a placeholder.
+    differentials:
+        Vec<BoundedModuleHomomorphism<FDModule<SteenrodAlgebra>, FDModule<SteenrodAlgebra>>>,
+    /// The top factor $Y_s$ is replaced by its quotient by the kernel of the differential.
+    /// `top_basis[t][i]` is the index of the `i`th basis element of the quotient in $Y_s$.
+    top_basis: BiVec<Vec<usize>>,
+}
+
+impl TensorPower {
+    fn new<CC: ChainComplex<Algebra = SteenrodAlgebra>>(
+        p: ValidPrime,
+        yoneda: &CC,
+        s: u32,
+        min_degree: i32,
+    ) -> Self
+    where
+        CC::Module: BoundedModule,
+    {
+        let num_factors = *p as usize;
+        let algebra = yoneda.algebra();
+        let mut factors: Vec<Arc<FDModule<SteenrodAlgebra>>> = (0..=s)
+            .map(|i| Arc::new(yoneda.module(i).to_fd_module()))
+            .collect();
+        let min_degree = min_degree * num_factors as i32;
+        let max_degree: i32 =
+            factors.iter().map(|m| m.max_degree()).max().unwrap() * num_factors as i32;
+
+        // The top module of the Yoneda representative need not inject into the one below, and
+        // its kernel would contribute homology to the tensor power. This kernel is killed by the
+        // class, so we quotient it out. top_basis[t][i] is the index of the ith basis element of
+        // the quotient in the original module.
+        let mut quotient = QuotientModule::new(Arc::clone(&factors[s as usize]));
+        quotient.compute_basis(quotient.module.max_degree());
+        if s > 0 {
+            let d = yoneda.differential(s);
+            for t in quotient.min_degree()..=quotient.module.max_degree() {
+                let kernel = kernel(&*d, t);
+                quotient.quotient_vectors(t, kernel.basis().to_vec());
+            }
+        }
+        let top_basis = BiVec::from_vec(
+            quotient.min_degree(),
+            (quotient.min_degree()..=quotient.module.max_degree())
+                .map(|t| quotient.basis_list[t].clone())
+                .collect(),
+        );
+        factors[s as usize] = Arc::new(quotient.to_fd_module());
+
+        let mut modules = Vec::new();
+        let mut basis = Vec::new();
+        let mut index = Vec::new();
+        for n in 0..=num_factors as u32 * s {
+            // The summands (i_1, ..., i_p) in lexicographic order
+            let blocks: Vec<Vec<u32>> = compositions(n, num_factors, s);
+            let block_modules = blocks
+                .iter()
+                .map(|block| {
+                    let mut module = Arc::clone(&factors[*block.last().unwrap() as usize]);
+                    for &i in block.iter().rev().skip(1) {
+                        module = Arc::new(
+                            TensorModule::new(Arc::clone(&factors[i as usize]), module)
+                                .to_fd_module(),
+                        );
+                    }
+                    module
+                })
+                .collect();
+            let module = Arc::new(
+                SumModule::new(Arc::clone(&algebra), block_modules, min_degree).to_fd_module(),
+            );
+
+            let mut n_basis = BiVec::new(min_degree);
+            let mut n_index: BiVec<HashMap<Tuple, usize>> = BiVec::new(min_degree);
+            for t in min_degree..=max_degree {
+                let mut t_basis = Vec::new();
+                for block in &blocks {
+                    block_basis(&factors, block, t, &mut Vec::new(), &mut t_basis);
+                }
+                assert_eq!(t_basis.len(), module.dimension(t));
+                n_index.push(
+                    t_basis
+                        .iter()
+                        .enumerate()
+                        .map(|(i, b)| (b.clone(), i))
+                        .collect(),
+                );
+                n_basis.push(t_basis);
+            }
+            modules.push(module);
+            basis.push(n_basis);
+            index.push(n_index);
+        }
+
+        let mut differentials = Vec::with_capacity(modules.len());
+        for n in 0..modules.len() {
+            let target = &modules[n.saturating_sub(1)];
+            let mut matrices = BiVec::new(min_degree);
+            for t in min_degree..=max_degree {
+                let mut matrix = Matrix::new(p, modules[n].dimension(t), target.dimension(t));
+                if n > 0 {
+                    for (row, tuple) in matrix.iter_mut().zip(&basis[n][t]) {
+                        let mut sign_degree = 0;
+                        for (k, &(i, factor_t, idx)) in tuple.iter().enumerate() {
+                            if i > 0 {
+                                let d = yoneda.differential(i);
+                                let idx = if i == s {
+                                    top_basis[factor_t][idx]
+                                } else {
+                                    idx
+                                };
+                                let mut dx =
+                                    FpVector::new(p, factors[i as usize - 1].dimension(factor_t));
+                                d.apply_to_basis_element(dx.as_slice_mut(), 1, factor_t, idx);
+                                let sign = minus_one_to_the_n(*p, sign_degree);
+                                for (j, v) in dx.iter_nonzero() {
+                                    let mut new = tuple.clone();
+                                    new[k] = (i - 1, factor_t, j);
+                                    row.add_basis_element(index[n - 1][t][&new], v * sign);
+                                }
+                            }
+                            sign_degree += i as i32;
+                        }
+                    }
+                }
+                matrices.push(matrix);
+            }
+            let d = BoundedModuleHomomorphism::from_matrices(
+                Arc::clone(&modules[n]),
+                Arc::clone(target),
+                0,
+                matrices,
+            );
+            if n > 0 {
+                d.compute_auxiliary_data_through_degree(max_degree);
+            }
+            differentials.push(d);
+        }
+
+        Self {
+            p,
+            factors,
+            modules,
+            basis,
+            index,
+            differentials,
+            top_basis,
+        }
+    }
+
+    /// Add `coeff` times the image of `input` under the cyclic permutation $a_1 \otimes \cdots
+    /// \otimes a_p \mapsto \pm a_p \otimes a_1 \otimes \cdots \otimes a_{p - 1}$ to `result`.
+    fn rotate(&self, mut result: SliceMut, coeff: u32, n: u32, t: i32, input: Slice) {
+        let p = *self.p;
+        for (i, v) in input.iter_nonzero() {
+            let tuple = &self.basis[n as usize][t][i];
+            let &(last_s, last_t, _) = tuple.last().unwrap();
+            let sign = minus_one_to_the_n(
+                p,
+                last_s as i32 * (n - last_s) as i32 + last_t * (t - last_t),
+            );
+            let mut new = Vec::with_capacity(tuple.len());
+            new.push(*tuple.last().unwrap());
+            new.extend_from_slice(&tuple[..tuple.len() - 1]);
+            result.add_basis_element(self.index[n as usize][t][&new], v * sign * coeff % p);
+        }
+    }
+
+    /// Add the image of `input` under $N = 1 + \tau + \cdots + \tau^{p - 1}$ to `result`.
+    fn norm(&self, mut result: SliceMut, n: u32, t: i32, input: Slice) {
+        let mut current = input.to_owned();
+        result.add(input, 1);
+        for _ in 1..*self.p {
+            let mut next = FpVector::new(self.p, current.len());
+            self.rotate(next.as_slice_mut(), 1, n, t, current.as_slice());
+            result.add(next.as_slice(), 1);
+            current = next;
+        }
+    }
+}
+
+/// The kernel of `f` in degree `t`.
+fn kernel(f: &impl ModuleHomomorphism, t: i32) -> Subspace {
+    let source_dim = f.source().dimension(t);
+    let target_dim = f.target().dimension(t);
+    let mut matrix = AugmentedMatrix::<2>::new(f.prime(), source_dim, [target_dim, source_dim]);
+    for i in 0..source_dim {
+        f.apply_to_basis_element(matrix.row_segment(i, 0, 0), 1, t, i);
+    }
+    matrix.segment(1, 1).add_identity();
+    matrix.row_reduce();
+    matrix.compute_kernel()
+}
+
+/// All sequences of `len` integers in `0..=max` summing to `n`, in lexicographic order.
+fn compositions(n: u32, len: usize, max: u32) -> Vec<Vec<u32>> {
+    if len == 1 {
+        return if n <= max { vec![vec![n]] } else { Vec::new() };
+    }
+    (0..=std::cmp::min(n, max))
+        .flat_map(|i| {
+            compositions(n - i, len - 1, max)
+                .into_iter()
+                .map(move |mut rest| {
+                    rest.insert(0, i);
+                    rest
+                })
+        })
+        .collect()
+}
+
+/// Append the basis of $Y_{i_1} \otimes \cdots \otimes Y_{i_p}$ in degree `t` to `result`, in the
+/// order used by [`TensorModule`].
+fn block_basis(
+    factors: &[Arc<FDModule<SteenrodAlgebra>>],
+    block: &[u32],
+    t: i32,
+    prefix: &mut Tuple,
+    result: &mut Vec<Tuple>,
+) {
+    let (&i, rest) = block.split_first().unwrap();
+    let module = &factors[i as usize];
+    if rest.is_empty() {
+        for idx in 0..module.dimension(t) {
+            let mut tuple = prefix.clone();
+            tuple.push((i, t, idx));
+            result.push(tuple);
+        }
+        return;
+    }
+    let rest_min: i32 = rest.iter().map(|&i| factors[i as usize].min_degree()).sum();
+    for left_t in module.min_degree()..=t - rest_min {
+        for idx in 0..module.dimension(left_t) {
+            prefix.push((i, left_t, idx));
+            block_basis(factors, rest, t - left_t, prefix, result);
+            prefix.pop();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::utils::construct;
+
+    #[test]
+    fn sphere_two() {
+        let resolution = Arc::new(construct("S_2@milnor", None).unwrap());
+        let steenrod = SteenrodOperations::new(Arc::clone(&resolution));
+
+        // Sq^0 h_i = h_{i + 1} and Sq^1 h_1 = h_1^2
+        for i in 0..3 {
+            let value = steenrod
+                .apply(SteenrodOperation::Sq(0), 1, 1 << i, &[1])
+                .unwrap();
+            assert_eq!(value.len(), 1);
+            assert_eq!(value.entry(0), 1);
+        }
+        assert_eq!(
+            steenrod
+                .apply(SteenrodOperation::Sq(1), 1, 2, &[1])
+                .unwrap()
+                .entry(0),
+            1
+        );
+        assert_eq!(steenrod.apply(SteenrodOperation::Sq(2), 1, 2, &[1]), None);
+
+        // Sq^2 h_0^2 = h_0^4, while Sq^1 h_0^2 = 0 by the Cartan formula
+        let ops = steenrod.operations(2, 2, 0);
+        assert_eq!(ops.len(), 3);
+        assert_eq!(ops[0].entry(0), 1);
+        assert!(ops[1].is_zero());
+        assert_eq!(ops[2].entry(0), 1);
+
+        // Sq^0 c_0 = c_1
+        let c1 = steenrod
+            .apply(SteenrodOperation::Sq(0), 3, 11, &[1])
+            .unwrap();
+        assert_eq!(c1.len(), 1);
+        assert_eq!(c1.entry(0), 1);
+    }
+
+    #[test]
+    fn sphere_three() {
+        let resolution = Arc::new(construct("S_3@adem", None).unwrap());
+        let steenrod = SteenrodOperations::new(Arc::clone(&resolution));
+
+        // h_0^3 = 0, while P^0 h_0 = h_1 and βP^0 h_0 = b_0 up to a unit
+        let ops = steenrod.operations(1, 4, 0);
+        assert_eq!(ops.len(), 3);
+        assert!(ops[0].is_zero());
+        assert_eq!(ops[1].len(), 1);
+        assert_eq!(ops[1].entry(0), 1);
+        assert_eq!(ops[2].len(), 1);
+        assert_eq!(ops[2].entry(0), 1);
+
+        // P^0 h_1 = h_2 up to a unit
+        let h2 = steenrod
+            .apply(SteenrodOperation::P(0), 1, 12, &[1])
+            .unwrap();
+        assert_eq!(h2.len(), 1);
+        assert_eq!(h2.entry(0), 1);
+    }
+
+    #[test]
+    fn diagonal() {
+        // The cohomology of S^0 ∨ S^4, with the diagonal ψ(x) = x ⊗ 1 + 1 ⊗ x.
+        let json = serde_json::json!({
+            "type": "finite dimensional module",
+            "p": 2,
+            "gens": {"x0": 0, "x4": 4},
+            "actions": [],
+        });
+        let resolution = Arc::new(construct((json, "milnor"), None).unwrap());
+        let module = resolution.target().module(0);
+        let square = Arc::new(TensorModule::new(Arc::clone(&module), Arc::clone(&module)));
+        square.compute_basis(8);
+        let mut diagonal = Diagonal::new(Arc::clone(&module), Arc::clone(&square), 0);
+        diagonal.matrices[0][0].set_entry(0, 1);
+        diagonal.matrices[4][0].set_entry(square.offset(4, 0), 1);
+        diagonal.matrices[4][0].set_entry(square.offset(4, 4), 1);
+
+        let steenrod =
+            SteenrodOperations::with_diagonal(Arc::clone(&resolution), Arc::new(diagonal));
+
+        // Sq^0 h_0 = h_1 on the bottom cell
+        let value = steenrod
+            .apply(SteenrodOperation::Sq(0), 1, 1, &[1])
+            .unwrap();
+        assert_eq!(value.len(), 1);
+        assert_eq!(value.entry(0), 1);
+
+        // Sq^1 h_1 = h_1^2 on the bottom cell
+        let value = steenrod
+            .apply(SteenrodOperation::Sq(1), 1, 2, &[1])
+            .unwrap();
+        assert_eq!(value.len(), 1);
+        assert_eq!(value.entry(0), 1);
+
+        let matrix = steenrod.matrix(SteenrodOperation::Sq(0), 1, 1).unwrap();
+        assert_eq!(matrix.rows(), 1);
+        assert_eq!(matrix[0].entry(0), 1);
+    }
+
+    #[test]
+    fn sseq_product() {
+        let resolution = Arc::new(construct("S_2@milnor", None).unwrap());
+        let steenrod = SteenrodOperations::new(Arc::clone(&resolution));
+        resolution.compute_through_stem(3, 8);
+
+        let p = resolution.prime();
+        let mut sseq = sseq::Sseq::<sseq::Adams>::new(p, 0, 0);
+        for s in 0..=3 {
+            for n in 0..=8 {
+                sseq.set_dimension(
+                    n,
+                    s as i32,
+                    resolution.number_of_gens_in_bidegree(s, n + s as i32),
+                );
+            }
+        }
+        steenrod.add_to_sseq(&mut sseq, SteenrodOperation::Sq(0), 1, 3);
+
+        let product = sseq.product("Sq^0").unwrap();
+        // Sq^0 h_0 = h_1 and Sq^0 h_1 = h_2
+        assert_eq!(product.target(0, 1), Some((1, 1)));
+        assert_eq!(product.target(1, 1), Some((3, 1)));
+        assert_eq!(product.target(4, 1), None);
+
+        let h0 = FpVector::from_slice(p, &[1]);
+        let mut result = FpVector::new(p, sseq.dimension(1, 1));
+        assert!(product.apply(result.as_slice_mut(), 1, 0, 1, h0.as_slice()));
+        assert_eq!(result, FpVector::from_slice(p, &[1]));
+    }
+}
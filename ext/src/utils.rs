@@ -1,4 +1,6 @@
 use crate::chain_complex::{ChainComplex, FiniteChainComplex, FreeChainComplex};
+use crate::module_expression::ModuleExpression;
+use crate::names::ClassNames;
 use crate::resolution::Resolution;
 use crate::CCC;
 use algebra::module::{FiniteModule, Module};
@@ -23,25 +25,10 @@ pub struct Config {
     pub algebra: AlgebraType,
 }
 
+/// Evaluate a module specification to the json of the module. This is either the name of a module
+/// file or a more general [module expression](crate::module_expression).
 pub fn parse_module_name(module_name: &str) -> anyhow::Result<Value> {
-    let mut args = module_name.split('[');
-    let module_file = args.next().unwrap();
-    let mut module = load_module_json(module_file)
-        .with_context(|| format!("Failed to load module file {}", module_file))?;
-    if let Some(shift) = args.next() {
-        let shift: i64 = match shift.strip_suffix(']') {
-            None => return Err(anyhow!("Unterminated shift [")),
-            Some(x) => x
-                .parse()
-                .with_context(|| format!("Cannot parse shift value ({}) as an integer", x))?,
-        };
-        if let Some(gens) = module["gens"].as_object_mut() {
-            for entry in gens.into_iter() {
-                *entry.1 = (entry.1.as_i64().unwrap() + shift).into()
-            }
-        }
-    }
-    Ok(module)
+    module_name.parse::<ModuleExpression>()?.evaluate()
 }
 
 impl TryFrom<&str> for Config {
@@ -110,11 +97,14 @@ impl<T: TryInto<AlgebraType>> TryFrom<(Value, T)> for Config {
 ///       [`algebra::AlgebraType`] object.
 ///     - `(module_name, algebra)`: The first argument is the name of the module and the second is
 ///       as above. Modules are searched in the current directory, `$CWD/steenrod_modules` and
-///       `ext/steenrod_modules`. The modules can be shifted by appending e.g. `S_2[2]`.
+///       `ext/steenrod_modules`. The modules can be shifted by appending e.g. `S_2[2]`, and
+///       combined using [module expressions](crate::module_expression), e.g. `C2 ^ Ceta`.
 ///     - `module_spec`, a single `&str` of the form `module_name@algebra`, where `module_name` and
 ///       `algebra` are as above.
 ///  - `save_file`: The save file for the module. If it points to an invalid save file, an error is
-///    returned.
+///    returned. The json of the module is written to `module.json` in the save directory, so that
+///    the result of evaluating a module expression can be reused by specifying the module as
+///    `save_dir/module`.
 pub fn construct<T, E>(module_spec: T, save_dir: Option<PathBuf>) -> anyhow::Result<Resolution<CCC>>
 where
     anyhow::Error: From<E>,
@@ -165,7 +155,15 @@ where
         chain_complex = Arc::new(yoneda);
     }
 
-    Resolution::new_with_save(chain_complex, save_dir)
+    let module_file = save_dir.as_ref().map(|dir| dir.join("module.json"));
+    let resolution = Resolution::new_with_save(chain_complex, save_dir)?;
+    if let Some(path) = module_file {
+        if !path.exists() {
+            std::fs::write(&path, json.to_string())
+                .with_context(|| format!("Failed to write module json to {:?}", path))?;
+        }
+    }
+    Ok(resolution)
 }
 
 pub fn load_module_json(name: &str) -> anyhow::Result<Value> {
@@ -291,20 +289,11 @@ pub fn query_module(
     Ok(resolution)
 }
 
-/// Prints an element in the bidegree `(n, s)` to stdout. For example, `[0, 2, 1]` will be printed
-/// as `2 x_(n, s, 1) + x_(f, s, 2)`.
-pub fn print_element(v: fp::vector::Slice, n: i32, s: u32) {
-    let mut first = true;
-    for (i, v) in v.iter_nonzero() {
-        if !first {
-            print!(" + ");
-        }
-        if v != 1 {
-            print!("{} ", v);
-        }
-        print!("x_({}, {}, {})", n, s, i);
-        first = false;
-    }
+/// Prints an element in the bidegree `(n, s)` to stdout, using `names` for the basis elements.
+/// For example, if no basis element is named, `[0, 2, 1]` will be printed as
+/// `2 x_(n, s, 1) + x_(n, s, 2)`. See [`ClassNames::format`].
+pub fn print_element(v: fp::vector::Slice, n: i32, s: u32, names: &ClassNames) {
+    print!("{}", names.format(v, n, s));
 }
 
 /// Given a function f(s, t), compute it for every `s` in `[min_s, max_s]` and every `t` in
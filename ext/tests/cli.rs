@@ -0,0 +1,171 @@
+#![cfg(feature = "cli")]
+
+use std::process::{Command, Output};
+
+fn ext(args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_ext"))
+        .args(args)
+        .env_remove("EXT_CONFIG")
+        .env_remove("EXT_MODULE")
+        .env_remove("EXT_MAX_N")
+        .env_remove("EXT_MAX_S")
+        .output()
+        .unwrap()
+}
+
+fn stdout(output: Output) -> String {
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    String::from_utf8(output.stdout).unwrap()
+}
+
+#[test]
+fn num_gens_flags() {
+    let output = stdout(ext(&[
+        "--batch", "num-gens", "--module", "C2", "--max-n", "2", "--max-s", "1",
+    ]));
+    assert_eq!(output, "0,0,1\n0,1,0\n1,0,0\n1,1,1\n2,0,0\n2,1,1\n");
+}
+
+#[test]
+fn config_file() {
+    let tempdir = tempfile::TempDir::new().unwrap();
+    let config = tempdir.path().join("ext.toml");
+    std::fs::write(
+        &config,
+        "module = \"C2\"\nmax_n = 5\nmax_s = 1\n\n[num_gens]\nmax_n = 2\n",
+    )
+    .unwrap();
+    let config = config.to_str().unwrap();
+
+    // The subcommand table takes precedence over the top level
+    let output = stdout(ext(&["--batch", "--config", config, "num-gens"]));
+    assert_eq!(output.lines().count(), 6);
+
+    // Flags take precedence over the config file
+    let output = stdout(ext(&[
+        "--batch", "--config", config, "num-gens", "--module", "S_2",
+    ]));
+    assert_eq!(output, "0,0,1\n0,1,1\n1,0,0\n1,1,1\n2,0,0\n2,1,0\n");
+}
+
+#[test]
+fn batch_missing_value() {
+    let output = ext(&["--batch", "lift-hom", "--max-n", "2", "--max-s", "1"]);
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("Missing value for name"));
+}
+
+#[test]
+fn job_file() {
+    let tempdir = tempfile::TempDir::new().unwrap();
+    let job = tempdir.path().join("job.toml");
+    std::fs::write(
+        &job,
+        r#"
+output_dir = "out"
+
+[[module]]
+name = "S_2"
+spec = "S_2@milnor"
+max_n = 16
+max_s = 4
+num_gens = "S_2.csv"
+d2 = "S_2-d2.txt"
+
+[[chart]]
+module = "S_2"
+output = "S_2.svg"
+d2 = true
+"#,
+    )
+    .unwrap();
+    let job = job.to_str().unwrap();
+    let out = tempdir.path().join("out");
+
+    let stderr = |output: Output| {
+        assert!(output.status.success());
+        String::from_utf8(output.stderr).unwrap()
+    };
+
+    assert!(stderr(ext(&["job", job])).contains("Resolving S_2"));
+    let d2 = std::fs::read_to_string(out.join("S_2-d2.txt")).unwrap();
+    assert!(d2.contains("d_2 x_(15, 1, 0) = [1]"));
+    assert!(out.join("S_2.csv").exists());
+    assert!(out.join("S_2.svg").exists());
+
+    // Only the missing output is regenerated, and steps whose outputs exist are skipped
+    std::fs::remove_file(out.join("S_2.csv")).unwrap();
+    let log = stderr(ext(&["job", job]));
+    assert!(log.contains("Skipping"));
+    assert!(!log.contains("secondary"));
+    assert!(out.join("S_2.csv").exists());
+}
+
+#[test]
+fn job_products() {
+    let tempdir = tempfile::TempDir::new().unwrap();
+    let job = tempdir.path().join("job.toml");
+    std::fs::write(
+        &job,
+        r#"
+[[module]]
+name = "S_2"
+spec = "S_2@milnor"
+max_n = 8
+max_s = 4
+
+[[module]]
+name = "C2"
+spec = "C2@milnor"
+max_n = 8
+max_s = 4
+
+[[product]]
+name = "h0"
+module = "S_2"
+n = 0
+s = 1
+class = [1]
+output = "S_2-h0.txt"
+
+[[product]]
+name = "h1"
+module = "C2"
+unit = "S_2"
+n = 1
+s = 1
+class = [1]
+output = "C2-h1.txt"
+"#,
+    )
+    .unwrap();
+    let output = ext(&["job", job.to_str().unwrap()]);
+    assert!(output.status.success());
+
+    // Products with the classes of the unit, which is the module itself for h0
+    let products = std::fs::read_to_string(tempdir.path().join("S_2-h0.txt")).unwrap();
+    assert_eq!(products.lines().count(), 15);
+    for line in [
+        "h0 x_(0, 0, 0) = [1]",
+        "h0 x_(0, 1, 0) = [1]",
+        "h0 x_(1, 1, 0) = []",
+        "h0 x_(3, 2, 0) = [1]",
+        "h0 x_(8, 2, 0) = [0]",
+    ] {
+        assert!(products.lines().any(|l| l == line), "{line}");
+    }
+
+    let products = std::fs::read_to_string(tempdir.path().join("C2-h1.txt")).unwrap();
+    for line in [
+        "h1 x_(0, 0, 0) = [1]",
+        "h1 x_(1, 1, 0) = [1]",
+        "h1 x_(3, 1, 0) = []",
+        "h1 x_(7, 1, 0) = [1]",
+    ] {
+        assert!(products.lines().any(|l| l == line), "{line}");
+    }
+}
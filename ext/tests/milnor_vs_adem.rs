@@ -12,7 +12,17 @@ use rstest::rstest;
 #[case("RP_-4_inf", 30)]
 #[case("Csigma", 30)]
 #[case("S_3", 30)]
+#[case("S_23", 200)]
+#[case("S_31", 200)]
 #[case("Calpha", 30)]
+#[case("CP_inf", 30)]
+#[case("HP_inf", 30)]
+#[case("BZ3", 30)]
+#[case("MSO_12", 20)]
+#[case("B4", 30)]
+#[case("BSO", 20)]
+#[case("KZ2_2", 20)]
+#[case("KZ_3", 30)]
 fn compare(#[case] module_name: &str, #[case] max_degree: i32) {
     let a = construct((module_name, "adem"), None).unwrap();
     let b = construct((module_name, "milnor"), None).unwrap();
@@ -1,11 +0,0 @@
-mod algebra_bindings;
-mod algebra_rust;
-mod adem_algebra;
-mod milnor_algebra;
-mod python_algebra;
-
-pub use algebra_bindings::*;
-pub use algebra_rust::*;
-pub use adem_algebra::*;
-pub use milnor_algebra::*;
-pub use python_algebra::*;
\ No newline at end of file
@@ -1,7 +0,0 @@
-mod free_module_homomorphism;
-mod module_homomorphism_rust;
-
-pub use free_module_homomorphism::*;
-pub use module_homomorphism_rust::*;
-
-pub use free_module_homomorphism::FreeModuleHomomorphism;
\ No newline at end of file
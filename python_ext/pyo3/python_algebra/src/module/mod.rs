@@ -1,20 +0,0 @@
-pub mod homomorphism;
-mod module_bindings;
-mod module_rust;
-
-mod finite_dimensional_module;
-mod free_module;
-mod free_unstable_module;
-mod rpn;
-mod kfpn;
-mod bcp;
-mod dickson2;
-
-pub use module_rust::ModuleRust;
-pub use finite_dimensional_module::FDModule;
-pub use free_module::*;
-pub use free_unstable_module::*;
-pub use rpn::RealProjectiveSpace;
-pub use kfpn::KFpn;
-pub use bcp::BCp;
-pub use dickson2::Dickson2;
\ No newline at end of file
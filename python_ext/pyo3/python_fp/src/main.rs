@@ -1,4 +0,0 @@
-
-fn main(){
-    println!("I do nothing.");
-}
\ No newline at end of file
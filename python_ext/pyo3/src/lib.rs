@@ -1,24 +1,49 @@
-pub mod resolution;
-pub mod resolution_homomorphism;
-
-use pyo3::prelude::*;
-use pyo3::{wrap_pymodule};//, wrap_pyfunction};
-// use pyo3::types::PyTuple;
-
-use python_utils;
-use python_fp::PyInit_python_fp;
-use python_algebra::PyInit_algebra;
-use python_algebra::PyInit_module;
-
-
-#[pymodule]
-fn rust_ext(_py: Python, m: &PyModule) -> PyResult<()> {
-    m.add_wrapped(wrap_pymodule!(python_fp))?;
-    python_utils::rename_submodule(m, "python_fp", "fp")?;
-    m.add_wrapped(wrap_pymodule!(algebra))?;
-    m.add_wrapped(wrap_pymodule!(module))?;
-    // python_utils::rename_submodule(m, "python_algebra", "algebra")?;
-    m.add_class::<resolution::Resolution>()?;
-    m.add_class::<resolution_homomorphism::ResolutionHomomorphism>()?;
-    Ok(())
-}
\ No newline at end of file
+//! Python bindings for the `ext` library.
+//!
+//! The bindings are thin wrappers around the Rust types, and mirror their names and methods. The
+//! main entry point is [`resolution::Resolution`], which is constructed from a module name in the
+//! same format as the examples accept (e.g. `S_2`, `C2@adem`, `Ceta[1]`), with an optional save
+//! directory.
+//!
+//! Linear algebra objects can be converted to and from numpy arrays. Entries of input arrays are
+//! reduced mod p.
+//!
+//! To build a Python module, use `maturin develop --features extension-module`.
+
+mod linear_algebra;
+mod nassau;
+mod resolution;
+mod secondary;
+
+use pyo3::exceptions::{PyIndexError, PyValueError};
+use pyo3::prelude::*;
+
+/// Convert an [`anyhow::Error`] into a Python `ValueError`, keeping the full context chain.
+pub(crate) fn value_error(e: impl std::fmt::Display) -> PyErr {
+    PyValueError::new_err(format!("{e:#}"))
+}
+
+pub(crate) fn index_error(msg: String) -> PyErr {
+    PyIndexError::new_err(msg)
+}
+
+/// Reduce an arbitrary integer mod p.
+pub(crate) fn reduce(p: fp::prime::ValidPrime, c: i64) -> u32 {
+    c.rem_euclid(*p as i64) as u32
+}
+
+pub(crate) fn valid_prime(p: u32) -> PyResult<fp::prime::ValidPrime> {
+    fp::prime::ValidPrime::try_from(p).map_err(value_error)
+}
+
+#[pymodule]
+fn rust_ext(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_class::<linear_algebra::FpVector>()?;
+    m.add_class::<linear_algebra::Matrix>()?;
+    m.add_class::<resolution::Resolution>()?;
+    m.add_class::<resolution::ResolutionHomomorphism>()?;
+    m.add_class::<secondary::SecondaryResolution>()?;
+    m.add_class::<secondary::Sseq>()?;
+    m.add_class::<nassau::NassauResolution>()?;
+    Ok(())
+}
@@ -0,0 +1,185 @@
+use numpy::{PyArray1, PyArray2, PyReadonlyArray1, PyReadonlyArray2};
+use pyo3::prelude::*;
+
+use crate::{index_error, reduce, valid_prime, value_error};
+
+/// A vector over F_p. This owns its data, and is copied when converted to and from the Rust side.
+#[pyclass(module = "rust_ext")]
+#[derive(Clone)]
+pub struct FpVector {
+    pub(crate) inner: fp::vector::FpVector,
+}
+
+impl FpVector {
+    fn check_index(&self, idx: isize) -> PyResult<usize> {
+        let len = self.inner.len() as isize;
+        let idx = if idx < 0 { idx + len } else { idx };
+        if idx < 0 || idx >= len {
+            Err(index_error(format!(
+                "Index {idx} out of range for vector of length {len}"
+            )))
+        } else {
+            Ok(idx as usize)
+        }
+    }
+}
+
+#[pymethods]
+impl FpVector {
+    #[new]
+    fn new(p: u32, len: usize) -> PyResult<Self> {
+        Ok(Self {
+            inner: fp::vector::FpVector::new(valid_prime(p)?, len),
+        })
+    }
+
+    #[staticmethod]
+    fn from_list(p: u32, entries: Vec<i64>) -> PyResult<Self> {
+        let p = valid_prime(p)?;
+        let entries: Vec<u32> = entries.into_iter().map(|c| reduce(p, c)).collect();
+        Ok(Self {
+            inner: fp::vector::FpVector::from_slice(p, &entries),
+        })
+    }
+
+    #[staticmethod]
+    fn from_numpy(p: u32, array: PyReadonlyArray1<'_, i64>) -> PyResult<Self> {
+        let p = valid_prime(p)?;
+        let entries: Vec<u32> = array.as_array().iter().map(|&c| reduce(p, c)).collect();
+        Ok(Self {
+            inner: fp::vector::FpVector::from_slice(p, &entries),
+        })
+    }
+
+    fn to_list(&self) -> Vec<u32> {
+        self.inner.iter().collect()
+    }
+
+    fn to_numpy<'py>(&self, py: Python<'py>) -> Bound<'py, PyArray1<u32>> {
+        PyArray1::from_vec(py, self.to_list())
+    }
+
+    #[getter]
+    fn prime(&self) -> u32 {
+        *self.inner.prime()
+    }
+
+    fn __len__(&self) -> usize {
+        self.inner.len()
+    }
+
+    fn __getitem__(&self, idx: isize) -> PyResult<u32> {
+        Ok(self.inner.entry(self.check_index(idx)?))
+    }
+
+    fn __setitem__(&mut self, idx: isize, value: i64) -> PyResult<()> {
+        let idx = self.check_index(idx)?;
+        let p = self.inner.prime();
+        self.inner.set_entry(idx, reduce(p, value));
+        Ok(())
+    }
+
+    fn __eq__(&self, other: &Self) -> bool {
+        self.inner == other.inner
+    }
+
+    fn __repr__(&self) -> String {
+        format!("FpVector({}, {})", self.inner.prime(), self.inner)
+    }
+
+    fn is_zero(&self) -> bool {
+        self.inner.is_zero()
+    }
+
+    /// Add `c * other` to `self`.
+    #[pyo3(signature = (other, c = 1))]
+    fn add(&mut self, other: &Self, c: i64) -> PyResult<()> {
+        if other.inner.len() != self.inner.len() || other.inner.prime() != self.inner.prime() {
+            return Err(value_error("Vectors must have the same length and prime"));
+        }
+        let p = self.inner.prime();
+        self.inner.add(&other.inner, reduce(p, c));
+        Ok(())
+    }
+
+    fn scale(&mut self, c: i64) {
+        let p = self.inner.prime();
+        self.inner.scale(reduce(p, c));
+    }
+}
+
+/// A matrix over F_p. As in the Rust library, matrices act on the right of row vectors.
+#[pyclass(module = "rust_ext")]
+pub struct Matrix {
+    pub(crate) inner: fp::matrix::Matrix,
+}
+
+#[pymethods]
+impl Matrix {
+    #[new]
+    fn new(p: u32, rows: usize, columns: usize) -> PyResult<Self> {
+        Ok(Self {
+            inner: fp::matrix::Matrix::new(valid_prime(p)?, rows, columns),
+        })
+    }
+
+    #[staticmethod]
+    fn from_numpy(p: u32, array: PyReadonlyArray2<'_, i64>) -> PyResult<Self> {
+        let p = valid_prime(p)?;
+        let array = array.as_array();
+        let (rows, columns) = array.dim();
+        let mut inner = fp::matrix::Matrix::new(p, rows, columns);
+        for (row, input) in inner.iter_mut().zip(array.rows()) {
+            for (i, &c) in input.iter().enumerate() {
+                row.set_entry(i, reduce(p, c));
+            }
+        }
+        Ok(Self { inner })
+    }
+
+    fn to_numpy<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyArray2<u32>>> {
+        if self.inner.rows() == 0 {
+            return Ok(PyArray2::zeros(py, [0, self.inner.columns()], false));
+        }
+        PyArray2::from_vec2(py, &self.inner.to_vec()).map_err(value_error)
+    }
+
+    #[getter]
+    fn prime(&self) -> u32 {
+        *self.inner.prime()
+    }
+
+    #[getter]
+    fn rows(&self) -> usize {
+        self.inner.rows()
+    }
+
+    #[getter]
+    fn columns(&self) -> usize {
+        self.inner.columns()
+    }
+
+    /// Get a copy of a row of the matrix.
+    fn __getitem__(&self, row: usize) -> PyResult<FpVector> {
+        if row >= self.inner.rows() {
+            return Err(index_error(format!("Row {row} out of range")));
+        }
+        Ok(FpVector {
+            inner: self.inner[row].clone(),
+        })
+    }
+
+    /// Row reduce the matrix in place and return its rank.
+    fn row_reduce(&mut self) -> usize {
+        self.inner.row_reduce()
+    }
+
+    /// The pivots of the matrix, as recorded by the last call to `row_reduce`.
+    fn pivots(&self) -> Vec<isize> {
+        self.inner.pivots().to_vec()
+    }
+
+    fn __repr__(&self) -> String {
+        format!("{}", self.inner)
+    }
+}
@@ -0,0 +1,42 @@
+use ext::chain_complex::{ChainComplex, FreeChainComplex};
+use pyo3::prelude::*;
+
+use crate::index_error;
+
+/// A minimal resolution of the sphere at the prime 2 computed using Nassau's algorithm.
+#[pyclass(module = "rust_ext", frozen)]
+pub struct NassauResolution {
+    inner: ext::nassau::Resolution,
+}
+
+#[pymethods]
+impl NassauResolution {
+    #[new]
+    fn new() -> Self {
+        Self {
+            inner: ext::nassau::Resolution::new(),
+        }
+    }
+
+    /// Compute the resolution for all `s <= max_s` and `n <= max_n`. This releases the GIL.
+    fn compute_through_stem(&self, py: Python<'_>, max_s: u32, max_n: i32) {
+        py.detach(|| self.inner.compute_through_stem(max_s, max_n))
+    }
+
+    fn has_computed_bidegree(&self, s: u32, t: i32) -> bool {
+        self.inner.has_computed_bidegree(s, t)
+    }
+
+    fn number_of_gens_in_bidegree(&self, s: u32, t: i32) -> PyResult<usize> {
+        if !self.inner.has_computed_bidegree(s, t) {
+            return Err(index_error(format!(
+                "Bidegree (s, t) = ({s}, {t}) has not been computed"
+            )));
+        }
+        Ok(self.inner.number_of_gens_in_bidegree(s, t))
+    }
+
+    fn graded_dimension_string(&self) -> String {
+        self.inner.graded_dimension_string()
+    }
+}
@@ -1,156 +1,246 @@
-use std::sync::Arc;
-use std::collections::hash_map::DefaultHasher;
-use std::hash::{Hash, Hasher};
-
-
-use pyo3::prelude::*;
-use ext::resolution::ResolutionInner as ResolutionRust;
-use ext::chain_complex::{AugmentedChainComplex, ChainComplex, FiniteChainComplex, FreeChainComplex};//, ChainMap};
-
-use python_algebra::module::{
-    ModuleRust,
-    FreeModule,
-    homomorphism::{
-        FreeModuleHomomorphism,
-        ModuleHomomorphismRust
-    }
-};
-
-pub type CCRust = FiniteChainComplex<ModuleRust, ModuleHomomorphismRust>;
-python_utils::rc_wrapper_type!(Resolution, ResolutionRust<CCRust>);
-
-#[pymethods]
-impl Resolution {
-    #[new]
-    pub fn new(module : PyObject) -> PyResult<Self> {
-        let chain_complex = Arc::new(
-            FiniteChainComplex::ccdz(
-                ModuleRust::from_py_object(module)?
-            )
-        );
-        Ok(Resolution::box_and_wrap(ResolutionRust::new(Arc::clone(&chain_complex))))
-    }
-
-    pub fn extended_degree(&self) -> PyResult<(u32, i32)> {
-        Ok(self.inner()?.extended_degree())
-    }
-
-    pub fn extend_through_degree(&self, max_s : u32, max_t : i32) -> PyResult<()> {
-        let (old_max_s, old_max_t) = self.extended_degree()?;
-        self.inner()?.extend_through_degree(old_max_s, max_s, old_max_t, max_t);
-        Ok(())
-    }
-
-    pub fn graded_dimension_string(&self, max_degree : i32 , max_hom_deg : u32) -> PyResult<String> {
-        Ok(self.inner()?.graded_dimension_string(max_degree, max_hom_deg))
-    }
-
-    pub fn step_resolution(&self, s : u32, t : i32) -> PyResult<()> {
-        let self_inner = self.inner()?;
-        let (max_s, max_t) = self_inner.extended_degree();
-        if max_s <= s || max_t <= t {
-            return Err(python_utils::exception!(ValueError,
-                "You need to run res.extend_degree(>={}, >={}) before res.step_resolution({}, {})",
-                s,t,s,t
-            ));
-        }
-        let next_t = self_inner.differential(s).next_degree();
-        if next_t > t {
-            // Already computed this degree.
-            return Ok(())
-        } 
-        // if next_t < t {
-        //     return Err(python_utils::exception!(ValueError,
-        //         "Out of order step_resolution."
-        //     ))
-        // }
-        python_utils::release_gil!(self_inner.step_resolution(s, t));
-        Ok(())
-    }
-
-    pub fn check_has_computed_bidegree(&self, hom_deg : u32, int_deg : i32) -> PyResult<()> {
-        if !self.inner()?.has_computed_bidegree(hom_deg, int_deg) {
-            Err(python_utils::exception!(IndexError,
-                "We haven't computed out to bidegree {} yet.",
-                python_utils::bidegree_string(hom_deg, int_deg)
-            ))
-        } else {
-            Ok(())
-        }
-    }
-
-    pub fn check_cocycle_idx(&self, hom_deg : u32, int_deg : i32, idx : usize) -> PyResult<()> {
-        self.check_has_computed_bidegree(hom_deg, int_deg)?;
-        if idx >= self.inner()?.number_of_gens_in_bidegree(hom_deg, int_deg) {
-            Err(python_utils::exception!(IndexError,
-                "Fewer than {} generators in bidegree {}.",
-                idx + 1,
-                python_utils::bidegree_string(hom_deg, int_deg)
-            ))
-        } else {
-            Ok(())
-        }
-    }
-
-    pub fn cocycle_string(&self, hom_deg : u32, int_deg : i32, idx : usize) -> PyResult<String> {
-        self.check_cocycle_idx(hom_deg, int_deg, idx)?;
-        Ok(self.inner()?.cocycle_string(hom_deg, int_deg, idx))
-    }
-
-    pub fn bidegree_hash(&self, hom_deg : u32, int_deg : i32) -> PyResult<u64> {
-        self.check_has_computed_bidegree(hom_deg, int_deg)?;
-        let self_inner = self.inner()?;
-        let num_gens = self_inner.number_of_gens_in_bidegree(hom_deg, int_deg);
-        let mut hasher = DefaultHasher::new();
-        hom_deg.hash(&mut hasher);
-        int_deg.hash(&mut hasher);
-        num_gens.hash(&mut hasher);
-        let ds = self_inner.differential(hom_deg);
-        for idx in 0 .. num_gens {
-            ds.output(int_deg, idx).hash(&mut hasher);
-        }
-        Ok(hasher.finish())
-    }
-
-    // pub fn complex(&self) -> Arc<CC> {
-    //     Arc::clone(&self.complex)
-    // }
-
-    pub fn number_of_gens_in_bidegree(&self, homological_degree : u32, internal_degree : i32) -> PyResult<usize> {
-        self.check_has_computed_bidegree(homological_degree, internal_degree)?;
-        Ok(self.inner()?.module(homological_degree).number_of_gens_in_degree(internal_degree))
-    }
-
-    pub fn prime(&self) -> PyResult<u32> {
-        Ok(*self.inner()?.complex().prime())
-    }
-
-    pub fn module(&self, homological_degree : u32) -> PyResult<FreeModule> {
-        Ok(FreeModule::wrap_immutable(self.inner()?.module(homological_degree)))
-    }
-
-    #[getter]
-    pub fn get_min_degree(&self) -> PyResult<i32> {
-        Ok(self.inner()?.min_degree())
-    }
-
-    pub fn differential(&self, s : u32) -> PyResult<FreeModuleHomomorphism> {
-        Ok(FreeModuleHomomorphism::wrap_to_free(self.inner()?.differential(s)))
-    }
-
-    pub fn chain_map(&self, s : u32) -> PyResult<FreeModuleHomomorphism> {
-        Ok(FreeModuleHomomorphism::wrap_to_other(self.inner()?.chain_map(s)))
-    }
-
-}
-
-
-use python_algebra::module::FreeUnstableModule;
-use python_algebra::algebra::{AdemAlgebra, AlgebraRust};
-pub fn test() -> PyResult<()> {
-    let a = Arc::new(AdemAlgebra::new(2, false, true, None)?);
-    let b = a.to_arc()?.clone();
-    let m = FreeUnstableModule::new(AlgebraRust::into_py_object(b), "i".to_string(), 0)?;
-    Resolution::new(ModuleRust::into_py_object(m.to_arc()?.clone()))?;
-    Ok(())
-}
\ No newline at end of file
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use ext::chain_complex::{AugmentedChainComplex, ChainComplex, FreeChainComplex};
+use ext::CCC;
+use pyo3::prelude::*;
+
+use crate::{index_error, value_error};
+
+pub(crate) type ResolutionInner = ext::resolution::Resolution<CCC>;
+
+/// A minimal resolution of a Steenrod module.
+///
+/// The module is specified by a string of the form `module_name@algebra`, e.g. `S_2@milnor`. If
+/// `save_dir` is given, the resolution is saved to and loaded from that directory.
+#[pyclass(module = "rust_ext", frozen)]
+pub struct Resolution {
+    pub(crate) inner: Arc<ResolutionInner>,
+}
+
+impl Resolution {
+    pub(crate) fn check_bidegree(&self, s: u32, t: i32) -> PyResult<()> {
+        if self.inner.has_computed_bidegree(s, t) {
+            Ok(())
+        } else {
+            Err(index_error(format!(
+                "Bidegree (s, t) = ({s}, {t}) has not been computed"
+            )))
+        }
+    }
+
+    pub(crate) fn check_class(&self, s: u32, t: i32, idx: usize) -> PyResult<()> {
+        self.check_bidegree(s, t)?;
+        let num_gens = self.inner.number_of_gens_in_bidegree(s, t);
+        if idx >= num_gens {
+            return Err(index_error(format!(
+                "Index {idx} out of range for bidegree (s, t) = ({s}, {t}) with {num_gens} generators"
+            )));
+        }
+        Ok(())
+    }
+}
+
+#[pymethods]
+impl Resolution {
+    #[new]
+    #[pyo3(signature = (module_spec, save_dir = None, load_quasi_inverse = None))]
+    fn new(
+        module_spec: &str,
+        save_dir: Option<PathBuf>,
+        load_quasi_inverse: Option<bool>,
+    ) -> PyResult<Self> {
+        let mut inner = ext::utils::construct(module_spec, save_dir).map_err(value_error)?;
+        inner.load_quasi_inverse = load_quasi_inverse.unwrap_or_else(|| inner.save_dir().is_none());
+        Ok(Self {
+            inner: Arc::new(inner),
+        })
+    }
+
+    #[getter]
+    fn prime(&self) -> u32 {
+        *self.inner.prime()
+    }
+
+    #[getter]
+    fn min_degree(&self) -> i32 {
+        self.inner.min_degree()
+    }
+
+    #[getter]
+    fn save_dir(&self) -> Option<PathBuf> {
+        self.inner.save_dir().map(PathBuf::from)
+    }
+
+    fn next_homological_degree(&self) -> u32 {
+        self.inner.next_homological_degree()
+    }
+
+    /// Compute the resolution through bidegree `(s, t)`. This releases the GIL.
+    fn compute_through_bidegree(&self, py: Python<'_>, s: u32, t: i32) {
+        py.detach(|| self.inner.compute_through_bidegree(s, t))
+    }
+
+    /// Compute the resolution for all `s <= max_s` and `n <= max_n`. This releases the GIL.
+    fn compute_through_stem(&self, py: Python<'_>, max_s: u32, max_n: i32) {
+        py.detach(|| self.inner.compute_through_stem(max_s, max_n))
+    }
+
+    fn has_computed_bidegree(&self, s: u32, t: i32) -> bool {
+        self.inner.has_computed_bidegree(s, t)
+    }
+
+    fn number_of_gens_in_bidegree(&self, s: u32, t: i32) -> PyResult<usize> {
+        self.check_bidegree(s, t)?;
+        Ok(self.inner.number_of_gens_in_bidegree(s, t))
+    }
+
+    /// The list of computed bidegrees `(s, n, t)`, ordered as in `ChainComplex::iter_stem`.
+    fn iter_stem(&self) -> Vec<(u32, i32, i32)> {
+        self.inner.iter_stem().collect()
+    }
+
+    /// The image of the generator under the differential, as a string.
+    fn cocycle_string(&self, s: u32, t: i32, idx: usize) -> PyResult<String> {
+        self.check_class(s, t, idx)?;
+        Ok(self.inner.cocycle_string(s, t, idx))
+    }
+
+    fn graded_dimension_string(&self) -> String {
+        self.inner.graded_dimension_string()
+    }
+
+    /// Compute the product of the class `(s, t, idx)` with the class in filtration one of internal
+    /// degree `op_deg` and index `op_idx`. Returns `None` if the target is not computed.
+    fn filtration_one_product(
+        &self,
+        op_deg: i32,
+        op_idx: usize,
+        s: u32,
+        t: i32,
+    ) -> PyResult<Option<Vec<Vec<u32>>>> {
+        self.check_bidegree(s, t)?;
+        Ok(self.inner.filtration_one_product(op_deg, op_idx, s, t))
+    }
+
+    fn __repr__(&self) -> String {
+        format!("Resolution({})", self.inner.target().module(0))
+    }
+}
+
+/// A chain map from a resolution to another lifting a class in Ext.
+#[pyclass(module = "rust_ext", frozen)]
+pub struct ResolutionHomomorphism {
+    inner: ext::resolution_homomorphism::ResolutionHomomorphism<ResolutionInner, ResolutionInner>,
+    source: Py<Resolution>,
+    target: Py<Resolution>,
+}
+
+#[pymethods]
+impl ResolutionHomomorphism {
+    /// Construct the chain map `source -> target` of degree `(s, t)` lifting `class`, which is an
+    /// element of Ext of `source` in bidegree `(s, t)`. If `target` is the resolution of the
+    /// sphere, this computes products with `class`.
+    #[staticmethod]
+    fn from_class(
+        name: String,
+        source: Py<Resolution>,
+        target: Py<Resolution>,
+        s: u32,
+        t: i32,
+        class: Vec<u32>,
+    ) -> PyResult<Self> {
+        let (source_res, target_res) = (&source.get().inner, &target.get().inner);
+        if source_res.prime() != target_res.prime() {
+            return Err(value_error("Source and target must have the same prime"));
+        }
+        source.get().check_bidegree(s, t)?;
+        let num_gens = source_res.number_of_gens_in_bidegree(s, t);
+        if class.len() != num_gens {
+            return Err(value_error(format!(
+                "Class has length {} but bidegree (s, t) = ({s}, {t}) has {num_gens} generators",
+                class.len()
+            )));
+        }
+        let p = *source_res.prime();
+        let class: Vec<u32> = class.into_iter().map(|c| c % p).collect();
+
+        let inner = ext::resolution_homomorphism::ResolutionHomomorphism::from_class(
+            name,
+            Arc::clone(source_res),
+            Arc::clone(target_res),
+            s,
+            t,
+            &class,
+        );
+        Ok(Self {
+            inner,
+            source,
+            target,
+        })
+    }
+
+    #[getter]
+    fn name(&self) -> &str {
+        self.inner.name()
+    }
+
+    #[getter]
+    fn source(&self, py: Python<'_>) -> Py<Resolution> {
+        self.source.clone_ref(py)
+    }
+
+    #[getter]
+    fn target(&self, py: Python<'_>) -> Py<Resolution> {
+        self.target.clone_ref(py)
+    }
+
+    #[getter]
+    fn shift_s(&self) -> u32 {
+        self.inner.shift_s
+    }
+
+    #[getter]
+    fn shift_t(&self) -> i32 {
+        self.inner.shift_t
+    }
+
+    /// Extend the map so that it is defined on the bidegree `(s, t)` of the source. This releases
+    /// the GIL.
+    fn extend(&self, py: Python<'_>, s: u32, t: i32) {
+        py.detach(|| self.inner.extend(s, t))
+    }
+
+    fn extend_through_stem(&self, py: Python<'_>, s: u32, n: i32) {
+        py.detach(|| self.inner.extend_through_stem(s, n))
+    }
+
+    /// Extend the map as far as the source and target resolutions allow.
+    fn extend_all(&self, py: Python<'_>) {
+        py.detach(|| self.inner.extend_all())
+    }
+
+    /// Compute the product of the defining class with the class `(s, t, idx)` of the target. The
+    /// result is an element of Ext of the source in bidegree `(s + shift_s, t + shift_t)`.
+    fn act(&self, s: u32, t: i32, idx: usize) -> PyResult<Vec<u32>> {
+        let source = self.source.get();
+        let target = self.target.get();
+        target.check_class(s, t, idx)?;
+        let (source_s, source_t) = (s + self.inner.shift_s, t + self.inner.shift_t);
+        source.check_bidegree(source_s, source_t)?;
+        if self.inner.next_homological_degree() <= source_s as i32
+            || self.inner.get_map(source_s).next_degree() <= source_t
+        {
+            return Err(index_error(format!(
+                "Map has not been extended to bidegree (s, t) = ({source_s}, {source_t})"
+            )));
+        }
+
+        let mut result = fp::vector::FpVector::new(
+            source.inner.prime(),
+            source.inner.number_of_gens_in_bidegree(source_s, source_t),
+        );
+        self.inner.act(result.as_slice_mut(), 1, s, t, idx);
+        Ok(result.iter().collect())
+    }
+}
@@ -0,0 +1,107 @@
+use std::sync::Arc;
+
+use ext::chain_complex::{AugmentedChainComplex, ChainComplex, FreeChainComplex};
+use ext::secondary::SecondaryLift;
+use pyo3::prelude::*;
+
+use crate::resolution::{Resolution, ResolutionInner};
+use crate::{index_error, value_error};
+
+/// The secondary resolution used to compute Adams $d_2$ differentials. This is only available
+/// at the prime 2 for finite dimensional modules; see `ext::secondary::can_compute`.
+#[pyclass(module = "rust_ext", frozen)]
+pub struct SecondaryResolution {
+    inner: ext::secondary::SecondaryResolution<algebra::SteenrodAlgebra, ResolutionInner>,
+}
+
+#[pymethods]
+impl SecondaryResolution {
+    #[new]
+    fn new(resolution: &Resolution) -> PyResult<Self> {
+        if !ext::secondary::can_compute(&resolution.inner) {
+            return Err(value_error(format!(
+                "Cannot compute d2 for the module {}",
+                resolution.inner.target().module(0)
+            )));
+        }
+        Ok(Self {
+            inner: ext::secondary::SecondaryResolution::new(Arc::clone(&resolution.inner)),
+        })
+    }
+
+    /// Compute the secondary data for everything computed in the underlying resolution. This
+    /// releases the GIL.
+    fn extend_all(&self, py: Python<'_>) {
+        py.detach(|| self.inner.extend_all())
+    }
+
+    /// The $d_2$ differentials on the classes in stem `n` and filtration `s`. The `i`th entry is
+    /// the image of the `i`th generator, expressed in the basis of bidegree `(n - 1, s + 2)`.
+    fn d2(&self, n: i32, s: u32) -> PyResult<Vec<Vec<u32>>> {
+        let t = n + s as i32;
+        let underlying = self.inner.underlying();
+        if !underlying.has_computed_bidegree(s + 2, t + 1) {
+            return Err(index_error(format!(
+                "Target of d2 from (n, s) = ({n}, {s}) has not been computed"
+            )));
+        }
+        if underlying.number_of_gens_in_bidegree(s, t) == 0 {
+            return Ok(Vec::new());
+        }
+        Ok(self.inner.homotopy(s + 2).homotopies.hom_k(t))
+    }
+
+    /// The $E_3$ page of the Adams spectral sequence.
+    fn e3_page(&self) -> Sseq {
+        Sseq {
+            inner: self.inner.e3_page(),
+        }
+    }
+}
+
+/// An Adams graded spectral sequence, indexed by `(n, s)`. The underlying Rust type is not `Send`,
+/// so this can only be accessed from the thread that created it.
+#[pyclass(module = "rust_ext", unsendable)]
+pub struct Sseq {
+    inner: sseq::Sseq<sseq::Adams>,
+}
+
+#[pymethods]
+impl Sseq {
+    #[getter]
+    fn min_x(&self) -> i32 {
+        self.inner.min_x()
+    }
+
+    #[getter]
+    fn max_x(&self) -> i32 {
+        self.inner.max_x()
+    }
+
+    fn range(&self, x: i32) -> (i32, i32) {
+        let r = self.inner.range(x);
+        (r.start, r.end)
+    }
+
+    fn defined(&self, x: i32, y: i32) -> bool {
+        self.inner.defined(x, y)
+    }
+
+    /// The dimension of the $E_2$ page at `(x, y)`.
+    fn dimension(&self, x: i32, y: i32) -> usize {
+        self.inner.dimension(x, y)
+    }
+
+    /// The dimension of the page `r` at `(x, y)`. Pages after the last computed page agree with
+    /// the last computed page.
+    fn page_dimension(&self, x: i32, y: i32, r: i32) -> PyResult<usize> {
+        if !self.inner.defined(x, y) {
+            return Err(index_error(format!("Bidegree ({x}, {y}) is not defined")));
+        }
+        let data = self.inner.page_data(x, y);
+        if r < data.min_degree() {
+            return Err(index_error(format!("Page {r} is before the first page")));
+        }
+        Ok(data[std::cmp::min(r, data.max_degree())].dimension())
+    }
+}
@@ -0,0 +1,68 @@
+import numpy as np
+import pytest
+
+import rust_ext
+
+
+def test_fp_vector():
+    v = rust_ext.FpVector.from_list(3, [1, 2, 4, -1])
+    assert v.to_list() == [1, 2, 1, 2]
+    assert len(v) == 4
+    assert v[-1] == 2
+
+    w = rust_ext.FpVector.from_numpy(3, np.array([1, 1, 1, 1]))
+    v.add(w, 2)
+    assert v.to_list() == [0, 1, 0, 1]
+    assert np.array_equal(v.to_numpy(), np.array([0, 1, 0, 1]))
+
+    with pytest.raises(IndexError):
+        v[4]
+
+
+def test_matrix():
+    m = rust_ext.Matrix.from_numpy(2, np.array([[1, 1, 0], [1, 1, 0], [0, 1, 1]]))
+    assert m.row_reduce() == 2
+    assert m.pivots() == [0, 1, -1]
+    assert np.array_equal(m.to_numpy(), np.array([[1, 0, 1], [0, 1, 1], [0, 0, 0]]))
+
+
+@pytest.fixture(scope="module")
+def sphere():
+    res = rust_ext.Resolution("S_2@milnor")
+    res.compute_through_stem(6, 20)
+    return res
+
+
+def test_resolution(sphere):
+    assert sphere.prime == 2
+    assert sphere.number_of_gens_in_bidegree(1, 2) == 1
+    assert sphere.number_of_gens_in_bidegree(4, 4) == 1
+    assert sphere.number_of_gens_in_bidegree(2, 5) == 0
+
+    with pytest.raises(IndexError):
+        sphere.number_of_gens_in_bidegree(100, 200)
+
+    with pytest.raises(ValueError):
+        rust_ext.Resolution("not_a_module")
+
+
+def test_products(sphere):
+    h0 = rust_ext.ResolutionHomomorphism.from_class("h0", sphere, sphere, 1, 1, [1])
+    h0.extend_all()
+    # h0 * h0 = h0^2
+    assert h0.act(1, 1, 0) == [1]
+    # h0 * h1 = 0, and the target bidegree is empty
+    assert h0.act(1, 2, 0) == []
+    # h0 * h0^2 h3 = h0^3 h3
+    assert h0.act(3, 10, 0) == [1]
+
+
+def test_d2(sphere):
+    sec = rust_ext.SecondaryResolution(sphere)
+    sec.extend_all()
+    # d2 h4 = h0 h3^2
+    assert sec.d2(15, 1) == [[1]]
+
+    e3 = sec.e3_page()
+    assert e3.dimension(15, 1) == 1
+    assert e3.page_dimension(15, 1, 3) == 0
@@ -26,6 +26,7 @@ adem_reln_element.innerHTML = katexMathInDelims(adem_reln_element.innerHTML);
 let prime_input = document.getElementById("calculator-prime");
 let generic_input = document.getElementById("calculator-generic");
 let calculator_input = document.getElementById("calculator-input");
+let coproduct_input = document.getElementById("calculator-coproduct");
 
 window.runAdem = function runAdem(){
     let p = Number.parseInt(prime_input.value);
@@ -44,6 +45,7 @@ window.runAdem = function runAdem(){
     worker.postMessage({
         "cmd" : "calculate",
         "basis" : basis,
+        "coproduct" : coproduct_input.checked,
         "prime" : p,
         "input" : calculator_input.value
     });
@@ -25,6 +25,8 @@ function to_latex(p, str) {
     return str.replace(/(?:P|Sq)(\d*)/g, "P^\{$1\}")
               .replace(/P/g, `${P_or_Sq}`)
               .replace(/\*/g, "")
+              .replace(/chi|χ/g, "\\chi")
+              .replace(/⊗/g, "\\otimes")
               .replace(/Q(\d*)/g,"Q_\{$1\}");
 }
 
@@ -41,11 +43,12 @@ message_handlers.calculate = function calculate(m) {
         self.calculators[m.prime].compute_basis(20);
     }
     let result;
+    let operation = m.coproduct ? "coproduct" : "evaluate";
     try {
         if(m.basis === "adem") {
-            result = self.calculators[m.prime].evaluate_adem(m.input);
+            result = self.calculators[m.prime][`${operation}_adem`](m.input);
         } else if(m.basis === "milnor") {
-            result = self.calculators[m.prime].evaluate_milnor(m.input);
+            result = self.calculators[m.prime][`${operation}_milnor`](m.input);
         } else {
             console.log(`Unknown basis ${m.basis}`);
             return;
@@ -55,7 +58,7 @@ message_handlers.calculate = function calculate(m) {
     }
     self.postMessage({
         "cmd" : "result",
-        "latex_input" : to_latex(m.prime, m.input),
+        "latex_input" : m.coproduct ? `\\Delta(${to_latex(m.prime, m.input)})` : to_latex(m.prime, m.input),
         "latex_result" : to_latex(m.prime, result),
         "simple_result" : to_input_form(m.prime, result)
     });
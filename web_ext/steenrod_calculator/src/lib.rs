@@ -1,6 +1,8 @@
 use algebra::steenrod_evaluator::{evaluate_algebra_adem, evaluate_algebra_milnor};
-use algebra::{AdemAlgebra, Algebra, MilnorAlgebra};
+use algebra::{AdemAlgebra, Algebra, HopfAlgebra, MilnorAlgebra};
 use fp::prime::ValidPrime;
+use fp::vector::Slice;
+use std::collections::BTreeMap;
 use std::convert::TryFrom;
 use wasm_bindgen::prelude::*;
 
@@ -36,4 +38,54 @@ impl SteenrodCalculator {
             .map(|(d, v)| self.milnor_algebra.element_to_string(d, v.as_slice()))
             .map_err(|e| JsValue::from(e.to_string()))
     }
+
+    pub fn coproduct_adem(&self, input: &str) -> Result<String, JsValue> {
+        evaluate_algebra_adem(&self.adem_algebra, &self.milnor_algebra, input)
+            .map(|(d, v)| coproduct_to_string(&self.adem_algebra, d, v.as_slice()))
+            .map_err(|e| JsValue::from(e.to_string()))
+    }
+
+    pub fn coproduct_milnor(&self, input: &str) -> Result<String, JsValue> {
+        evaluate_algebra_milnor(&self.adem_algebra, &self.milnor_algebra, input)
+            .map(|(d, v)| coproduct_to_string(&self.milnor_algebra, d, v.as_slice()))
+            .map_err(|e| JsValue::from(e.to_string()))
+    }
+}
+
+fn coproduct_to_string(algebra: &impl HopfAlgebra, degree: i32, element: Slice) -> String {
+    let p = *algebra.prime();
+    let mut terms = BTreeMap::new();
+    for (i, v) in element.iter_nonzero() {
+        for (c, left, right) in algebra.coproduct_basis_element(degree, i) {
+            let entry = terms.entry((left, right)).or_insert(0);
+            *entry = (*entry + c * v) % p;
+        }
+    }
+
+    // The Adem basis element in degree 0 is the empty string
+    let name = |degree, idx| {
+        if degree == 0 {
+            String::from("1")
+        } else {
+            algebra.basis_element_to_string(degree, idx)
+        }
+    };
+    let result = terms
+        .into_iter()
+        .filter(|&(_, c)| c != 0)
+        .map(|(((l_deg, l_idx), (r_deg, r_idx)), c)| {
+            let term = format!("{} ⊗ {}", name(l_deg, l_idx), name(r_deg, r_idx));
+            if c == 1 {
+                term
+            } else {
+                format!("{c} * {term}")
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(" + ");
+    if result.is_empty() {
+        String::from("0")
+    } else {
+        result
+    }
 }